@@ -4,13 +4,24 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
+
+use std::marker::PhantomData;
+
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use dusk_bls12_381::BlsScalar;
 use dusk_jubjub::{JubJubAffine, JubJubScalar, GENERATOR_EXTENDED};
 use dusk_plonk::prelude::Error as PlonkError;
 use dusk_plonk::prelude::*;
-use dusk_poseidon::{decrypt, decrypt_gadget, encrypt};
+use dusk_safe::{Encryption, Safe};
 use ff::Field;
+use zk_dusk::news::{NewableSafe, NewableScalar};
+use zk_dusk::{
+    anemoi, arion, decrypt_gadget_with, decrypt_with, encrypt_with, griffin,
+    hades, rescue,
+};
 use once_cell::sync::Lazy;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
@@ -20,20 +31,25 @@ const MESSAGE_LEN: usize = 2;
 static PUB_PARAMS: Lazy<PublicParameters> = Lazy::new(|| {
     let mut rng = StdRng::seed_from_u64(0xfab);
 
-    const CAPACITY: usize = 11;
+    // Rescue, the largest permutation, takes about 2500 gates
+    const CAPACITY: usize = 13;
     PublicParameters::setup(1 << CAPACITY, &mut rng)
         .expect("Setup of public params should pass")
 });
 static LABEL: &[u8] = b"hash-gadget-tester";
 
 #[derive(Debug)]
-struct DecryptionCircuit {
+struct DecryptionCircuit<P, G, const W: usize> {
     pub cipher: Vec<BlsScalar>,
     pub shared_secret: JubJubAffine,
     pub nonce: BlsScalar,
+    permutation: PhantomData<(P, G)>,
 }
 
-impl DecryptionCircuit {
+impl<P, G, const W: usize> DecryptionCircuit<P, G, W>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
     pub fn random(rng: &mut StdRng) -> Self {
         let mut message = [BlsScalar::zero(); MESSAGE_LEN];
         message
@@ -43,18 +59,19 @@ impl DecryptionCircuit {
             GENERATOR_EXTENDED * &JubJubScalar::random(&mut *rng);
         let shared_secret = shared_secret.into();
         let nonce = BlsScalar::random(&mut *rng);
-        let cipher = encrypt(&message, &shared_secret, &nonce)
+        let cipher = encrypt_with::<P, W>(&message, &shared_secret, &nonce)
             .expect("encryption should not fail");
 
         Self {
             cipher,
             shared_secret,
             nonce,
+            permutation: PhantomData,
         }
     }
 }
 
-impl Default for DecryptionCircuit {
+impl<P, G, const W: usize> Default for DecryptionCircuit<P, G, W> {
     fn default() -> Self {
         let message = [BlsScalar::zero(); MESSAGE_LEN];
         let mut cipher = message.to_vec();
@@ -66,11 +83,16 @@ impl Default for DecryptionCircuit {
             cipher,
             shared_secret,
             nonce,
+            permutation: PhantomData,
         }
     }
 }
 
-impl Circuit for DecryptionCircuit {
+impl<P, G, const W: usize> Circuit for DecryptionCircuit<P, G, W>
+where
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
     fn circuit(&self, composer: &mut Composer) -> Result<(), PlonkError> {
         // append all variables to the circuit
         let mut cipher_wit = Vec::with_capacity(MESSAGE_LEN + 1);
@@ -82,28 +104,38 @@ impl Circuit for DecryptionCircuit {
 
         // decrypt the cipher with the gadget
         let _cipher_result =
-            decrypt_gadget(composer, &cipher_wit, &secret_wit, &nonce_wit)
-                .expect("decryption should pass");
+            decrypt_gadget_with::<G, W>(
+                composer,
+                &cipher_wit,
+                &secret_wit,
+                &nonce_wit,
+            )
+            .expect("decryption should pass");
 
         Ok(())
     }
 }
 
-fn bench_decryption(c: &mut Criterion) {
+fn bench_decryption_with<P, G, const W: usize>(c: &mut Criterion, name: &str)
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
     let mut rng = StdRng::seed_from_u64(0x42424242);
 
     let (prover, verifier) =
-        Compiler::compile::<DecryptionCircuit>(&PUB_PARAMS, LABEL)
+        Compiler::compile::<DecryptionCircuit<P, G, W>>(&PUB_PARAMS, LABEL)
             .expect("compilation should pass");
 
-    let circuit: DecryptionCircuit = DecryptionCircuit::random(&mut rng);
+    let circuit: DecryptionCircuit<P, G, W> = DecryptionCircuit::random(&mut rng);
     let public_inputs = Vec::new();
     let mut proof = Proof::default();
 
     // Benchmark native cipher decryption
-    c.bench_function("decrypt 2 BlsScalar", |b| {
+    c.bench_function(&format!("{} decrypt 2 BlsScalar", name), |b| {
         b.iter(|| {
-            _ = decrypt(
+            _ = decrypt_with::<P, W>(
                 black_box(&circuit.cipher),
                 black_box(&circuit.shared_secret),
                 black_box(&circuit.nonce),
@@ -112,7 +144,7 @@ fn bench_decryption(c: &mut Criterion) {
     });
 
     // Benchmark proof creation
-    c.bench_function("decrypt 2 BlsScalar proof generation", |b| {
+    c.bench_function(&format!("{} decrypt 2 BlsScalar proof generation", name), |b| {
         b.iter(|| {
             (proof, _) = prover
                 .prove(&mut rng, black_box(&circuit))
@@ -121,7 +153,7 @@ fn bench_decryption(c: &mut Criterion) {
     });
 
     // Benchmark proof verification
-    c.bench_function("decrypt 2 BlsScalar proof verification", |b| {
+    c.bench_function(&format!("{} decrypt 2 BlsScalar proof verification", name), |b| {
         b.iter(|| {
             verifier
                 .verify(black_box(&proof), &public_inputs)
@@ -130,9 +162,50 @@ fn bench_decryption(c: &mut Criterion) {
     });
 }
 
+fn bench_decryption_hades(c: &mut Criterion) {
+    bench_decryption_with::<
+        hades::ScalarPermutation,
+        hades::GadgetPermutation,
+        { hades::WIDTH },
+    >(c, "Poseidon");
+}
+
+fn bench_decryption_rescue(c: &mut Criterion) {
+    bench_decryption_with::<
+        rescue::ScalarPermutation,
+        rescue::GadgetPermutation,
+        { rescue::WIDTH },
+    >(c, "Rescue");
+}
+
+fn bench_decryption_griffin(c: &mut Criterion) {
+    bench_decryption_with::<
+        griffin::ScalarPermutation,
+        griffin::GadgetPermutation,
+        { griffin::WIDTH },
+    >(c, "Griffin");
+}
+
+fn bench_decryption_anemoi(c: &mut Criterion) {
+    bench_decryption_with::<
+        anemoi::ScalarPermutation,
+        anemoi::GadgetPermutation,
+        { anemoi::WIDTH },
+    >(c, "Anemoi");
+}
+
+fn bench_decryption_arion(c: &mut Criterion) {
+    bench_decryption_with::<
+        arion::ScalarPermutation,
+        arion::GadgetPermutation,
+        { arion::WIDTH },
+    >(c, "Arion");
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(10);
-    targets = bench_decryption
+    targets = bench_decryption_hades, bench_decryption_rescue, bench_decryption_griffin,
+        bench_decryption_anemoi, bench_decryption_arion
 }
 criterion_main!(benches);
@@ -4,13 +4,23 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
+
+use std::marker::PhantomData;
+
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use dusk_bls12_381::BlsScalar;
 use dusk_jubjub::{JubJubAffine, JubJubScalar, GENERATOR_EXTENDED};
 use dusk_plonk::prelude::Error as PlonkError;
 use dusk_plonk::prelude::*;
-use dusk_poseidon::{encrypt, encrypt_gadget};
+use dusk_safe::{Encryption, Safe};
 use ff::Field;
+use zk_dusk::news::{NewableSafe, NewableScalar};
+use zk_dusk::{
+    anemoi, arion, encrypt_gadget_with, encrypt_with, griffin, hades, rescue,
+};
 use once_cell::sync::Lazy;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
@@ -20,20 +30,25 @@ const MESSAGE_LEN: usize = 2;
 static PUB_PARAMS: Lazy<PublicParameters> = Lazy::new(|| {
     let mut rng = StdRng::seed_from_u64(0xfab);
 
-    const CAPACITY: usize = 11;
+    // Rescue, the largest permutation, takes about 2500 gates
+    const CAPACITY: usize = 13;
     PublicParameters::setup(1 << CAPACITY, &mut rng)
         .expect("Setup of public params should pass")
 });
 static LABEL: &[u8] = b"hash-gadget-tester";
 
 #[derive(Debug)]
-struct EncryptionCircuit {
+struct EncryptionCircuit<P, G, const W: usize> {
     pub message: [BlsScalar; MESSAGE_LEN],
     pub shared_secret: JubJubAffine,
     pub nonce: BlsScalar,
+    permutation: PhantomData<(P, G)>,
 }
 
-impl EncryptionCircuit {
+impl<P, G, const W: usize> EncryptionCircuit<P, G, W>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
     pub fn random(rng: &mut StdRng) -> Self {
         let mut message = [BlsScalar::zero(); MESSAGE_LEN];
         message
@@ -47,11 +62,12 @@ impl EncryptionCircuit {
             message,
             shared_secret: shared_secret.into(),
             nonce,
+            permutation: PhantomData,
         }
     }
 }
 
-impl Default for EncryptionCircuit {
+impl<P, G, const W: usize> Default for EncryptionCircuit<P, G, W> {
     fn default() -> Self {
         let message = [BlsScalar::zero(); MESSAGE_LEN];
         let shared_secret = JubJubAffine::identity();
@@ -61,11 +77,16 @@ impl Default for EncryptionCircuit {
             message,
             shared_secret,
             nonce,
+            permutation: PhantomData,
         }
     }
 }
 
-impl Circuit for EncryptionCircuit {
+impl<P, G, const W: usize> Circuit for EncryptionCircuit<P, G, W>
+where
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
     fn circuit(&self, composer: &mut Composer) -> Result<(), PlonkError> {
         // append all variables to the circuit
         let mut message_wit = [Composer::ZERO; MESSAGE_LEN];
@@ -78,28 +99,38 @@ impl Circuit for EncryptionCircuit {
 
         // encrypt the message with the gadget
         let _cipher_result =
-            encrypt_gadget(composer, &message_wit, &secret_wit, &nonce_wit)
-                .expect("encryption should pass");
+            encrypt_gadget_with::<G, W>(
+                composer,
+                &message_wit,
+                &secret_wit,
+                &nonce_wit,
+            )
+            .expect("encryption should pass");
 
         Ok(())
     }
 }
 
-fn bench_encryption(c: &mut Criterion) {
+fn bench_encryption_with<P, G, const W: usize>(c: &mut Criterion, name: &str)
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
     let mut rng = StdRng::seed_from_u64(0x42424242);
 
     let (prover, verifier) =
-        Compiler::compile::<EncryptionCircuit>(&PUB_PARAMS, LABEL)
+        Compiler::compile::<EncryptionCircuit<P, G, W>>(&PUB_PARAMS, LABEL)
             .expect("compilation should pass");
 
-    let circuit: EncryptionCircuit = EncryptionCircuit::random(&mut rng);
+    let circuit: EncryptionCircuit<P, G, W> = EncryptionCircuit::random(&mut rng);
     let public_inputs = Vec::new();
     let mut proof = Proof::default();
 
     // Benchmark native cipher decryption
-    c.bench_function("encrypt 2 BlsScalar", |b| {
+    c.bench_function(&format!("{} encrypt 2 BlsScalar", name), |b| {
         b.iter(|| {
-            let _ = encrypt(
+            let _ = encrypt_with::<P, W>(
                 black_box(&circuit.message),
                 black_box(&circuit.shared_secret),
                 black_box(&circuit.nonce),
@@ -108,7 +139,7 @@ fn bench_encryption(c: &mut Criterion) {
     });
 
     // Benchmark proof creation
-    c.bench_function("encrypt 2 BlsScalar proof generation", |b| {
+    c.bench_function(&format!("{} encrypt 2 BlsScalar proof generation", name), |b| {
         b.iter(|| {
             (proof, _) = prover
                 .prove(&mut rng, black_box(&circuit))
@@ -117,7 +148,7 @@ fn bench_encryption(c: &mut Criterion) {
     });
 
     // Benchmark proof verification
-    c.bench_function("encrypt 2 BlsScalar proof verification", |b| {
+    c.bench_function(&format!("{} encrypt 2 BlsScalar proof verification", name), |b| {
         b.iter(|| {
             verifier
                 .verify(black_box(&proof), &public_inputs)
@@ -126,9 +157,50 @@ fn bench_encryption(c: &mut Criterion) {
     });
 }
 
+fn bench_encryption_hades(c: &mut Criterion) {
+    bench_encryption_with::<
+        hades::ScalarPermutation,
+        hades::GadgetPermutation,
+        { hades::WIDTH },
+    >(c, "Poseidon");
+}
+
+fn bench_encryption_rescue(c: &mut Criterion) {
+    bench_encryption_with::<
+        rescue::ScalarPermutation,
+        rescue::GadgetPermutation,
+        { rescue::WIDTH },
+    >(c, "Rescue");
+}
+
+fn bench_encryption_griffin(c: &mut Criterion) {
+    bench_encryption_with::<
+        griffin::ScalarPermutation,
+        griffin::GadgetPermutation,
+        { griffin::WIDTH },
+    >(c, "Griffin");
+}
+
+fn bench_encryption_anemoi(c: &mut Criterion) {
+    bench_encryption_with::<
+        anemoi::ScalarPermutation,
+        anemoi::GadgetPermutation,
+        { anemoi::WIDTH },
+    >(c, "Anemoi");
+}
+
+fn bench_encryption_arion(c: &mut Criterion) {
+    bench_encryption_with::<
+        arion::ScalarPermutation,
+        arion::GadgetPermutation,
+        { arion::WIDTH },
+    >(c, "Arion");
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(10);
-    targets = bench_encryption
+    targets = bench_encryption_hades, bench_encryption_rescue, bench_encryption_griffin,
+        bench_encryption_anemoi, bench_encryption_arion
 }
 criterion_main!(benches);
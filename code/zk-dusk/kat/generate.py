@@ -0,0 +1,341 @@
+#!/usr/bin/env python3
+"""Known-answer tests of the zk-dusk permutations over the BLS12-381 scalar field.
+
+The vectors are computed without the Rust sources and, but for Arion, without `assets`: every
+constant is derived again from the constant scripts that produced the assets, which are kept
+with the zk-lib hashes in `../zk-lib/src/hashes`.
+
+- Rescue: the Rescue-Prime permutation, with the round constants, number of rounds and MDS
+  matrix of the `*_bls` functions of `rescue_constants.sage`.
+- Griffin: the constants, matrix and number of rounds of `griffin_constants.sage`, and the
+  permutation of the Griffin paper.
+- Anemoi: `AnemoiPermutation` of the Anemoi authors' `anemoi.sage`, run with zk-lib's
+  `kat/sage_shim.py`. The script does not ship the round function, which follows the paper.
+- Arion: the `ArionHash` class of the Arion authors' `ArionHash.sage`, run with the shim. It
+  draws its constants at random, so they are the only values read from `assets`, as
+  little-endian scalars of 32 bytes like `BlsScalar::from_raw` reads them.
+
+    python3 kat/generate.py
+
+rewrites `kat/<permutation>.json`.
+"""
+
+import contextlib
+import hashlib
+import io
+import json
+import math
+import os
+import random
+import sys
+
+ROOT = os.path.dirname(os.path.abspath(__file__))
+ASSETS = os.path.join(ROOT, "..", "assets")
+ZK_LIB = os.path.join(ROOT, "..", "..", "zk-lib")
+REFERENCES = os.path.join(ZK_LIB, "src", "hashes")
+
+sys.path.insert(0, os.path.join(ZK_LIB, "kat"))
+import sage_shim  # noqa: E402
+
+P = 0x73EDA753299D7D483339D80809A1D80553BDA402FFFE5BFEFFFFFFFF00000001
+SECURITY_LEVEL = 128
+
+
+def inverse_exponent(d):
+    return pow(d, -1, P - 1)
+
+
+def asset(path):
+    """The scalars of the asset `path`."""
+    with open(os.path.join(ASSETS, path), "rb") as f:
+        data = f.read()
+    assert len(data) % 32 == 0, path
+    return [int.from_bytes(data[i:i + 32], "little") for i in range(0, len(data), 32)]
+
+
+def rows(values, length):
+    return [values[i:i + length] for i in range(0, len(values), length)]
+
+
+def mat_vec(matrix, v):
+    return [sum(a * b for a, b in zip(row, v)) % P for row in matrix]
+
+
+def shake_elements(seed, n):
+    """`n` field elements squeezed from SHAKE256, as the constant scripts of Rescue and
+    Griffin do: little-endian integers of one byte more than the modulus, reduced."""
+    bytes_per_int = (P.bit_length() + 7) // 8 + 1
+    stream = hashlib.shake_256(seed.encode()).digest(bytes_per_int * n)
+    return [int.from_bytes(stream[i:i + bytes_per_int], "little") % P
+            for i in range(0, len(stream), bytes_per_int)]
+
+
+# Rescue-Prime
+
+RESCUE_ALPHA = 5
+# The capacity in the seed of the constants of each width
+RESCUE_CAPACITIES = {4: 2, 5: 1, 6: 2, 8: 4}
+
+
+def rescue_rounds(width, capacity):
+    """`get_number_of_rounds_bls` of `rescue_constants.sage`."""
+    rate = width - capacity
+    target = 2 ** SECURITY_LEVEL
+    for rounds in range(1, 25):
+        dcon = math.floor(0.5 * (RESCUE_ALPHA - 1) * width * (rounds - 1) + 2)
+        v = width * (rounds - 1) + rate
+        if math.comb(v + dcon, v) ** 2 > target:
+            break
+    return math.ceil(1.5 * max(5, rounds))
+
+
+def rescue_mds(width):
+    """`get_mds_matrix_bls` of `rescue_constants.sage`: the transpose of the right half of
+    the reduced echelon form of the Vandermonde matrix of 7, the smallest generator."""
+    v = [[pow(7, i * j, P) for j in range(2 * width)] for i in range(width)]
+    for c in range(width):
+        pivot = next(r for r in range(c, width) if v[r][c])
+        v[c], v[pivot] = v[pivot], v[c]
+        inverse = pow(v[c][c], -1, P)
+        v[c] = [x * inverse % P for x in v[c]]
+        for r in range(width):
+            if r != c and v[r][c]:
+                factor = v[r][c]
+                v[r] = [(a - factor * b) % P for a, b in zip(v[r], v[c])]
+    return [[v[j][width + i] for j in range(width)] for i in range(width)]
+
+
+def rescue_permutation(width):
+    capacity = RESCUE_CAPACITIES[width]
+    rounds = rescue_rounds(width, capacity)
+    # `get_round_constants_bls` of `rescue_constants.sage`
+    round_constants = shake_elements(
+        "Rescue -XLIX(%i,%i,%i,%i)" % (P, width, capacity, SECURITY_LEVEL),
+        2 * width * rounds,
+    )
+    mds = rescue_mds(width)
+    alpha_inv = inverse_exponent(RESCUE_ALPHA)
+
+    def permute(state):
+        for r in range(rounds):
+            for half, exponent in [(0, RESCUE_ALPHA), (1, alpha_inv)]:
+                state = [pow(x, exponent, P) for x in state]
+                state = mat_vec(mds, state)
+                offset = (2 * r + half) * width
+                state = [(x + round_constants[offset + i]) % P for i, x in enumerate(state)]
+        return state
+
+    return permute
+
+
+# Griffin
+
+GRIFFIN_D = 5
+# The capacity in the seed of the constants of each width
+GRIFFIN_CAPACITIES = {4: 1, 8: 4}
+
+
+def griffin_rounds(width):
+    """`get_number_of_rounds` of `griffin_constants.sage`."""
+    target = 2 ** (SECURITY_LEVEL // 2)
+    for rounds in range(1, 25):
+        left = math.comb(rounds * (GRIFFIN_D + width) + 1, 1 + width * rounds)
+        right = math.comb(GRIFFIN_D**rounds + 1 + rounds, 1 + rounds)
+        if min(left, right) >= target:
+            break
+    return math.ceil(1.2 * max(6, 1 + rounds))
+
+
+def griffin_matrix(width):
+    """`get_matrix` of `griffin_constants.sage`, for a width multiple of 4: M_4 on the
+    diagonal times circ(2 I_4, I_4, ..., I_4)."""
+    m4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]
+    if width == 4:
+        return m4
+    m1 = [[m4[i % 4][j % 4] if i // 4 == j // 4 else 0 for j in range(width)]
+          for i in range(width)]
+    m2 = [[(2 if i == j else 1) if i % 4 == j % 4 else 0 for j in range(width)]
+          for i in range(width)]
+    return [[sum(m1[i][k] * m2[k][j] for k in range(width)) % P for j in range(width)]
+            for i in range(width)]
+
+
+def griffin_permutation(width):
+    rounds = griffin_rounds(width)
+    # `get_round_constants` of `griffin_constants.sage`
+    elements = shake_elements(
+        "Griffin(%i,%i,%i,%i)" % (P, width, GRIFFIN_CAPACITIES[width], SECURITY_LEVEL),
+        width * (rounds - 1) + 2,
+    )
+    alpha, beta = elements[0], elements[1]
+    alphas = [(i - 1) * alpha % P for i in range(2, width)]
+    betas = [(i - 1) ** 2 * beta % P for i in range(2, width)]
+    round_constants = elements[2:]
+    mds = griffin_matrix(width)
+    d_inv = inverse_exponent(GRIFFIN_D)
+
+    def non_linear(x):
+        y = x[:]
+        y[0] = pow(x[0], d_inv, P)
+        y[1] = pow(x[1], GRIFFIN_D, P)
+        for i in range(2, width):
+            # L_i(y_0, y_1, x_{i-1}) = (i - 1) y_0 + y_1 + x_{i-1}, with 0 in place of x_1
+            z = 0 if i == 2 else x[i - 1]
+            l = ((i - 1) * y[0] + y[1] + z) % P
+            y[i] = x[i] * (l * l + alphas[i - 2] * l + betas[i - 2]) % P
+        return y
+
+    def permute(state):
+        state = mat_vec(mds, state)
+        for r in range(rounds):
+            state = mat_vec(mds, non_linear(state))
+            # No constants in the last round
+            if r < rounds - 1:
+                state = [(x + round_constants[width * r + i]) % P for i, x in enumerate(state)]
+        return state
+
+    return permute
+
+
+# Anemoi
+
+
+def anemoi_reference():
+    """The namespace of `anemoi.sage`, whose `constants` import is `constants.py`, without the
+    call that writes the tables."""
+    directory = os.path.join(REFERENCES, "anemoi", "constanta_gen")
+    names = sage_shim.run(os.path.join(directory, "constants.py"))
+    return sage_shim.run(os.path.join(directory, "anemoi.sage"),
+                         strip=[r"^from constants import \*$", r"^get_constants\(.*\)\s*$"],
+                         names=names)
+
+
+def anemoi_permutation(reference, width):
+    columns = width // 2
+    permutation = reference["AnemoiPermutation"](
+        q=P, alpha=5, n_cols=columns, security_level=SECURITY_LEVEL)
+    rounds = permutation.n_rounds
+    c = [[int(x) for x in row] for row in permutation.C]
+    d = [[int(x) for x in row] for row in permutation.D]
+    mds = [[int(x) for x in row] for row in permutation.mat.rows]
+    g, delta = int(permutation.g), int(permutation.delta)
+    alpha_inv = int(permutation.alpha_inv)
+
+    def linear_layer(x, y):
+        x = mat_vec(mds, x)
+        y = mat_vec(mds, y[1:] + y[:1])
+        # Pseudo-Hadamard transform on each (x, y) pair
+        y = [(a + b) % P for a, b in zip(y, x)]
+        x = [(a + b) % P for a, b in zip(x, y)]
+        return x, y
+
+    def permute(state):
+        x, y = state[:columns], state[columns:]
+        for r in range(rounds):
+            x = [(a + c[r][i]) % P for i, a in enumerate(x)]
+            y = [(a + d[r][i]) % P for i, a in enumerate(y)]
+            x, y = linear_layer(x, y)
+            # Open Flystel
+            for i in range(columns):
+                x[i] = (x[i] - g * y[i] * y[i]) % P
+                y[i] = (y[i] - pow(x[i], alpha_inv, P)) % P
+                x[i] = (x[i] + g * y[i] * y[i] + delta) % P
+        x, y = linear_layer(x, y)
+        return x + y
+
+    return permute
+
+
+# Arion
+
+
+def arion_reference():
+    """The namespace of `ArionHash.sage`, without the instance drawing random constants."""
+    return sage_shim.run(os.path.join(REFERENCES, "arion", "arion-constants", "ArionHash.sage"),
+                         strip=[r"^A = ArionHash\(.*\)\s*$"])
+
+
+def arion_permutation(reference, width):
+    # Drawn at random by the reference, they cannot be derived again
+    constants_g = rows(asset("arion/G_%d.bin" % width), 2)
+    constants_h = asset("arion/H_%d.bin" % width)
+    constants_aff = rows(asset("arion/Affine_%d.bin" % width), width)
+    rounds = len(constants_aff)
+
+    field = sage_shim.GF(P)
+    # The constructor prints the parameters and writes the constants to files. It checks
+    # that every g_i has no root, and derives d_1 = 5 and d_2 = 257
+    with contextlib.redirect_stdout(io.StringIO()), sage_shim.scratch_directory():
+        arion_hash = reference["ArionHash"](
+            field=field,
+            branches=width,
+            rounds=rounds,
+            constants_g=[[field(x) for x in pair] for pair in constants_g],
+            constants_h=[field(x) for x in constants_h],
+            constants_aff=[[field(x) for x in row] for row in constants_aff],
+        )
+    branches = width - 1
+
+    def permute(state):
+        # The permutation inside `ArionHash.hash`
+        state = arion_hash.matrix * sage_shim.vector(field, state)
+        for r in range(rounds):
+            state = arion_hash.round_function(
+                state,
+                arion_hash.constants_g[r * branches:(r + 1) * branches],
+                arion_hash.constants_h[r * branches:(r + 1) * branches],
+                sage_shim.vector(field, arion_hash.constants_aff[r]),
+            )
+        return [int(x) for x in state]
+
+    return permute
+
+
+# Vectors
+
+
+def inputs(rng, width):
+    """The input states of a width: a counter, random scalars, and the largest scalar."""
+    return [
+        list(range(width)),
+        [rng.randrange(P) for _ in range(width)],
+        [P - 1] * width,
+    ]
+
+
+def hex_scalars(scalars):
+    # As `BlsScalar` is displayed
+    return ["0x%064x" % x for x in scalars]
+
+
+def write(name, reference, permutation, widths, rng):
+    vectors = []
+    for width in widths:
+        permute = permutation(width)
+        for state in inputs(rng, width):
+            vectors.append({
+                "width": width,
+                "input": hex_scalars(state),
+                "output": hex_scalars(permute(state)),
+            })
+    content = {"hash": name, "field": "bls12-381", "reference": reference,
+               "vectors": vectors}
+    with open(os.path.join(ROOT, name + ".json"), "w") as f:
+        json.dump(content, f, indent=2)
+        f.write("\n")
+
+
+def main():
+    rng = random.Random(0x6b6174)
+    write("rescue", "rescue_constants.sage", rescue_permutation, [4, 5, 6, 8], rng)
+    write("griffin", "griffin_constants.sage", griffin_permutation, [4, 8], rng)
+    anemoi = anemoi_reference()
+    write("anemoi", "anemoi.sage", lambda width: anemoi_permutation(anemoi, width),
+          [4, 6, 8], rng)
+    arion = arion_reference()
+    write("arion", "ArionHash.sage", lambda width: arion_permutation(arion, width),
+          [4, 5, 6, 8], rng)
+
+
+if __name__ == "__main__":
+    main()
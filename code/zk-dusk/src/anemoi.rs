@@ -1,26 +1,32 @@
-mod mds_matrix;
 mod permutation;
-mod round_constants;
+mod widths;
 
 use dusk_bls12_381::BlsScalar;
 
-use mds_matrix::MDS_MATRIX;
-use round_constants::{C, D};
-
-const NUM_COLUMNS: usize = WIDTH / 2;
-
-// Width = 4 -> Rounds=12
-// Width = 6 -> Rounds=10
-// Width = 8 -> Rounds=10
-const NUMBER_OF_ROUNDS: usize = 10;
-
 #[cfg(feature = "zk")]
 pub use permutation::gadget::GadgetPermutation;
 pub use permutation::scalar::ScalarPermutation;
 
-/// Width of the Anemoi sponge
+/// Default width of the Anemoi sponge
 pub const WIDTH: usize = 8;
 
+/// Parameters of the Anemoi permutation for a state width of `W / 2`
+/// columns, implemented for [`Width`](crate::Width) 4, 6 and 8.
+pub trait AnemoiParameters<const W: usize> {
+    /// Number of rounds: 14, 12 and 12 for `W` of 4, 6 and 8.
+    const NUMBER_OF_ROUNDS: usize;
+
+    /// The `W / 2` constants `C` of each round, round after round.
+    const C: &'static [BlsScalar];
+
+    /// The `W / 2` constants `D` of each round, round after round.
+    const D: &'static [BlsScalar];
+
+    /// The `W / 2 x W / 2` MDS matrix applied to each half of the state, row
+    /// after row.
+    const MDS_MATRIX: &'static [u64];
+}
+
 const ALPHA: u64 = 5;
 
 /// ALPHA_IN of Anemoi
@@ -60,4 +66,37 @@ const fn u64_from_buffer<const N: usize>(buf: &[u8; N], i: usize) -> u64 {
         buf[i + 6],
         buf[i + 7],
     ])
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zk_lib::parameters::anemoi;
+
+    // Bits of the Bls12-381 scalar field and targeted security level
+    const FIELD_BITS: usize = 255;
+    const SECURITY_LEVEL: usize = 128;
+
+    #[test]
+    fn number_of_rounds() {
+        use crate::Width;
+
+        // Every width has the rounds recommended with the security margin of
+        // the paper
+        fn check<const W: usize>()
+        where
+            Width<W>: AnemoiParameters<W>,
+        {
+            let bounds = anemoi::round_bounds(FIELD_BITS, W / 2, ALPHA, SECURITY_LEVEL);
+            assert!(<Width<W>>::NUMBER_OF_ROUNDS >= bounds.minimum());
+            assert_eq!(
+                <Width<W>>::NUMBER_OF_ROUNDS,
+                anemoi::number_of_rounds(FIELD_BITS, W / 2, ALPHA, SECURITY_LEVEL)
+            );
+        }
+
+        check::<4>();
+        check::<6>();
+        check::<8>();
+    }
+}
@@ -1,22 +1,26 @@
-use crate::anemoi::{NUMBER_OF_ROUNDS, WIDTH, NUM_COLUMNS};
+use crate::anemoi::AnemoiParameters;
+use crate::width::Width;
 
 #[cfg(feature = "zk")]
 pub(crate) mod gadget;
 
 pub(crate) mod scalar;
 
-pub(crate) trait Anemoi<T> {
-    fn linear_layer(&mut self, state: &mut[T; WIDTH]);
+pub(crate) trait Anemoi<T, const W: usize>
+where
+    Width<W>: AnemoiParameters<W>,
+{
+    fn linear_layer(&mut self, state: &mut[T; W]);
 
-    fn add_round_constants_leyer(&mut self, state: &mut[T; WIDTH], round: usize, column: usize);
+    fn add_round_constants_leyer(&mut self, state: &mut[T; W], round: usize, column: usize);
 
-    fn evaluate_sbox_layer(&mut self, state: &mut[T; WIDTH]);
+    fn evaluate_sbox_layer(&mut self, state: &mut[T; W]);
     
     fn inverse_sbox(&mut self, value: &mut T);
 
-    fn perm(&mut self, state: &mut[T; WIDTH]) {
-        for round in 0..NUMBER_OF_ROUNDS {
-            for column in 0..NUM_COLUMNS {
+    fn perm(&mut self, state: &mut[T; W]) {
+        for round in 0..<Width<W>>::NUMBER_OF_ROUNDS {
+            for column in 0..W / 2 {
                 self.add_round_constants_leyer(state, round, column);
             }
             self.linear_layer(state);
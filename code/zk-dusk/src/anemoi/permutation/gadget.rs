@@ -3,36 +3,75 @@ use dusk_plonk::prelude::*;
 use dusk_safe::Safe;
 
 use crate::{
-    anemoi::{
-        round_constants::{C, D},
-        ALPHA_INV, BETA, DELTA, MDS_MATRIX, NUM_COLUMNS, WIDTH,
-    },
+    anemoi::{AnemoiParameters, ALPHA_INV, BETA, DELTA, WIDTH},
     news::NewableSafe,
+    width::Width,
 };
 
 use super::Anemoi;
 
-pub struct GadgetPermutation<'a> {
+/// GadgetPermutation of Anemoi over a state of `W` elements
+pub struct GadgetPermutation<'a, const W: usize = WIDTH> {
     composer: &'a mut Composer,
 }
 
-impl<'a> GadgetPermutation<'a> {
+impl<'a, const W: usize> GadgetPermutation<'a, W> {
     /// Constructs a new `GadgetPermutation` with the constraint system.
     pub fn new(composer: &'a mut Composer) -> Self {
         Self { composer }
     }
+
+    /// Constrains `sum_{k} ( coefficients[k] * values[k] )`, with three terms
+    /// in the first gate and two more terms in each following gate.
+    fn linear_combination(
+        &mut self,
+        coefficients: &[u64],
+        values: &[Witness],
+    ) -> Witness {
+        let mut constraint = Constraint::new()
+            .left(coefficients[0])
+            .a(values[0])
+            .right(coefficients[1])
+            .b(values[1]);
+        if values.len() > 2 {
+            constraint = constraint.fourth(coefficients[2]).d(values[2]);
+        }
+
+        let mut result = self.composer.gate_add(constraint);
+
+        for k in (3..values.len()).step_by(2) {
+            let mut constraint = Constraint::new()
+                .left(coefficients[k])
+                .a(values[k])
+                .fourth(1)
+                .d(result);
+            if k + 1 < values.len() {
+                constraint =
+                    constraint.right(coefficients[k + 1]).b(values[k + 1]);
+            }
+
+            result = self.composer.gate_add(constraint);
+        }
+        result
+    }
 }
 
-impl<'a> NewableSafe<WIDTH> for GadgetPermutation<'a> {
-    type T<'b> = GadgetPermutation<'b>;
+impl<'a, const W: usize> NewableSafe<W> for GadgetPermutation<'a, W>
+where
+    Width<W>: AnemoiParameters<W>,
+{
+    type T<'b> = GadgetPermutation<'b, W>;
 
     fn new(composer: &mut Composer) -> Self::T<'_> {
         Self::T::new(composer)
     }
 }
 
-impl<'a> Safe<Witness, WIDTH> for GadgetPermutation<'a> {
-    fn permute(&mut self, state: &mut [Witness; WIDTH]) {
+impl<'a, const W: usize> Safe<Witness, W> for GadgetPermutation<'a, W>
+where
+    Width<W>: AnemoiParameters<W>,
+{
+    fn permute(&mut self, state: &mut [Witness; W]) {
         self.perm(state);
     }
 
@@ -48,228 +87,102 @@ impl<'a> Safe<Witness, WIDTH> for GadgetPermutation<'a> {
     }
 }
 
-impl<'a> Anemoi<Witness> for GadgetPermutation<'a> {
-    fn linear_layer(&mut self, state: &mut [Witness; WIDTH]) {
-        let mut x = [Composer::ZERO; NUM_COLUMNS];
-        x.copy_from_slice(&state[..NUM_COLUMNS]);
-        let mut y = [Composer::ZERO; NUM_COLUMNS];
-        y.copy_from_slice(&state[NUM_COLUMNS..]);
-
-        // mds matrix * x
-        // WIDTH=6
-        if WIDTH == 6 {
-            let constraint = Constraint::new()
-                .left(7)
-                .a(x[0])
-                .right(1)
-                .b(x[1])
-                .fourth(1)
-                .d(x[2]);
-
-            x[2] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(1)
-                .a(x[0])
-                .right(7)
-                .b(x[2])
-                .fourth(1)
-                .d(x[2]);
-
-            x[0] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(1)
-                .a(x[1])
-                .right(1)
-                .b(x[0])
-                .fourth(7)
-                .d(x[2]);
-
-            x[1] = self.composer.gate_add(constraint);
-        }
-
-        // WIDTH=4
-        if WIDTH == 4 {
-            let constraint = Constraint::new().left(1).a(x[0]).right(7).b(x[1]);
-            x[0] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(x[1]).right(7).b(x[0]);
-            x[1] = self.composer.gate_add(constraint);
+impl<'a, const W: usize> Anemoi<Witness, W> for GadgetPermutation<'a, W>
+where
+    Width<W>: AnemoiParameters<W>,
+{
+    fn linear_layer(&mut self, state: &mut [Witness; W]) {
+        let columns = W / 2;
+        let mds = <Width<W>>::MDS_MATRIX;
+
+        // y rotated by one column
+        let mut y_rotated = [Composer::ZERO; W];
+        for i in 0..columns {
+            y_rotated[i] = state[columns + (i + 1) % columns];
         }
 
-        // Width = 8
-        if WIDTH == 8 {
-            let constraint = Constraint::new().left(1).a(x[0]).right(1).b(x[1]);
-            x[0] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(x[2]).right(1).b(x[3]);
-            x[2] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(x[3]).right(7).b(x[0]);
-            x[3] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(7).a(x[1]).right(7).b(x[2]);
-            x[1] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(x[0]).right(1).b(x[1]);
-            x[0] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(x[2]).right(7).b(x[3]);
-            x[2] = self.composer.gate_add(constraint);
+        let mut result = [Composer::ZERO; W];
+        for i in 0..columns {
+            let row = &mds[i * columns..(i + 1) * columns];
 
-            let constraint = Constraint::new().left(1).a(x[1]).right(1).b(x[2]);
-            x[1] = self.composer.gate_add(constraint);
+            // mds matrix * x
+            result[i] = self.linear_combination(row, &state[..columns]);
 
-            let constraint = Constraint::new().left(1).a(x[3]).right(1).b(x[0]);
-            x[3] = self.composer.gate_add(constraint);
+            // mds matrix * y
+            result[columns + i] =
+                self.linear_combination(row, &y_rotated[..columns]);
         }
 
-        // mds matrix * y
-        let mut y_rotated = [Composer::ZERO; NUM_COLUMNS];
-        for i in 0..NUM_COLUMNS {
-            if i != NUM_COLUMNS - 1 {
-                y_rotated[i] = y[i + 1];
-            } else {
-                y_rotated[i] = y[0];
-            }
-        }
-
-        // WIDTH = 6
-        if WIDTH == 6 {
-            let constraint = Constraint::new()
-                .left(7)
-                .a(y_rotated[0])
-                .right(1)
-                .b(y_rotated[1])
-                .fourth(1)
-                .d(y_rotated[2]);
-
-            y_rotated[2] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(1)
-                .a(y_rotated[0])
-                .right(7)
-                .b(y_rotated[2])
-                .fourth(1)
-                .d(y_rotated[2]);
-
-            y_rotated[0] = self.composer.gate_add(constraint);
-
+        // Pseudo-Hadamard transform P
+        for i in 0..columns {
             let constraint = Constraint::new()
                 .left(1)
-                .a(y_rotated[1])
+                .a(result[columns + i])
                 .right(1)
-                .b(y_rotated[0])
-                .fourth(7)
-                .d(y_rotated[2]);
+                .b(result[i]);
 
-            y_rotated[1] = self.composer.gate_add(constraint);
-        }
+            result[columns + i] = self.composer.gate_add(constraint);
 
-        // WIDTH = 4
-        if WIDTH == 4 {
             let constraint = Constraint::new()
                 .left(1)
-                .a(y_rotated[0])
-                .right(7)
-                .b(y_rotated[1]);
-            y_rotated[0] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(1)
-                .a(y_rotated[1])
-                .right(7)
-                .b(y_rotated[0]);
-            y_rotated[1] = self.composer.gate_add(constraint);
-        }
-
-        // Width == 8
-        let constraint = Constraint::new().left(1).a(y_rotated[0]).right(1).b(y_rotated[1]);
-            y_rotated[0] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(y_rotated[2]).right(1).b(y_rotated[3]);
-            y_rotated[2] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(y_rotated[3]).right(7).b(y_rotated[0]);
-            y_rotated[3] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(7).a(y_rotated[1]).right(7).b(y_rotated[2]);
-            y_rotated[1] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(y_rotated[0]).right(1).b(y_rotated[1]);
-            y_rotated[0] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(y_rotated[2]).right(7).b(y_rotated[3]);
-            y_rotated[2] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(y_rotated[1]).right(1).b(y_rotated[2]);
-            y_rotated[1] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(y_rotated[3]).right(1).b(y_rotated[0]);
-            y_rotated[3] = self.composer.gate_add(constraint);
-
-        // Pseudo-Hadamard transform P
-        for i in 0..NUM_COLUMNS {
-            let constraint = Constraint::new().left(1).a(y_rotated[i]).right(1).b(x[i]);
-
-            y_rotated[i] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new().left(1).a(x[i]).right(1).b(y_rotated[i]);
+                .a(result[i])
+                .right(1)
+                .b(result[columns + i]);
 
-            x[i] = self.composer.gate_add(constraint);
+            result[i] = self.composer.gate_add(constraint);
         }
 
-        state[..NUM_COLUMNS].copy_from_slice(&x);
-        state[NUM_COLUMNS..].copy_from_slice(&y_rotated);
+        state.copy_from_slice(&result);
     }
 
     fn add_round_constants_leyer(
         &mut self,
-        state: &mut [Witness; WIDTH],
+        state: &mut [Witness; W],
         round: usize,
         column: usize,
     ) {
+        let columns = W / 2;
+
         let constraint = Constraint::new()
             .left(1)
             .a(state[column])
-            .constant(C[round][column]);
+            .constant(<Width<W>>::C[round * columns + column]);
         state[column] = self.composer.gate_add(constraint);
 
         let constraint = Constraint::new()
             .left(1)
-            .a(state[NUM_COLUMNS + column])
-            .constant(D[round][column]);
-        state[NUM_COLUMNS + column] = self.composer.gate_add(constraint);
+            .a(state[columns + column])
+            .constant(<Width<W>>::D[round * columns + column]);
+        state[columns + column] = self.composer.gate_add(constraint);
     }
 
-    fn evaluate_sbox_layer(&mut self, state: &mut [Witness; WIDTH]) {
-        for i in 0..NUM_COLUMNS {
+    fn evaluate_sbox_layer(&mut self, state: &mut [Witness; W]) {
+        let columns = W / 2;
+        for i in 0..columns {
             // x = x - BETA * y^QUAD
             let constraint = Constraint::new()
                 .mult(BlsScalar::from(BETA).neg())
-                .a(state[NUM_COLUMNS + i])
-                .b(state[NUM_COLUMNS + i])
+                .a(state[columns + i])
+                .b(state[columns + i])
                 .fourth(1)
                 .d(state[i]);
             state[i] = self.composer.gate_add(constraint);
 
             // y = y - x^ALPHA_INV
-            let mut wit = Composer::ZERO;
+            let mut wit = state[i];
             Self::inverse_sbox(self, &mut wit);
             let constraint = Constraint::new()
                 .left(1)
-                .a(state[NUM_COLUMNS + i])
+                .a(state[columns + i])
                 .right(BlsScalar::from(1u64).neg())
                 .b(wit);
-            state[NUM_COLUMNS + i] = self.composer.gate_add(constraint);
+            state[columns + i] = self.composer.gate_add(constraint);
 
             // x = x + BETA * y^QUAD + DELTA
             let constraint = Constraint::new()
                 .mult(1)
-                .a(state[NUM_COLUMNS + i])
-                .b(state[NUM_COLUMNS + i]);
+                .a(state[columns + i])
+                .b(state[columns + i]);
             let exp = self.composer.gate_mul(constraint);
             let constraint = Constraint::new()
                 .left(1)
@@ -286,15 +199,77 @@ impl<'a> Anemoi<Witness> for GadgetPermutation<'a> {
         let tmp = tmp.pow_vartime(&ALPHA_INV);
 
         let wit = self.composer.append_witness(tmp);
-        // y^2
-        let constraint = Constraint::new().mult(1).a(wit).b(wit);
-        let tmp_wit = self.composer.gate_mul(constraint);
-        // y^4
-        let constraint = Constraint::new().mult(1).a(tmp_wit).b(tmp_wit);
-        let tmp_wit = self.composer.gate_mul(constraint);
-        // y^5
-        let constraint = Constraint::new().mult(1).a(tmp_wit).b(wit);
-        *value = self.composer.gate_mul(constraint);
+        assert_root(self.composer, wit, *value);
         *value = wit;
     }
 }
+
+/// Constrains `root^5 == value`, so that the inverse S-box can only be
+/// given the unique root of `value`.
+fn assert_root(composer: &mut Composer, root: Witness, value: Witness) {
+    // y^2
+    let constraint = Constraint::new().mult(1).a(root).b(root);
+    let tmp_wit = composer.gate_mul(constraint);
+    // y^4
+    let constraint = Constraint::new().mult(1).a(tmp_wit).b(tmp_wit);
+    let tmp_wit = composer.gate_mul(constraint);
+    // y^5
+    let constraint = Constraint::new().mult(1).a(tmp_wit).b(root);
+    let tmp_wit = composer.gate_mul(constraint);
+    // y^5 == x
+    composer.assert_equal(tmp_wit, value);
+}
+
+#[cfg(feature = "encryption")]
+impl<const W: usize> dusk_safe::Encryption<Witness, W>
+    for GadgetPermutation<'_, W>
+where
+    Width<W>: AnemoiParameters<W>,
+{
+    fn subtract(&mut self, minuend: &Witness, subtrahend: &Witness) -> Witness {
+        let constraint = Constraint::new()
+            .left(1)
+            .a(*minuend)
+            .right(-BlsScalar::one())
+            .b(*subtrahend);
+        self.composer.gate_add(constraint)
+    }
+
+    fn is_equal(&mut self, lhs: &Witness, rhs: &Witness) -> bool {
+        self.composer.assert_equal(*lhs, *rhs);
+        // for the encryption to work we need to return true here, the proof
+        // creation will fail at a later point if the above assertion isn't met
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anemoi::ScalarPermutation;
+    use crate::kat::gadget::{check_gadget, check_root, RootConstraint};
+
+    const KAT: &str = include_str!("../../../kat/anemoi.json");
+
+    struct InverseSbox;
+
+    impl RootConstraint for InverseSbox {
+        const D: u64 = 5;
+
+        fn assert_root(composer: &mut Composer, root: Witness, value: Witness) {
+            super::assert_root(composer, root, value);
+        }
+    }
+
+    #[test]
+    fn gadget_kat() {
+        check_gadget::<ScalarPermutation<4>, GadgetPermutation<4>, 4>(KAT, "anemoi");
+        check_gadget::<ScalarPermutation<6>, GadgetPermutation<6>, 6>(KAT, "anemoi");
+        check_gadget::<ScalarPermutation<8>, GadgetPermutation<8>, 8>(KAT, "anemoi");
+    }
+
+    #[test]
+    fn forged_root() {
+        check_root::<InverseSbox>("anemoi");
+    }
+}
@@ -1,26 +1,30 @@
 use dusk_bls12_381::BlsScalar;
-use dusk_plonk::prelude::Constraint;
 use dusk_safe::Safe;
 
 use super::Anemoi;
 
 use crate::{
-    anemoi::{ALPHA_INV, BETA, C, D, DELTA, MDS_MATRIX, NUM_COLUMNS, WIDTH},
+    anemoi::{AnemoiParameters, ALPHA_INV, BETA, DELTA, WIDTH},
     news::NewableScalar,
+    width::Width,
 };
 
+/// ScalarPermutation of Anemoi over a state of `W` elements
 #[derive(Default)]
-pub struct ScalarPermutation();
+pub struct ScalarPermutation<const W: usize = WIDTH>();
 
-impl NewableScalar for ScalarPermutation {
+impl<const W: usize> NewableScalar for ScalarPermutation<W> {
     /// Constructs a new `ScalarPermutation`.
     fn new() -> Self {
         Self()
     }
 }
 
-impl Safe<BlsScalar, WIDTH> for ScalarPermutation {
-    fn permute(&mut self, state: &mut [BlsScalar; WIDTH]) {
+impl<const W: usize> Safe<BlsScalar, W> for ScalarPermutation<W>
+where
+    Width<W>: AnemoiParameters<W>,
+{
+    fn permute(&mut self, state: &mut [BlsScalar; W]) {
         self.perm(state);
     }
 
@@ -33,108 +37,62 @@ impl Safe<BlsScalar, WIDTH> for ScalarPermutation {
     }
 }
 
-impl Anemoi<BlsScalar> for ScalarPermutation {
-    fn linear_layer(&mut self, state: &mut [BlsScalar; WIDTH]) {
-        let mut x = [BlsScalar::zero(); NUM_COLUMNS];
-        x.copy_from_slice(&state[..NUM_COLUMNS]);
-        let mut y = [BlsScalar::zero(); NUM_COLUMNS];
-        y.copy_from_slice(&state[NUM_COLUMNS..]);
-
-        // mds matrix * x
-
-        // WIDTH = 6
-        if WIDTH == 6 {
-            x[2] = x[2] + x[1] + BlsScalar::from(7) * x[0];
-            x[0] = x[0] + BlsScalar::from(7) * x[2] + x[2];
-            x[1] = x[1] + x[0] + BlsScalar::from(7) * x[2];
-        }
-
-        // WIDTH = 4
-        if WIDTH == 4 {
-            x[0] = x[0] + BlsScalar::from(7) * x[1];
-            x[1] = x[1] + BlsScalar::from(7) * x[0];
-        }
-
-        // WIDTH == 8
-        if WIDTH == 8 {
-            x[0] += x[1];
-            x[2] += x[3];
-            x[3] += BlsScalar::from(7) * x[0];
-            x[1] = BlsScalar::from(7) * (x[1] + x[2]);
-            x[0] += x[1];
-            x[2] += BlsScalar::from(7) * x[3];
-            x[1] += x[2];
-            x[3] += x[0];
-        }
-
-        // mds matrix * y
-        let mut y_rotated = [BlsScalar::zero(); NUM_COLUMNS];
-        for i in 0..NUM_COLUMNS {
-            if i != NUM_COLUMNS - 1 {
-                y_rotated[i] = y[i + 1];
-            } else {
-                y_rotated[i] = y[0];
+impl<const W: usize> Anemoi<BlsScalar, W> for ScalarPermutation<W>
+where
+    Width<W>: AnemoiParameters<W>,
+{
+    fn linear_layer(&mut self, state: &mut [BlsScalar; W]) {
+        let columns = W / 2;
+        let mds = <Width<W>>::MDS_MATRIX;
+
+        let mut result = [BlsScalar::zero(); W];
+        for i in 0..columns {
+            for j in 0..columns {
+                let coefficient = BlsScalar::from(mds[i * columns + j]);
+
+                // mds matrix * x
+                result[i] += coefficient * state[j];
+
+                // mds matrix * y, with y rotated by one column
+                result[columns + i] +=
+                    coefficient * state[columns + (j + 1) % columns];
             }
         }
 
-        // WIDTH = 6
-        if WIDTH == 6 {
-            y_rotated[2] = y_rotated[2] + y_rotated[1] + BlsScalar::from(7) * y_rotated[0];
-            y_rotated[0] = y_rotated[0] + BlsScalar::from(7) * y_rotated[2] + y_rotated[2];
-            y_rotated[1] = y_rotated[1] + y_rotated[0] + BlsScalar::from(7) * y_rotated[2];
-        }
-
-        // WIDTH = 4
-        if WIDTH == 4 {
-            y_rotated[0] = y_rotated[0] + BlsScalar::from(7) * y_rotated[1];
-            y_rotated[1] = y_rotated[1] + BlsScalar::from(7) * y_rotated[0];
-        }
-
-        // WIDTH ==8
-        if WIDTH == 8 {
-            y_rotated[0] += y_rotated[1];
-            y_rotated[2] += y_rotated[3];
-            y_rotated[3] += BlsScalar::from(7) * y_rotated[0];
-            y_rotated[1] = BlsScalar::from(7) * (y_rotated[1] + y_rotated[2]);
-            y_rotated[0] += y_rotated[1];
-            y_rotated[2] += BlsScalar::from(7) * y_rotated[3];
-            y_rotated[1] += y_rotated[2];
-            y_rotated[3] += y_rotated[0];
-        }
-
         // Pseudo-Hadamard transform P
-        for i in 0..NUM_COLUMNS {
-            y_rotated[i] += x[i];
-            x[i] += y_rotated[i];
+        for i in 0..columns {
+            result[columns + i] += result[i];
+            result[i] += result[columns + i];
         }
 
-        state[..NUM_COLUMNS].copy_from_slice(&x);
-        state[NUM_COLUMNS..].copy_from_slice(&y_rotated);
+        state.copy_from_slice(&result);
     }
 
     fn add_round_constants_leyer(
         &mut self,
-        state: &mut [BlsScalar; WIDTH],
+        state: &mut [BlsScalar; W],
         round: usize,
         column: usize,
     ) {
-        state[column] += C[round][column];
-        state[NUM_COLUMNS + column] += D[round][column];
+        let columns = W / 2;
+        state[column] += <Width<W>>::C[round * columns + column];
+        state[columns + column] += <Width<W>>::D[round * columns + column];
     }
 
-    fn evaluate_sbox_layer(&mut self, state: &mut [BlsScalar; WIDTH]) {
-        for i in 0..NUM_COLUMNS {
+    fn evaluate_sbox_layer(&mut self, state: &mut [BlsScalar; W]) {
+        let columns = W / 2;
+        for i in 0..columns {
             // x = x - BETA * y^QUAD
-            state[i] -= BlsScalar::from(BETA) * state[NUM_COLUMNS + i] * state[NUM_COLUMNS + i];
+            state[i] -= BlsScalar::from(BETA) * state[columns + i] * state[columns + i];
 
             // y = y - x^ALPHA_INV
-            let mut exp = BlsScalar::default();
+            let mut exp = state[i];
             Self::inverse_sbox(self, &mut exp);
-            state[NUM_COLUMNS + i] -= exp;
+            state[columns + i] -= exp;
 
             // x = x + BETA * y^QUAD + DELTA
             state[i] +=
-                BlsScalar::from(BETA) * state[NUM_COLUMNS + i] * state[NUM_COLUMNS + i] + DELTA;
+                BlsScalar::from(BETA) * state[columns + i] * state[columns + i] + DELTA;
         }
     }
 
@@ -142,3 +100,37 @@ impl Anemoi<BlsScalar> for ScalarPermutation {
         *value = value.pow(&ALPHA_INV);
     }
 }
+
+#[cfg(feature = "encryption")]
+impl<const W: usize> dusk_safe::Encryption<BlsScalar, W>
+    for ScalarPermutation<W>
+where
+    Width<W>: AnemoiParameters<W>,
+{
+    fn subtract(
+        &mut self,
+        minuend: &BlsScalar,
+        subtrahend: &BlsScalar,
+    ) -> BlsScalar {
+        minuend - subtrahend
+    }
+
+    fn is_equal(&mut self, lhs: &BlsScalar, rhs: &BlsScalar) -> bool {
+        lhs == rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kat::check_scalar;
+
+    const KAT: &str = include_str!("../../../kat/anemoi.json");
+
+    #[test]
+    fn kat() {
+        check_scalar::<ScalarPermutation<4>, 4>(KAT, "anemoi");
+        check_scalar::<ScalarPermutation<6>, 6>(KAT, "anemoi");
+        check_scalar::<ScalarPermutation<8>, 8>(KAT, "anemoi");
+    }
+}
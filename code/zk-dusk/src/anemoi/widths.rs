@@ -0,0 +1,63 @@
+//! Anemoi constants of each width, loaded from
+//! `assets/anemoi/{c,d}_{W}.bin`.
+//!
+//! The MDS matrices are the ones of the reference for 2, 3 and 4 columns.
+
+use dusk_bls12_381::BlsScalar;
+
+use super::AnemoiParameters;
+use crate::width::{scalars, Width};
+
+impl AnemoiParameters<4> for Width<4> {
+    const NUMBER_OF_ROUNDS: usize = 14;
+    const C: &'static [BlsScalar] =
+        &scalars::<28>(include_bytes!("../../assets/anemoi/c_4.bin"));
+    const D: &'static [BlsScalar] =
+        &scalars::<28>(include_bytes!("../../assets/anemoi/d_4.bin"));
+    const MDS_MATRIX: &'static [u64] = &[1, 7, 7, 50];
+}
+
+impl AnemoiParameters<6> for Width<6> {
+    const NUMBER_OF_ROUNDS: usize = 12;
+    const C: &'static [BlsScalar] =
+        &scalars::<36>(include_bytes!("../../assets/anemoi/c_6.bin"));
+    const D: &'static [BlsScalar] =
+        &scalars::<36>(include_bytes!("../../assets/anemoi/d_6.bin"));
+    const MDS_MATRIX: &'static [u64] = &[8, 1, 8, 1, 1, 7, 7, 1, 1];
+}
+
+impl AnemoiParameters<8> for Width<8> {
+    const NUMBER_OF_ROUNDS: usize = 12;
+    const C: &'static [BlsScalar] =
+        &scalars::<48>(include_bytes!("../../assets/anemoi/c_8.bin"));
+    const D: &'static [BlsScalar] =
+        &scalars::<48>(include_bytes!("../../assets/anemoi/d_8.bin"));
+    const MDS_MATRIX: &'static [u64] = &[
+        1, 8, 7, 7, //
+        49, 56, 8, 15, //
+        49, 49, 1, 8, //
+        8, 15, 7, 8,
+    ];
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check_constants<const W: usize>()
+    where
+        Width<W>: AnemoiParameters<W>,
+    {
+        let columns = W / 2;
+        assert_eq!(<Width<W>>::C.len(), columns * <Width<W>>::NUMBER_OF_ROUNDS);
+        assert_eq!(<Width<W>>::D.len(), columns * <Width<W>>::NUMBER_OF_ROUNDS);
+        assert_eq!(<Width<W>>::MDS_MATRIX.len(), columns * columns);
+    }
+
+    #[test]
+    fn test_constants() {
+        check_constants::<4>();
+        check_constants::<6>();
+        check_constants::<8>();
+    }
+}
@@ -1,17 +1,30 @@
-mod mds_matrix;
 mod permutation;
-mod round_constants;
+mod widths;
 
-use round_constants::{H, G, AFFINE};
+use dusk_bls12_381::BlsScalar;
 
-// Width = 4 -> Rounds = 5
-// Width = 5 -> Rounds = 5
-// Width = 6 -> Rounds = 5
-const NUMBER_OF_ROUNDS: usize = 4;
-
-/// Width of the Arion sponge
+/// Default width of the Arion sponge
 pub const WIDTH: usize = 8;
 
+/// Parameters of the Arion permutation for a state width, implemented for
+/// [`Width`](crate::Width) 4, 5, 6 and 8.
+pub trait ArionParameters<const W: usize> {
+    /// Number of rounds: 5 for `W` of 4, 5 and 6, and 4 for `W` of 8.
+    const NUMBER_OF_ROUNDS: usize;
+
+    /// The `2 * (W - 1)` coefficients of the polynomials `g` of each round,
+    /// round after round.
+    const G: &'static [BlsScalar];
+
+    /// The `W - 1` coefficients of the polynomials `h` of each round, round
+    /// after round.
+    const H: &'static [BlsScalar];
+
+    /// The `W` constants of the affine layer of each round, round after
+    /// round.
+    const AFFINE: &'static [BlsScalar];
+}
+
 /// D_1 of Arion
 pub const D_1: u64 = 5;
 
@@ -45,4 +58,32 @@ const fn u64_from_buffer<const N: usize>(buf: &[u8; N], i: usize) -> u64 {
         buf[i + 6],
         buf[i + 7],
     ])
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zk_lib::parameters::arion;
+
+    // Bits of the Bls12-381 scalar field and targeted security level
+    const FIELD_BITS: usize = 255;
+    const SECURITY_LEVEL: usize = 128;
+
+    #[test]
+    fn number_of_rounds() {
+        use crate::Width;
+
+        fn check<const W: usize>()
+        where
+            Width<W>: ArionParameters<W>,
+        {
+            let bounds = arion::round_bounds(FIELD_BITS, W, D_1, D_2, SECURITY_LEVEL);
+            assert!(<Width<W>>::NUMBER_OF_ROUNDS >= bounds.minimum());
+        }
+
+        check::<4>();
+        check::<5>();
+        check::<6>();
+        check::<8>();
+    }
+}
@@ -1,8 +0,0 @@
-#[allow(dead_code)]
-pub const MATRIX: [[u64; 5]; 5] = [
-    [1, 2, 3, 4, 5],
-    [5, 1, 2, 3, 4],
-    [4, 5, 1, 2, 3],
-    [3, 4, 5, 1, 2],
-    [2, 3, 4, 5, 1],
-];
\ No newline at end of file
@@ -1,4 +1,5 @@
-use crate::arion::{NUMBER_OF_ROUNDS, WIDTH, H, G, AFFINE};
+use crate::arion::ArionParameters;
+use crate::width::Width;
 
 use dusk_bls12_381::BlsScalar;
 
@@ -7,23 +8,33 @@ pub(crate) mod gadget;
 
 pub(crate) mod scalar;
 
-pub(crate) trait Arion<T> {
-    fn linear_layer(&mut self, state: &mut[T; WIDTH]);
+pub(crate) trait Arion<T, const W: usize>
+where
+    Width<W>: ArionParameters<W>,
+{
+    fn linear_layer(&mut self, state: &mut[T; W]);
     
-    fn affine_layer(&mut self, state: &mut[T; WIDTH], constants_aff: &[BlsScalar; WIDTH]);
+    fn affine_layer(&mut self, state: &mut[T; W], constants_aff: &[BlsScalar]);
 
-    fn gtds(&mut self, state: &mut[T; WIDTH], constants_g: &[BlsScalar; 2 * (WIDTH - 1)], constants_h: &[BlsScalar; WIDTH - 1]);
+    fn gtds(&mut self, state: &mut[T; W], constants_g: &[BlsScalar], constants_h: &[BlsScalar]);
 
     fn inverse_sbox(&mut self, state: &mut T);
 
     fn sbox_layer(&mut self, value: &mut T);
 
-    fn perm(&mut self, state: &mut[T; WIDTH]) {
+    fn perm(&mut self, state: &mut[T; W]) {
         self.linear_layer(state);
-        self.affine_layer(state, &[BlsScalar::zero(); WIDTH]);
-        for round in 0..NUMBER_OF_ROUNDS {
-            self.gtds(state, &G[round], &H[round]);
-            self.affine_layer(state, &AFFINE[round]);
+        for round in 0..<Width<W>>::NUMBER_OF_ROUNDS {
+            let g = 2 * (W - 1) * round;
+            let h = (W - 1) * round;
+            let affine = W * round;
+
+            self.gtds(
+                state,
+                &<Width<W>>::G[g..g + 2 * (W - 1)],
+                &<Width<W>>::H[h..h + W - 1],
+            );
+            self.affine_layer(state, &<Width<W>>::AFFINE[affine..affine + W]);
         }
     }
 }
\ No newline at end of file
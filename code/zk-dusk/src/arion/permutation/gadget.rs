@@ -3,34 +3,41 @@ use dusk_plonk::prelude::*;
 use dusk_safe::Safe;
 
 use crate::{
-    arion::{E_2, WIDTH},
+    arion::{ArionParameters, E_2, WIDTH},
     news::NewableSafe,
+    width::Width,
 };
 
 use super::Arion;
 
-/// GadgetPermutation of Arion
-pub struct GadgetPermutation<'a> {
+/// GadgetPermutation of Arion over a state of `W` elements
+pub struct GadgetPermutation<'a, const W: usize = WIDTH> {
     composer: &'a mut Composer,
 }
 
-impl<'a> GadgetPermutation<'a> {
+impl<'a, const W: usize> GadgetPermutation<'a, W> {
     /// Constructs a new `GadgetPermutation` with the constraint system.
     pub fn new(composer: &'a mut Composer) -> Self {
         Self { composer }
     }
 }
 
-impl<'a> NewableSafe<WIDTH> for GadgetPermutation<'a> {
-    type T<'b> = GadgetPermutation<'b>;
+impl<'a, const W: usize> NewableSafe<W> for GadgetPermutation<'a, W>
+where
+    Width<W>: ArionParameters<W>,
+{
+    type T<'b> = GadgetPermutation<'b, W>;
 
     fn new(composer: &mut Composer) -> Self::T<'_> {
         Self::T::new(composer)
     }
 }
 
-impl<'a> Safe<Witness, WIDTH> for GadgetPermutation<'a> {
-    fn permute(&mut self, state: &mut [Witness; WIDTH]) {
+impl<'a, const W: usize> Safe<Witness, W> for GadgetPermutation<'a, W>
+where
+    Width<W>: ArionParameters<W>,
+{
+    fn permute(&mut self, state: &mut [Witness; W]) {
         self.perm(state);
     }
 
@@ -46,9 +53,12 @@ impl<'a> Safe<Witness, WIDTH> for GadgetPermutation<'a> {
     }
 }
 
-impl<'a> Arion<Witness> for GadgetPermutation<'a> {
-    fn linear_layer(&mut self, state: &mut [Witness; WIDTH]) {
-        let mut w = [Composer::ZERO; WIDTH];
+impl<'a, const W: usize> Arion<Witness, W> for GadgetPermutation<'a, W>
+where
+    Width<W>: ArionParameters<W>,
+{
+    fn linear_layer(&mut self, state: &mut [Witness; W]) {
+        let mut w = [Composer::ZERO; W];
         let mut sigma = Composer::ZERO;
         for val in &mut *state {
             let constraint = Constraint::new().left(1).a(sigma).right(1).b(*val);
@@ -56,7 +66,7 @@ impl<'a> Arion<Witness> for GadgetPermutation<'a> {
         }
 
         let mut sum = Composer::ZERO;
-        for i in 0..WIDTH {
+        for i in 0..W {
             let constraint = Constraint::new()
                 .left(BlsScalar::from(i as u64))
                 .a(state[i])
@@ -68,14 +78,14 @@ impl<'a> Arion<Witness> for GadgetPermutation<'a> {
         w[0] = self.composer.gate_add(constraint);
 
         let mut i = 1;
-        // w[i] = w[i-1] - sigma + WIDTH * state[i-1]
-        while i < WIDTH {
+        // w[i] = w[i-1] - sigma + W * state[i-1]
+        while i < W {
             let constraint = Constraint::new()
                 .left(1)
                 .a(w[i - 1])
                 .right(BlsScalar::from(1).neg())
                 .b(sigma)
-                .fourth(BlsScalar::from(WIDTH as u64))
+                .fourth(BlsScalar::from(W as u64))
                 .d(state[i - 1]);
 
             w[i] = self.composer.gate_add(constraint);
@@ -84,10 +94,10 @@ impl<'a> Arion<Witness> for GadgetPermutation<'a> {
         state.copy_from_slice(&w);
     }
 
-    fn affine_layer(&mut self, state: &mut [Witness; WIDTH], constants_aff: &[BlsScalar; WIDTH]) {
+    fn affine_layer(&mut self, state: &mut [Witness; W], constants_aff: &[BlsScalar]) {
         self.linear_layer(state);
-        let mut inner = [Composer::ZERO; WIDTH];
-        for i in 0..WIDTH {
+        let mut inner = [Composer::ZERO; W];
+        for i in 0..W {
             let constraint = Constraint::new()
                 .left(1)
                 .a(inner[i])
@@ -101,26 +111,27 @@ impl<'a> Arion<Witness> for GadgetPermutation<'a> {
 
     fn gtds(
         &mut self,
-        state: &mut [Witness; WIDTH],
-        constants_g: &[BlsScalar; 2 * (WIDTH - 1)],
-        constants_h: &[BlsScalar; WIDTH - 1],
+        state: &mut [Witness; W],
+        constants_g: &[BlsScalar],
+        constants_h: &[BlsScalar],
     ) {
-        let mut output = [Composer::ZERO; WIDTH];
+        let mut output = [Composer::ZERO; W];
         output.copy_from_slice(state);
 
-        Self::inverse_sbox(self, &mut output[WIDTH - 1]);
+        Self::inverse_sbox(self, &mut output[W - 1]);
 
-        let mut sigma = state[WIDTH - 1].clone();
+        let mut sigma = state[W - 1].clone();
         let constraint = Constraint::new()
             .left(1)
             .a(sigma)
             .right(1)
-            .b(output[WIDTH - 1]);
+            .b(output[W - 1]);
         sigma = self.composer.gate_add(constraint);
 
-        let mut j = 0;
-        let mut pos = 20;
-        for i in (0..(WIDTH - 1)).rev() {
+        for i in (0..(W - 1)).rev() {
+            // The coefficients of g are stored in pairs, one pair per element
+            let j = 2 * i;
+
             Self::sbox_layer(self, &mut output[i]);
 
             // Evaluate g
@@ -159,11 +170,6 @@ impl<'a> Arion<Witness> for GadgetPermutation<'a> {
                 .fourth(1)
                 .d(state[i]);
             sigma = self.composer.gate_add(constraint);
-
-            if pos > 0 {
-                pos -= 2;
-                j += 2;
-            }
         }
         state.copy_from_slice(&output);
     }
@@ -173,15 +179,7 @@ impl<'a> Arion<Witness> for GadgetPermutation<'a> {
         let tmp = tmp.pow_vartime(&E_2);
 
         let wit = self.composer.append_witness(tmp);
-        // y^2
-        let constraint = Constraint::new().mult(1).a(wit).b(wit);
-        let tmp_wit = self.composer.gate_mul(constraint);
-        // y^4
-        let constraint = Constraint::new().mult(1).a(tmp_wit).b(tmp_wit);
-        let tmp_wit = self.composer.gate_mul(constraint);
-        // y^5
-        let constraint = Constraint::new().mult(1).a(tmp_wit).b(wit);
-        *value = self.composer.gate_mul(constraint);
+        assert_root(self.composer, wit, *value);
         *value = wit;
     }
 
@@ -196,3 +194,74 @@ impl<'a> Arion<Witness> for GadgetPermutation<'a> {
         *value = self.composer.gate_mul(constraint);
     }
 }
+
+/// Constrains `root^257 == value`, so that the inverse S-box can only be
+/// given the unique root of `value`.
+fn assert_root(composer: &mut Composer, root: Witness, value: Witness) {
+    // y^256, with eight squarings
+    let mut tmp_wit = root;
+    for _ in 0..8 {
+        let constraint = Constraint::new().mult(1).a(tmp_wit).b(tmp_wit);
+        tmp_wit = composer.gate_mul(constraint);
+    }
+    // y^257
+    let constraint = Constraint::new().mult(1).a(tmp_wit).b(root);
+    let tmp_wit = composer.gate_mul(constraint);
+    // y^257 == x
+    composer.assert_equal(tmp_wit, value);
+}
+
+#[cfg(feature = "encryption")]
+impl<const W: usize> dusk_safe::Encryption<Witness, W>
+    for GadgetPermutation<'_, W>
+where
+    Width<W>: ArionParameters<W>,
+{
+    fn subtract(&mut self, minuend: &Witness, subtrahend: &Witness) -> Witness {
+        let constraint = Constraint::new()
+            .left(1)
+            .a(*minuend)
+            .right(-BlsScalar::one())
+            .b(*subtrahend);
+        self.composer.gate_add(constraint)
+    }
+
+    fn is_equal(&mut self, lhs: &Witness, rhs: &Witness) -> bool {
+        self.composer.assert_equal(*lhs, *rhs);
+        // for the encryption to work we need to return true here, the proof
+        // creation will fail at a later point if the above assertion isn't met
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arion::{ScalarPermutation, D_2};
+    use crate::kat::gadget::{check_gadget, check_root, RootConstraint};
+
+    const KAT: &str = include_str!("../../../kat/arion.json");
+
+    struct InverseSbox;
+
+    impl RootConstraint for InverseSbox {
+        const D: u64 = D_2;
+
+        fn assert_root(composer: &mut Composer, root: Witness, value: Witness) {
+            super::assert_root(composer, root, value);
+        }
+    }
+
+    #[test]
+    fn gadget_kat() {
+        check_gadget::<ScalarPermutation<4>, GadgetPermutation<4>, 4>(KAT, "arion");
+        check_gadget::<ScalarPermutation<5>, GadgetPermutation<5>, 5>(KAT, "arion");
+        check_gadget::<ScalarPermutation<6>, GadgetPermutation<6>, 6>(KAT, "arion");
+        check_gadget::<ScalarPermutation<8>, GadgetPermutation<8>, 8>(KAT, "arion");
+    }
+
+    #[test]
+    fn forged_root() {
+        check_root::<InverseSbox>("arion");
+    }
+}
@@ -3,23 +3,28 @@ use dusk_safe::Safe;
 
 use super::Arion;
 use crate::{
-    arion::{E_2, WIDTH},
+    arion::{ArionParameters, E_2, WIDTH},
     news::NewableScalar,
+    width::Width,
 };
 
-/// An implementation of the [`Permutation`] for `BlsScalar` as input values.
+/// An implementation of the [`Permutation`] for `BlsScalar` as input values,
+/// over a state of `W` elements.
 #[derive(Default)]
-pub struct ScalarPermutation();
+pub struct ScalarPermutation<const W: usize = WIDTH>();
 
-impl NewableScalar for ScalarPermutation {
+impl<const W: usize> NewableScalar for ScalarPermutation<W> {
     /// Constructs a new `ScalarPermutation`.
     fn new() -> Self {
         Self()
     }
 }
 
-impl Safe<BlsScalar, WIDTH> for ScalarPermutation {
-    fn permute(&mut self, state: &mut [BlsScalar; WIDTH]) {
+impl<const W: usize> Safe<BlsScalar, W> for ScalarPermutation<W>
+where
+    Width<W>: ArionParameters<W>,
+{
+    fn permute(&mut self, state: &mut [BlsScalar; W]) {
         self.perm(state);
     }
 
@@ -32,33 +37,36 @@ impl Safe<BlsScalar, WIDTH> for ScalarPermutation {
     }
 }
 
-impl Arion<BlsScalar> for ScalarPermutation {
-    fn linear_layer(&mut self, state: &mut [BlsScalar; WIDTH]) {
-        let mut w = [BlsScalar::zero(); WIDTH];
+impl<const W: usize> Arion<BlsScalar, W> for ScalarPermutation<W>
+where
+    Width<W>: ArionParameters<W>,
+{
+    fn linear_layer(&mut self, state: &mut [BlsScalar; W]) {
+        let mut w = [BlsScalar::zero(); W];
         let mut sigma = BlsScalar::zero();
         for val in &mut *state {
             sigma += *val;
         }
 
         let mut sum = BlsScalar::zero();
-        for i in 0..WIDTH {
+        for i in 0..W {
             sum += BlsScalar::from(i as u64) * state[i];
         }
 
         w[0] = sigma + sum;
 
         let mut i = 1;
-        while i < WIDTH {
-            w[i] = w[i - 1] - sigma + BlsScalar::from(WIDTH as u64) * state[i - 1];
+        while i < W {
+            w[i] = w[i - 1] - sigma + BlsScalar::from(W as u64) * state[i - 1];
             i += 1;
         }
         state.copy_from_slice(&w);
     }
 
-    fn affine_layer(&mut self, state: &mut [BlsScalar; WIDTH], constants_aff: &[BlsScalar; WIDTH]) {
+    fn affine_layer(&mut self, state: &mut [BlsScalar; W], constants_aff: &[BlsScalar]) {
         Self::linear_layer(self, state);
-        let mut inner = [BlsScalar::zero(); WIDTH];
-        for i in 0..WIDTH {
+        let mut inner = [BlsScalar::zero(); W];
+        for i in 0..W {
             inner[i] += state[i] + constants_aff[i];
         }
         state.copy_from_slice(&inner);
@@ -66,21 +74,22 @@ impl Arion<BlsScalar> for ScalarPermutation {
 
     fn gtds(
         &mut self,
-        state: &mut [BlsScalar; WIDTH],
-        constants_g: &[BlsScalar; 2 * (WIDTH - 1)],
-        constants_h: &[BlsScalar; WIDTH - 1],
+        state: &mut [BlsScalar; W],
+        constants_g: &[BlsScalar],
+        constants_h: &[BlsScalar],
     ) {
-        let mut output = [BlsScalar::zero(); WIDTH];
+        let mut output = [BlsScalar::zero(); W];
         output.copy_from_slice(state);
 
-        Self::inverse_sbox(self, &mut output[WIDTH - 1]);
+        Self::inverse_sbox(self, &mut output[W - 1]);
 
-        let mut sigma = state[WIDTH - 1].clone();
-        sigma += output[WIDTH - 1];
+        let mut sigma = state[W - 1].clone();
+        sigma += output[W - 1];
+
+        for i in (0..(W - 1)).rev() {
+            // The coefficients of g are stored in pairs, one pair per element
+            let j = 2 * i;
 
-        let mut j = 0;
-        let mut pos = 20;
-        for i in (0..(WIDTH - 1)).rev() {
             Self::sbox_layer(self, &mut output[i]);
 
             // Evaluate g and h
@@ -91,11 +100,6 @@ impl Arion<BlsScalar> for ScalarPermutation {
             output[i] = output[i] * g + h;
 
             sigma = sigma + output[i] + state[i];
-
-            if pos > 0 {
-                pos -= 2;
-                j += 2;
-            }
         }
         state.copy_from_slice(&output);
     }
@@ -108,3 +112,38 @@ impl Arion<BlsScalar> for ScalarPermutation {
         *value = value.square().square() * *value;
     }
 }
+
+#[cfg(feature = "encryption")]
+impl<const W: usize> dusk_safe::Encryption<BlsScalar, W>
+    for ScalarPermutation<W>
+where
+    Width<W>: ArionParameters<W>,
+{
+    fn subtract(
+        &mut self,
+        minuend: &BlsScalar,
+        subtrahend: &BlsScalar,
+    ) -> BlsScalar {
+        minuend - subtrahend
+    }
+
+    fn is_equal(&mut self, lhs: &BlsScalar, rhs: &BlsScalar) -> bool {
+        lhs == rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kat::check_scalar;
+
+    const KAT: &str = include_str!("../../../kat/arion.json");
+
+    #[test]
+    fn kat() {
+        check_scalar::<ScalarPermutation<4>, 4>(KAT, "arion");
+        check_scalar::<ScalarPermutation<5>, 5>(KAT, "arion");
+        check_scalar::<ScalarPermutation<6>, 6>(KAT, "arion");
+        check_scalar::<ScalarPermutation<8>, 8>(KAT, "arion");
+    }
+}
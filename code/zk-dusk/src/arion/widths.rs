@@ -0,0 +1,71 @@
+//! Arion constants of each width, loaded from
+//! `assets/arion/{G,H,Affine}_{W}.bin`.
+
+use dusk_bls12_381::BlsScalar;
+
+use super::ArionParameters;
+use crate::width::{scalars, Width};
+
+impl ArionParameters<4> for Width<4> {
+    const NUMBER_OF_ROUNDS: usize = 5;
+    const G: &'static [BlsScalar] =
+        &scalars::<30>(include_bytes!("../../assets/arion/G_4.bin"));
+    const H: &'static [BlsScalar] =
+        &scalars::<15>(include_bytes!("../../assets/arion/H_4.bin"));
+    const AFFINE: &'static [BlsScalar] =
+        &scalars::<20>(include_bytes!("../../assets/arion/Affine_4.bin"));
+}
+
+impl ArionParameters<5> for Width<5> {
+    const NUMBER_OF_ROUNDS: usize = 5;
+    const G: &'static [BlsScalar] =
+        &scalars::<40>(include_bytes!("../../assets/arion/G_5.bin"));
+    const H: &'static [BlsScalar] =
+        &scalars::<20>(include_bytes!("../../assets/arion/H_5.bin"));
+    const AFFINE: &'static [BlsScalar] =
+        &scalars::<25>(include_bytes!("../../assets/arion/Affine_5.bin"));
+}
+
+impl ArionParameters<6> for Width<6> {
+    const NUMBER_OF_ROUNDS: usize = 5;
+    const G: &'static [BlsScalar] =
+        &scalars::<50>(include_bytes!("../../assets/arion/G_6.bin"));
+    const H: &'static [BlsScalar] =
+        &scalars::<25>(include_bytes!("../../assets/arion/H_6.bin"));
+    const AFFINE: &'static [BlsScalar] =
+        &scalars::<30>(include_bytes!("../../assets/arion/Affine_6.bin"));
+}
+
+impl ArionParameters<8> for Width<8> {
+    const NUMBER_OF_ROUNDS: usize = 4;
+    const G: &'static [BlsScalar] =
+        &scalars::<56>(include_bytes!("../../assets/arion/G_8.bin"));
+    const H: &'static [BlsScalar] =
+        &scalars::<28>(include_bytes!("../../assets/arion/H_8.bin"));
+    const AFFINE: &'static [BlsScalar] =
+        &scalars::<32>(include_bytes!("../../assets/arion/Affine_8.bin"));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check_constants<const W: usize>()
+    where
+        Width<W>: ArionParameters<W>,
+    {
+        let rounds = <Width<W>>::NUMBER_OF_ROUNDS;
+        assert_eq!(<Width<W>>::G.len(), 2 * (W - 1) * rounds);
+        assert_eq!(<Width<W>>::H.len(), (W - 1) * rounds);
+        assert_eq!(<Width<W>>::AFFINE.len(), W * rounds);
+        assert!(!<Width<W>>::G.contains(&BlsScalar::zero()));
+    }
+
+    #[test]
+    fn test_constants() {
+        check_constants::<4>();
+        check_constants::<5>();
+        check_constants::<6>();
+        check_constants::<8>();
+    }
+}
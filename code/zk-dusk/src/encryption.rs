@@ -4,15 +4,22 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+pub(crate) mod chunks;
 #[cfg(feature = "zk")]
 pub(crate) mod gadget;
+pub(crate) mod seal;
 
+use alloc::vec;
 use alloc::vec::Vec;
 
 use dusk_bls12_381::BlsScalar;
 use dusk_jubjub::JubJubAffine;
+use dusk_safe::{Call, Encryption, Safe, Sponge};
+use zeroize::Zeroize;
 
-use crate::hades::ScalarPermutation;
+use crate::hades::{ScalarPermutation, WIDTH};
+use crate::hash::tag_input;
+use crate::news::NewableScalar;
 use crate::{Domain, Error};
 
 /// This function encrypts a given message with a shared secret point on the
@@ -26,13 +33,11 @@ pub fn encrypt(
     shared_secret: &JubJubAffine,
     nonce: &BlsScalar,
 ) -> Result<Vec<BlsScalar>, Error> {
-    Ok(dusk_safe::encrypt(
-        ScalarPermutation::new(),
-        Domain::Encryption,
+    encrypt_with::<ScalarPermutation<WIDTH>, WIDTH>(
         message,
-        &[shared_secret.get_u(), shared_secret.get_v()],
+        shared_secret,
         nonce,
-    )?)
+    )
 }
 
 /// This function decrypts a message from a given cipher-text with a shared
@@ -47,11 +52,293 @@ pub fn decrypt(
     shared_secret: &JubJubAffine,
     nonce: &BlsScalar,
 ) -> Result<Vec<BlsScalar>, Error> {
+    decrypt_with::<ScalarPermutation<WIDTH>, WIDTH>(
+        cipher,
+        shared_secret,
+        nonce,
+    )
+}
+
+/// Same as [`encrypt`], with the permutation `P` over a state of `W`
+/// elements, e.g. `encrypt_with::<rescue::ScalarPermutation<8>, 8>`.
+pub fn encrypt_with<P, const W: usize>(
+    message: impl AsRef<[BlsScalar]>,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> Result<Vec<BlsScalar>, Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
+    Ok(dusk_safe::encrypt(
+        P::new(),
+        Domain::Encryption,
+        message,
+        &[shared_secret.get_u(), shared_secret.get_v()],
+        nonce,
+    )?)
+}
+
+/// Same as [`decrypt`], with the permutation `P` over a state of `W`
+/// elements, which must be the one of the encryption.
+pub fn decrypt_with<P, const W: usize>(
+    cipher: impl AsRef<[BlsScalar]>,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> Result<Vec<BlsScalar>, Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
     Ok(dusk_safe::decrypt(
-        ScalarPermutation::new(),
+        P::new(),
         Domain::Encryption,
         cipher,
         &[shared_secret.get_u(), shared_secret.get_v()],
         nonce,
     )?)
 }
+
+/// The IO-pattern of the authenticated encryption: the shared secret, the
+/// nonce and the associated data are absorbed before the key-stream is
+/// squeezed, and the message is absorbed before the tag is squeezed.
+///
+/// Without associated data this is the IO-pattern of [`encrypt`].
+pub(crate) fn aead_io_pattern(ad_len: usize, message_len: usize) -> Vec<Call> {
+    let mut io_pattern = vec![Call::Absorb(2), Call::Absorb(1)];
+    if ad_len > 0 {
+        io_pattern.push(Call::Absorb(ad_len));
+    }
+    io_pattern.push(Call::Squeeze(message_len));
+    io_pattern.push(Call::Absorb(message_len));
+    io_pattern.push(Call::Squeeze(1));
+    io_pattern
+}
+
+/// Runs the sponge of the authenticated encryption, returning the
+/// `message.len()` elements of the key-stream followed by the tag.
+fn aead_sponge<P, const W: usize>(
+    associated_data: &[BlsScalar],
+    message: &[BlsScalar],
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> Result<Vec<BlsScalar>, Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    let ad_len = associated_data.len();
+    let message_len = message.len();
+
+    let mut sponge = Sponge::start(
+        P::new(),
+        aead_io_pattern(ad_len, message_len),
+        Domain::Encryption.into(),
+    )?;
+    sponge.absorb(2, [shared_secret.get_u(), shared_secret.get_v()])?;
+    sponge.absorb(1, [*nonce])?;
+    if ad_len > 0 {
+        sponge.absorb(ad_len, associated_data)?;
+    }
+    sponge.squeeze(message_len)?;
+    sponge.absorb(message_len, message)?;
+    sponge.squeeze(1)?;
+
+    Ok(sponge.finish()?)
+}
+
+/// Runs the sponge of the authenticated decryption in a single pass,
+/// returning the message decrypted with the key-stream followed by the tag of
+/// that message, which is the last element of an authentic cipher-text.
+///
+/// [`Sponge`] only gives its output at the end, while the message to absorb
+/// depends on the key-stream, so the permutation is driven here as
+/// [`Sponge`] does.
+pub(crate) fn aead_decrypt_sponge<P, const W: usize>(
+    cipher: &[BlsScalar],
+    associated_data: &[BlsScalar],
+    shared_secret: [BlsScalar; 2],
+    nonce: BlsScalar,
+) -> Result<(Vec<BlsScalar>, BlsScalar), Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    let message_len = match cipher.len() {
+        0 => return Err(Error::DecryptionFailed),
+        len => len - 1,
+    };
+
+    let io_pattern = aead_io_pattern(associated_data.len(), message_len);
+    let mut duplex = Duplex::<P, W>::start(&io_pattern)?;
+    duplex.absorb(&shared_secret);
+    duplex.absorb(&[nonce]);
+    duplex.absorb(associated_data);
+    let message: Vec<BlsScalar> =
+        cipher[..message_len].iter().map(|c| c - duplex.squeeze()).collect();
+    duplex.absorb(&message);
+    let tag = duplex.squeeze();
+
+    Ok((message, tag))
+}
+
+/// The state of a [`Sponge`] over scalars, whose squeezed elements are read
+/// as they come instead of at the end.
+struct Duplex<P, const W: usize>
+where
+    P: Safe<BlsScalar, W>,
+{
+    permutation: P,
+    state: [BlsScalar; W],
+    pos_absorb: usize,
+    pos_squeeze: usize,
+}
+
+impl<P, const W: usize> Duplex<P, W>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    const CAPACITY: usize = 1;
+    const RATE: usize = W - Self::CAPACITY;
+
+    fn start(io_pattern: &[Call]) -> Result<Self, Error> {
+        let mut permutation = P::new();
+        let tag =
+            permutation.tag(&tag_input(io_pattern, Domain::Encryption)?);
+
+        Ok(Self {
+            permutation,
+            state: P::initialized_state(tag),
+            pos_absorb: 0,
+            pos_squeeze: 0,
+        })
+    }
+
+    fn absorb(&mut self, input: &[BlsScalar]) {
+        for element in input {
+            if self.pos_absorb == Self::RATE {
+                self.permutation.permute(&mut self.state);
+                self.pos_absorb = 0;
+            }
+            let pos = self.pos_absorb + Self::CAPACITY;
+            self.state[pos] = self.permutation.add(&self.state[pos], element);
+            self.pos_absorb += 1;
+        }
+        // the next squeeze starts with a permutation
+        self.pos_squeeze = Self::RATE;
+    }
+
+    fn squeeze(&mut self) -> BlsScalar {
+        if self.pos_squeeze == Self::RATE {
+            self.permutation.permute(&mut self.state);
+            self.pos_squeeze = 0;
+            self.pos_absorb = 0;
+        }
+        let element = self.state[self.pos_squeeze + Self::CAPACITY];
+        self.pos_squeeze += 1;
+        element
+    }
+}
+
+impl<P, const W: usize> Drop for Duplex<P, W>
+where
+    P: Safe<BlsScalar, W>,
+{
+    // as the sponge of `dusk_safe` does, erase the state from memory
+    fn drop(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+/// This function encrypts a given message with a shared secret point on the
+/// jubjub-curve and a bls-scalar nonce using the poseidon hash function, and
+/// authenticates the associated data along with it.
+///
+/// The associated data is not encrypted; it must be sent along with the
+/// cipher-text and given again to [`aead_decrypt`].
+///
+/// The cipher-text will always yield exactly one element more than the message.
+pub fn aead_encrypt(
+    message: impl AsRef<[BlsScalar]>,
+    associated_data: impl AsRef<[BlsScalar]>,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> Result<Vec<BlsScalar>, Error> {
+    aead_encrypt_with::<ScalarPermutation<WIDTH>, WIDTH>(
+        message,
+        associated_data,
+        shared_secret,
+        nonce,
+    )
+}
+
+/// This function decrypts a message from a given cipher-text with a shared
+/// secret point on the jubjub-curve and a bls-scalar nonce using the poseidon
+/// hash function, and checks that the associated data is the one given to
+/// [`aead_encrypt`].
+///
+/// The decryption fails with [`Error::DecryptionFailed`] if the cipher-text,
+/// the shared secret, the nonce or the associated data differ.
+pub fn aead_decrypt(
+    cipher: impl AsRef<[BlsScalar]>,
+    associated_data: impl AsRef<[BlsScalar]>,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> Result<Vec<BlsScalar>, Error> {
+    aead_decrypt_with::<ScalarPermutation<WIDTH>, WIDTH>(
+        cipher,
+        associated_data,
+        shared_secret,
+        nonce,
+    )
+}
+
+/// Same as [`aead_encrypt`], with the permutation `P` over a state of `W`
+/// elements.
+pub fn aead_encrypt_with<P, const W: usize>(
+    message: impl AsRef<[BlsScalar]>,
+    associated_data: impl AsRef<[BlsScalar]>,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> Result<Vec<BlsScalar>, Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    let message = message.as_ref();
+
+    // the cipher is the key-stream with the message added, followed by the
+    // tag
+    let mut cipher = aead_sponge::<P, W>(
+        associated_data.as_ref(),
+        message,
+        shared_secret,
+        nonce,
+    )?;
+    cipher.iter_mut().zip(message).for_each(|(c, m)| *c += m);
+
+    Ok(cipher)
+}
+
+/// Same as [`aead_decrypt`], with the permutation `P` over a state of `W`
+/// elements, which must be the one of the encryption.
+pub fn aead_decrypt_with<P, const W: usize>(
+    cipher: impl AsRef<[BlsScalar]>,
+    associated_data: impl AsRef<[BlsScalar]>,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> Result<Vec<BlsScalar>, Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    let cipher = cipher.as_ref();
+    let (mut message, tag) = aead_decrypt_sponge::<P, W>(
+        cipher,
+        associated_data.as_ref(),
+        [shared_secret.get_u(), shared_secret.get_v()],
+        *nonce,
+    )?;
+
+    // the tag of the decrypted message must be the one of the cipher
+    if cipher[message.len()] != tag {
+        message.zeroize();
+        return Err(Error::DecryptionFailed);
+    }
+
+    Ok(message)
+}
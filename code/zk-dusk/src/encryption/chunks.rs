@@ -0,0 +1,263 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Chunked encryption of long messages.
+//!
+//! The message is split into chunks of a fixed length, the last one possibly
+//! shorter, and every chunk is encrypted with [`aead_encrypt_with`] with its
+//! index and whether it is the last chunk as associated data. Every chunk is
+//! then authenticated on its own and bound to its position, so reordering,
+//! truncating or extending the chunks makes the decryption fail.
+
+use core::iter::Peekable;
+use core::marker::PhantomData;
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::JubJubAffine;
+use dusk_safe::Safe;
+
+use super::{aead_decrypt_with, aead_encrypt_with};
+use crate::hades::{ScalarPermutation, WIDTH};
+use crate::news::NewableScalar;
+use crate::Error;
+
+/// The associated data of the chunk at `index`.
+fn chunk_associated_data(index: u64, is_last: bool) -> [BlsScalar; 2] {
+    [BlsScalar::from(index), BlsScalar::from(is_last as u64)]
+}
+
+/// An iterator over the encrypted chunks of a message, created by
+/// [`encrypt_chunks`].
+///
+/// Every item is the cipher-text of a chunk, one element longer than the
+/// chunk.
+pub struct ChunkEncryptor<I: Iterator<Item = BlsScalar>, P, const W: usize> {
+    message: Peekable<I>,
+    chunk_len: usize,
+    shared_secret: JubJubAffine,
+    nonce: BlsScalar,
+    index: u64,
+    done: bool,
+    permutation: PhantomData<P>,
+}
+
+impl<I, P, const W: usize> Iterator for ChunkEncryptor<I, P, W>
+where
+    I: Iterator<Item = BlsScalar>,
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    type Item = Result<Vec<BlsScalar>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        // the message and the chunks can't be empty
+        let chunk: Vec<BlsScalar> =
+            self.message.by_ref().take(self.chunk_len).collect();
+        if chunk.is_empty() {
+            self.done = true;
+            return Some(Err(Error::EncryptionFailed));
+        }
+
+        let is_last = self.message.peek().is_none();
+        let associated_data = chunk_associated_data(self.index, is_last);
+        let cipher = aead_encrypt_with::<P, W>(
+            &chunk,
+            associated_data,
+            &self.shared_secret,
+            &self.nonce,
+        );
+        self.index += 1;
+        self.done = is_last || cipher.is_err();
+
+        Some(cipher)
+    }
+}
+
+/// An iterator over the decrypted chunks of a message, created by
+/// [`decrypt_chunks`].
+pub struct ChunkDecryptor<I: Iterator, P, const W: usize> {
+    cipher: Peekable<I>,
+    shared_secret: JubJubAffine,
+    nonce: BlsScalar,
+    index: u64,
+    done: bool,
+    permutation: PhantomData<P>,
+}
+
+impl<I, P, const W: usize> Iterator for ChunkDecryptor<I, P, W>
+where
+    I: Iterator,
+    I::Item: AsRef<[BlsScalar]>,
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    type Item = Result<Vec<BlsScalar>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        // the chunks end with the last chunk, so running out of chunks means
+        // that there were none
+        let cipher = match self.cipher.next() {
+            Some(cipher) => cipher,
+            None => {
+                self.done = true;
+                return Some(Err(Error::DecryptionFailed));
+            }
+        };
+
+        let is_last = self.cipher.peek().is_none();
+        let message = decrypt_chunk_with::<P, W>(
+            cipher,
+            self.index,
+            is_last,
+            &self.shared_secret,
+            &self.nonce,
+        );
+        self.index += 1;
+        self.done = is_last || message.is_err();
+
+        Some(message)
+    }
+}
+
+/// This function encrypts a message given as an iterator in chunks of
+/// `chunk_len` elements with a shared secret point on the jubjub-curve and a
+/// bls-scalar nonce using the poseidon hash function.
+///
+/// The chunks are encrypted as they are iterated over, so the message doesn't
+/// need to be in memory at once. The iterator yields
+/// [`Error::EncryptionFailed`] if the message is empty or `chunk_len` is zero.
+pub fn encrypt_chunks<I>(
+    message: I,
+    chunk_len: usize,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> ChunkEncryptor<I::IntoIter, ScalarPermutation<WIDTH>, WIDTH>
+where
+    I: IntoIterator<Item = BlsScalar>,
+{
+    encrypt_chunks_with::<ScalarPermutation<WIDTH>, WIDTH, I>(
+        message,
+        chunk_len,
+        shared_secret,
+        nonce,
+    )
+}
+
+/// This function decrypts the chunks of a message encrypted with
+/// [`encrypt_chunks`], given as an iterator, with a shared secret point on
+/// the jubjub-curve and a bls-scalar nonce using the poseidon hash function.
+///
+/// The iterator yields [`Error::DecryptionFailed`] for the first chunk that
+/// doesn't decrypt, e.g. because the chunks have been reordered or truncated,
+/// and then ends.
+pub fn decrypt_chunks<I>(
+    cipher: I,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> ChunkDecryptor<I::IntoIter, ScalarPermutation<WIDTH>, WIDTH>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[BlsScalar]>,
+{
+    decrypt_chunks_with::<ScalarPermutation<WIDTH>, WIDTH, I>(
+        cipher,
+        shared_secret,
+        nonce,
+    )
+}
+
+/// This function decrypts the single chunk at `index` of a message encrypted
+/// with [`encrypt_chunks`], without the other chunks.
+///
+/// `is_last` tells whether it is the last chunk of the message.
+pub fn decrypt_chunk(
+    cipher: impl AsRef<[BlsScalar]>,
+    index: u64,
+    is_last: bool,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> Result<Vec<BlsScalar>, Error> {
+    decrypt_chunk_with::<ScalarPermutation<WIDTH>, WIDTH>(
+        cipher,
+        index,
+        is_last,
+        shared_secret,
+        nonce,
+    )
+}
+
+/// Same as [`encrypt_chunks`], with the permutation `P` over a state of `W`
+/// elements.
+pub fn encrypt_chunks_with<P, const W: usize, I>(
+    message: I,
+    chunk_len: usize,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> ChunkEncryptor<I::IntoIter, P, W>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+    I: IntoIterator<Item = BlsScalar>,
+{
+    ChunkEncryptor {
+        message: message.into_iter().peekable(),
+        chunk_len,
+        shared_secret: *shared_secret,
+        nonce: *nonce,
+        index: 0,
+        done: false,
+        permutation: PhantomData,
+    }
+}
+
+/// Same as [`decrypt_chunks`], with the permutation `P` over a state of `W`
+/// elements, which must be the one of the encryption.
+pub fn decrypt_chunks_with<P, const W: usize, I>(
+    cipher: I,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> ChunkDecryptor<I::IntoIter, P, W>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+    I: IntoIterator,
+    I::Item: AsRef<[BlsScalar]>,
+{
+    ChunkDecryptor {
+        cipher: cipher.into_iter().peekable(),
+        shared_secret: *shared_secret,
+        nonce: *nonce,
+        index: 0,
+        done: false,
+        permutation: PhantomData,
+    }
+}
+
+/// Same as [`decrypt_chunk`], with the permutation `P` over a state of `W`
+/// elements, which must be the one of the encryption.
+pub fn decrypt_chunk_with<P, const W: usize>(
+    cipher: impl AsRef<[BlsScalar]>,
+    index: u64,
+    is_last: bool,
+    shared_secret: &JubJubAffine,
+    nonce: &BlsScalar,
+) -> Result<Vec<BlsScalar>, Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    aead_decrypt_with::<P, W>(
+        cipher,
+        chunk_associated_data(index, is_last),
+        shared_secret,
+        nonce,
+    )
+}
@@ -6,10 +6,16 @@
 
 use alloc::vec::Vec;
 
-use dusk_plonk::prelude::{Composer, Witness, WitnessPoint};
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::GENERATOR_EXTENDED;
+use dusk_plonk::prelude::{Composer, Constraint, Witness, WitnessPoint};
+use dusk_safe::{Encryption, Safe, Sponge};
 
-use crate::hades::GadgetPermutation;
-use crate::{Domain, Error};
+use super::seal::SEAL_NONCE_LABEL;
+use super::{aead_decrypt_sponge, aead_io_pattern};
+use crate::hades::{GadgetPermutation, ScalarPermutation, WIDTH};
+use crate::news::{NewableSafe, NewableScalar};
+use crate::{Domain, Error, HashGadget, HashableGadget};
 
 /// This function encrypts a given message with a shared secret point on the
 /// jubjub-curve and a bls-scalar nonce using the poseidon hash function.
@@ -23,13 +29,12 @@ pub fn encrypt_gadget(
     shared_secret: &WitnessPoint,
     nonce: &Witness,
 ) -> Result<Vec<Witness>, Error> {
-    Ok(dusk_safe::encrypt(
-        GadgetPermutation::new(composer),
-        Domain::Encryption,
+    encrypt_gadget_with::<GadgetPermutation<WIDTH>, WIDTH>(
+        composer,
         message,
-        &[*shared_secret.x(), *shared_secret.y()],
+        shared_secret,
         nonce,
-    )?)
+    )
 }
 
 /// This function decrypts a message from a given cipher-text with a shared
@@ -45,11 +50,420 @@ pub fn decrypt_gadget(
     shared_secret: &WitnessPoint,
     nonce: &Witness,
 ) -> Result<Vec<Witness>, Error> {
+    decrypt_gadget_with::<GadgetPermutation<WIDTH>, WIDTH>(
+        composer,
+        cipher,
+        shared_secret,
+        nonce,
+    )
+}
+
+/// Same as [`encrypt_gadget`], with the gadget permutation `G` over a state
+/// of `W` elements, e.g.
+/// `encrypt_gadget_with::<rescue::GadgetPermutation<8>, 8>`.
+pub fn encrypt_gadget_with<G, const W: usize>(
+    composer: &mut Composer,
+    message: impl AsRef<[Witness]>,
+    shared_secret: &WitnessPoint,
+    nonce: &Witness,
+) -> Result<Vec<Witness>, Error>
+where
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    Ok(dusk_safe::encrypt(
+        G::new(composer),
+        Domain::Encryption,
+        message,
+        &[*shared_secret.x(), *shared_secret.y()],
+        nonce,
+    )?)
+}
+
+/// Same as [`decrypt_gadget`], with the gadget permutation `G` over a state
+/// of `W` elements, which must be the one of the encryption.
+pub fn decrypt_gadget_with<G, const W: usize>(
+    composer: &mut Composer,
+    cipher: impl AsRef<[Witness]>,
+    shared_secret: &WitnessPoint,
+    nonce: &Witness,
+) -> Result<Vec<Witness>, Error>
+where
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
     Ok(dusk_safe::decrypt(
-        GadgetPermutation::new(composer),
+        G::new(composer),
         Domain::Encryption,
         cipher,
         &[*shared_secret.x(), *shared_secret.y()],
         nonce,
     )?)
 }
+
+/// Runs the sponge of the authenticated encryption in the circuit, returning
+/// the `message.len()` elements of the key-stream followed by the tag.
+fn aead_sponge_gadget<G, const W: usize>(
+    composer: &mut Composer,
+    associated_data: &[Witness],
+    message: &[Witness],
+    shared_secret: [Witness; 2],
+    nonce: Witness,
+) -> Result<Vec<Witness>, Error>
+where
+    G: NewableSafe<W>,
+{
+    let ad_len = associated_data.len();
+    let message_len = message.len();
+
+    let mut sponge = Sponge::start(
+        G::new(composer),
+        aead_io_pattern(ad_len, message_len),
+        Domain::Encryption.into(),
+    )?;
+    sponge.absorb(2, shared_secret)?;
+    sponge.absorb(1, [nonce])?;
+    if ad_len > 0 {
+        sponge.absorb(ad_len, associated_data)?;
+    }
+    sponge.squeeze(message_len)?;
+    sponge.absorb(message_len, message)?;
+    sponge.squeeze(1)?;
+
+    Ok(sponge.finish()?)
+}
+
+/// This function encrypts a given message with a shared secret point on the
+/// jubjub-curve and a bls-scalar nonce using the poseidon hash function, and
+/// authenticates the associated data along with it.
+///
+/// The cipher-text will always yield exactly one element more than the message.
+pub fn aead_encrypt_gadget(
+    composer: &mut Composer,
+    message: impl AsRef<[Witness]>,
+    associated_data: impl AsRef<[Witness]>,
+    shared_secret: &WitnessPoint,
+    nonce: &Witness,
+) -> Result<Vec<Witness>, Error> {
+    aead_encrypt_gadget_with::<GadgetPermutation<WIDTH>, WIDTH>(
+        composer,
+        message,
+        associated_data,
+        shared_secret,
+        nonce,
+    )
+}
+
+/// This function decrypts a message from a given cipher-text with a shared
+/// secret point on the jubjub-curve and a bls-scalar nonce using the poseidon
+/// hash function, and constrains the associated data to be the one of the
+/// encryption.
+///
+/// The proof creation fails if the cipher-text, the shared secret, the nonce
+/// or the associated data differ from the ones of the encryption.
+pub fn aead_decrypt_gadget(
+    composer: &mut Composer,
+    cipher: impl AsRef<[Witness]>,
+    associated_data: impl AsRef<[Witness]>,
+    shared_secret: &WitnessPoint,
+    nonce: &Witness,
+) -> Result<Vec<Witness>, Error> {
+    aead_decrypt_gadget_with::<
+        ScalarPermutation<WIDTH>,
+        GadgetPermutation<WIDTH>,
+        WIDTH,
+    >(
+        composer,
+        cipher,
+        associated_data,
+        shared_secret,
+        nonce,
+    )
+}
+
+/// Same as [`aead_encrypt_gadget`], with the gadget permutation `G` over a
+/// state of `W` elements.
+pub fn aead_encrypt_gadget_with<G, const W: usize>(
+    composer: &mut Composer,
+    message: impl AsRef<[Witness]>,
+    associated_data: impl AsRef<[Witness]>,
+    shared_secret: &WitnessPoint,
+    nonce: &Witness,
+) -> Result<Vec<Witness>, Error>
+where
+    G: NewableSafe<W>,
+{
+    let message = message.as_ref();
+
+    // the cipher is the key-stream with the message added, followed by the
+    // tag
+    let mut cipher = aead_sponge_gadget::<G, W>(
+        composer,
+        associated_data.as_ref(),
+        message,
+        [*shared_secret.x(), *shared_secret.y()],
+        *nonce,
+    )?;
+    for (c, m) in cipher.iter_mut().zip(message) {
+        let constraint = Constraint::new().left(1).a(*c).right(1).b(*m);
+        *c = composer.gate_add(constraint);
+    }
+
+    Ok(cipher)
+}
+
+/// Same as [`aead_decrypt_gadget`], with the scalar permutation `P` and the
+/// gadget permutation `G` over a state of `W` elements, which must be the ones
+/// of the encryption. The message is decrypted with `P` outside of the circuit
+/// and constrained with `G`.
+pub fn aead_decrypt_gadget_with<P, G, const W: usize>(
+    composer: &mut Composer,
+    cipher: impl AsRef<[Witness]>,
+    associated_data: impl AsRef<[Witness]>,
+    shared_secret: &WitnessPoint,
+    nonce: &Witness,
+) -> Result<Vec<Witness>, Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+{
+    let cipher = cipher.as_ref();
+    let associated_data = associated_data.as_ref();
+    let shared_secret = [*shared_secret.x(), *shared_secret.y()];
+    let message_len = match cipher.len() {
+        0 => return Err(Error::DecryptionFailed),
+        len => len - 1,
+    };
+
+    // decrypt the message outside of the circuit, from the values of the
+    // witnesses, and append it as witnesses
+    let values = |witnesses: &[Witness]| -> Vec<BlsScalar> {
+        witnesses.iter().map(|w| composer[*w]).collect()
+    };
+    let (message, _tag) = aead_decrypt_sponge::<P, W>(
+        &values(cipher),
+        &values(associated_data),
+        [composer[shared_secret[0]], composer[shared_secret[1]]],
+        composer[*nonce],
+    )?;
+    let message: Vec<Witness> =
+        message.into_iter().map(|m| composer.append_witness(m)).collect();
+
+    // constrain the cipher to be the key-stream with the decrypted message
+    // added, followed by the tag of the decrypted message
+    let output = aead_sponge_gadget::<G, W>(
+        composer,
+        associated_data,
+        &message,
+        shared_secret,
+        *nonce,
+    )?;
+    for ((o, m), c) in output.iter().zip(&message).zip(cipher) {
+        let constraint = Constraint::new().left(1).a(*o).right(1).b(*m);
+        let sum = composer.gate_add(constraint);
+        composer.assert_equal(sum, *c);
+    }
+    composer.assert_equal(output[message_len], cipher[message_len]);
+
+    Ok(message)
+}
+
+/// Computes the shared secret of a Diffie-Hellman key exchange on the
+/// jubjub-curve in the circuit, as the `secret_key` multiple of the
+/// `public_key` of the other party.
+///
+/// The secret key is expected to be a jubjub-scalar.
+pub fn shared_secret_gadget(
+    composer: &mut Composer,
+    secret_key: &Witness,
+    public_key: &WitnessPoint,
+) -> WitnessPoint {
+    composer.component_mul_point(*secret_key, *public_key)
+}
+
+/// This function encrypts a given message to the public key of the receiver
+/// with the secret key of the sender and a bls-scalar nonce using the poseidon
+/// hash function.
+///
+/// Unlike [`encrypt_gadget`], the shared secret is derived in the circuit with
+/// [`shared_secret_gadget`], which proves that the cipher-text can be
+/// decrypted with the secret key of the receiver and the public key of the
+/// sender. The public key of the sender is derived from its secret key in the
+/// circuit and returned with the cipher-text, so that it can be constrained to
+/// the public key the receiver knows the sender by.
+///
+/// The cipher-text will always yield exactly one element more than the message.
+pub fn encrypt_to_public_key_gadget(
+    composer: &mut Composer,
+    message: impl AsRef<[Witness]>,
+    secret_key: &Witness,
+    public_key: &WitnessPoint,
+    nonce: &Witness,
+) -> Result<(WitnessPoint, Vec<Witness>), Error> {
+    encrypt_to_public_key_gadget_with::<GadgetPermutation<WIDTH>, WIDTH>(
+        composer, message, secret_key, public_key, nonce,
+    )
+}
+
+/// Same as [`encrypt_to_public_key_gadget`], with the gadget permutation `G`
+/// over a state of `W` elements.
+pub fn encrypt_to_public_key_gadget_with<G, const W: usize>(
+    composer: &mut Composer,
+    message: impl AsRef<[Witness]>,
+    secret_key: &Witness,
+    public_key: &WitnessPoint,
+    nonce: &Witness,
+) -> Result<(WitnessPoint, Vec<Witness>), Error>
+where
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    // the secret key is only malformed if it doesn't fit a jubjub-scalar
+    let sender_public_key = composer
+        .component_mul_generator(*secret_key, GENERATOR_EXTENDED)
+        .map_err(|_| Error::EncryptionFailed)?;
+    let shared_secret = shared_secret_gadget(composer, secret_key, public_key);
+
+    let cipher =
+        encrypt_gadget_with::<G, W>(composer, message, &shared_secret, nonce)?;
+
+    Ok((sender_public_key, cipher))
+}
+
+/// The witnesses of a message sealed in the circuit with [`seal_gadget`].
+#[derive(Debug, Clone)]
+pub struct WitnessSealedMessage {
+    /// The public key of the ephemeral key pair of the sender.
+    pub ephemeral_public_key: WitnessPoint,
+    /// The nonce of the encryption.
+    pub nonce: Witness,
+    /// The cipher-text, one element longer than the message.
+    pub cipher: Vec<Witness>,
+}
+
+/// This function seals a given message to the public key of the receiver with
+/// the secret key of an ephemeral key pair, as [`crate::seal`] does, using
+/// the poseidon hash function.
+///
+/// The message is constrained to hash to the `commitment`, as
+/// [`crate::Hash::digest`] with [`Domain::Other`] would. The commitment, the
+/// public key and the returned sealed message must be public inputs, e.g.
+/// appended with `append_public` and `append_public_point`, for the proof to
+/// show that the sealed message encrypts the committed message to that
+/// public key.
+pub fn seal_gadget(
+    composer: &mut Composer,
+    public_key: &WitnessPoint,
+    message: impl AsRef<[Witness]>,
+    commitment: &Witness,
+    ephemeral_secret_key: &Witness,
+) -> Result<WitnessSealedMessage, Error> {
+    seal_gadget_with::<GadgetPermutation<WIDTH>, WIDTH>(
+        composer,
+        public_key,
+        message,
+        commitment,
+        ephemeral_secret_key,
+    )
+}
+
+/// Same as [`seal_gadget`], with the gadget permutation `G` over a state of
+/// `W` elements.
+pub fn seal_gadget_with<G, const W: usize>(
+    composer: &mut Composer,
+    public_key: &WitnessPoint,
+    message: impl AsRef<[Witness]>,
+    commitment: &Witness,
+    ephemeral_secret_key: &Witness,
+) -> Result<WitnessSealedMessage, Error>
+where
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    let message = message.as_ref();
+
+    // the message is the one of the commitment
+    let digest =
+        HashGadget::<G, W>::try_digest(Domain::Other, composer, message)?[0];
+    composer.assert_equal(digest, *commitment);
+
+    // the secret key is only malformed if it doesn't fit a jubjub-scalar
+    let ephemeral_public_key = composer
+        .component_mul_generator(*ephemeral_secret_key, GENERATOR_EXTENDED)
+        .map_err(|_| Error::EncryptionFailed)?;
+    let shared_secret =
+        shared_secret_gadget(composer, ephemeral_secret_key, public_key);
+
+    let keys = [
+        *ephemeral_public_key.x(),
+        *ephemeral_public_key.y(),
+        *public_key.x(),
+        *public_key.y(),
+    ];
+    let nonce_domain = Domain::from_label(SEAL_NONCE_LABEL);
+    let nonce = HashGadget::<G, W>::digest(nonce_domain, composer, &keys)[0];
+    let cipher =
+        encrypt_gadget_with::<G, W>(composer, message, &shared_secret, &nonce)?;
+
+    Ok(WitnessSealedMessage {
+        ephemeral_public_key,
+        nonce,
+        cipher,
+    })
+}
+
+/// This function decrypts the single chunk at `index` of a message encrypted
+/// with [`crate::encrypt_chunks`] in the circuit, with a shared secret point
+/// on the jubjub-curve and a bls-scalar nonce using the poseidon hash
+/// function.
+///
+/// `is_last` is constrained to be a boolean telling whether it is the last
+/// chunk of the message. The proof creation fails if the chunk isn't the one
+/// at `index`.
+pub fn decrypt_chunk_gadget(
+    composer: &mut Composer,
+    cipher: impl AsRef<[Witness]>,
+    index: &Witness,
+    is_last: &Witness,
+    shared_secret: &WitnessPoint,
+    nonce: &Witness,
+) -> Result<Vec<Witness>, Error> {
+    decrypt_chunk_gadget_with::<
+        ScalarPermutation<WIDTH>,
+        GadgetPermutation<WIDTH>,
+        WIDTH,
+    >(
+        composer,
+        cipher,
+        index,
+        is_last,
+        shared_secret,
+        nonce,
+    )
+}
+
+/// Same as [`decrypt_chunk_gadget`], with the scalar permutation `P` and the
+/// gadget permutation `G` over a state of `W` elements, which must be the ones
+/// of the encryption.
+pub fn decrypt_chunk_gadget_with<P, G, const W: usize>(
+    composer: &mut Composer,
+    cipher: impl AsRef<[Witness]>,
+    index: &Witness,
+    is_last: &Witness,
+    shared_secret: &WitnessPoint,
+    nonce: &Witness,
+) -> Result<Vec<Witness>, Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+{
+    composer.component_boolean(*is_last);
+
+    aead_decrypt_gadget_with::<P, G, W>(
+        composer,
+        cipher,
+        [*index, *is_last],
+        shared_secret,
+        nonce,
+    )
+}
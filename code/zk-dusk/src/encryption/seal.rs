@@ -0,0 +1,192 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Hybrid public-key encryption over the jubjub-curve.
+//!
+//! [`seal`] generates an ephemeral key pair, derives the shared secret with
+//! the public key of the receiver, derives the nonce by hashing both public
+//! keys, and encrypts the message with them. [`open`] derives the same shared
+//! secret and nonce from the secret key of the receiver and the ephemeral
+//! public key.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::{Error as BytesError, Serializable};
+use dusk_jubjub::{JubJubAffine, JubJubScalar, GENERATOR_EXTENDED};
+use dusk_safe::{Encryption, Safe};
+use ff::Field;
+use rand::{CryptoRng, RngCore};
+
+use super::{decrypt_with, encrypt_with};
+use crate::hades::{ScalarPermutation, WIDTH};
+use crate::news::NewableScalar;
+use crate::{Domain, Error, Hash};
+
+/// A message sealed to a public key with [`seal`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SealedMessage {
+    /// The public key of the ephemeral key pair of the sender.
+    pub ephemeral_public_key: JubJubAffine,
+    /// The nonce of the encryption.
+    pub nonce: BlsScalar,
+    /// The cipher-text, one element longer than the message.
+    pub cipher: Vec<BlsScalar>,
+}
+
+impl SealedMessage {
+    /// Serializes the sealed message as the compressed ephemeral public key,
+    /// the nonce and the elements of the cipher-text, each in 32 bytes.
+    pub fn to_var_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            JubJubAffine::SIZE + (1 + self.cipher.len()) * BlsScalar::SIZE,
+        );
+        bytes.extend(self.ephemeral_public_key.to_bytes());
+        bytes.extend(self.nonce.to_bytes());
+        self.cipher
+            .iter()
+            .for_each(|c| bytes.extend(c.to_bytes()));
+        bytes
+    }
+
+    /// Deserializes a sealed message from the bytes of
+    /// [`SealedMessage::to_var_bytes`], whose ephemeral public key must be in
+    /// the prime-order subgroup.
+    pub fn from_var_bytes(bytes: &[u8]) -> Result<Self, BytesError> {
+        // the cipher-text has at least the tag
+        let min_len = JubJubAffine::SIZE + 2 * BlsScalar::SIZE;
+        if bytes.len() < min_len
+            || (bytes.len() - JubJubAffine::SIZE) % BlsScalar::SIZE != 0
+        {
+            return Err(BytesError::BadLength {
+                found: bytes.len(),
+                expected: min_len,
+            });
+        }
+
+        let (key, bytes) = bytes.split_at(JubJubAffine::SIZE);
+        let ephemeral_public_key = <JubJubAffine as Serializable<32>>::from_bytes(
+            key.try_into().expect("32 bytes"),
+        )?;
+        if !bool::from(ephemeral_public_key.is_prime_order()) {
+            return Err(BytesError::InvalidData);
+        }
+        let mut scalars = bytes.chunks_exact(BlsScalar::SIZE).map(|chunk| {
+            <BlsScalar as Serializable<32>>::from_bytes(
+                chunk.try_into().expect("32 bytes"),
+            )
+        });
+        let nonce = scalars.next().expect("the length was checked")?;
+        let cipher = scalars.collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            ephemeral_public_key,
+            nonce,
+            cipher,
+        })
+    }
+}
+
+/// The label of the domain of the hash deriving the nonce of a sealed
+/// message, so that the nonce can't collide with another hash of the public
+/// keys.
+pub(crate) const SEAL_NONCE_LABEL: &[u8] = b"zk-dusk-seal-nonce";
+
+/// Derives the nonce of a sealed message from the ephemeral public key of
+/// the sender and the public key of the receiver.
+pub(crate) fn seal_nonce<P, const W: usize>(
+    ephemeral_public_key: &JubJubAffine,
+    public_key: &JubJubAffine,
+) -> BlsScalar
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    let input = [
+        ephemeral_public_key.get_u(),
+        ephemeral_public_key.get_v(),
+        public_key.get_u(),
+        public_key.get_v(),
+    ];
+    Hash::<P, W>::digest(Domain::from_label(SEAL_NONCE_LABEL), &input)[0]
+}
+
+/// Seals a message to the public key of the receiver with a fresh ephemeral
+/// key pair, using the poseidon hash function.
+///
+/// Only the owner of the secret key of `public_key` can [`open`] the sealed
+/// message.
+pub fn seal<R: RngCore + CryptoRng>(
+    public_key: &JubJubAffine,
+    message: impl AsRef<[BlsScalar]>,
+    rng: &mut R,
+) -> Result<SealedMessage, Error> {
+    seal_with::<ScalarPermutation<WIDTH>, WIDTH, R>(public_key, message, rng)
+}
+
+/// Opens a message sealed to the public key of `secret_key`.
+///
+/// Opening fails with [`Error::DecryptionFailed`] if the sealed message
+/// wasn't sealed to that public key or has been tampered with, or if its
+/// ephemeral public key isn't in the prime-order subgroup.
+pub fn open(
+    secret_key: &JubJubScalar,
+    sealed: &SealedMessage,
+) -> Result<Vec<BlsScalar>, Error> {
+    open_with::<ScalarPermutation<WIDTH>, WIDTH>(secret_key, sealed)
+}
+
+/// Same as [`seal`], with the permutation `P` over a state of `W` elements.
+pub fn seal_with<P, const W: usize, R: RngCore + CryptoRng>(
+    public_key: &JubJubAffine,
+    message: impl AsRef<[BlsScalar]>,
+    rng: &mut R,
+) -> Result<SealedMessage, Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
+    let ephemeral_secret_key = JubJubScalar::random(rng);
+    let ephemeral_public_key: JubJubAffine =
+        (GENERATOR_EXTENDED * ephemeral_secret_key).into();
+    let shared_secret: JubJubAffine =
+        (public_key * ephemeral_secret_key).into();
+
+    let nonce = seal_nonce::<P, W>(&ephemeral_public_key, public_key);
+    let cipher = encrypt_with::<P, W>(message, &shared_secret, &nonce)?;
+
+    Ok(SealedMessage {
+        ephemeral_public_key,
+        nonce,
+        cipher,
+    })
+}
+
+/// Same as [`open`], with the permutation `P` over a state of `W` elements,
+/// which must be the one of the sealing.
+pub fn open_with<P, const W: usize>(
+    secret_key: &JubJubScalar,
+    sealed: &SealedMessage,
+) -> Result<Vec<BlsScalar>, Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
+    // the shared secret with a key of small order would only depend on the
+    // secret key modulo the cofactor, which would leak whether opening fails
+    if !bool::from(sealed.ephemeral_public_key.is_prime_order()) {
+        return Err(Error::DecryptionFailed);
+    }
+
+    let public_key: JubJubAffine = (GENERATOR_EXTENDED * secret_key).into();
+    let shared_secret: JubJubAffine =
+        (sealed.ephemeral_public_key * secret_key).into();
+
+    // the nonce is derived from the public keys, and can't be chosen
+    let nonce = seal_nonce::<P, W>(&sealed.ephemeral_public_key, &public_key);
+    if nonce != sealed.nonce {
+        return Err(Error::DecryptionFailed);
+    }
+
+    decrypt_with::<P, W>(&sealed.cipher, &shared_secret, &nonce)
+}
@@ -29,6 +29,9 @@ pub enum Error {
 
     /// Invalid point on the jubjub-curve
     InvalidPoint,
+
+    /// An ID or a name that is not assigned to a hash algorithm.
+    UnknownHashAlgorithm,
 }
 
 impl From<SafeError> for Error {
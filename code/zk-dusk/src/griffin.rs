@@ -1,18 +1,31 @@
-mod mds_matrix;
 mod permutation;
-mod round_constants;
+mod widths;
 
-use mds_matrix::MDS_MATRIX;
-use round_constants::ROUND_CONSTANTS;
+use dusk_bls12_381::BlsScalar;
 
-/// Width of the Griffin sponge
+/// Default width of the Griffin sponge
 pub const WIDTH: usize = 8;
 
 const D: usize = 5;
 
-// Width = 4 -> ROUNDS = 11
-// Width = 8 -> ROUNDS= 9
-const NUMBER_OF_ROUNDS: usize = 9;
+/// Parameters of the Griffin permutation for a state width, implemented for
+/// [`Width`](crate::Width) 4 and 8.
+pub trait GriffinParameters<const W: usize> {
+    /// Number of rounds: 11 and 9 for `W` of 4 and 8.
+    const NUMBER_OF_ROUNDS: usize;
+
+    /// The `W * (NUMBER_OF_ROUNDS - 1)` round constants.
+    const ROUND_CONSTANTS: &'static [BlsScalar];
+
+    /// The `W - 2` alphas of the non-linear layer.
+    const ALPHAS: &'static [BlsScalar];
+
+    /// The `W - 2` betas of the non-linear layer.
+    const BETAS: &'static [BlsScalar];
+
+    /// The `W x W` MDS matrix of the linear layer.
+    const MDS_MATRIX: [[u64; W]; W];
+}
 
 #[cfg(feature = "zk")]
 pub use permutation::gadget::GadgetPermutation;
@@ -41,4 +54,32 @@ const fn u64_from_buffer<const N: usize>(buf: &[u8; N], i: usize) -> u64 {
         buf[i + 6],
         buf[i + 7],
     ])
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zk_lib::parameters::griffin;
+
+    // Targeted security level
+    const SECURITY_LEVEL: usize = 128;
+
+    #[test]
+    fn number_of_rounds() {
+        use crate::Width;
+
+        fn check<const W: usize>()
+        where
+            Width<W>: GriffinParameters<W>,
+        {
+            let rounds = <Width<W>>::NUMBER_OF_ROUNDS;
+
+            let bounds = griffin::round_bounds(W, D as u64, SECURITY_LEVEL);
+            assert!(rounds >= bounds.minimum());
+            assert_eq!(rounds, griffin::number_of_rounds(W, D as u64, SECURITY_LEVEL));
+        }
+
+        check::<4>();
+        check::<8>();
+    }
+}
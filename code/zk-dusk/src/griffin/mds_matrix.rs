@@ -1,25 +0,0 @@
-use super::WIDTH;
-
-// Width = 4
-/* pub const MDS_MATRIX: [[u64; WIDTH]; WIDTH] = [
-    [5, 7, 1, 3],
-    [4, 6, 1, 1],
-    [1, 3, 5, 7],
-    [1, 1, 4, 6],
-];
-
-pub const CIRC_MAT: [u64; WIDTH] = [5, 7, 1, 3]; */
-
-// Width = 8
-pub const MDS_MATRIX: [[u64; WIDTH]; WIDTH] = [
-    [10, 14, 2, 6, 5, 7, 1, 3],
-    [8, 12, 2, 2, 4, 6, 1, 1],
-    [2, 6, 10, 14, 1, 3, 5, 7],
-    [2, 2, 8, 12, 1, 1, 4, 6],
-    [5, 7, 1, 3, 10, 14, 2, 6],
-    [4, 6, 1, 1, 8, 12, 2, 2],
-    [1, 3, 5, 7, 2, 6, 10, 14],
-    [1, 1, 4, 6, 2, 2, 8, 12],
-];
-
-pub const CIRC_MAT: [u64; WIDTH] = [10, 14, 2, 6, 5, 7, 1, 3];
@@ -1,23 +1,28 @@
-use crate::griffin::{NUMBER_OF_ROUNDS, WIDTH};
+use crate::griffin::GriffinParameters;
+use crate::width::Width;
 
 #[cfg(feature = "zk")]
 pub(crate) mod gadget;
 
 pub(crate) mod scalar;
 
-pub(crate) trait Griffin<T> {
-    fn non_liner_layer(&mut self, state: &mut[T; WIDTH]);
+pub(crate) trait Griffin<T, const W: usize>
+where
+    Width<W>: GriffinParameters<W>,
+{
+    fn non_liner_layer(&mut self, state: &mut[T; W]);
 
-    fn linear_layer(&mut self, state: &mut[T; WIDTH]);
+    fn linear_layer(&mut self, state: &mut[T; W]);
 
-    fn add_round_constants_leyer(&mut self, state: &mut[T; WIDTH], round: usize);
+    fn add_round_constants_leyer(&mut self, state: &mut[T; W], round: usize);
 
     fn sbox_layer(&mut self, value: &mut T);
 
     fn inverse_sbox(&mut self, value: &mut T);
 
-    fn perm(&mut self, state: &mut[T; WIDTH]) {
-        for round in 0..(NUMBER_OF_ROUNDS - 1) {
+    fn perm(&mut self, state: &mut[T; W]) {
+        self.linear_layer(state);
+        for round in 0..(<Width<W>>::NUMBER_OF_ROUNDS - 1) {
             self.non_liner_layer(state);
             self.linear_layer(state);
             self.add_round_constants_leyer(state, round);
@@ -3,36 +3,41 @@ use dusk_plonk::prelude::*;
 use dusk_safe::Safe;
 
 use crate::{
-    griffin::{
-        mds_matrix::CIRC_MAT, round_constants::{ALPHAS, BETAS}, D_INV, MDS_MATRIX, ROUND_CONSTANTS, WIDTH
-    },
+    griffin::{GriffinParameters, D_INV, WIDTH},
     news::NewableSafe,
+    width::Width,
 };
 
 use super::Griffin;
 
-/// GadgetPermutation of Griffin
-pub struct GadgetPermutation<'a> {
+/// GadgetPermutation of Griffin over a state of `W` elements
+pub struct GadgetPermutation<'a, const W: usize = WIDTH> {
     composer: &'a mut Composer,
 }
 
-impl<'a> GadgetPermutation<'a> {
+impl<'a, const W: usize> GadgetPermutation<'a, W> {
     /// Constructs a new `GadgetPermutation` with the constraint system.
     pub fn new(composer: &'a mut Composer) -> Self {
         Self { composer }
     }
 }
 
-impl<'a> NewableSafe<WIDTH> for GadgetPermutation<'a> {
-    type T<'b> = GadgetPermutation<'b>;
+impl<'a, const W: usize> NewableSafe<W> for GadgetPermutation<'a, W>
+where
+    Width<W>: GriffinParameters<W>,
+{
+    type T<'b> = GadgetPermutation<'b, W>;
 
     fn new(composer: &mut Composer) -> Self::T<'_> {
         Self::T::new(composer)
     }
 }
 
-impl<'a> Safe<Witness, WIDTH> for GadgetPermutation<'a> {
-    fn permute(&mut self, state: &mut [Witness; WIDTH]) {
+impl<'a, const W: usize> Safe<Witness, W> for GadgetPermutation<'a, W>
+where
+    Width<W>: GriffinParameters<W>,
+{
+    fn permute(&mut self, state: &mut [Witness; W]) {
         self.perm(state);
     }
 
@@ -48,8 +53,11 @@ impl<'a> Safe<Witness, WIDTH> for GadgetPermutation<'a> {
     }
 }
 
-impl<'a> Griffin<Witness> for GadgetPermutation<'a> {
-    fn non_liner_layer(&mut self, state: &mut [Witness; WIDTH]) {
+impl<'a, const W: usize> Griffin<Witness, W> for GadgetPermutation<'a, W>
+where
+    Width<W>: GriffinParameters<W>,
+{
+    fn non_liner_layer(&mut self, state: &mut [Witness; W]) {
         fn li(z0: &Witness, z1: &Witness, z2: &Witness, i: usize) -> Constraint {
             Constraint::new()
                 .left(BlsScalar::from((i - 1) as u64))
@@ -60,6 +68,9 @@ impl<'a> Griffin<Witness> for GadgetPermutation<'a> {
                 .d(*z2)
         }
 
+        // L_i takes x_{i-1} before the layer
+        let input = *state;
+
         self.inverse_sbox(&mut state[0]);
         self.sbox_layer(&mut state[1]);
 
@@ -67,61 +78,70 @@ impl<'a> Griffin<Witness> for GadgetPermutation<'a> {
         let mut l = self.composer.gate_add(constraint);
 
         let constraint = Constraint::new()
-            .left(ALPHAS[0])
+            .left(<Width<W>>::ALPHAS[0])
             .a(l)
             .mult(1)
             .a(l)
             .b(l)
-            .constant(BETAS[0]);
+            .constant(<Width<W>>::BETAS[0]);
         let op1 = self.composer.gate_add(constraint);
         let constraint = Constraint::new().mult(1).a(state[2]).b(op1);
         state[2] = self.composer.gate_add(constraint);
 
-        for i in 3..WIDTH {
-            let constraint = li(&state[0], &state[1], &state[i - 1], i);
+        for i in 3..W {
+            let constraint = li(&state[0], &state[1], &input[i - 1], i);
             l = self.composer.gate_add(constraint);
 
             let constraint = Constraint::new()
-                .left(ALPHAS[i - 2])
+                .left(<Width<W>>::ALPHAS[i - 2])
                 .a(l)
                 .mult(1)
                 .a(l)
                 .b(l)
-                .constant(BETAS[i - 2]);
+                .constant(<Width<W>>::BETAS[i - 2]);
             let op1 = self.composer.gate_add(constraint);
             let constraint = Constraint::new().mult(1).a(state[i]).b(op1);
             state[i] = self.composer.gate_add(constraint);
         }
     }
 
-    fn linear_layer(&mut self, state: &mut [Witness; WIDTH]) {
-        let mut sum = [Composer::ZERO; WIDTH];
+    fn linear_layer(&mut self, state: &mut [Witness; W]) {
+        let mut sum = [Composer::ZERO; W];
 
-        for i in 0..(WIDTH / 4) {
-            for j in 0..(WIDTH / 4) {
-                let mut off = 0;
-                if i != j {
-                    off = 4;
-                }
-                for k in 0..4 {
-                    for l in 0..4 {
-                        let constraint = Constraint::new()
-                            .left(CIRC_MAT[off + l])
-                            .a(state[4 * j + l])
-                            .right(1)
-                            .b(sum[4 * i + k]);
-
-                        sum[4 * i + k] = self.composer.gate_add(constraint);
-                    }
+        // sum[j] = sum_{k=0..W} ( MDS[j][k] * state[k] ), with three terms
+        // in the first gate and two more terms in each following gate
+        for j in 0..W {
+            let mds = &<Width<W>>::MDS_MATRIX[j];
+
+            let constraint = Constraint::new()
+                .left(mds[0])
+                .a(state[0])
+                .right(mds[1])
+                .b(state[1])
+                .fourth(mds[2])
+                .d(state[2]);
+
+            sum[j] = self.composer.gate_add(constraint);
+
+            for k in (3..W).step_by(2) {
+                let mut constraint = Constraint::new()
+                    .left(mds[k])
+                    .a(state[k])
+                    .fourth(1)
+                    .d(sum[j]);
+                if k + 1 < W {
+                    constraint = constraint.right(mds[k + 1]).b(state[k + 1]);
                 }
+
+                sum[j] = self.composer.gate_add(constraint);
             }
         }
         state.copy_from_slice(&sum);
     }
 
-    fn add_round_constants_leyer(&mut self, state: &mut [Witness; WIDTH], round: usize) {
+    fn add_round_constants_leyer(&mut self, state: &mut [Witness; W], round: usize) {
         state.iter_mut().enumerate().for_each(|(i, w)| {
-            let constant = ROUND_CONSTANTS[round * WIDTH + i];
+            let constant = <Width<W>>::ROUND_CONSTANTS[round * W + i];
             let constraint = Constraint::new().left(1).a(*w).constant(constant);
 
             *w = self.composer.gate_add(constraint);
@@ -144,15 +164,76 @@ impl<'a> Griffin<Witness> for GadgetPermutation<'a> {
         let tmp = tmp.pow_vartime(&D_INV);
 
         let wit = self.composer.append_witness(tmp);
-        // y^2
-        let constraint = Constraint::new().mult(1).a(wit).b(wit);
-        let tmp_wit = self.composer.gate_mul(constraint);
-        // y^4
-        let constraint = Constraint::new().mult(1).a(tmp_wit).b(tmp_wit);
-        let tmp_wit = self.composer.gate_mul(constraint);
-        // y^5
-        let constraint = Constraint::new().mult(1).a(tmp_wit).b(wit);
-        *value = self.composer.gate_mul(constraint);
+        assert_root(self.composer, wit, *value);
         *value = wit;
     }
 }
+
+/// Constrains `root^5 == value`, so that the inverse S-box can only be
+/// given the unique root of `value`.
+fn assert_root(composer: &mut Composer, root: Witness, value: Witness) {
+    // y^2
+    let constraint = Constraint::new().mult(1).a(root).b(root);
+    let tmp_wit = composer.gate_mul(constraint);
+    // y^4
+    let constraint = Constraint::new().mult(1).a(tmp_wit).b(tmp_wit);
+    let tmp_wit = composer.gate_mul(constraint);
+    // y^5
+    let constraint = Constraint::new().mult(1).a(tmp_wit).b(root);
+    let tmp_wit = composer.gate_mul(constraint);
+    // y^5 == x
+    composer.assert_equal(tmp_wit, value);
+}
+
+#[cfg(feature = "encryption")]
+impl<const W: usize> dusk_safe::Encryption<Witness, W>
+    for GadgetPermutation<'_, W>
+where
+    Width<W>: GriffinParameters<W>,
+{
+    fn subtract(&mut self, minuend: &Witness, subtrahend: &Witness) -> Witness {
+        let constraint = Constraint::new()
+            .left(1)
+            .a(*minuend)
+            .right(-BlsScalar::one())
+            .b(*subtrahend);
+        self.composer.gate_add(constraint)
+    }
+
+    fn is_equal(&mut self, lhs: &Witness, rhs: &Witness) -> bool {
+        self.composer.assert_equal(*lhs, *rhs);
+        // for the encryption to work we need to return true here, the proof
+        // creation will fail at a later point if the above assertion isn't met
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::griffin::ScalarPermutation;
+    use crate::kat::gadget::{check_gadget, check_root, RootConstraint};
+
+    const KAT: &str = include_str!("../../../kat/griffin.json");
+
+    struct InverseSbox;
+
+    impl RootConstraint for InverseSbox {
+        const D: u64 = 5;
+
+        fn assert_root(composer: &mut Composer, root: Witness, value: Witness) {
+            super::assert_root(composer, root, value);
+        }
+    }
+
+    #[test]
+    fn gadget_kat() {
+        check_gadget::<ScalarPermutation<4>, GadgetPermutation<4>, 4>(KAT, "griffin");
+        check_gadget::<ScalarPermutation<8>, GadgetPermutation<8>, 8>(KAT, "griffin");
+    }
+
+    #[test]
+    fn forged_root() {
+        check_root::<InverseSbox>("griffin");
+    }
+}
@@ -3,21 +3,28 @@ use dusk_safe::Safe;
 
 use super::Griffin;
 
-use crate::{griffin::{mds_matrix::CIRC_MAT, round_constants::{ALPHAS, BETAS}, D_INV, MDS_MATRIX, ROUND_CONSTANTS, WIDTH}, news::NewableScalar};
+use crate::{
+    griffin::{GriffinParameters, D_INV, WIDTH},
+    news::NewableScalar,
+    width::Width,
+};
 
-/// ScalarPermutation of Griffin
+/// ScalarPermutation of Griffin over a state of `W` elements
 #[derive(Default)]
-pub struct ScalarPermutation();
+pub struct ScalarPermutation<const W: usize = WIDTH>();
 
-impl NewableScalar for ScalarPermutation {
+impl<const W: usize> NewableScalar for ScalarPermutation<W> {
     /// Constructs a new `ScalarPermutation`.
     fn new() -> Self {
         Self()
     }
 }
 
-impl Safe<BlsScalar, WIDTH> for ScalarPermutation {
-    fn permute(&mut self, state: &mut [BlsScalar; WIDTH]) {
+impl<const W: usize> Safe<BlsScalar, W> for ScalarPermutation<W>
+where
+    Width<W>: GriffinParameters<W>,
+{
+    fn permute(&mut self, state: &mut [BlsScalar; W]) {
         self.perm(state);
     }
 
@@ -30,49 +37,47 @@ impl Safe<BlsScalar, WIDTH> for ScalarPermutation {
     }
 }
 
-impl Griffin<BlsScalar> for ScalarPermutation {
-    fn non_liner_layer(&mut self, state: &mut[BlsScalar; WIDTH]) {
+impl<const W: usize> Griffin<BlsScalar, W> for ScalarPermutation<W>
+where
+    Width<W>: GriffinParameters<W>,
+{
+    fn non_liner_layer(&mut self, state: &mut[BlsScalar; W]) {
         fn li(z0: &BlsScalar, z1: &BlsScalar, z2: &BlsScalar, i: usize) -> BlsScalar {
             let prod1 = z0.mul(&BlsScalar::from((i-1) as u64));
             let prod2 = prod1.add(z1);
             prod2.add(z2)
         }
 
+        // L_i takes x_{i-1} before the layer
+        let input = *state;
+
         self.inverse_sbox(&mut state[0]);
         self.sbox_layer(&mut state[1]);
 
         let mut l = li(&state[0], &state[1], &BlsScalar::zero(), 2);
 
-        state[2] = state[2] * (l.square() + ALPHAS[0] * l + BETAS[0]);
+        state[2] = state[2] * (l.square() + <Width<W>>::ALPHAS[0] * l + <Width<W>>::BETAS[0]);
 
-        for i in 3..WIDTH {
-            l = li(&state[0], &state[1], &state[i - 1], i);
-            state[i] = state[i] * (l.square() + ALPHAS[i - 2] * l + BETAS[i - 2]);
+        for i in 3..W {
+            l = li(&state[0], &state[1], &input[i - 1], i);
+            state[i] = state[i] * (l.square() + <Width<W>>::ALPHAS[i - 2] * l + <Width<W>>::BETAS[i - 2]);
         }
     }
 
-    fn linear_layer(&mut self, state: &mut[BlsScalar; WIDTH]) {
-        let mut sum = [BlsScalar::default(); WIDTH];
-
-        for i in 0..(WIDTH / 4) {
-            for j in 0..(WIDTH / 4) {
-                let mut off = 0;
-                if i != j {
-                    off = 4;
-                }
-                for k in 0..4 {
-                    for l in 0..4 {
-                        sum[4 * i + k] += BlsScalar::from(CIRC_MAT[off + l]) * state[4 * j + l];
-                    }
-                }
+    fn linear_layer(&mut self, state: &mut[BlsScalar; W]) {
+        let mut sum = [BlsScalar::default(); W];
+
+        for (j, value) in state.iter().enumerate() {
+            for k in 0..W {
+                sum[k] += BlsScalar::from(<Width<W>>::MDS_MATRIX[k][j]) * value;
             }
         }
         state.copy_from_slice(&sum);
     }
 
-    fn add_round_constants_leyer(&mut self, state: &mut[BlsScalar; WIDTH], round: usize) {
-        for j in 0..WIDTH {
-            state[j] += ROUND_CONSTANTS[round * WIDTH + j];
+    fn add_round_constants_leyer(&mut self, state: &mut[BlsScalar; W], round: usize) {
+        for j in 0..W {
+            state[j] += <Width<W>>::ROUND_CONSTANTS[round * W + j];
         }
     }
 
@@ -83,4 +88,37 @@ impl Griffin<BlsScalar> for ScalarPermutation {
     fn inverse_sbox(&mut self, value: &mut BlsScalar) {
         *value = value.pow(&D_INV);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "encryption")]
+impl<const W: usize> dusk_safe::Encryption<BlsScalar, W>
+    for ScalarPermutation<W>
+where
+    Width<W>: GriffinParameters<W>,
+{
+    fn subtract(
+        &mut self,
+        minuend: &BlsScalar,
+        subtrahend: &BlsScalar,
+    ) -> BlsScalar {
+        minuend - subtrahend
+    }
+
+    fn is_equal(&mut self, lhs: &BlsScalar, rhs: &BlsScalar) -> bool {
+        lhs == rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kat::check_scalar;
+
+    const KAT: &str = include_str!("../../../kat/griffin.json");
+
+    #[test]
+    fn kat() {
+        check_scalar::<ScalarPermutation<4>, 4>(KAT, "griffin");
+        check_scalar::<ScalarPermutation<8>, 8>(KAT, "griffin");
+    }
+}
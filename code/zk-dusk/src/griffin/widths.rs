@@ -0,0 +1,65 @@
+//! Griffin constants of each width, loaded from
+//! `assets/griffin/{round_constants,alphas,betas}_{W}.bin`.
+//!
+//! The MDS matrix is `M_4` for 4 elements and
+//! `[[2 * M_4, M_4], [M_4, 2 * M_4]]` for 8 elements.
+
+use dusk_bls12_381::BlsScalar;
+
+use super::GriffinParameters;
+use crate::width::{scalars, Width};
+
+impl GriffinParameters<4> for Width<4> {
+    const NUMBER_OF_ROUNDS: usize = 11;
+    const ROUND_CONSTANTS: &'static [BlsScalar] = &scalars::<40>(include_bytes!(
+        "../../assets/griffin/round_constants_4.bin"
+    ));
+    const ALPHAS: &'static [BlsScalar] =
+        &scalars::<2>(include_bytes!("../../assets/griffin/alphas_4.bin"));
+    const BETAS: &'static [BlsScalar] =
+        &scalars::<2>(include_bytes!("../../assets/griffin/betas_4.bin"));
+    const MDS_MATRIX: [[u64; 4]; 4] =
+        [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+}
+
+impl GriffinParameters<8> for Width<8> {
+    const NUMBER_OF_ROUNDS: usize = 9;
+    const ROUND_CONSTANTS: &'static [BlsScalar] = &scalars::<64>(include_bytes!(
+        "../../assets/griffin/round_constants_8.bin"
+    ));
+    const ALPHAS: &'static [BlsScalar] =
+        &scalars::<6>(include_bytes!("../../assets/griffin/alphas_8.bin"));
+    const BETAS: &'static [BlsScalar] =
+        &scalars::<6>(include_bytes!("../../assets/griffin/betas_8.bin"));
+    const MDS_MATRIX: [[u64; 8]; 8] = [
+        [10, 14, 2, 6, 5, 7, 1, 3],
+        [8, 12, 2, 2, 4, 6, 1, 1],
+        [2, 6, 10, 14, 1, 3, 5, 7],
+        [2, 2, 8, 12, 1, 1, 4, 6],
+        [5, 7, 1, 3, 10, 14, 2, 6],
+        [4, 6, 1, 1, 8, 12, 2, 2],
+        [1, 3, 5, 7, 2, 6, 10, 14],
+        [1, 1, 4, 6, 2, 2, 8, 12],
+    ];
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check_constants<const W: usize>()
+    where
+        Width<W>: GriffinParameters<W>,
+    {
+        let constants = <Width<W>>::ROUND_CONSTANTS;
+        assert_eq!(constants.len(), W * (<Width<W>>::NUMBER_OF_ROUNDS - 1));
+        assert_eq!(<Width<W>>::ALPHAS.len(), W - 2);
+        assert_eq!(<Width<W>>::BETAS.len(), W - 2);
+    }
+
+    #[test]
+    fn test_constants() {
+        check_constants::<4>();
+        check_constants::<8>();
+    }
+}
@@ -10,52 +10,47 @@
 //! ## Parameters
 //!
 //! - `p = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001`
-//! - Permutation container `WIDTH` is 5 field elements
+//! - Permutation container of `W` field elements, with `W` one of 4, 5, 6
+//!   and 8 (the default [`WIDTH`]), selected by [`Width`](crate::Width)
 //! - 8 full rounds: 4 full rounds at the beginning and 4 full rounds at the
-//!   end, and each full round has `WIDTH` quintic S-Boxes.
-//! - 60 partial rounds: each partial round has `WIDTH - 1` identity function
-//!   and one quintic S-Box.
-//! - 340 round constants which are generated using [this algorithm](https://extgit.iaik.tugraz.at/krypto/hadesmimc/blob/master/code/calc_round_numbers.py)
+//!   end, and each full round has `W` quintic S-Boxes.
+//! - 56, 60, 57 and 57 partial rounds for `W` of 4, 5, 6 and 8: each partial
+//!   round has `W - 1` identity function and one quintic S-Box.
+//! - `W * (8 + PARTIAL_ROUNDS)` round constants which are generated using [this algorithm](https://extgit.iaik.tugraz.at/krypto/hadesmimc/blob/master/code/calc_round_numbers.py)
 //! - The MDS matrix is a cauchy matrix, the method used to generate it, is
 //!   noted in section "Concrete Instantiations Poseidon and Starkad"
 
-mod mds_matrix;
 mod permutation;
-mod round_constants;
+mod widths;
 
-use mds_matrix::MDS_MATRIX;
-use round_constants::ROUND_CONSTANTS;
-
-// WIDTH=5 -> FULL=8
-// WIDTH=4 -> FULL=8
-// WIDTH=6 -> FULL=8
-const FULL_ROUNDS: usize = 8;
-
-//WIDTH=5 -> PARTIAL=60
-// WIDTH=4 -> PARTIAL=56
-// WIDTH=6 -> PARTIAL=57
-const PARTIAL_ROUNDS: usize = 57;
+use dusk_bls12_381::BlsScalar;
 
 /// The amount of field elements that fit into the hades permutation container
+/// by default
 pub const WIDTH: usize = 8;
 
+/// Parameters of the Hades permutation for a state width, implemented for
+/// [`Width`](crate::Width) 4, 5, 6 and 8.
+pub trait HadesParameters<const W: usize> {
+    /// Number of full rounds, half of them at the beginning and half at the
+    /// end.
+    const FULL_ROUNDS: usize;
+
+    /// Number of partial rounds.
+    const PARTIAL_ROUNDS: usize;
+
+    /// The `W` round constants of each of the `FULL_ROUNDS + PARTIAL_ROUNDS`
+    /// rounds.
+    const ROUND_CONSTANTS: &'static [[BlsScalar; W]];
+
+    /// The `W x W` cauchy MDS matrix.
+    const MDS_MATRIX: [[BlsScalar; W]; W];
+}
+
 #[cfg(feature = "zk")]
 pub use permutation::gadget::GadgetPermutation;
 pub use permutation::scalar::ScalarPermutation;
 
-const fn u64_from_buffer<const N: usize>(buf: &[u8; N], i: usize) -> u64 {
-    u64::from_le_bytes([
-        buf[i],
-        buf[i + 1],
-        buf[i + 2],
-        buf[i + 3],
-        buf[i + 4],
-        buf[i + 5],
-        buf[i + 6],
-        buf[i + 7],
-    ])
-}
-
 // Test the sponge with an internal hades permutation against some predefined
 // input and output values. The sponge is initialized with the capacity element
 // being zero and the padding is one `BlsScalar::one()`.
@@ -72,12 +67,15 @@ mod tests {
     use crate::hades::{ScalarPermutation, WIDTH};
     use crate::news::NewableScalar;
 
+    // The test vectors are those of the original Hades252 over 5 elements
+    const TEST_WIDTH: usize = 5;
+
     #[derive(Default, Debug, Clone, Copy, PartialEq)]
     struct Test();
 
-    impl Safe<BlsScalar, WIDTH> for Test {
+    impl Safe<BlsScalar, TEST_WIDTH> for Test {
         // apply hades permutation
-        fn permute(&mut self, state: &mut [BlsScalar; WIDTH]) {
+        fn permute(&mut self, state: &mut [BlsScalar; TEST_WIDTH]) {
             ScalarPermutation::new().permute(state);
         }
 
@@ -169,4 +167,38 @@ mod tests {
         format!("{:?}", create_poseidon_hash(&test_inputs[..10]))
     );
     }
+
+    #[test]
+    fn number_of_rounds() {
+        use super::HadesParameters;
+        use crate::Width;
+        use zk_lib::parameters::poseidon;
+
+        fn check<const W: usize>()
+        where
+            Width<W>: HadesParameters<W>,
+        {
+            // Bls12-381 scalar field of 255 bits, quintic S-Box and 128 bits
+            // of security
+            let full = <Width<W>>::FULL_ROUNDS;
+            let partial = <Width<W>>::PARTIAL_ROUNDS;
+            assert!(poseidon::is_secure(255, W, 5, 128, full, partial));
+
+            let (min_full, min_partial) = poseidon::number_of_rounds(255, W, 5, 128);
+            assert_eq!(full, min_full);
+            assert!(partial >= min_partial);
+        }
+
+        // Width 5 keeps the 60 partial rounds of the original Hades252, a
+        // margin over the 56 rounds of the calculator
+        check::<4>();
+        check::<5>();
+        check::<6>();
+        check::<8>();
+
+        assert_eq!(
+            poseidon::number_of_rounds(255, WIDTH, 5, 128),
+            (<Width<WIDTH>>::FULL_ROUNDS, <Width<WIDTH>>::PARTIAL_ROUNDS)
+        );
+    }
 }
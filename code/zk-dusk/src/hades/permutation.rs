@@ -13,7 +13,8 @@
 //! scalar Field of the bls12_381 curve so over a modulus
 //! `p = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001`.
 
-use crate::hades::{FULL_ROUNDS, PARTIAL_ROUNDS, WIDTH};
+use crate::hades::HadesParameters;
+use crate::width::Width;
 
 /// Hades permutation struct operating in a plonk-circuit.
 #[cfg(feature = "zk")]
@@ -31,8 +32,13 @@ pub(crate) mod scalar;
 ///
 /// This structure allows to minimize the number of non-linear ops while
 /// maintaining the security.
-pub(crate) trait Hades<T> {
-    const ROUNDS: usize = FULL_ROUNDS + PARTIAL_ROUNDS;
+pub(crate) trait Hades<T, const W: usize>
+where
+    Width<W>: HadesParameters<W>,
+{
+    const FULL_ROUNDS: usize = <Width<W>>::FULL_ROUNDS;
+    const PARTIAL_ROUNDS: usize = <Width<W>>::PARTIAL_ROUNDS;
+    const ROUNDS: usize = Self::FULL_ROUNDS + Self::PARTIAL_ROUNDS;
 
     /// Add round constants to the state.
     ///
@@ -41,7 +47,7 @@ pub(crate) trait Hades<T> {
     ///
     /// Basically it allows to destroy any connection between the inputs and the
     /// outputs of the function.
-    fn add_round_constants(&mut self, round: usize, state: &mut [T; WIDTH]);
+    fn add_round_constants(&mut self, round: usize, state: &mut [T; W]);
 
     /// Computes `input ^ 5 (mod p)`
     ///
@@ -51,7 +57,7 @@ pub(crate) trait Hades<T> {
     fn quintic_s_box(&mut self, value: &mut T);
 
     /// Multiply the MDS matrix with the state.
-    fn mul_matrix(&mut self, round: usize, state: &mut [T; WIDTH]);
+    fn mul_matrix(&mut self, round: usize, state: &mut [T; W]);
 
     /// Applies a `Partial Round` also known as a `Partial S-Box layer` to a set
     /// of inputs.
@@ -62,12 +68,12 @@ pub(crate) trait Hades<T> {
     ///   state** generated from the first step.
     /// - Mix Layer: Multiplies the output state from the second step by the
     ///   `MDS_MATRIX`.
-    fn apply_partial_round(&mut self, round: usize, state: &mut [T; WIDTH]) {
+    fn apply_partial_round(&mut self, round: usize, state: &mut [T; W]) {
         // Add round constants to each state element
         self.add_round_constants(round, state);
 
         // Then apply quintic s-box to the last element of the state
-        self.quintic_s_box(&mut state[WIDTH - 1]);
+        self.quintic_s_box(&mut state[W - 1]);
 
         // Multiply this result by the MDS matrix
         self.mul_matrix(round, state);
@@ -82,7 +88,7 @@ pub(crate) trait Hades<T> {
     ///   generated from the first step.
     /// - Mix Layer: Multiplies the output state from the second step by the
     ///   `MDS_MATRIX`.
-    fn apply_full_round(&mut self, round: usize, state: &mut [T; WIDTH]) {
+    fn apply_full_round(&mut self, round: usize, state: &mut [T; W]) {
         // Add round constants to each state element
         self.add_round_constants(round, state);
 
@@ -104,21 +110,21 @@ pub(crate) trait Hades<T> {
     ///
     /// This structure allows to minimize the number of non-linear ops while
     /// maintaining the security.
-    fn perm(&mut self, state: &mut [T; WIDTH]) {
+    fn perm(&mut self, state: &mut [T; W]) {
         // Apply R_f full rounds
-        for round in 0..FULL_ROUNDS / 2 {
+        for round in 0..Self::FULL_ROUNDS / 2 {
             self.apply_full_round(round, state);
         }
 
         // Apply R_P partial rounds
-        for round in 0..PARTIAL_ROUNDS {
-            self.apply_partial_round(round + FULL_ROUNDS / 2, state);
+        for round in 0..Self::PARTIAL_ROUNDS {
+            self.apply_partial_round(round + Self::FULL_ROUNDS / 2, state);
         }
 
         // Apply R_f full rounds
-        for round in 0..FULL_ROUNDS / 2 {
+        for round in 0..Self::FULL_ROUNDS / 2 {
             self.apply_full_round(
-                round + FULL_ROUNDS / 2 + PARTIAL_ROUNDS,
+                round + Self::FULL_ROUNDS / 2 + Self::PARTIAL_ROUNDS,
                 state,
             );
         }
@@ -10,34 +10,42 @@ use dusk_safe::Safe;
 
 use crate::news::NewableSafe;
 
-use crate::hades::{MDS_MATRIX, ROUND_CONSTANTS, WIDTH};
+use crate::hades::{HadesParameters, WIDTH};
+use crate::width::Width;
 
 use super::Hades;
 
-/// An implementation for the [`Hades`] permutation operating on [`Witness`]es.
+/// An implementation for the [`Hades`] permutation operating on [`Witness`]es,
+/// over a state of `W` elements.
 /// Requires a reference to a plonk circuit [`Composer`].
-pub struct GadgetPermutation<'a> {
+pub struct GadgetPermutation<'a, const W: usize = WIDTH> {
     /// A reference to the constraint system used by the gadgets
     composer: &'a mut Composer,
 }
 
-impl<'a> GadgetPermutation<'a> {
+impl<'a, const W: usize> GadgetPermutation<'a, W> {
     /// Constructs a new `GadgetPermutation` with the constraint system.
     pub fn new(composer: &'a mut Composer) -> Self {
         Self { composer }
     }
 }
 
-impl<'a> NewableSafe<WIDTH> for GadgetPermutation<'a> {
-    type T<'b> = GadgetPermutation<'b>;
+impl<'a, const W: usize> NewableSafe<W> for GadgetPermutation<'a, W>
+where
+    Width<W>: HadesParameters<W>,
+{
+    type T<'b> = GadgetPermutation<'b, W>;
 
     fn new(composer: &mut Composer) -> Self::T<'_> {
         Self::T::new(composer)
     }
 }
 
-impl<'a> Safe<Witness, WIDTH> for GadgetPermutation<'a> {
-    fn permute(&mut self, state: &mut [Witness; WIDTH]) {
+impl<'a, const W: usize> Safe<Witness, W> for GadgetPermutation<'a, W>
+where
+    Width<W>: HadesParameters<W>,
+{
+    fn permute(&mut self, state: &mut [Witness; W]) {
         self.perm(state);
     }
 
@@ -53,14 +61,17 @@ impl<'a> Safe<Witness, WIDTH> for GadgetPermutation<'a> {
     }
 }
 
-impl<'a> Hades<Witness> for GadgetPermutation<'a> {
-    fn add_round_constants(&mut self, round: usize, state: &mut [Witness; WIDTH]) {
+impl<'a, const W: usize> Hades<Witness, W> for GadgetPermutation<'a, W>
+where
+    Width<W>: HadesParameters<W>,
+{
+    fn add_round_constants(&mut self, round: usize, state: &mut [Witness; W]) {
         // To save constraints we only add the constants here in the first
         // round. The remaining constants will be added in the matrix
         // multiplication.
         if round == 0 {
             state.iter_mut().enumerate().for_each(|(i, w)| {
-                let constant = ROUND_CONSTANTS[0][i];
+                let constant = <Width<W>>::ROUND_CONSTANTS[0][i];
                 let constraint = Constraint::new().left(1).a(*w).constant(constant);
 
                 *w = self.composer.gate_add(constraint);
@@ -80,15 +91,15 @@ impl<'a> Hades<Witness> for GadgetPermutation<'a> {
     }
 
     /// Adds a constraint for each matrix coefficient multiplication
-    fn mul_matrix(&mut self, round: usize, state: &mut [Witness; WIDTH]) {
-        let mut result = [Composer::ZERO; WIDTH];
+    fn mul_matrix(&mut self, round: usize, state: &mut [Witness; W]) {
+        let mut result = [Composer::ZERO; W];
 
-        // Implementation optimized for WIDTH = 5
-        //
         // The resulting array `r` will be defined as
-        // r[x] = sum_{j=0..WIDTH} ( MDS[x][j] * state[j] ) + c
+        // r[x] = sum_{j=0..W} ( MDS[x][j] * state[j] ) + c
         // with c being the constant for the next round.
         //
+        // The first gate adds three terms of the sum:
+        //
         // q_l = MDS[x][0]
         // q_r = MDS[x][1]
         // q_4 = MDS[x][2]
@@ -97,140 +108,61 @@ impl<'a> Hades<Witness> for GadgetPermutation<'a> {
         // w_4 = state[2]
         // r[x] = q_l · w_l + q_r · w_r + q_4 · w_4;
         //
-        // q_l = MDS[x][3]
-        // q_r = MDS[x][4]
+        // and every following gate adds two more terms, and c in the last
+        // one:
+        //
+        // q_l = MDS[x][k]
+        // q_r = MDS[x][k + 1]
         // q_4 = 1
-        // w_l = state[3]
-        // w_r = state[4]
+        // w_l = state[k]
+        // w_r = state[k + 1]
         // w_4 = r[x]
         // r[x] = q_l · w_l + q_r · w_r + q_4 · w_4 + c;
-        for j in 0..WIDTH {
+        for j in 0..W {
+            let mds = &<Width<W>>::MDS_MATRIX[j];
+
             // c is the next round's constant and hence zero for the last round.
             let c = match round + 1 < Self::ROUNDS {
-                true => ROUND_CONSTANTS[round + 1][j],
+                true => <Width<W>>::ROUND_CONSTANTS[round + 1][j],
                 false => BlsScalar::zero(),
             };
 
-            // WIDTH = 5
-            /* let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][0])
-                .a(state[0])
-                .right(MDS_MATRIX[j][1])
-                .b(state[1])
-                .fourth(MDS_MATRIX[j][2])
-                .d(state[2]);
-
-            result[j] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][3])
-                .a(state[3])
-                .right(MDS_MATRIX[j][4])
-                .b(state[4])
-                .fourth(1)
-                .d(result[j])
-                .constant(c);
-
-            result[j] = self.composer.gate_add(constraint); */
-
-            // WIDTH = 4
-            /* let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][0])
-                .a(state[0])
-                .right(MDS_MATRIX[j][1])
-                .b(state[1])
-                .fourth(MDS_MATRIX[j][2])
-                .d(state[2]);
-
-            result[j] = self.composer.gate_add(constraint);
-
             let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][3])
-                .a(state[3])
-                .right(1)
-                .b(result[j])
-                .constant(c);
-
-            result[j] = self.composer.gate_add(constraint); */
-
-            // Width=6
-            /* let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][0])
+                .left(mds[0])
                 .a(state[0])
-                .right(MDS_MATRIX[j][1])
+                .right(mds[1])
                 .b(state[1])
-                .fourth(MDS_MATRIX[j][2])
+                .fourth(mds[2])
                 .d(state[2]);
 
             result[j] = self.composer.gate_add(constraint);
 
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][3])
-                .a(state[3])
-                .right(MDS_MATRIX[j][4])
-                .b(state[4])
-                .fourth(1)
-                .d(result[j]);
-
-            result[j] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][5])
-                .a(state[5])
-                .right(1)
-                .b(result[j])
-                .constant(c);
-
-            result[j] = self.composer.gate_add(constraint);
-        }
-        state.copy_from_slice(&result); */
-
-        // Width = 8
-        let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][0])
-                .a(state[0])
-                .right(MDS_MATRIX[j][1])
-                .b(state[1])
-                .fourth(MDS_MATRIX[j][2])
-                .d(state[2]);
-
-            result[j] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][3])
-                .a(state[3])
-                .right(MDS_MATRIX[j][4])
-                .b(state[4])
-                .fourth(1)
-                .d(result[j]);
-
-            result[j] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][5])
-                .a(state[5])
-                .right(MDS_MATRIX[j][6])
-                .b(state[6])
-                .fourth(1)
-                .d(result[j])
-                .constant(c);
-
-            result[j] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][7])
-                .a(state[7])
-                .right(1)
-                .b(result[j]);
-
-            result[j] = self.composer.gate_add(constraint);
+            for k in (3..W).step_by(2) {
+                let mut constraint = Constraint::new()
+                    .left(mds[k])
+                    .a(state[k])
+                    .fourth(1)
+                    .d(result[j]);
+                if k + 1 < W {
+                    constraint = constraint.right(mds[k + 1]).b(state[k + 1]);
+                }
+                if k + 2 >= W {
+                    constraint = constraint.constant(c);
+                }
+
+                result[j] = self.composer.gate_add(constraint);
+            }
         }
         state.copy_from_slice(&result);
     }
 }
 
 #[cfg(feature = "encryption")]
-impl dusk_safe::Encryption<Witness, WIDTH> for GadgetPermutation<'_> {
+impl<const W: usize> dusk_safe::Encryption<Witness, W>
+    for GadgetPermutation<'_, W>
+where
+    Width<W>: HadesParameters<W>,
+{
     fn subtract(&mut self, minuend: &Witness, subtrahend: &Witness) -> Witness {
         let constraint = Constraint::new()
             .left(1)
@@ -260,24 +192,35 @@ mod tests {
     use rand::rngs::StdRng;
     use rand::SeedableRng;
 
-    #[derive(Default)]
-    struct TestCircuit {
-        i: [BlsScalar; WIDTH],
-        o: [BlsScalar; WIDTH],
+    struct TestCircuit<const W: usize> {
+        i: [BlsScalar; W],
+        o: [BlsScalar; W],
+    }
+
+    impl<const W: usize> Default for TestCircuit<W> {
+        fn default() -> Self {
+            Self {
+                i: [BlsScalar::zero(); W],
+                o: [BlsScalar::zero(); W],
+            }
+        }
     }
 
-    impl Circuit for TestCircuit {
+    impl<const W: usize> Circuit for TestCircuit<W>
+    where
+        Width<W>: HadesParameters<W>,
+    {
         fn circuit(&self, composer: &mut Composer) -> Result<(), Error> {
             let zero = Composer::ZERO;
 
-            let mut perm: [Witness; WIDTH] = [zero; WIDTH];
+            let mut perm: [Witness; W] = [zero; W];
 
-            let mut i_wit: [Witness; WIDTH] = [zero; WIDTH];
+            let mut i_wit: [Witness; W] = [zero; W];
             self.i.iter().zip(i_wit.iter_mut()).for_each(|(i, w)| {
                 *w = composer.append_witness(*i);
             });
 
-            let mut o_wit: [Witness; WIDTH] = [zero; WIDTH];
+            let mut o_wit: [Witness; W] = [zero; W];
             self.o.iter().zip(o_wit.iter_mut()).for_each(|(o, w)| {
                 *w = composer.append_witness(*o);
             });
@@ -298,8 +241,11 @@ mod tests {
     }
 
     /// Generate a random input and perform a permutation
-    fn hades() -> ([BlsScalar; WIDTH], [BlsScalar; WIDTH]) {
-        let mut input = [BlsScalar::zero(); WIDTH];
+    fn hades<const W: usize>() -> ([BlsScalar; W], [BlsScalar; W])
+    where
+        Width<W>: HadesParameters<W>,
+    {
+        let mut input = [BlsScalar::zero(); W];
 
         let mut rng = StdRng::seed_from_u64(0xbeef);
 
@@ -307,7 +253,7 @@ mod tests {
             .iter_mut()
             .for_each(|s| *s = BlsScalar::random(&mut rng));
 
-        let mut output = [BlsScalar::zero(); WIDTH];
+        let mut output = [BlsScalar::zero(); W];
 
         output.copy_from_slice(&input);
         ScalarPermutation::new().permute(&mut output);
@@ -316,22 +262,27 @@ mod tests {
     }
 
     /// Setup the test circuit prover and verifier
-    fn setup() -> Result<(Prover, Verifier), Error> {
-        const CAPACITY: usize = 1 << 10;
+    fn setup<const W: usize>() -> Result<(Prover, Verifier), Error>
+    where
+        Width<W>: HadesParameters<W>,
+    {
+        const CAPACITY: usize = 1 << 12;
 
         let mut rng = StdRng::seed_from_u64(0xbeef);
 
         let pp = PublicParameters::setup(CAPACITY, &mut rng)?;
         let label = b"hades_gadget_tester";
 
-        Compiler::compile::<TestCircuit>(&pp, label)
+        Compiler::compile::<TestCircuit<W>>(&pp, label)
     }
 
-    #[test]
-    fn preimage() -> Result<(), Error> {
-        let (prover, verifier) = setup()?;
+    fn check_preimage<const W: usize>() -> Result<(), Error>
+    where
+        Width<W>: HadesParameters<W>,
+    {
+        let (prover, verifier) = setup::<W>()?;
 
-        let (i, o) = hades();
+        let (i, o) = hades::<W>();
 
         let circuit = TestCircuit { i, o };
         let mut rng = StdRng::seed_from_u64(0xbeef);
@@ -345,9 +296,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn preimage() -> Result<(), Error> {
+        check_preimage::<4>()?;
+        check_preimage::<5>()?;
+        check_preimage::<6>()?;
+        check_preimage::<8>()
+    }
+
     #[test]
     fn preimage_constant() -> Result<(), Error> {
-        let (prover, verifier) = setup()?;
+        let (prover, verifier) = setup::<WIDTH>()?;
 
         // Prepare input & output
         let i = [BlsScalar::from(5000u64); WIDTH];
@@ -368,7 +327,7 @@ mod tests {
 
     #[test]
     fn preimage_fails() -> Result<(), Error> {
-        let (prover, _) = setup()?;
+        let (prover, _) = setup::<WIDTH>()?;
 
         // Generate [31, 0, 0, 0, 0] as real input to the perm but build the
         // proof with [31, 31, 31, 31, 31]. This should fail on verification
@@ -9,23 +9,28 @@ use dusk_safe::Safe;
 
 use super::Hades;
 use crate::{
-    hades::{MDS_MATRIX, ROUND_CONSTANTS, WIDTH},
+    hades::{HadesParameters, WIDTH},
     news::NewableScalar,
+    width::Width,
 };
 
-/// An implementation of the [`Permutation`] for `BlsScalar` as input values.
+/// An implementation of the [`Permutation`] for `BlsScalar` as input values,
+/// over a state of `W` elements.
 #[derive(Default)]
-pub struct ScalarPermutation();
+pub struct ScalarPermutation<const W: usize = WIDTH>();
 
-impl NewableScalar for ScalarPermutation {
+impl<const W: usize> NewableScalar for ScalarPermutation<W> {
     /// Constructs a new `ScalarPermutation`.
     fn new() -> Self {
         Self()
     }
 }
 
-impl Safe<BlsScalar, WIDTH> for ScalarPermutation {
-    fn permute(&mut self, state: &mut [BlsScalar; WIDTH]) {
+impl<const W: usize> Safe<BlsScalar, W> for ScalarPermutation<W>
+where
+    Width<W>: HadesParameters<W>,
+{
+    fn permute(&mut self, state: &mut [BlsScalar; W]) {
         self.perm(state);
     }
 
@@ -38,28 +43,31 @@ impl Safe<BlsScalar, WIDTH> for ScalarPermutation {
     }
 }
 
-impl Hades<BlsScalar> for ScalarPermutation {
+impl<const W: usize> Hades<BlsScalar, W> for ScalarPermutation<W>
+where
+    Width<W>: HadesParameters<W>,
+{
     fn add_round_constants(
         &mut self,
         round: usize,
-        state: &mut [BlsScalar; WIDTH],
+        state: &mut [BlsScalar; W],
     ) {
         state
             .iter_mut()
             .enumerate()
-            .for_each(|(i, s)| *s += ROUND_CONSTANTS[round][i]);
+            .for_each(|(i, s)| *s += <Width<W>>::ROUND_CONSTANTS[round][i]);
     }
 
     fn quintic_s_box(&mut self, value: &mut BlsScalar) {
         *value = value.square().square() * *value;
     }
 
-    fn mul_matrix(&mut self, _round: usize, state: &mut [BlsScalar; WIDTH]) {
-        let mut result = [BlsScalar::zero(); WIDTH];
+    fn mul_matrix(&mut self, _round: usize, state: &mut [BlsScalar; W]) {
+        let mut result = [BlsScalar::zero(); W];
 
         for (j, value) in state.iter().enumerate() {
-            for k in 0..WIDTH {
-                result[k] += MDS_MATRIX[k][j] * value;
+            for k in 0..W {
+                result[k] += <Width<W>>::MDS_MATRIX[k][j] * value;
             }
         }
 
@@ -68,7 +76,11 @@ impl Hades<BlsScalar> for ScalarPermutation {
 }
 
 #[cfg(feature = "encryption")]
-impl dusk_safe::Encryption<BlsScalar, WIDTH> for ScalarPermutation {
+impl<const W: usize> dusk_safe::Encryption<BlsScalar, W>
+    for ScalarPermutation<W>
+where
+    Width<W>: HadesParameters<W>,
+{
     fn subtract(
         &mut self,
         minuend: &BlsScalar,
@@ -0,0 +1,87 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! This module loads the `ROUND_CONSTANTS` of each width from
+//! `assets/poseidon/arc_{W}.bin` and its `MDS_MATRIX` from
+//! `assets/poseidon/mds_{W}.bin`.
+//!
+//! The constants were originally computed using:
+//! https://extgit.iaik.tugraz.at/krypto/hadesmimc/blob/master/code/calc_round_numbers.py
+//! and then mapped onto `BlsScalar` in the Bls12_381 scalar field.
+
+use dusk_bls12_381::BlsScalar;
+
+use super::HadesParameters;
+use crate::width::{table, Width};
+
+impl HadesParameters<4> for Width<4> {
+    const FULL_ROUNDS: usize = 8;
+    const PARTIAL_ROUNDS: usize = 56;
+    const ROUND_CONSTANTS: &'static [[BlsScalar; 4]] =
+        &table::<4, 64>(include_bytes!("../../assets/poseidon/arc_4.bin"));
+    const MDS_MATRIX: [[BlsScalar; 4]; 4] =
+        table(include_bytes!("../../assets/poseidon/mds_4.bin"));
+}
+
+impl HadesParameters<5> for Width<5> {
+    const FULL_ROUNDS: usize = 8;
+    const PARTIAL_ROUNDS: usize = 60;
+    const ROUND_CONSTANTS: &'static [[BlsScalar; 5]] =
+        &table::<5, 68>(include_bytes!("../../assets/poseidon/arc_5.bin"));
+    const MDS_MATRIX: [[BlsScalar; 5]; 5] =
+        table(include_bytes!("../../assets/poseidon/mds_5.bin"));
+}
+
+impl HadesParameters<6> for Width<6> {
+    const FULL_ROUNDS: usize = 8;
+    const PARTIAL_ROUNDS: usize = 57;
+    const ROUND_CONSTANTS: &'static [[BlsScalar; 6]] =
+        &table::<6, 65>(include_bytes!("../../assets/poseidon/arc_6.bin"));
+    const MDS_MATRIX: [[BlsScalar; 6]; 6] =
+        table(include_bytes!("../../assets/poseidon/mds_6.bin"));
+}
+
+impl HadesParameters<8> for Width<8> {
+    const FULL_ROUNDS: usize = 8;
+    const PARTIAL_ROUNDS: usize = 57;
+    const ROUND_CONSTANTS: &'static [[BlsScalar; 8]] =
+        &table::<8, 65>(include_bytes!("../../assets/poseidon/arc_8.bin"));
+    const MDS_MATRIX: [[BlsScalar; 8]; 8] =
+        table(include_bytes!("../../assets/poseidon/mds_8.bin"));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check_round_constants<const W: usize>()
+    where
+        Width<W>: HadesParameters<W>,
+    {
+        let constants = <Width<W>>::ROUND_CONSTANTS;
+        assert_eq!(
+            constants.len(),
+            <Width<W>>::FULL_ROUNDS + <Width<W>>::PARTIAL_ROUNDS
+        );
+
+        // Check each element is non-zero
+        let zero = BlsScalar::zero();
+        let has_zero = constants.iter().flatten().any(|&x| x == zero);
+        for ctant in constants.iter().flatten() {
+            let bytes = ctant.to_bytes();
+            assert!(&BlsScalar::from_bytes(&bytes).unwrap() == ctant);
+        }
+        assert!(!has_zero);
+    }
+
+    #[test]
+    fn test_round_constants() {
+        check_round_constants::<4>();
+        check_round_constants::<5>();
+        check_round_constants::<6>();
+        check_round_constants::<8>();
+    }
+}
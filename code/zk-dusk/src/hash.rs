@@ -1,14 +1,21 @@
 #[cfg(feature = "zk")]
 pub mod gadget;
 pub mod scalar;
+pub mod stream;
 
 use crate::Error;
 
 use alloc::vec::Vec;
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::JubJubScalar;
 use dusk_safe::Call;
+use sha2::{Digest, Sha256};
 
 /// The Domain Separation for Poseidon
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// Domains compare equal when they hash the same, so `Merkle(2)` and
+/// `Merkle(4)` are equal to [`Domain::Merkle2`] and [`Domain::Merkle4`].
+#[derive(Debug, Clone, Copy)]
 pub enum Domain {
     /// Domain to specify hashing of 4-arity merkle tree.
     /// Note that selecting this domain-separator means that the total hash
@@ -20,10 +27,66 @@ pub enum Domain {
     /// input must be exactly 2 `BlsScalar` long, and any empty slots of the
     /// merkle tree level need to be filled with the zero element.
     Merkle2,
+    /// Domain to specify hashing of a merkle tree of the given arity, from 2
+    /// to 64, e.g. `Merkle(8)` or `Merkle(16)` for the wider permutations.
+    /// Note that selecting this domain-separator means that the total hash
+    /// input must be exactly `arity` `BlsScalar` long, and any empty slots of
+    /// the merkle tree level need to be filled with the zero element.
+    /// `Merkle(2)` and `Merkle(4)` hash as [`Domain::Merkle2`] and
+    /// [`Domain::Merkle4`].
+    Merkle(usize),
     /// Domain to specify hash used for encryption
     Encryption,
     /// Domain to specify hash for any other input
     Other,
+    /// Domain to specify hash for any input, with an application-defined
+    /// domain-separator, so that protocols using the same permutation don't
+    /// collide. The separators of the other domains, 0, 2^32 and 2^n - 1 for
+    /// n from 2 to 64, are reserved and return an
+    /// [`Error::InvalidIOPattern`]. See [`Domain::from_label`] for a
+    /// separator derived from a label.
+    Custom(u64),
+}
+
+impl Domain {
+    /// Create a [`Domain::Custom`] with the domain-separator derived from a
+    /// label, the first 8 bytes of its SHA-256 hash read as big-endian, e.g.
+    /// `Domain::from_label(b"my-protocol-v1")`.
+    pub fn from_label(label: impl AsRef<[u8]>) -> Self {
+        let hash = Sha256::digest(label.as_ref());
+        let mut separator = [0u8; 8];
+        separator.copy_from_slice(&hash[..8]);
+
+        Domain::Custom(u64::from_be_bytes(separator))
+    }
+}
+
+impl Domain {
+    // `Merkle(2)` and `Merkle(4)` are `Merkle2` and `Merkle4` under another
+    // name.
+    fn normalize(self) -> Self {
+        match self {
+            Domain::Merkle(2) => Domain::Merkle2,
+            Domain::Merkle(4) => Domain::Merkle4,
+            domain => domain,
+        }
+    }
+}
+
+impl PartialEq for Domain {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.normalize(), other.normalize()) {
+            (Domain::Merkle4, Domain::Merkle4)
+            | (Domain::Merkle2, Domain::Merkle2)
+            | (Domain::Encryption, Domain::Encryption)
+            | (Domain::Other, Domain::Other) => true,
+            (Domain::Merkle(arity), Domain::Merkle(other_arity)) => arity == other_arity,
+            (Domain::Custom(separator), Domain::Custom(other_separator)) => {
+                separator == other_separator
+            }
+            _ => false,
+        }
+    }
 }
 
 impl From<Domain> for u64 {
@@ -32,18 +95,106 @@ impl From<Domain> for u64 {
     /// When `Other` is selected we set the domain-separator to zero. We can do
     /// this since the io-pattern will be encoded in the tag in any case,
     /// ensuring safety from collision attacks.
+    /// When `Custom` is selected the domain-separator is the given one.
     fn from(domain: Domain) -> Self {
         match domain {
             // 2^4 - 1
             Domain::Merkle4 => 0x0000_0000_0000_000f,
             // 2^2 - 1
             Domain::Merkle2 => 0x0000_0000_0000_0003,
+            // 2^arity - 1
+            Domain::Merkle(arity) if arity < 64 => (1 << arity) - 1,
+            Domain::Merkle(_) => u64::MAX,
             // 2^32
             Domain::Encryption => 0x0000_0001_0000_0000,
             // 0
             Domain::Other => 0x0000_0000_0000_0000,
+            Domain::Custom(separator) => separator,
+        }
+    }
+}
+
+// The output length can only be overridden for `Domain::Other` and
+// `Domain::Custom`, and can't be zero.
+fn check_output_len(domain: Domain, output_len: usize) -> Result<(), Error> {
+    if !matches!(domain, Domain::Other | Domain::Custom(_)) {
+        return Err(Error::IOPatternViolation);
+    }
+    if output_len == 0 {
+        return Err(Error::InvalidIOPattern);
+    }
+    Ok(())
+}
+
+// The input length of the merkle domains is their arity, from 2 to 64, with a
+// single output element, and the custom domains can't use the separator of
+// another domain.
+fn check_input_len(domain: Domain, input_len: usize, output_len: usize) -> Result<(), Error> {
+    match domain {
+        Domain::Merkle2 if input_len != 2 || output_len != 1 => Err(Error::IOPatternViolation),
+        Domain::Merkle4 if input_len != 4 || output_len != 1 => Err(Error::IOPatternViolation),
+        Domain::Merkle(arity) if !(2..=64).contains(&arity) => Err(Error::InvalidIOPattern),
+        Domain::Merkle(arity) if input_len != arity || output_len != 1 => {
+            Err(Error::IOPatternViolation)
+        }
+        // 0, 2^32 and 2^n - 1, whose successor has no bit in common with it
+        Domain::Custom(separator)
+            if separator == 0
+                || separator == 1 << 32
+                || (separator > 1 && separator & separator.wrapping_add(1) == 0) =>
+        {
+            Err(Error::InvalidIOPattern)
+        }
+        _ => Ok(()),
+    }
+}
+
+// The input of the tag of an io-pattern, encoded as in `dusk_safe`: the calls
+// as big-endian u32 with the highest bit set for absorb, consecutive calls of
+// the same kind aggregated, followed by the big-endian domain separator. It
+// is used by the sponges that drive the permutation themselves instead of
+// going through `dusk_safe::Sponge`.
+pub(crate) fn tag_input(io_pattern: &[Call], domain: Domain) -> Result<Vec<u8>, Error> {
+    const ABSORB_MASK: u32 = 0x8000_0000;
+    const MAX_LEN: usize = u32::MAX as usize >> 1;
+    match (io_pattern.first(), io_pattern.last()) {
+        (Some(Call::Absorb(_)), Some(Call::Squeeze(_))) => {}
+        _ => return Err(Error::InvalidIOPattern),
+    }
+
+    let mut calls: Vec<u32> = Vec::with_capacity(io_pattern.len());
+    for call in io_pattern {
+        let len = *call.call_len();
+        if len == 0 || len > MAX_LEN {
+            return Err(Error::InvalidIOPattern);
+        }
+        let mask = match call {
+            Call::Absorb(_) => ABSORB_MASK,
+            Call::Squeeze(_) => 0,
+        };
+        match calls.last_mut() {
+            Some(previous) if *previous & ABSORB_MASK == mask => *previous += len as u32,
+            _ => calls.push(mask + len as u32),
         }
     }
+
+    let mut input: Vec<u8> = calls.iter().flat_map(|call| call.to_be_bytes()).collect();
+    input.extend(u64::from(domain).to_be_bytes());
+
+    Ok(input)
+}
+
+// 'Cast' a bls-scalar hash output to a jubjub-scalar by truncating the 6
+// highest bits.
+fn truncate(bls: &BlsScalar) -> JubJubScalar {
+    const TRUNCATION_MASK: BlsScalar = BlsScalar::from_raw([
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0x03ff_ffff_ffff_ffff,
+    ]);
+
+    JubJubScalar::from_raw((bls & &TRUNCATION_MASK).reduce().0)
 }
 
 // This function, which is called during the finalization step of the hash, will
@@ -54,15 +205,7 @@ fn io_pattern<T>(domain: Domain, input: &[&[T]], output_len: usize) -> Result<Ve
     let mut io_pattern = Vec::new();
     // check total input length against domain
     let input_len = input.iter().fold(0, |acc, input| acc + input.len());
-    match domain {
-        Domain::Merkle2 if input_len != 2 || output_len != 1 => {
-            return Err(Error::IOPatternViolation);
-        }
-        Domain::Merkle4 if input_len != 4 || output_len != 1 => {
-            return Err(Error::IOPatternViolation);
-        }
-        _ => {}
-    }
+    check_input_len(domain, input_len, output_len)?;
     for input in input.iter() {
         io_pattern.push(Call::Absorb(input.len()));
     }
@@ -70,3 +213,231 @@ fn io_pattern<T>(domain: Domain, input: &[&[T]], output_len: usize) -> Result<Ve
 
     Ok(io_pattern)
 }
+
+#[cfg(test)]
+mod tests {
+    use dusk_bls12_381::BlsScalar;
+
+    use super::*;
+    use crate::{hades, Hash};
+
+    type HadesHash<'a> = Hash<'a, hades::ScalarPermutation, { hades::WIDTH }>;
+
+    #[test]
+    fn try_finalize() {
+        let input = [BlsScalar::one(), BlsScalar::from(2), BlsScalar::from(3)];
+
+        let mut hash = HadesHash::new(Domain::Other);
+        hash.update(&input);
+        assert_eq!(hash.try_finalize(), Ok(hash.finalize()));
+        assert_eq!(hash.try_finalize_truncated(), Ok(hash.finalize_truncated()));
+
+        // a merkle domain with the wrong number of inputs
+        assert_eq!(
+            HadesHash::try_digest(Domain::Merkle4, &input),
+            Err(Error::IOPatternViolation)
+        );
+        assert_eq!(
+            HadesHash::try_digest_truncated(Domain::Merkle2, &input),
+            Err(Error::IOPatternViolation)
+        );
+        assert_eq!(
+            HadesHash::try_digest(Domain::Merkle2, &input[..2]),
+            Ok(HadesHash::digest(Domain::Merkle2, &input[..2]))
+        );
+    }
+
+    #[test]
+    fn output_len() {
+        let input = [BlsScalar::one(), BlsScalar::from(2)];
+
+        let mut hash = HadesHash::new(Domain::Other);
+        hash.update(&input);
+        assert_eq!(hash.output_len(0), Err(Error::InvalidIOPattern));
+        assert_eq!(hash.finalize().len(), 1);
+        assert_eq!(hash.output_len(3), Ok(()));
+        assert_eq!(hash.finalize().len(), 3);
+
+        // the output length is ignored for the other domains
+        for domain in [Domain::Merkle2, Domain::Merkle4, Domain::Encryption] {
+            let mut hash = HadesHash::new(domain);
+            assert_eq!(hash.output_len(2), Err(Error::IOPatternViolation));
+        }
+        let mut hash = HadesHash::new(Domain::Merkle2);
+        hash.update(&input);
+        let _ = hash.output_len(2);
+        assert_eq!(hash.try_finalize().map(|output| output.len()), Ok(1));
+    }
+
+    #[test]
+    fn merkle_domains() {
+        let input: Vec<BlsScalar> = (0..16u64).map(BlsScalar::from).collect();
+
+        // the arities of the fixed merkle domains hash the same, and are the
+        // same domain
+        assert_eq!(Domain::Merkle(2), Domain::Merkle2);
+        assert_eq!(Domain::Merkle4, Domain::Merkle(4));
+        assert_ne!(Domain::Merkle(2), Domain::Merkle4);
+        assert_ne!(Domain::Merkle(8), Domain::Merkle(16));
+        assert_ne!(Domain::Custom(u64::from(Domain::Merkle4)), Domain::Merkle4);
+        assert_eq!(
+            HadesHash::digest(Domain::Merkle(2), &input[..2]),
+            HadesHash::digest(Domain::Merkle2, &input[..2])
+        );
+        assert_eq!(
+            HadesHash::digest(Domain::Merkle(4), &input[..4]),
+            HadesHash::digest(Domain::Merkle4, &input[..4])
+        );
+
+        for arity in [8, 16] {
+            let output = HadesHash::try_digest(Domain::Merkle(arity), &input[..arity]);
+            assert_eq!(output.map(|output| output.len()), Ok(1));
+            assert_ne!(
+                HadesHash::digest(Domain::Merkle(arity), &input[..arity]),
+                HadesHash::digest(Domain::Other, &input[..arity])
+            );
+            assert_eq!(
+                HadesHash::try_digest(Domain::Merkle(arity), &input[..arity - 1]),
+                Err(Error::IOPatternViolation)
+            );
+            assert_eq!(
+                HadesHash::new(Domain::Merkle(arity)).output_len(2),
+                Err(Error::IOPatternViolation)
+            );
+        }
+        assert_eq!(u64::from(Domain::Merkle(8)), 0xff);
+        assert_eq!(u64::from(Domain::Merkle(16)), 0xffff);
+        assert_eq!(u64::from(Domain::Merkle(64)), u64::MAX);
+
+        // the arities that don't have a separator
+        assert_eq!(
+            HadesHash::try_digest(Domain::Merkle(1), &input[..1]),
+            Err(Error::InvalidIOPattern)
+        );
+        assert_eq!(
+            HadesHash::try_digest(Domain::Merkle(65), &input),
+            Err(Error::InvalidIOPattern)
+        );
+    }
+
+    #[test]
+    fn custom_domains() {
+        let input = [BlsScalar::one(), BlsScalar::from(2), BlsScalar::from(3)];
+
+        let first = HadesHash::digest(Domain::Custom(42), &input);
+        assert_ne!(first, HadesHash::digest(Domain::Custom(43), &input));
+        assert_ne!(first, HadesHash::digest(Domain::Other, &input));
+
+        // the output length can be overridden
+        let mut hash = HadesHash::new(Domain::Custom(42));
+        hash.update(&input);
+        assert_eq!(hash.output_len(3), Ok(()));
+        let output = hash.finalize();
+        assert_eq!(output.len(), 3);
+        // the output length is part of the tag
+        assert_ne!(output[0], first[0]);
+
+        // a label gives a stable separator
+        let domain = Domain::from_label(b"zk-dusk-test");
+        assert_eq!(domain, Domain::from_label("zk-dusk-test"));
+        assert_ne!(domain, Domain::from_label(b"zk-dusk-test2"));
+        assert!(matches!(domain, Domain::Custom(_)));
+        assert_eq!(
+            HadesHash::try_digest(domain, &input),
+            Ok(HadesHash::digest(Domain::Custom(u64::from(domain)), &input))
+        );
+
+        // the separators of the other domains are reserved
+        for separator in [0, 1 << 32, 3, 0xf, 0xff, u64::MAX] {
+            assert_eq!(
+                HadesHash::try_digest(Domain::Custom(separator), &input),
+                Err(Error::InvalidIOPattern)
+            );
+        }
+        assert_eq!(
+            HadesHash::try_digest(Domain::Custom(1), &input).map(|output| output.len()),
+            Ok(1)
+        );
+    }
+
+    #[cfg(feature = "zk")]
+    #[test]
+    fn new_domains_gadget() {
+        use dusk_plonk::prelude::Composer;
+
+        use crate::{HashGadget, HashableGadget};
+
+        type HadesGadget<'a, 'b> = HashGadget<'a, hades::GadgetPermutation<'b>, { hades::WIDTH }>;
+
+        let input: Vec<BlsScalar> = (0..16u64).map(BlsScalar::from).collect();
+        let mut composer = Composer::initialized();
+        let witnesses: Vec<_> = input
+            .iter()
+            .map(|scalar| composer.append_witness(*scalar))
+            .collect();
+
+        for (domain, len) in [
+            (Domain::Merkle(8), 8),
+            (Domain::Merkle(16), 16),
+            (Domain::Custom(42), 3),
+            (Domain::from_label(b"zk-dusk-test"), 5),
+        ] {
+            let output = HadesGadget::digest(domain, &mut composer, &witnesses[..len]);
+            assert_eq!(
+                output.iter().map(|w| composer[*w]).collect::<Vec<_>>(),
+                HadesHash::digest(domain, &input[..len])
+            );
+        }
+
+        assert_eq!(
+            HadesGadget::try_digest(Domain::Merkle(8), &mut composer, &witnesses[..7]),
+            Err(Error::IOPatternViolation)
+        );
+        assert_eq!(
+            HadesGadget::try_digest(Domain::Custom(0xff), &mut composer, &witnesses),
+            Err(Error::InvalidIOPattern)
+        );
+        assert_eq!(HadesGadget::new(Domain::Custom(42)).output_len(2), Ok(()));
+    }
+
+    #[cfg(feature = "zk")]
+    #[test]
+    fn try_finalize_gadget() {
+        use dusk_plonk::prelude::Composer;
+
+        use crate::{HashGadget, HashableGadget};
+
+        type HadesGadget<'a, 'b> = HashGadget<'a, hades::GadgetPermutation<'b>, { hades::WIDTH }>;
+
+        let input = [BlsScalar::one(), BlsScalar::from(2), BlsScalar::from(3)];
+        let mut composer = Composer::initialized();
+        let witnesses = input.map(|scalar| composer.append_witness(scalar));
+
+        let mut hash = HadesGadget::new(Domain::Other);
+        hash.update(&witnesses);
+        assert_eq!(hash.output_len(2), Ok(()));
+        let output = hash
+            .try_finalize(&mut composer)
+            .expect("a valid io-pattern");
+        let mut expected = HadesHash::new(Domain::Other);
+        expected.update(&input);
+        let _ = expected.output_len(2);
+        assert_eq!(
+            output.iter().map(|w| composer[*w]).collect::<Vec<_>>(),
+            expected.finalize()
+        );
+
+        assert_eq!(
+            HadesGadget::try_digest(Domain::Merkle4, &mut composer, &witnesses),
+            Err(Error::IOPatternViolation)
+        );
+        assert_eq!(
+            HadesGadget::try_digest_truncated(Domain::Merkle2, &mut composer, &witnesses),
+            Err(Error::IOPatternViolation)
+        );
+        assert_eq!(
+            HadesGadget::new(Domain::Merkle2).output_len(2),
+            Err(Error::IOPatternViolation)
+        );
+    }
+}
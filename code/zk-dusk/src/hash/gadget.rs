@@ -11,12 +11,24 @@ use alloc::vec::Vec;
 use dusk_plonk::prelude::{Composer, Witness};
 use dusk_safe::Sponge;
 
-use crate::{news::NewableSafe, Domain};
+use crate::{news::NewableSafe, Domain, Error};
 
 use super::io_pattern;
 
 pub trait HashableGadget {
     fn digest(domain: Domain, composer: &mut Composer, input: &[Witness]) -> Vec<Witness>;
+
+    /// Same as [`HashableGadget::digest`], returning an error instead of
+    /// panicking. The default implementation calls
+    /// [`HashableGadget::digest`], and so still panics, for the implementors
+    /// that predate it.
+    fn try_digest(
+        domain: Domain,
+        composer: &mut Composer,
+        input: &[Witness],
+    ) -> Result<Vec<Witness>, Error> {
+        Ok(Self::digest(domain, composer, input))
+    }
 }
 
 /// OK
@@ -41,10 +53,14 @@ impl<'a, GadPerm: NewableSafe<WIDTH>, const WIDTH: usize> HashGadget<'a, GadPerm
     /// Override the length of the hash output (default value is 1) when using
     /// the hash for anything other than hashing a merkle tree or
     /// encryption.
-    pub fn output_len(&mut self, output_len: usize) {
-        if self.domain == Domain::Other && output_len > 0 {
-            self.output_len = output_len;
-        }
+    ///
+    /// The length is ignored, and an [`Error::IOPatternViolation`] returned,
+    /// for any other domain than [`Domain::Other`] and [`Domain::Custom`], and
+    /// an [`Error::InvalidIOPattern`] returned for a length of 0.
+    pub fn output_len(&mut self, output_len: usize) -> Result<(), Error> {
+        super::check_output_len(self.domain, output_len)?;
+        self.output_len = output_len;
+        Ok(())
     }
 
     /// Update the hash input.
@@ -57,33 +73,35 @@ impl<'a, GadPerm: NewableSafe<WIDTH>, const WIDTH: usize> HashGadget<'a, GadPerm
     /// # Panics
     /// This function panics when the io-pattern can not be created with the
     /// given domain and input, e.g. using [`Domain::Merkle4`] with an input
-    /// anything other than 4 Scalar.
+    /// anything other than 4 Scalar. See [`HashGadget::try_finalize`] for a
+    /// function that returns an error instead.
     pub fn finalize(&self, composer: &mut Composer) -> Vec<Witness> {
+        self.try_finalize(composer)
+            .expect("io-pattern should be valid")
+    }
+
+    /// Finalize the hash, or return an error when the io-pattern can not be
+    /// created with the given domain and input, e.g. an
+    /// [`Error::IOPatternViolation`] using [`Domain::Merkle4`] with an input
+    /// anything other than 4 Scalar.
+    pub fn try_finalize(&self, composer: &mut Composer) -> Result<Vec<Witness>, Error> {
         // Generate the hash using the sponge framework:
         let mut sponge = Sponge::start(
             GadPerm::new(composer),
-            io_pattern(self.domain, &self.input, self.output_len)
-                .expect("io-pattern should be valid"),
+            io_pattern(self.domain, &self.input, self.output_len)?,
             self.domain.into(),
-        )
-        .expect("at this point the io-pattern is valid");
+        )?;
 
         // absorb the input
         for input in self.input.iter() {
-            sponge
-                .absorb(input.len(), input)
-                .expect("at this point the io-pattern is valid");
+            sponge.absorb(input.len(), input)?;
         }
 
         // squeeze output_len elements
-        sponge
-            .squeeze(self.output_len)
-            .expect("at this point the io-pattern is valid");
+        sponge.squeeze(self.output_len)?;
 
         // return the result
-        sponge
-            .finish()
-            .expect("at this point the io-pattern is valid")
+        Ok(sponge.finish()?)
     }
 
     /// Finalize the hash and output JubJubScalar.
@@ -91,16 +109,24 @@ impl<'a, GadPerm: NewableSafe<WIDTH>, const WIDTH: usize> HashGadget<'a, GadPerm
     /// # Panics
     /// This function panics when the io-pattern can not be created with the
     /// given domain and input, e.g. using [`Domain::Merkle4`] with an input
-    /// anything other than 4 Scalar.
+    /// anything other than 4 Scalar. See [`HashGadget::try_finalize_truncated`]
+    /// for a function that returns an error instead.
     pub fn finalize_truncated(&self, composer: &mut Composer) -> Vec<Witness> {
+        self.try_finalize_truncated(composer)
+            .expect("io-pattern should be valid")
+    }
+
+    /// Same as [`HashGadget::finalize_truncated`], returning an error instead
+    /// of panicking, as [`HashGadget::try_finalize`] does.
+    pub fn try_finalize_truncated(&self, composer: &mut Composer) -> Result<Vec<Witness>, Error> {
         // finalize the hash as bls-scalar witnesses
-        let bls_output = self.finalize(composer);
+        let bls_output = self.try_finalize(composer)?;
 
         // truncate the bls witnesses to 250 bits
-        bls_output
+        Ok(bls_output
             .iter()
             .map(|bls: &Witness| composer.append_logic_xor::<125>(*bls, Composer::ZERO))
-            .collect()
+            .collect())
     }
 
     /// Digest an input and calculate the hash as jubjub-scalar immediately
@@ -118,6 +144,18 @@ impl<'a, GadPerm: NewableSafe<WIDTH>, const WIDTH: usize> HashGadget<'a, GadPerm
         hash.update(input);
         hash.finalize_truncated(composer)
     }
+
+    /// Same as [`HashGadget::digest_truncated`], returning an error instead of
+    /// panicking, as [`HashGadget::try_finalize`] does.
+    pub fn try_digest_truncated(
+        domain: Domain,
+        composer: &mut Composer,
+        input: &'a [Witness],
+    ) -> Result<Vec<Witness>, Error> {
+        let mut hash = Self::new(domain);
+        hash.update(input);
+        hash.try_finalize_truncated(composer)
+    }
 }
 
 impl<'a, GadPerm: NewableSafe<WIDTH>, const WIDTH: usize> HashableGadget
@@ -134,4 +172,16 @@ impl<'a, GadPerm: NewableSafe<WIDTH>, const WIDTH: usize> HashableGadget
         hash.update(input);
         hash.finalize(composer)
     }
+
+    /// Same as [`HashableGadget::digest`], returning an error instead of
+    /// panicking, as [`HashGadget::try_finalize`] does.
+    fn try_digest(
+        domain: Domain,
+        composer: &mut Composer,
+        input: &[Witness],
+    ) -> Result<Vec<Witness>, Error> {
+        let mut hash = Self::new(domain);
+        hash.update(input);
+        hash.try_finalize(composer)
+    }
 }
@@ -9,11 +9,12 @@ use core::marker::PhantomData;
 use alloc::vec::Vec;
 
 use crate::news::NewableScalar;
+use crate::Error;
 use dusk_bls12_381::BlsScalar;
 use dusk_jubjub::JubJubScalar;
 use dusk_safe::{Safe, Sponge};
 
-use super::{io_pattern, Domain};
+use super::{io_pattern, truncate, Domain};
 
 /// Hash any given input into one or several scalar using the Hades
 /// permutation strategy. The Hash can absorb multiple chunks of input but will
@@ -43,10 +44,14 @@ impl<'a, ScalarPermutation: Safe<BlsScalar, WIDTH> + NewableScalar, const WIDTH:
     /// Override the length of the hash output (default value is 1) when using
     /// the hash for anything other than hashing a merkle tree or
     /// encryption.
-    pub fn output_len(&mut self, output_len: usize) {
-        if self.domain == Domain::Other && output_len > 0 {
-            self.output_len = output_len;
-        }
+    ///
+    /// The length is ignored, and an [`Error::IOPatternViolation`] returned,
+    /// for any other domain than [`Domain::Other`] and [`Domain::Custom`], and
+    /// an [`Error::InvalidIOPattern`] returned for a length of 0.
+    pub fn output_len(&mut self, output_len: usize) -> Result<(), Error> {
+        super::check_output_len(self.domain, output_len)?;
+        self.output_len = output_len;
+        Ok(())
     }
 
     /// Update the hash input.
@@ -59,34 +64,35 @@ impl<'a, ScalarPermutation: Safe<BlsScalar, WIDTH> + NewableScalar, const WIDTH:
     /// # Panics
     /// This function panics when the io-pattern can not be created with the
     /// given domain and input, e.g. using [`Domain::Merkle4`] with an input
-    /// anything other than 4 Scalar.
+    /// anything other than 4 Scalar. See [`Hash::try_finalize`] for a
+    /// function that returns an error instead.
     pub fn finalize(&self) -> Vec<BlsScalar> {
+        self.try_finalize().expect("io-pattern should be valid")
+    }
+
+    /// Finalize the hash, or return an error when the io-pattern can not be
+    /// created with the given domain and input, e.g. an
+    /// [`Error::IOPatternViolation`] using [`Domain::Merkle4`] with an input
+    /// anything other than 4 Scalar.
+    pub fn try_finalize(&self) -> Result<Vec<BlsScalar>, Error> {
         // Generate the hash using the sponge framework:
         // initialize the sponge
         let mut sponge = Sponge::start(
             ScalarPermutation::new(),
-            io_pattern(self.domain, &self.input, self.output_len)
-                .expect("io-pattern should be valid"),
+            io_pattern(self.domain, &self.input, self.output_len)?,
             self.domain.into(),
-        )
-        .expect("at this point the io-pattern is valid");
+        )?;
 
         // absorb the input
         for input in self.input.iter() {
-            sponge
-                .absorb(input.len(), input)
-                .expect("at this point the io-pattern is valid");
+            sponge.absorb(input.len(), input)?;
         }
 
         // squeeze output_len elements
-        sponge
-            .squeeze(self.output_len)
-            .expect("at this point the io-pattern is valid");
+        sponge.squeeze(self.output_len)?;
 
         // return the result
-        sponge
-            .finish()
-            .expect("at this point the io-pattern is valid")
+        Ok(sponge.finish()?)
     }
 
     /// Finalize the hash and output the result as a `JubJubScalar` by
@@ -95,24 +101,17 @@ impl<'a, ScalarPermutation: Safe<BlsScalar, WIDTH> + NewableScalar, const WIDTH:
     /// # Panics
     /// This function panics when the io-pattern can not be created with the
     /// given domain and input, e.g. using [`Domain::Merkle4`] with an input
-    /// anything other than 4 Scalar.
+    /// anything other than 4 Scalar. See [`Hash::try_finalize_truncated`] for
+    /// a function that returns an error instead.
     pub fn finalize_truncated(&self) -> Vec<JubJubScalar> {
-        // bit-mask to 'cast' a bls-scalar result to a jubjub-scalar by
-        // truncating the 6 highest bits
-        const TRUNCATION_MASK: BlsScalar = BlsScalar::from_raw([
-            0xffff_ffff_ffff_ffff,
-            0xffff_ffff_ffff_ffff,
-            0xffff_ffff_ffff_ffff,
-            0x03ff_ffff_ffff_ffff,
-        ]);
-
-        // finalize the hash as bls-scalar
-        let bls_output = self.finalize();
-
-        bls_output
-            .iter()
-            .map(|bls| JubJubScalar::from_raw((bls & &TRUNCATION_MASK).reduce().0))
-            .collect()
+        self.try_finalize_truncated()
+            .expect("io-pattern should be valid")
+    }
+
+    /// Same as [`Hash::finalize_truncated`], returning an error instead of
+    /// panicking, as [`Hash::try_finalize`] does.
+    pub fn try_finalize_truncated(&self) -> Result<Vec<JubJubScalar>, Error> {
+        Ok(self.try_finalize()?.iter().map(truncate).collect())
     }
 
     /// Digest an input and calculate the hash immediately
@@ -138,4 +137,23 @@ impl<'a, ScalarPermutation: Safe<BlsScalar, WIDTH> + NewableScalar, const WIDTH:
         hash.update(input);
         hash.finalize_truncated()
     }
+
+    /// Same as [`Hash::digest`], returning an error instead of panicking, as
+    /// [`Hash::try_finalize`] does.
+    pub fn try_digest(domain: Domain, input: &'a [BlsScalar]) -> Result<Vec<BlsScalar>, Error> {
+        let mut hash = Self::new(domain);
+        hash.update(input);
+        hash.try_finalize()
+    }
+
+    /// Same as [`Hash::digest_truncated`], returning an error instead of
+    /// panicking, as [`Hash::try_finalize`] does.
+    pub fn try_digest_truncated(
+        domain: Domain,
+        input: &'a [BlsScalar],
+    ) -> Result<Vec<JubJubScalar>, Error> {
+        let mut hash = Self::new(domain);
+        hash.update(input);
+        hash.try_finalize_truncated()
+    }
 }
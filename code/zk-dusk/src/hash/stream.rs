@@ -0,0 +1,199 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use core::marker::PhantomData;
+
+use alloc::vec::Vec;
+
+use crate::news::NewableScalar;
+use crate::Error;
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::JubJubScalar;
+use dusk_safe::{Call, Safe};
+use zeroize::Zeroize;
+
+use super::{check_input_len, check_output_len, tag_input, truncate, Domain};
+
+/// Hash an input of a length declared upfront into one or several scalar,
+/// absorbing the input as it arrives instead of at the finalization like
+/// [`Hash`](crate::Hash) does.
+///
+/// The hash doesn't borrow its input, so it can be updated with streamed
+/// data, and can be cloned in the middle of the input to hash several inputs
+/// with the same prefix. The output is the one of [`Hash`](crate::Hash) for
+/// the same domain and input.
+pub struct StreamingHash<ScalarPermutation: Safe<BlsScalar, WIDTH> + NewableScalar, const WIDTH: usize>
+{
+    state: [BlsScalar; WIDTH],
+    input_len: usize,
+    absorbed: usize,
+    pos_absorb: usize,
+    output_len: usize,
+    phantom: PhantomData<ScalarPermutation>,
+}
+
+impl<ScalarPermutation: Safe<BlsScalar, WIDTH> + NewableScalar, const WIDTH: usize>
+    StreamingHash<ScalarPermutation, WIDTH>
+{
+    /// The capacity of the sponge, which is the one of
+    /// [`dusk_safe::Sponge`].
+    const CAPACITY: usize = 1;
+
+    /// The rate of the sponge.
+    const RATE: usize = WIDTH - Self::CAPACITY;
+
+    /// Create a new hash of an input of `input_len` elements in total, with
+    /// an output of 1 element.
+    ///
+    /// Returns an [`Error::IOPatternViolation`] when the input length doesn't
+    /// fit the domain, e.g. using [`Domain::Merkle4`] with an input length
+    /// other than 4, and an [`Error::InvalidIOPattern`] for an input length
+    /// of 0.
+    pub fn new(domain: Domain, input_len: usize) -> Result<Self, Error> {
+        Self::start(domain, input_len, 1)
+    }
+
+    /// Create a new hash of an input of `input_len` elements in total, with
+    /// an output of `output_len` elements.
+    ///
+    /// As with [`Hash::output_len`](crate::Hash::output_len), the output
+    /// length can only be set for [`Domain::Other`] and [`Domain::Custom`], and
+    /// returns an [`Error::IOPatternViolation`] for any other domain and an
+    /// [`Error::InvalidIOPattern`] for a length of 0.
+    pub fn with_output_len(
+        domain: Domain,
+        input_len: usize,
+        output_len: usize,
+    ) -> Result<Self, Error> {
+        check_output_len(domain, output_len)?;
+        Self::start(domain, input_len, output_len)
+    }
+
+    fn start(domain: Domain, input_len: usize, output_len: usize) -> Result<Self, Error> {
+        check_input_len(domain, input_len, output_len)?;
+
+        // the io-pattern of the hash is a call to absorb the whole input
+        // followed by a call to squeeze, so the tag is the one of the sponge
+        // of `Hash`, which aggregates its calls to absorb
+        let io_pattern = [Call::Absorb(input_len), Call::Squeeze(output_len)];
+        let tag = ScalarPermutation::new().tag(&tag_input(&io_pattern, domain)?);
+
+        Ok(Self {
+            state: ScalarPermutation::initialized_state(tag),
+            input_len,
+            absorbed: 0,
+            pos_absorb: 0,
+            output_len,
+            phantom: PhantomData,
+        })
+    }
+
+    /// The number of input elements that remain to be absorbed before the
+    /// hash can be finalized.
+    pub fn remaining(&self) -> usize {
+        self.input_len - self.absorbed
+    }
+
+    /// Absorb the next elements of the input.
+    ///
+    /// Returns an [`Error::IOPatternViolation`] when the input exceeds the
+    /// declared length, in which case nothing is absorbed.
+    pub fn update(&mut self, input: &[BlsScalar]) -> Result<(), Error> {
+        if input.len() > self.remaining() {
+            return Err(Error::IOPatternViolation);
+        }
+
+        let mut permutation = ScalarPermutation::new();
+        for element in input {
+            if self.pos_absorb == Self::RATE {
+                permutation.permute(&mut self.state);
+                self.pos_absorb = 0;
+            }
+            let pos = self.pos_absorb + Self::CAPACITY;
+            self.state[pos] = permutation.add(&self.state[pos], element);
+            self.pos_absorb += 1;
+        }
+        self.absorbed += input.len();
+
+        Ok(())
+    }
+
+    /// Finalize the hash.
+    ///
+    /// # Panics
+    /// This function panics when less than the declared number of input
+    /// elements have been absorbed. See [`StreamingHash::try_finalize`] for a
+    /// function that returns an error instead.
+    pub fn finalize(self) -> Vec<BlsScalar> {
+        self.try_finalize().expect("io-pattern should be followed")
+    }
+
+    /// Finalize the hash, or return an [`Error::IOPatternViolation`] when less
+    /// than the declared number of input elements have been absorbed.
+    pub fn try_finalize(mut self) -> Result<Vec<BlsScalar>, Error> {
+        if self.remaining() != 0 {
+            return Err(Error::IOPatternViolation);
+        }
+
+        // squeeze output_len elements, starting with a permutation
+        let mut permutation = ScalarPermutation::new();
+        let mut output = Vec::with_capacity(self.output_len);
+        let mut pos_squeeze = Self::RATE;
+        for _ in 0..self.output_len {
+            if pos_squeeze == Self::RATE {
+                permutation.permute(&mut self.state);
+                pos_squeeze = 0;
+            }
+            output.push(self.state[pos_squeeze + Self::CAPACITY]);
+            pos_squeeze += 1;
+        }
+
+        Ok(output)
+    }
+
+    /// Finalize the hash and output the result as a `JubJubScalar` by
+    /// truncating the `BlsScalar` output to 250 bits.
+    ///
+    /// # Panics
+    /// This function panics when less than the declared number of input
+    /// elements have been absorbed. See
+    /// [`StreamingHash::try_finalize_truncated`] for a function that returns
+    /// an error instead.
+    pub fn finalize_truncated(self) -> Vec<JubJubScalar> {
+        self.try_finalize_truncated()
+            .expect("io-pattern should be followed")
+    }
+
+    /// Same as [`StreamingHash::finalize_truncated`], returning an error
+    /// instead of panicking, as [`StreamingHash::try_finalize`] does.
+    pub fn try_finalize_truncated(self) -> Result<Vec<JubJubScalar>, Error> {
+        Ok(self.try_finalize()?.iter().map(truncate).collect())
+    }
+}
+
+impl<ScalarPermutation: Safe<BlsScalar, WIDTH> + NewableScalar, const WIDTH: usize> Clone
+    for StreamingHash<ScalarPermutation, WIDTH>
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state,
+            input_len: self.input_len,
+            absorbed: self.absorbed,
+            pos_absorb: self.pos_absorb,
+            output_len: self.output_len,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<ScalarPermutation: Safe<BlsScalar, WIDTH> + NewableScalar, const WIDTH: usize> Drop
+    for StreamingHash<ScalarPermutation, WIDTH>
+{
+    // as the sponge of `Hash` does, erase the state from memory
+    fn drop(&mut self) {
+        self.state.zeroize();
+    }
+}
@@ -0,0 +1,255 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Known-answer tests of the permutations.
+//!
+//! The vectors in `kat/` are computed by `kat/generate.py` from the reference
+//! implementation of each permutation over the BLS12-381 scalar field, with
+//! the constants derived again from their definition, but for the random ones
+//! of Arion. Every vector gives the width of the state, the input state and the
+//! expected output state, with each scalar in big-endian hex as `BlsScalar` is
+//! displayed.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_safe::Safe;
+use serde_json::Value;
+
+use crate::news::NewableScalar;
+
+/// A vector of a KAT file.
+pub(crate) struct Vector<const W: usize> {
+    pub input: [BlsScalar; W],
+    pub output: [BlsScalar; W],
+}
+
+fn scalar(value: &Value) -> BlsScalar {
+    let digits = value.as_str().expect("a scalar in hex");
+    let digits = digits.trim_start_matches("0x");
+    assert_eq!(digits.len(), 64, "a scalar of 32 bytes");
+
+    // Most significant limb first
+    let mut limbs = [0u64; 4];
+    for (i, limb) in limbs.iter_mut().rev().enumerate() {
+        *limb = u64::from_str_radix(&digits[16 * i..16 * (i + 1)], 16).unwrap();
+    }
+    BlsScalar::from_raw(limbs)
+}
+
+fn state<const W: usize>(value: &Value) -> [BlsScalar; W] {
+    let scalars: Vec<BlsScalar> = value
+        .as_array()
+        .expect("an array of scalars")
+        .iter()
+        .map(scalar)
+        .collect();
+    scalars.try_into().expect("a state of the width of the vector")
+}
+
+/// The vectors of the KAT file `json` with a state of `W` elements, which
+/// must be the file of the permutation `name`.
+pub(crate) fn vectors<const W: usize>(json: &str, name: &str) -> Vec<Vector<W>> {
+    let file: Value = serde_json::from_str(json).expect("a valid KAT file");
+    assert_eq!(file["hash"], name);
+    assert_eq!(file["field"], "bls12-381");
+
+    let vectors: Vec<Vector<W>> = file["vectors"]
+        .as_array()
+        .expect("a list of vectors")
+        .iter()
+        .filter(|vector| vector["width"].as_u64() == Some(W as u64))
+        .map(|vector| Vector {
+            input: state(&vector["input"]),
+            output: state(&vector["output"]),
+        })
+        .collect();
+    assert!(!vectors.is_empty(), "no {} vector of width {}", name, W);
+    vectors
+}
+
+/// Checks the scalar permutation `P` against the vectors of width `W`.
+pub(crate) fn check_scalar<P, const W: usize>(json: &str, name: &str)
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    for vector in vectors::<W>(json, name) {
+        let mut state = vector.input;
+        P::new().permute(&mut state);
+        assert_eq!(state, vector.output, "{} of width {}", name, W);
+    }
+}
+
+#[cfg(feature = "zk")]
+pub(crate) mod gadget {
+    use core::marker::PhantomData;
+
+    use dusk_plonk::prelude::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::news::NewableSafe;
+
+    /// Constrains the gadget permutation `G` of the input `i` to equal the
+    /// output `o`.
+    pub(crate) struct PermutationCircuit<G, const W: usize> {
+        i: [BlsScalar; W],
+        o: [BlsScalar; W],
+        gadget: PhantomData<G>,
+    }
+
+    impl<G, const W: usize> Default for PermutationCircuit<G, W> {
+        fn default() -> Self {
+            Self {
+                i: [BlsScalar::zero(); W],
+                o: [BlsScalar::zero(); W],
+                gadget: PhantomData,
+            }
+        }
+    }
+
+    impl<G: NewableSafe<W>, const W: usize> Circuit for PermutationCircuit<G, W> {
+        fn circuit(&self, composer: &mut Composer) -> Result<(), Error> {
+            let mut state = self.i.map(|i| composer.append_witness(i));
+            let output = self.o.map(|o| composer.append_witness(o));
+
+            G::new(composer).permute(&mut state);
+
+            state.iter().zip(output.iter()).for_each(|(p, o)| {
+                composer.assert_equal(*p, *o);
+            });
+
+            Ok(())
+        }
+    }
+
+    /// Checks the gadget permutation `G` against the scalar permutation `P`
+    /// on the inputs of the vectors of width `W`: the witnesses of the gadget
+    /// must equal the expected outputs, a proof of them must verify, and a
+    /// wrong output must not be provable.
+    pub(crate) fn check_gadget<P, G, const W: usize>(json: &str, name: &str)
+    where
+        P: Safe<BlsScalar, W> + NewableScalar,
+        G: NewableSafe<W>,
+    {
+        check_scalar::<P, W>(json, name);
+
+        let size = PermutationCircuit::<G, W>::default().size();
+        let mut rng = StdRng::seed_from_u64(0xbeef);
+        let pp = PublicParameters::setup(2 * size.next_power_of_two(), &mut rng)
+            .expect("public parameters");
+        let (prover, verifier) =
+            Compiler::compile::<PermutationCircuit<G, W>>(&pp, name.as_bytes())
+                .expect("the circuit should compile");
+
+        for vector in vectors::<W>(json, name) {
+            // The witnesses of the gadget equal the outputs of the scalar
+            // permutation
+            let mut composer = Composer::initialized();
+            let mut state = vector.input.map(|i| composer.append_witness(i));
+            G::new(&mut composer).permute(&mut state);
+            assert_eq!(state.map(|w| composer[w]), vector.output, "{} gadget of width {}", name, W);
+
+            let circuit = PermutationCircuit::<G, W> {
+                i: vector.input,
+                o: vector.output,
+                gadget: PhantomData,
+            };
+            let (proof, public_inputs) =
+                prover.prove(&mut rng, &circuit).expect("proving should succeed");
+            verifier
+                .verify(&proof, &public_inputs)
+                .expect("the proof should verify");
+
+            let mut o = vector.output;
+            o[0] += BlsScalar::one();
+            let circuit = PermutationCircuit::<G, W> {
+                i: vector.input,
+                o,
+                gadget: PhantomData,
+            };
+            assert!(
+                prover.prove(&mut rng, &circuit).is_err(),
+                "proving a wrong output should fail"
+            );
+        }
+    }
+
+    /// The constraint of an inverse S-box: `root^D == value`.
+    pub(crate) trait RootConstraint {
+        const D: u64;
+
+        fn assert_root(composer: &mut Composer, root: Witness, value: Witness);
+    }
+
+    /// Constrains `root` to be the root of `value` by the inverse S-box
+    /// constraint `R`.
+    pub(crate) struct RootCircuit<R> {
+        value: BlsScalar,
+        root: BlsScalar,
+        constraint: PhantomData<R>,
+    }
+
+    impl<R> Default for RootCircuit<R> {
+        fn default() -> Self {
+            Self {
+                value: BlsScalar::zero(),
+                root: BlsScalar::zero(),
+                constraint: PhantomData,
+            }
+        }
+    }
+
+    impl<R: RootConstraint> Circuit for RootCircuit<R> {
+        fn circuit(&self, composer: &mut Composer) -> Result<(), Error> {
+            let value = composer.append_witness(self.value);
+            let root = composer.append_witness(self.root);
+
+            R::assert_root(composer, root, value);
+
+            Ok(())
+        }
+    }
+
+    /// Checks the inverse S-box constraint `R` on its own, as the KAT of the
+    /// permutation can't tell it apart from a missing one: the root of a
+    /// value must be provable, and any other witness must not.
+    pub(crate) fn check_root<R: RootConstraint>(name: &str) {
+        let size = RootCircuit::<R>::default().size();
+        let mut rng = StdRng::seed_from_u64(0xbeef);
+        let pp = PublicParameters::setup(2 * size.next_power_of_two(), &mut rng)
+            .expect("public parameters");
+        let (prover, verifier) = Compiler::compile::<RootCircuit<R>>(&pp, name.as_bytes())
+            .expect("the circuit should compile");
+
+        let root = BlsScalar::from(0x5eed_u64);
+        let value = root.pow_vartime(&[R::D, 0, 0, 0]);
+
+        let circuit = RootCircuit::<R> {
+            value,
+            root,
+            constraint: PhantomData,
+        };
+        let (proof, public_inputs) =
+            prover.prove(&mut rng, &circuit).expect("proving should succeed");
+        verifier
+            .verify(&proof, &public_inputs)
+            .expect("the proof should verify");
+
+        for forged in [root + BlsScalar::one(), value, BlsScalar::zero()] {
+            let circuit = RootCircuit::<R> {
+                value,
+                root: forged,
+                constraint: PhantomData,
+            };
+            assert!(
+                prover.prove(&mut rng, &circuit).is_err(),
+                "proving a forged root should fail"
+            );
+        }
+    }
+}
@@ -15,13 +15,21 @@ extern crate alloc;
 mod error;
 pub use error::Error;
 
+mod width;
+pub use width::Width;
+
 pub mod hades;
 pub mod rescue;
 pub mod griffin;
 pub mod anemoi;
 pub mod arion;
 
+pub mod registry;
+pub use registry::HashAlgorithm;
+
 mod hash;
+#[cfg(test)]
+mod kat;
 /// OK
 pub mod news;
 
@@ -29,6 +37,7 @@ pub mod news;
 pub use hash::gadget::HashGadget;
 pub use hash::gadget::HashableGadget;
 pub use hash::scalar::Hash;
+pub use hash::stream::StreamingHash;
 pub use hash::Domain;
 
 #[cfg(feature = "encryption")]
@@ -36,6 +45,23 @@ mod encryption;
 
 #[cfg(feature = "encryption")]
 #[cfg(feature = "zk")]
-pub use encryption::gadget::{decrypt_gadget, encrypt_gadget};
+pub use encryption::gadget::{
+    aead_decrypt_gadget, aead_decrypt_gadget_with, aead_encrypt_gadget,
+    aead_encrypt_gadget_with, decrypt_chunk_gadget, decrypt_chunk_gadget_with,
+    decrypt_gadget, decrypt_gadget_with,
+    encrypt_gadget, encrypt_gadget_with, encrypt_to_public_key_gadget,
+    encrypt_to_public_key_gadget_with, seal_gadget, seal_gadget_with,
+    shared_secret_gadget, WitnessSealedMessage,
+};
+#[cfg(feature = "encryption")]
+pub use encryption::{
+    aead_decrypt, aead_decrypt_with, aead_encrypt, aead_encrypt_with, decrypt,
+    decrypt_with, encrypt, encrypt_with,
+};
+#[cfg(feature = "encryption")]
+pub use encryption::chunks::{
+    decrypt_chunk, decrypt_chunk_with, decrypt_chunks, decrypt_chunks_with,
+    encrypt_chunks, encrypt_chunks_with, ChunkDecryptor, ChunkEncryptor,
+};
 #[cfg(feature = "encryption")]
-pub use encryption::{decrypt, encrypt};
+pub use encryption::seal::{open, open_with, seal, seal_with, SealedMessage};
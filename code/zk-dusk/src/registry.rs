@@ -0,0 +1,521 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Runtime selection of the hash algorithms.
+//!
+//! [`HashAlgorithm`] names every permutation of the crate with a stable
+//! numeric ID, which is serialized as a single byte, so that config files and
+//! wire formats can carry the chosen algorithm. [`HashAlgorithm::hasher`]
+//! returns the algorithm as a [`HashFunction`] trait object, which hashes and
+//! builds the gadgets through dynamic dispatch, and [`DigestCircuit`] proves a
+//! digest with an algorithm picked at runtime.
+//!
+//! The IDs are shared with the registry of zk-lib, where ID 6 is MiMC, which
+//! has no implementation over the Bls12-381 scalar field.
+
+use core::{fmt, str::FromStr};
+
+use alloc::vec::Vec;
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::{Error as BytesError, Serializable};
+use dusk_jubjub::JubJubScalar;
+#[cfg(feature = "zk")]
+use dusk_plonk::prelude::{Circuit, Composer, Error as PlonkError, Witness};
+
+use crate::{anemoi, arion, griffin, hades, rescue, Domain, Error, Hash};
+#[cfg(feature = "zk")]
+use crate::{HashGadget, HashableGadget};
+
+/// A hash algorithm of the crate, identified by its ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum HashAlgorithm {
+    /// Poseidon, with the Hades permutation
+    Poseidon = 1,
+    /// Rescue-Prime
+    Rescue = 2,
+    /// Griffin
+    Griffin = 3,
+    /// Anemoi
+    Anemoi = 4,
+    /// Arion
+    Arion = 5,
+}
+
+/// A hash algorithm picked at runtime, as returned by
+/// [`HashAlgorithm::hasher`]. The methods are the ones of [`Hash`] and
+/// [`HashGadget`] with the permutation of the algorithm at its default width.
+pub trait HashFunction: Sync {
+    /// The algorithm, with its ID.
+    fn algorithm(&self) -> HashAlgorithm;
+
+    /// The width of the permutation.
+    fn width(&self) -> usize;
+
+    /// Digest an input, as [`Hash::try_digest`] does.
+    fn try_digest(&self, domain: Domain, input: &[BlsScalar]) -> Result<Vec<BlsScalar>, Error>;
+
+    /// Digest an input as jubjub-scalars, as [`Hash::try_digest_truncated`]
+    /// does.
+    fn try_digest_truncated(
+        &self,
+        domain: Domain,
+        input: &[BlsScalar],
+    ) -> Result<Vec<JubJubScalar>, Error>;
+
+    /// Digest an input in a circuit, as [`HashableGadget::try_digest`] does.
+    #[cfg(feature = "zk")]
+    fn try_digest_gadget(
+        &self,
+        domain: Domain,
+        composer: &mut Composer,
+        input: &[Witness],
+    ) -> Result<Vec<Witness>, Error>;
+}
+
+/// Registers every algorithm with the module of its permutations and its
+/// name, generating its [`HashFunction`] and the dispatch of
+/// [`HashAlgorithm`].
+macro_rules! registry {
+    ($($algorithm:ident => $module:ident, $name:literal;)*) => {
+        impl HashAlgorithm {
+            /// The lowercase name of the algorithm, which [`FromStr`] parses
+            /// back.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(HashAlgorithm::$algorithm => $name,)*
+                }
+            }
+
+            /// The algorithm as a [`HashFunction`] trait object.
+            pub fn hasher(self) -> &'static dyn HashFunction {
+                match self {
+                    $(HashAlgorithm::$algorithm => &hashers::$algorithm,)*
+                }
+            }
+        }
+
+        mod hashers {
+            $(pub(super) struct $algorithm;)*
+        }
+
+        $(
+            impl HashFunction for hashers::$algorithm {
+                fn algorithm(&self) -> HashAlgorithm {
+                    HashAlgorithm::$algorithm
+                }
+
+                fn width(&self) -> usize {
+                    $module::WIDTH
+                }
+
+                fn try_digest(
+                    &self,
+                    domain: Domain,
+                    input: &[BlsScalar],
+                ) -> Result<Vec<BlsScalar>, Error> {
+                    Hash::<$module::ScalarPermutation, { $module::WIDTH }>::try_digest(
+                        domain, input,
+                    )
+                }
+
+                fn try_digest_truncated(
+                    &self,
+                    domain: Domain,
+                    input: &[BlsScalar],
+                ) -> Result<Vec<JubJubScalar>, Error> {
+                    Hash::<$module::ScalarPermutation, { $module::WIDTH }>::try_digest_truncated(
+                        domain, input,
+                    )
+                }
+
+                #[cfg(feature = "zk")]
+                fn try_digest_gadget(
+                    &self,
+                    domain: Domain,
+                    composer: &mut Composer,
+                    input: &[Witness],
+                ) -> Result<Vec<Witness>, Error> {
+                    HashGadget::<$module::GadgetPermutation, { $module::WIDTH }>::try_digest(
+                        domain, composer, input,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+registry! {
+    Poseidon => hades, "poseidon";
+    Rescue => rescue, "rescue";
+    Griffin => griffin, "griffin";
+    Anemoi => anemoi, "anemoi";
+    Arion => arion, "arion";
+}
+
+impl HashAlgorithm {
+    /// Every algorithm of the registry, in the order of the IDs.
+    pub const ALL: [HashAlgorithm; 5] = [
+        HashAlgorithm::Poseidon,
+        HashAlgorithm::Rescue,
+        HashAlgorithm::Griffin,
+        HashAlgorithm::Anemoi,
+        HashAlgorithm::Arion,
+    ];
+
+    /// The stable ID of the algorithm.
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+
+    /// The algorithm with the given ID.
+    pub fn from_id(id: u8) -> Result<Self, Error> {
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.id() == id)
+            .ok_or(Error::UnknownHashAlgorithm)
+    }
+
+    /// The width of the permutation of the algorithm.
+    pub fn width(self) -> usize {
+        self.hasher().width()
+    }
+
+    /// Digest an input with the algorithm, as [`Hash::digest`] does.
+    ///
+    /// # Panics
+    /// This function panics when the io-pattern can not be created with the
+    /// given domain and input, e.g. using [`Domain::Merkle4`] with an input
+    /// anything other than 4 Scalar.
+    pub fn digest(self, domain: Domain, input: &[BlsScalar]) -> Vec<BlsScalar> {
+        self.try_digest(domain, input)
+            .expect("io-pattern should be valid")
+    }
+
+    /// Same as [`HashAlgorithm::digest`], returning an error instead of
+    /// panicking, as [`Hash::try_digest`] does.
+    pub fn try_digest(self, domain: Domain, input: &[BlsScalar]) -> Result<Vec<BlsScalar>, Error> {
+        self.hasher().try_digest(domain, input)
+    }
+
+    /// Digest an input with the algorithm as jubjub-scalars, as
+    /// [`Hash::digest_truncated`] does.
+    ///
+    /// # Panics
+    /// This function panics when the io-pattern can not be created with the
+    /// given domain and input, e.g. using [`Domain::Merkle4`] with an input
+    /// anything other than 4 Scalar.
+    pub fn digest_truncated(self, domain: Domain, input: &[BlsScalar]) -> Vec<JubJubScalar> {
+        self.try_digest_truncated(domain, input)
+            .expect("io-pattern should be valid")
+    }
+
+    /// Same as [`HashAlgorithm::digest_truncated`], returning an error
+    /// instead of panicking, as [`Hash::try_digest_truncated`] does.
+    pub fn try_digest_truncated(
+        self,
+        domain: Domain,
+        input: &[BlsScalar],
+    ) -> Result<Vec<JubJubScalar>, Error> {
+        self.hasher().try_digest_truncated(domain, input)
+    }
+
+    /// Digest an input in a circuit with the gadget of the algorithm, as
+    /// [`HashableGadget::digest`] does.
+    ///
+    /// # Panics
+    /// This function panics when the io-pattern can not be created with the
+    /// given domain and input, e.g. using [`Domain::Merkle4`] with an input
+    /// anything other than 4 Scalar.
+    #[cfg(feature = "zk")]
+    pub fn digest_gadget(
+        self,
+        domain: Domain,
+        composer: &mut Composer,
+        input: &[Witness],
+    ) -> Vec<Witness> {
+        self.try_digest_gadget(domain, composer, input)
+            .expect("io-pattern should be valid")
+    }
+
+    /// Same as [`HashAlgorithm::digest_gadget`], returning an error instead
+    /// of panicking, as [`HashableGadget::try_digest`] does.
+    #[cfg(feature = "zk")]
+    pub fn try_digest_gadget(
+        self,
+        domain: Domain,
+        composer: &mut Composer,
+        input: &[Witness],
+    ) -> Result<Vec<Witness>, Error> {
+        self.hasher().try_digest_gadget(domain, composer, input)
+    }
+}
+
+/// Proves the digest of a private input with an algorithm picked at runtime.
+/// The digest is the public input of the proofs.
+///
+/// The circuit depends on the algorithm, the domain and the length of the
+/// input, so it is compiled with `Compiler::compile_with_circuit`. The default
+/// circuit digests a single element with Poseidon in [`Domain::Other`].
+#[cfg(feature = "zk")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigestCircuit {
+    algorithm: HashAlgorithm,
+    domain: Domain,
+    input: Vec<BlsScalar>,
+}
+
+#[cfg(feature = "zk")]
+impl DigestCircuit {
+    /// The circuit of the digest of `input` in `domain`, or the error of
+    /// [`HashAlgorithm::try_digest`] when the input doesn't fit the domain.
+    pub fn new(
+        algorithm: HashAlgorithm,
+        domain: Domain,
+        input: Vec<BlsScalar>,
+    ) -> Result<Self, Error> {
+        algorithm.try_digest(domain, &input)?;
+        Ok(Self {
+            algorithm,
+            domain,
+            input,
+        })
+    }
+
+    /// The public inputs of the proofs: the digest of the input.
+    pub fn public_inputs(&self) -> Vec<BlsScalar> {
+        self.algorithm.digest(self.domain, &self.input)
+    }
+}
+
+#[cfg(feature = "zk")]
+impl Default for DigestCircuit {
+    fn default() -> Self {
+        Self {
+            algorithm: HashAlgorithm::Poseidon,
+            domain: Domain::Other,
+            input: alloc::vec![BlsScalar::zero()],
+        }
+    }
+}
+
+#[cfg(feature = "zk")]
+impl Circuit for DigestCircuit {
+    fn circuit(&self, composer: &mut Composer) -> Result<(), PlonkError> {
+        let input: Vec<Witness> = self
+            .input
+            .iter()
+            .map(|scalar| composer.append_witness(*scalar))
+            .collect();
+
+        // the input was checked against the domain on creation
+        let digest = self.algorithm.digest_gadget(self.domain, composer, &input);
+        for witness in digest {
+            let value = composer[witness];
+            composer.assert_equal_constant(witness, 0, Some(value));
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<u8> for HashAlgorithm {
+    type Error = Error;
+
+    fn try_from(id: u8) -> Result<Self, Error> {
+        Self::from_id(id)
+    }
+}
+
+impl From<HashAlgorithm> for u8 {
+    fn from(algorithm: HashAlgorithm) -> Self {
+        algorithm.id()
+    }
+}
+
+impl Serializable<1> for HashAlgorithm {
+    type Error = BytesError;
+
+    fn from_bytes(buf: &[u8; 1]) -> Result<Self, Self::Error> {
+        Self::from_id(buf[0]).map_err(|_| BytesError::InvalidData)
+    }
+
+    fn to_bytes(&self) -> [u8; 1] {
+        [self.id()]
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = Error;
+
+    /// Parses the name of an algorithm, ignoring the case. `hades` is
+    /// accepted for Poseidon.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if s.eq_ignore_ascii_case("hades") {
+            return Ok(HashAlgorithm::Poseidon);
+        }
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| s.eq_ignore_ascii_case(algorithm.name()))
+            .ok_or(Error::UnknownHashAlgorithm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids() {
+        for algorithm in HashAlgorithm::ALL {
+            assert_eq!(HashAlgorithm::try_from(algorithm.id()), Ok(algorithm));
+            assert_eq!(
+                HashAlgorithm::from_bytes(&algorithm.to_bytes()),
+                Ok(algorithm)
+            );
+            assert_eq!(algorithm.name().parse(), Ok(algorithm));
+            assert_eq!(algorithm.hasher().algorithm(), algorithm);
+        }
+        assert_eq!(HashAlgorithm::ALL.map(HashAlgorithm::id), [1, 2, 3, 4, 5]);
+        assert_eq!(HashAlgorithm::from_id(6), Err(Error::UnknownHashAlgorithm));
+        assert_eq!(
+            HashAlgorithm::from_bytes(&[0]),
+            Err(BytesError::InvalidData)
+        );
+        assert_eq!("Hades".parse(), Ok(HashAlgorithm::Poseidon));
+        assert!("mimc".parse::<HashAlgorithm>().is_err());
+    }
+
+    #[test]
+    fn digest() {
+        let input = [BlsScalar::one(), BlsScalar::from(2), BlsScalar::from(3)];
+        assert_eq!(
+            HashAlgorithm::Poseidon.digest(Domain::Other, &input),
+            Hash::<hades::ScalarPermutation, { hades::WIDTH }>::digest(Domain::Other, &input)
+        );
+        assert_eq!(
+            HashAlgorithm::Arion.digest_truncated(Domain::Other, &input),
+            Hash::<arion::ScalarPermutation, { arion::WIDTH }>::digest_truncated(
+                Domain::Other,
+                &input
+            )
+        );
+
+        // Every algorithm gives another digest
+        let digests: Vec<_> = HashAlgorithm::ALL
+            .iter()
+            .map(|algorithm| algorithm.digest(Domain::Other, &input))
+            .collect();
+        for (i, digest) in digests.iter().enumerate() {
+            assert!(digests[i + 1..].iter().all(|other| other != digest));
+        }
+    }
+
+    #[cfg(feature = "zk")]
+    #[test]
+    fn digest_gadget() {
+        use dusk_plonk::prelude::*;
+
+        let input = [BlsScalar::from(7), BlsScalar::from(11)];
+        for algorithm in HashAlgorithm::ALL {
+            let mut composer = Composer::initialized();
+            let witnesses = input.map(|scalar| composer.append_witness(scalar));
+            let output = algorithm.digest_gadget(Domain::Merkle2, &mut composer, &witnesses);
+            assert_eq!(
+                composer[output[0]],
+                algorithm.digest(Domain::Merkle2, &input)[0]
+            );
+        }
+    }
+
+    #[cfg(feature = "zk")]
+    #[test]
+    fn prove_by_id() {
+        use dusk_plonk::prelude::*;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let input = alloc::vec![BlsScalar::from(7), BlsScalar::from(11)];
+        let circuits: Vec<DigestCircuit> = HashAlgorithm::ALL
+            .map(|algorithm| DigestCircuit::new(algorithm, Domain::Merkle2, input.clone()).unwrap())
+            .to_vec();
+        let size = circuits.iter().map(Circuit::size).max().unwrap();
+        let mut rng = StdRng::seed_from_u64(0xbeef);
+        let pp = PublicParameters::setup(2 * size.next_power_of_two(), &mut rng)
+            .expect("public parameters");
+
+        // The algorithm is only known at runtime, from its ID
+        let mut verifiers = Vec::new();
+        let mut proofs = Vec::new();
+        for id in 1..=5 {
+            let algorithm = HashAlgorithm::from_id(id).unwrap();
+            let circuit = DigestCircuit::new(algorithm, Domain::Merkle2, input.clone()).unwrap();
+            let (prover, verifier) = Compiler::compile_with_circuit(&pp, b"digest", &circuit)
+                .expect("the circuit should compile");
+            let (proof, public_inputs) = prover
+                .prove(&mut rng, &circuit)
+                .expect("proving should succeed");
+            assert_eq!(public_inputs, algorithm.digest(Domain::Merkle2, &input));
+            assert_eq!(public_inputs, circuit.public_inputs());
+            verifier
+                .verify(&proof, &public_inputs)
+                .expect("the proof should verify");
+            verifiers.push(verifier);
+            proofs.push((proof, public_inputs));
+        }
+
+        // A proof only verifies against the circuit of its algorithm
+        let (proof, public_inputs) = &proofs[0];
+        assert!(verifiers[1].verify(proof, public_inputs).is_err());
+        assert!(verifiers[0].verify(proof, &proofs[1].1).is_err());
+
+        assert_eq!(
+            DigestCircuit::new(HashAlgorithm::Arion, Domain::Merkle4, input),
+            Err(crate::Error::IOPatternViolation)
+        );
+    }
+
+    #[test]
+    fn try_digest() {
+        let input = [BlsScalar::one(), BlsScalar::from(2), BlsScalar::from(3)];
+        for algorithm in HashAlgorithm::ALL {
+            assert_eq!(
+                algorithm.try_digest(Domain::Other, &input),
+                Ok(algorithm.digest(Domain::Other, &input))
+            );
+            assert_eq!(
+                algorithm.try_digest_truncated(Domain::Other, &input),
+                Ok(algorithm.digest_truncated(Domain::Other, &input))
+            );
+            assert_eq!(
+                algorithm.try_digest(Domain::Merkle4, &input),
+                Err(Error::IOPatternViolation)
+            );
+            assert_eq!(
+                algorithm.try_digest_truncated(Domain::Merkle2, &input),
+                Err(Error::IOPatternViolation)
+            );
+
+            #[cfg(feature = "zk")]
+            {
+                use dusk_plonk::prelude::Composer;
+
+                let mut composer = Composer::initialized();
+                let witnesses = input.map(|scalar| composer.append_witness(scalar));
+                assert_eq!(
+                    algorithm.try_digest_gadget(Domain::Merkle4, &mut composer, &witnesses),
+                    Err(Error::IOPatternViolation)
+                );
+            }
+        }
+    }
+}
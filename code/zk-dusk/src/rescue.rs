@@ -1,18 +1,23 @@
-mod mds_matrix;
 mod permutation;
-mod round_constants;
+mod widths;
 
-use mds_matrix::MDS_MATRIX;
-use round_constants::ROUND_CONSTANTS;
+use dusk_bls12_381::BlsScalar;
 
-/// Width of the rescue sponge
+/// Default width of the rescue sponge
 pub const WIDTH: usize = 8;
 
-// WIDTH = 4 -> Rounds=11
-// WIDTH= 5 -> Rounds=9
-// WIDTH= 6 -> Rounds=8
-// WIDTH= 8 -> Rounds=8
-const NUMBER_OF_ROUNDS: usize = 8;
+/// Parameters of the Rescue permutation for a state width, implemented for
+/// [`Width`](crate::Width) 4, 5, 6 and 8.
+pub trait RescueParameters<const W: usize> {
+    /// Number of rounds: 11, 9, 8 and 8 for `W` of 4, 5, 6 and 8.
+    const NUMBER_OF_ROUNDS: usize;
+
+    /// The `2 * W * NUMBER_OF_ROUNDS` round constants.
+    const ROUND_CONSTANTS: &'static [BlsScalar];
+
+    /// The `W x W` MDS matrix.
+    const MDS_MATRIX: [[BlsScalar; W]; W];
+}
 
 #[cfg(feature = "zk")]
 pub use permutation::gadget::GadgetPermutation;
@@ -44,4 +49,39 @@ const fn u64_from_buffer<const N: usize>(buf: &[u8; N], i: usize) -> u64 {
         buf[i + 6],
         buf[i + 7],
     ])
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zk_lib::parameters::rescue_prime;
+
+    // Bits of the Bls12-381 scalar field and targeted security level
+    const FIELD_BITS: usize = 255;
+    const SECURITY_LEVEL: usize = 128;
+
+    #[test]
+    fn number_of_rounds() {
+        use crate::Width;
+
+        fn check<const W: usize>()
+        where
+            Width<W>: RescueParameters<W>,
+        {
+            let rounds = <Width<W>>::NUMBER_OF_ROUNDS;
+
+            // The sponge has a capacity of one element
+            let bounds = rescue_prime::round_bounds(FIELD_BITS, W, 1, ALPHA as u64, SECURITY_LEVEL);
+            assert!(rounds >= bounds.minimum());
+            assert_eq!(
+                rounds,
+                rescue_prime::number_of_rounds(FIELD_BITS, W, 1, ALPHA as u64, SECURITY_LEVEL)
+            );
+        }
+
+        check::<4>();
+        check::<5>();
+        check::<6>();
+        check::<8>();
+    }
+}
@@ -1,31 +1,35 @@
-use crate::rescue::{NUMBER_OF_ROUNDS, WIDTH};
+use crate::rescue::RescueParameters;
+use crate::width::Width;
 
 #[cfg(feature = "zk")]
 pub(crate) mod gadget;
 
 pub(crate) mod scalar;
 
-pub(crate) trait Rescue<T> {
+pub(crate) trait Rescue<T, const W: usize>
+where
+    Width<W>: RescueParameters<W>,
+{
     fn add_round_constants_leyer_1(
         &mut self,
         round: usize,
-        state: &mut [T; WIDTH],
+        state: &mut [T; W],
     );
     
     fn add_round_constants_leyer_2(
         &mut self,
         round: usize,
-        state: &mut [T; WIDTH],
+        state: &mut [T; W],
     );
 
     fn sbox_layer(&mut self, value: &mut T);
 
-    fn mds_leyer(&mut self, state: &mut [T; WIDTH]);
+    fn mds_leyer(&mut self, state: &mut [T; W]);
 
     fn inverse_sbox(&mut self, value: &mut T);
 
-    fn perm(&mut self, state: &mut [T; WIDTH]) {
-        for round in 0..NUMBER_OF_ROUNDS {
+    fn perm(&mut self, state: &mut [T; W]) {
+        for round in 0..<Width<W>>::NUMBER_OF_ROUNDS {
             // Sbox
             state.iter_mut().for_each(|w| self.sbox_layer(w));
 
@@ -4,33 +4,40 @@ use dusk_safe::Safe;
 
 use crate::{
     news::NewableSafe,
-    rescue::{ALPHA_INV, MDS_MATRIX, ROUND_CONSTANTS, WIDTH},
+    rescue::{RescueParameters, ALPHA_INV, WIDTH},
+    width::Width,
 };
 
 use super::Rescue;
 
-/// Gadget Permutation of the Rescue
-pub struct GadgetPermutation<'a> {
+/// Gadget Permutation of the Rescue over a state of `W` elements
+pub struct GadgetPermutation<'a, const W: usize = WIDTH> {
     composer: &'a mut Composer,
 }
 
-impl<'a> GadgetPermutation<'a> {
+impl<'a, const W: usize> GadgetPermutation<'a, W> {
     /// Constructs a new `GadgetPermutation` with the constraint system.
     pub fn new(composer: &'a mut Composer) -> Self {
         Self { composer }
     }
 }
 
-impl<'a> NewableSafe<WIDTH> for GadgetPermutation<'a> {
-    type T<'b> = GadgetPermutation<'b>;
+impl<'a, const W: usize> NewableSafe<W> for GadgetPermutation<'a, W>
+where
+    Width<W>: RescueParameters<W>,
+{
+    type T<'b> = GadgetPermutation<'b, W>;
 
     fn new(composer: &mut Composer) -> Self::T<'_> {
         Self::T::new(composer)
     }
 }
 
-impl<'a> Safe<Witness, WIDTH> for GadgetPermutation<'a> {
-    fn permute(&mut self, state: &mut [Witness; WIDTH]) {
+impl<'a, const W: usize> Safe<Witness, W> for GadgetPermutation<'a, W>
+where
+    Width<W>: RescueParameters<W>,
+{
+    fn permute(&mut self, state: &mut [Witness; W]) {
         self.perm(state);
     }
 
@@ -46,19 +53,22 @@ impl<'a> Safe<Witness, WIDTH> for GadgetPermutation<'a> {
     }
 }
 
-impl<'a> Rescue<Witness> for GadgetPermutation<'a> {
-    fn add_round_constants_leyer_1(&mut self, round: usize, state: &mut [Witness; WIDTH]) {
+impl<'a, const W: usize> Rescue<Witness, W> for GadgetPermutation<'a, W>
+where
+    Width<W>: RescueParameters<W>,
+{
+    fn add_round_constants_leyer_1(&mut self, round: usize, state: &mut [Witness; W]) {
         state.iter_mut().enumerate().for_each(|(i, w)| {
-            let constant = ROUND_CONSTANTS[round * 2 * WIDTH + i];
+            let constant = <Width<W>>::ROUND_CONSTANTS[round * 2 * W + i];
             let constraint = Constraint::new().left(1).a(*w).constant(constant);
 
             *w = self.composer.gate_add(constraint);
         })
     }
 
-    fn add_round_constants_leyer_2(&mut self, round: usize, state: &mut [Witness; WIDTH]) {
+    fn add_round_constants_leyer_2(&mut self, round: usize, state: &mut [Witness; W]) {
         state.iter_mut().enumerate().for_each(|(i, w)| {
-            let constant = ROUND_CONSTANTS[round * 2 * WIDTH + WIDTH + i];
+            let constant = <Width<W>>::ROUND_CONSTANTS[round * 2 * W + W + i];
             let constraint = Constraint::new().left(1).a(*w).constant(constant);
             
             *w = self.composer.gate_add(constraint);
@@ -76,146 +86,117 @@ impl<'a> Rescue<Witness> for GadgetPermutation<'a> {
         *value = self.composer.gate_mul(constraint);
     }
 
-    fn mds_leyer(&mut self, state: &mut [Witness; WIDTH]) {
-        // For t = 5, r = 4
-        let mut result = [Composer::ZERO; WIDTH];
-        
-        /* for j in 0..WIDTH {
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][0])
-                .a(state[0])
-                .right(MDS_MATRIX[j][1])
-                .b(state[1])
-                .fourth(MDS_MATRIX[j][2])
-                .d(state[2]);
-
-            result[j] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][3])
-                .a(state[3])
-                .right(MDS_MATRIX[j][4])
-                .b(state[4])
-                .fourth(1)
-                .d(result[j]);
+    fn mds_leyer(&mut self, state: &mut [Witness; W]) {
+        let mut result = [Composer::ZERO; W];
 
-            result[j] = self.composer.gate_add(constraint);
-        }
-        state.copy_from_slice(&result); */
+        // r[j] = sum_{k=0..W} ( MDS[j][k] * state[k] ), with three terms in
+        // the first gate and two more terms in each following gate
+        for j in 0..W {
+            let mds = &<Width<W>>::MDS_MATRIX[j];
 
-        // For t=4, r=2
-        /* let mut result = [Composer::ZERO; WIDTH];
-
-        for j in 0..WIDTH {
             let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][0])
+                .left(mds[0])
                 .a(state[0])
-                .right(MDS_MATRIX[j][1])
+                .right(mds[1])
                 .b(state[1])
-                .fourth(MDS_MATRIX[j][2])
+                .fourth(mds[2])
                 .d(state[2]);
-        
-            result[j] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][3])
-                .a(state[3])
-                .right(1)
-                .b(result[j]);
 
             result[j] = self.composer.gate_add(constraint);
-        }
-        state.copy_from_slice(&result); */
 
-        // For t = 6, r=4
-        /* for j in 0..WIDTH {
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][0])
-                .a(state[0])
-                .right(MDS_MATRIX[j][1])
-                .b(state[1])
-                .fourth(MDS_MATRIX[j][2])
-                .d(state[2]);
-
-            result[j] = self.composer.gate_add(constraint);
-
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][3])
-                .a(state[3])
-                .right(MDS_MATRIX[j][4])
-                .b(state[4])
-                .fourth(1)
-                .d(result[j]);
-
-            result[j] = self.composer.gate_add(constraint);
+            for k in (3..W).step_by(2) {
+                let mut constraint = Constraint::new()
+                    .left(mds[k])
+                    .a(state[k])
+                    .fourth(1)
+                    .d(result[j]);
+                if k + 1 < W {
+                    constraint = constraint.right(mds[k + 1]).b(state[k + 1]);
+                }
+
+                result[j] = self.composer.gate_add(constraint);
+            }
+        }
+        state.copy_from_slice(&result);
+    }
 
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][5])
-                .a(state[5])
-                .right(1)
-                .b(result[j]);
+    fn inverse_sbox(&mut self, value: &mut Witness) {
+        let tmp = self.composer[*value];
+        let tmp = tmp.pow_vartime(&ALPHA_INV);
 
-            result[j] = self.composer.gate_add(constraint);
-        }
-        state.copy_from_slice(&result); */
+        let wit = self.composer.append_witness(tmp);
+        assert_root(self.composer, wit, *value);
+        *value = wit;
+    }
+}
 
-        // For t = 8, r = 4
-        for j in 0..WIDTH {
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][0])
-                .a(state[0])
-                .right(MDS_MATRIX[j][1])
-                .b(state[1])
-                .fourth(MDS_MATRIX[j][2])
-                .d(state[2]);
+/// Constrains `root^5 == value`, so that the inverse S-box can only be
+/// given the unique root of `value`.
+fn assert_root(composer: &mut Composer, root: Witness, value: Witness) {
+    // y^2
+    let constraint = Constraint::new().mult(1).a(root).b(root);
+    let tmp_wit = composer.gate_mul(constraint);
+    // y^4
+    let constraint = Constraint::new().mult(1).a(tmp_wit).b(tmp_wit);
+    let tmp_wit = composer.gate_mul(constraint);
+    // y^5
+    let constraint = Constraint::new().mult(1).a(tmp_wit).b(root);
+    let tmp_wit = composer.gate_mul(constraint);
+    // y^5 == x
+    composer.assert_equal(tmp_wit, value);
+}
 
-            result[j] = self.composer.gate_add(constraint);
+#[cfg(feature = "encryption")]
+impl<const W: usize> dusk_safe::Encryption<Witness, W>
+    for GadgetPermutation<'_, W>
+where
+    Width<W>: RescueParameters<W>,
+{
+    fn subtract(&mut self, minuend: &Witness, subtrahend: &Witness) -> Witness {
+        let constraint = Constraint::new()
+            .left(1)
+            .a(*minuend)
+            .right(-BlsScalar::one())
+            .b(*subtrahend);
+        self.composer.gate_add(constraint)
+    }
 
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][3])
-                .a(state[3])
-                .right(MDS_MATRIX[j][4])
-                .b(state[4])
-                .fourth(1)
-                .d(result[j]);
+    fn is_equal(&mut self, lhs: &Witness, rhs: &Witness) -> bool {
+        self.composer.assert_equal(*lhs, *rhs);
+        // for the encryption to work we need to return true here, the proof
+        // creation will fail at a later point if the above assertion isn't met
+        true
+    }
+}
 
-            result[j] = self.composer.gate_add(constraint);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rescue::ScalarPermutation;
+    use crate::kat::gadget::{check_gadget, check_root, RootConstraint};
 
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][5])
-                .a(state[5])
-                .right(MDS_MATRIX[j][6])
-                .b(state[6])
-                .fourth(1)
-                .d(result[j]);
+    const KAT: &str = include_str!("../../../kat/rescue.json");
 
-            result[j] = self.composer.gate_add(constraint);
+    struct InverseSbox;
 
-            let constraint = Constraint::new()
-                .left(MDS_MATRIX[j][7])
-                .a(state[7])
-                .fourth(1)
-                .d(result[j]);
+    impl RootConstraint for InverseSbox {
+        const D: u64 = 5;
 
-            result[j] = self.composer.gate_add(constraint);
+        fn assert_root(composer: &mut Composer, root: Witness, value: Witness) {
+            super::assert_root(composer, root, value);
         }
-        state.copy_from_slice(&result);
     }
 
-    fn inverse_sbox(&mut self, value: &mut Witness) {
-        let tmp = self.composer[*value];
-        let tmp = tmp.pow_vartime(&ALPHA_INV);
+    #[test]
+    fn gadget_kat() {
+        check_gadget::<ScalarPermutation<4>, GadgetPermutation<4>, 4>(KAT, "rescue");
+        check_gadget::<ScalarPermutation<5>, GadgetPermutation<5>, 5>(KAT, "rescue");
+        check_gadget::<ScalarPermutation<6>, GadgetPermutation<6>, 6>(KAT, "rescue");
+        check_gadget::<ScalarPermutation<8>, GadgetPermutation<8>, 8>(KAT, "rescue");
+    }
 
-        let wit = self.composer.append_witness(tmp);
-        // y^2
-        let constraint = Constraint::new().mult(1).a(wit).b(wit);
-        let tmp_wit = self.composer.gate_mul(constraint);
-        // y^4
-        let constraint = Constraint::new().mult(1).a(tmp_wit).b(tmp_wit);
-        let tmp_wit = self.composer.gate_mul(constraint);
-        // y^5
-        let constraint = Constraint::new().mult(1).a(tmp_wit).b(wit);
-        *value = self.composer.gate_mul(constraint);
-        *value = wit;
+    #[test]
+    fn forged_root() {
+        check_root::<InverseSbox>("rescue");
     }
 }
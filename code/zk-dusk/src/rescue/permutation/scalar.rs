@@ -5,22 +5,26 @@ use super::Rescue;
 
 use crate::{
     news::NewableScalar,
-    rescue::{ALPHA_INV, MDS_MATRIX, ROUND_CONSTANTS, WIDTH},
+    rescue::{RescueParameters, ALPHA_INV, WIDTH},
+    width::Width,
 };
 
-/// ScalarPermutation of Rescue
+/// ScalarPermutation of Rescue over a state of `W` elements
 #[derive(Default)]
-pub struct ScalarPermutation();
+pub struct ScalarPermutation<const W: usize = WIDTH>();
 
-impl NewableScalar for ScalarPermutation {
+impl<const W: usize> NewableScalar for ScalarPermutation<W> {
     /// Constructs a new `ScalarPermutation`.
     fn new() -> Self {
         Self()
     }
 }
 
-impl Safe<BlsScalar, WIDTH> for ScalarPermutation {
-    fn permute(&mut self, state: &mut [BlsScalar; WIDTH]) {
+impl<const W: usize> Safe<BlsScalar, W> for ScalarPermutation<W>
+where
+    Width<W>: RescueParameters<W>,
+{
+    fn permute(&mut self, state: &mut [BlsScalar; W]) {
         self.perm(state);
     }
 
@@ -33,24 +37,27 @@ impl Safe<BlsScalar, WIDTH> for ScalarPermutation {
     }
 }
 
-impl Rescue<BlsScalar> for ScalarPermutation {
+impl<const W: usize> Rescue<BlsScalar, W> for ScalarPermutation<W>
+where
+    Width<W>: RescueParameters<W>,
+{
     fn add_round_constants_leyer_1(
         &mut self,
         round: usize,
-        state: &mut [BlsScalar; WIDTH],
+        state: &mut [BlsScalar; W],
     ) {
         state.iter_mut().enumerate().for_each(|(i, w)| {
-            *w += ROUND_CONSTANTS[round * 2 * WIDTH + i]
+            *w += <Width<W>>::ROUND_CONSTANTS[round * 2 * W + i]
         })
     }
 
     fn add_round_constants_leyer_2(
         &mut self,
         round: usize,
-        state: &mut [BlsScalar; WIDTH],
+        state: &mut [BlsScalar; W],
     ) {
         state.iter_mut().enumerate().for_each(|(i, w)| {
-            *w += ROUND_CONSTANTS[round * 2 * WIDTH + WIDTH + i]
+            *w += <Width<W>>::ROUND_CONSTANTS[round * 2 * W + W + i]
         })
     }
 
@@ -58,12 +65,12 @@ impl Rescue<BlsScalar> for ScalarPermutation {
         *value = value.square().square() * *value;
     }
 
-    fn mds_leyer(&mut self, state: &mut [BlsScalar; WIDTH]) {
-        let mut result = [BlsScalar::zero(); WIDTH];
+    fn mds_leyer(&mut self, state: &mut [BlsScalar; W]) {
+        let mut result = [BlsScalar::zero(); W];
 
         for (j, value) in state.iter().enumerate() {
-            for k in 0..WIDTH {
-                result[k] += MDS_MATRIX[k][j] * value;
+            for k in 0..W {
+                result[k] += <Width<W>>::MDS_MATRIX[k][j] * value;
             }
         }
         state.copy_from_slice(&result);
@@ -73,3 +80,38 @@ impl Rescue<BlsScalar> for ScalarPermutation {
         *value = value.pow(&ALPHA_INV);
     }
 }
+
+#[cfg(feature = "encryption")]
+impl<const W: usize> dusk_safe::Encryption<BlsScalar, W>
+    for ScalarPermutation<W>
+where
+    Width<W>: RescueParameters<W>,
+{
+    fn subtract(
+        &mut self,
+        minuend: &BlsScalar,
+        subtrahend: &BlsScalar,
+    ) -> BlsScalar {
+        minuend - subtrahend
+    }
+
+    fn is_equal(&mut self, lhs: &BlsScalar, rhs: &BlsScalar) -> bool {
+        lhs == rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kat::check_scalar;
+
+    const KAT: &str = include_str!("../../../kat/rescue.json");
+
+    #[test]
+    fn kat() {
+        check_scalar::<ScalarPermutation<4>, 4>(KAT, "rescue");
+        check_scalar::<ScalarPermutation<5>, 5>(KAT, "rescue");
+        check_scalar::<ScalarPermutation<6>, 6>(KAT, "rescue");
+        check_scalar::<ScalarPermutation<8>, 8>(KAT, "rescue");
+    }
+}
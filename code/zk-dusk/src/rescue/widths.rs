@@ -0,0 +1,66 @@
+//! Rescue constants of each width, loaded from
+//! `assets/rescue/round_constants_{W}.bin` and
+//! `assets/rescue/mds_matrix_{W}.bin`.
+
+use dusk_bls12_381::BlsScalar;
+
+use super::RescueParameters;
+use crate::width::{scalars, table, Width};
+
+impl RescueParameters<4> for Width<4> {
+    const NUMBER_OF_ROUNDS: usize = 11;
+    const ROUND_CONSTANTS: &'static [BlsScalar] = &scalars::<88>(include_bytes!(
+        "../../assets/rescue/round_constants_4.bin"
+    ));
+    const MDS_MATRIX: [[BlsScalar; 4]; 4] =
+        table(include_bytes!("../../assets/rescue/mds_matrix_4.bin"));
+}
+
+impl RescueParameters<5> for Width<5> {
+    const NUMBER_OF_ROUNDS: usize = 9;
+    const ROUND_CONSTANTS: &'static [BlsScalar] = &scalars::<90>(include_bytes!(
+        "../../assets/rescue/round_constants_5.bin"
+    ));
+    const MDS_MATRIX: [[BlsScalar; 5]; 5] =
+        table(include_bytes!("../../assets/rescue/mds_matrix_5.bin"));
+}
+
+impl RescueParameters<6> for Width<6> {
+    const NUMBER_OF_ROUNDS: usize = 8;
+    const ROUND_CONSTANTS: &'static [BlsScalar] = &scalars::<96>(include_bytes!(
+        "../../assets/rescue/round_constants_6.bin"
+    ));
+    const MDS_MATRIX: [[BlsScalar; 6]; 6] =
+        table(include_bytes!("../../assets/rescue/mds_matrix_6.bin"));
+}
+
+impl RescueParameters<8> for Width<8> {
+    const NUMBER_OF_ROUNDS: usize = 8;
+    const ROUND_CONSTANTS: &'static [BlsScalar] = &scalars::<128>(include_bytes!(
+        "../../assets/rescue/round_constants_8.bin"
+    ));
+    const MDS_MATRIX: [[BlsScalar; 8]; 8] =
+        table(include_bytes!("../../assets/rescue/mds_matrix_8.bin"));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check_round_constants<const W: usize>()
+    where
+        Width<W>: RescueParameters<W>,
+    {
+        let constants = <Width<W>>::ROUND_CONSTANTS;
+        assert_eq!(constants.len(), 2 * W * <Width<W>>::NUMBER_OF_ROUNDS);
+        assert!(!constants.contains(&BlsScalar::zero()));
+    }
+
+    #[test]
+    fn test_round_constants() {
+        check_round_constants::<4>();
+        check_round_constants::<5>();
+        check_round_constants::<6>();
+        check_round_constants::<8>();
+    }
+}
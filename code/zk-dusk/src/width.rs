@@ -0,0 +1,71 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Selection of the state width of the permutations.
+
+use dusk_bls12_381::BlsScalar;
+
+/// State width of a permutation.
+///
+/// Each permutation implements its parameter trait, such as
+/// [`HadesParameters`](crate::hades::HadesParameters), for the widths whose
+/// constants are shipped in `assets`, so an unsupported width does not
+/// compile.
+pub struct Width<const W: usize>;
+
+const fn u64_from_buffer(buf: &[u8], i: usize) -> u64 {
+    u64::from_le_bytes([
+        buf[i],
+        buf[i + 1],
+        buf[i + 2],
+        buf[i + 3],
+        buf[i + 4],
+        buf[i + 5],
+        buf[i + 6],
+        buf[i + 7],
+    ])
+}
+
+/// Loads the `N` scalars of an asset, which must hold exactly `N` scalars of
+/// 32 bytes.
+pub(crate) const fn scalars<const N: usize>(bytes: &[u8]) -> [BlsScalar; N] {
+    assert!(bytes.len() == N * 32, "wrong number of constants in the asset");
+
+    let mut cnst = [BlsScalar::zero(); N];
+
+    let mut i = 0;
+    while i < N {
+        let a = u64_from_buffer(bytes, 32 * i);
+        let b = u64_from_buffer(bytes, 32 * i + 8);
+        let c = u64_from_buffer(bytes, 32 * i + 16);
+        let d = u64_from_buffer(bytes, 32 * i + 24);
+
+        cnst[i] = BlsScalar::from_raw([a, b, c, d]);
+        i += 1;
+    }
+    cnst
+}
+
+/// Loads a table of `M` rows of `N` scalars, stored row after row.
+pub(crate) const fn table<const N: usize, const M: usize>(
+    bytes: &[u8],
+) -> [[BlsScalar; N]; M] {
+    assert!(bytes.len() == N * M * 32, "wrong number of constants in the asset");
+
+    let mut cnst = [[BlsScalar::zero(); N]; M];
+
+    let mut i = 0;
+    while i < N * M {
+        let a = u64_from_buffer(bytes, 32 * i);
+        let b = u64_from_buffer(bytes, 32 * i + 8);
+        let c = u64_from_buffer(bytes, 32 * i + 16);
+        let d = u64_from_buffer(bytes, 32 * i + 24);
+
+        cnst[i / N][i % N] = BlsScalar::from_raw([a, b, c, d]);
+        i += 1;
+    }
+    cnst
+}
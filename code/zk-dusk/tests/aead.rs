@@ -0,0 +1,177 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "encryption")]
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
+
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::JubJubAffine;
+use dusk_safe::{Encryption, Safe};
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use zk_dusk::news::NewableScalar;
+use zk_dusk::{
+    aead_decrypt, aead_decrypt_with, aead_encrypt, aead_encrypt_with,
+    encrypt_with, hades, Error,
+};
+
+mod common;
+
+use common::{encryption_variables, for_each_permutation};
+
+fn aead_variables(
+    rng: &mut StdRng,
+    message_len: usize,
+    ad_len: usize,
+) -> (Vec<BlsScalar>, Vec<BlsScalar>, JubJubAffine, BlsScalar) {
+    let (message, shared_secret, nonce) = encryption_variables(rng, message_len);
+    // e.g. the note type and the recipient index
+    let associated_data = (0..ad_len).map(|i| BlsScalar::from(i as u64 + 1)).collect();
+
+    (message, associated_data, shared_secret, nonce)
+}
+
+fn check_encrypt_decrypt<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    for (message_len, ad_len) in [(1, 1), (3, 2), (W, W), (2 * W + 1, 3)] {
+        let (message, ad, shared_secret, nonce) =
+            aead_variables(&mut rng, message_len, ad_len);
+
+        let cipher = aead_encrypt_with::<P, W>(&message, &ad, &shared_secret, &nonce)?;
+        assert_eq!(cipher.len(), message_len + 1);
+
+        let decrypted_message =
+            aead_decrypt_with::<P, W>(&cipher, &ad, &shared_secret, &nonce)?;
+        assert_eq!(decrypted_message, message);
+    }
+
+    Ok(())
+}
+
+fn check_incorrect_associated_data_fails<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (message, ad, shared_secret, nonce) = aead_variables(&mut rng, 5, 2);
+
+    let cipher = aead_encrypt_with::<P, W>(&message, &ad, &shared_secret, &nonce)?;
+
+    let mut wrong_ad = ad.clone();
+    wrong_ad[1] += BlsScalar::one();
+    let mut longer_ad = ad.clone();
+    longer_ad.push(BlsScalar::zero());
+    let shorter_ad = &ad[..1];
+
+    for wrong_ad in [&wrong_ad[..], &longer_ad[..], shorter_ad, &[]] {
+        assert_eq!(
+            aead_decrypt_with::<P, W>(&cipher, wrong_ad, &shared_secret, &nonce)
+                .unwrap_err(),
+            Error::DecryptionFailed
+        );
+    }
+
+    Ok(())
+}
+
+fn check_incorrect_cipher_fails<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (message, ad, shared_secret, nonce) = aead_variables(&mut rng, 5, 2);
+
+    let cipher = aead_encrypt_with::<P, W>(&message, &ad, &shared_secret, &nonce)?;
+
+    for i in [0, 5] {
+        let mut wrong_cipher = cipher.clone();
+        wrong_cipher[i] += BlsScalar::from(42);
+        assert_eq!(
+            aead_decrypt_with::<P, W>(&wrong_cipher, &ad, &shared_secret, &nonce)
+                .unwrap_err(),
+            Error::DecryptionFailed
+        );
+    }
+
+    let wrong_nonce = BlsScalar::random(&mut rng);
+    assert_eq!(
+        aead_decrypt_with::<P, W>(&cipher, &ad, &shared_secret, &wrong_nonce)
+            .unwrap_err(),
+        Error::DecryptionFailed
+    );
+
+    assert_eq!(
+        aead_decrypt_with::<P, W>(&[], &ad, &shared_secret, &nonce).unwrap_err(),
+        Error::DecryptionFailed
+    );
+
+    Ok(())
+}
+
+fn check_without_associated_data<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (message, _, shared_secret, nonce) = aead_variables(&mut rng, 5, 0);
+
+    // without associated data, the cipher-text is the one of `encrypt`
+    let cipher = aead_encrypt_with::<P, W>(&message, &[], &shared_secret, &nonce)?;
+    assert_eq!(cipher, encrypt_with::<P, W>(&message, &shared_secret, &nonce)?);
+    assert_eq!(
+        aead_decrypt_with::<P, W>(&cipher, &[], &shared_secret, &nonce)?,
+        message
+    );
+
+    Ok(())
+}
+
+#[test]
+fn encrypt_decrypt() -> Result<(), Error> {
+    for_each_permutation!(check_encrypt_decrypt)
+}
+
+#[test]
+fn incorrect_associated_data_fails() -> Result<(), Error> {
+    for_each_permutation!(check_incorrect_associated_data_fails)
+}
+
+#[test]
+fn incorrect_cipher_fails() -> Result<(), Error> {
+    for_each_permutation!(check_incorrect_cipher_fails)
+}
+
+#[test]
+fn without_associated_data() -> Result<(), Error> {
+    for_each_permutation!(check_without_associated_data)
+}
+
+#[test]
+fn hades_is_the_default() -> Result<(), Error> {
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (message, ad, shared_secret, nonce) = aead_variables(&mut rng, 4, 2);
+
+    let cipher = aead_encrypt(&message, &ad, &shared_secret, &nonce)?;
+    assert_eq!(
+        cipher,
+        aead_encrypt_with::<hades::ScalarPermutation, { hades::WIDTH }>(
+            &message,
+            &ad,
+            &shared_secret,
+            &nonce
+        )?
+    );
+    assert_eq!(aead_decrypt(&cipher, &ad, &shared_secret, &nonce)?, message);
+
+    Ok(())
+}
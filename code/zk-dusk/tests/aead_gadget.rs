@@ -0,0 +1,196 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "encryption")]
+#![cfg(feature = "zk")]
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
+
+use std::marker::PhantomData;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::JubJubAffine;
+use dusk_plonk::prelude::Error as PlonkError;
+use dusk_plonk::prelude::*;
+use dusk_safe::Safe;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use zk_dusk::news::{NewableSafe, NewableScalar};
+use zk_dusk::{
+    aead_decrypt_gadget_with, aead_encrypt_gadget_with, aead_encrypt_with,
+};
+
+mod common;
+
+use common::{encryption_variables, for_each_gadget_permutation, PUB_PARAMS};
+
+static LABEL: &[u8] = b"aead-circuit";
+
+const MESSAGE_LEN: usize = 4;
+const AD_LEN: usize = 2;
+
+/// Encrypts a message of `MESSAGE_LEN` elements with `associated_data`, and
+/// decrypts the cipher-text with `decryption_ad`, with the scalar
+/// permutation `P` and the gadget permutation `G` over a state of `W`
+/// elements.
+#[derive(Debug)]
+struct AeadCircuit<P, G, const W: usize> {
+    pub message: [BlsScalar; MESSAGE_LEN],
+    pub associated_data: [BlsScalar; AD_LEN],
+    pub decryption_ad: [BlsScalar; AD_LEN],
+    pub cipher: Vec<BlsScalar>,
+    pub shared_secret: JubJubAffine,
+    pub nonce: BlsScalar,
+    permutation: PhantomData<(P, G)>,
+}
+
+impl<P, G, const W: usize> AeadCircuit<P, G, W>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    pub fn random(rng: &mut StdRng) -> Self {
+        let (message, shared_secret, nonce) = encryption_variables(rng, MESSAGE_LEN);
+        let message: [BlsScalar; MESSAGE_LEN] =
+            message.try_into().expect("the message has MESSAGE_LEN elements");
+        let associated_data = [BlsScalar::from(7), BlsScalar::from(42)];
+        let cipher = aead_encrypt_with::<P, W>(
+            &message,
+            &associated_data,
+            &shared_secret,
+            &nonce,
+        )
+        .expect("encryption should pass");
+
+        Self {
+            message,
+            associated_data,
+            decryption_ad: associated_data,
+            cipher,
+            shared_secret,
+            nonce,
+            permutation: PhantomData,
+        }
+    }
+}
+
+impl<P, G, const W: usize> Default for AeadCircuit<P, G, W> {
+    fn default() -> Self {
+        Self {
+            message: [BlsScalar::zero(); MESSAGE_LEN],
+            associated_data: [BlsScalar::zero(); AD_LEN],
+            decryption_ad: [BlsScalar::zero(); AD_LEN],
+            cipher: vec![BlsScalar::zero(); MESSAGE_LEN + 1],
+            shared_secret: JubJubAffine::identity(),
+            nonce: BlsScalar::zero(),
+            permutation: PhantomData,
+        }
+    }
+}
+
+impl<P, G, const W: usize> Circuit for AeadCircuit<P, G, W>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+{
+    fn circuit(&self, composer: &mut Composer) -> Result<(), PlonkError> {
+        // append all variables to the circuit
+        let message_wit = self.message.map(|m| composer.append_witness(m));
+        let ad_wit = self.associated_data.map(|a| composer.append_witness(a));
+        let decryption_ad_wit =
+            self.decryption_ad.map(|a| composer.append_witness(a));
+        let secret_wit = composer.append_point(self.shared_secret);
+        let nonce_wit = composer.append_witness(self.nonce);
+
+        // encrypt the message with the gadget and ensure that the resulting
+        // cipher-text is correct
+        let cipher_result = aead_encrypt_gadget_with::<G, W>(
+            composer,
+            &message_wit,
+            &ad_wit,
+            &secret_wit,
+            &nonce_wit,
+        )
+        .expect("encryption should pass");
+        assert_eq!(cipher_result.len(), self.cipher.len());
+        cipher_result
+            .iter()
+            .zip(&self.cipher)
+            .for_each(|(r, c)| composer.assert_equal_constant(*r, 0, Some(*c)));
+
+        // decrypt the cipher-text with the gadget and the associated data of
+        // the decryption
+        let cipher_wit: Vec<Witness> = self
+            .cipher
+            .iter()
+            .map(|c| composer.append_witness(*c))
+            .collect();
+        let message_result = aead_decrypt_gadget_with::<P, G, W>(
+            composer,
+            &cipher_wit,
+            &decryption_ad_wit,
+            &secret_wit,
+            &nonce_wit,
+        )
+        .expect("decryption should pass");
+
+        // assert that the decrypted message is the same as in the beginning
+        assert_eq!(message_result.len(), MESSAGE_LEN);
+        message_result
+            .iter()
+            .zip(message_wit)
+            .for_each(|(r, w)| composer.assert_equal(*r, w));
+
+        Ok(())
+    }
+}
+
+fn check_encrypt_decrypt<P, G, const W: usize>() -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    let (prover, verifier) =
+        Compiler::compile::<AeadCircuit<P, G, W>>(&PUB_PARAMS, LABEL)?;
+
+    let circuit: AeadCircuit<P, G, W> = AeadCircuit::random(&mut rng);
+
+    let (proof, _public_inputs) = prover.prove(&mut rng, &circuit)?;
+
+    let public_inputs = &circuit.cipher;
+    verifier.verify(&proof, public_inputs)
+}
+
+fn check_incorrect_associated_data_fails<P, G, const W: usize>(
+) -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    let (prover, _verifier) =
+        Compiler::compile::<AeadCircuit<P, G, W>>(&PUB_PARAMS, LABEL)?;
+
+    let mut circuit: AeadCircuit<P, G, W> = AeadCircuit::random(&mut rng);
+    circuit.decryption_ad[1] += BlsScalar::one();
+
+    assert!(prover.prove(&mut rng, &circuit).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn encrypt_decrypt() -> Result<(), PlonkError> {
+    for_each_gadget_permutation!(check_encrypt_decrypt)
+}
+
+#[test]
+fn incorrect_associated_data_fails() -> Result<(), PlonkError> {
+    for_each_gadget_permutation!(check_incorrect_associated_data_fails)
+}
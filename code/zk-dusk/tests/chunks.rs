@@ -0,0 +1,192 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "encryption")]
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
+
+use dusk_bls12_381::BlsScalar;
+use dusk_safe::Safe;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use zk_dusk::news::NewableScalar;
+use zk_dusk::{
+    decrypt_chunk, decrypt_chunks, decrypt_chunks_with, encrypt_chunks,
+    encrypt_chunks_with, Error,
+};
+
+mod common;
+
+use common::{encryption_variables, for_each_permutation};
+
+const CHUNK_LEN: usize = 16;
+
+fn check_encrypt_decrypt<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    for message_len in [1, CHUNK_LEN, 3 * CHUNK_LEN, 3 * CHUNK_LEN + 5] {
+        let (message, shared_secret, nonce) =
+            encryption_variables(&mut rng, message_len);
+
+        let cipher = encrypt_chunks_with::<P, W, _>(
+            message.iter().copied(),
+            CHUNK_LEN,
+            &shared_secret,
+            &nonce,
+        )
+        .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(cipher.len(), message_len.div_ceil(CHUNK_LEN));
+        cipher
+            .iter()
+            .zip(message.chunks(CHUNK_LEN))
+            .for_each(|(c, m)| assert_eq!(c.len(), m.len() + 1));
+
+        let decrypted_message = decrypt_chunks_with::<P, W, _>(
+            &cipher,
+            &shared_secret,
+            &nonce,
+        )
+        .collect::<Result<Vec<_>, _>>()?
+        .concat();
+        assert_eq!(decrypted_message, message);
+    }
+
+    Ok(())
+}
+
+fn check_rearranged_chunks_fail<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (message, shared_secret, nonce) =
+        encryption_variables(&mut rng, 4 * CHUNK_LEN);
+
+    let cipher = encrypt_chunks_with::<P, W, _>(
+        message,
+        CHUNK_LEN,
+        &shared_secret,
+        &nonce,
+    )
+    .collect::<Result<Vec<_>, _>>()?;
+
+    let decrypt = |cipher: &[Vec<BlsScalar>]| {
+        decrypt_chunks_with::<P, W, _>(cipher, &shared_secret, &nonce)
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    // swapped chunks
+    let mut swapped = cipher.clone();
+    swapped.swap(1, 2);
+    assert_eq!(decrypt(&swapped).unwrap_err(), Error::DecryptionFailed);
+
+    // truncated, at the end and at the beginning
+    assert_eq!(
+        decrypt(&cipher[..3]).unwrap_err(),
+        Error::DecryptionFailed
+    );
+    assert_eq!(
+        decrypt(&cipher[1..]).unwrap_err(),
+        Error::DecryptionFailed
+    );
+
+    // extended with a chunk
+    let mut extended = cipher.clone();
+    extended.push(cipher[0].clone());
+    assert_eq!(decrypt(&extended).unwrap_err(), Error::DecryptionFailed);
+
+    // a tampered chunk
+    let mut tampered = cipher.clone();
+    tampered[2][0] += BlsScalar::one();
+    assert_eq!(decrypt(&tampered).unwrap_err(), Error::DecryptionFailed);
+
+    // no chunk
+    assert_eq!(decrypt(&[]).unwrap_err(), Error::DecryptionFailed);
+
+    Ok(())
+}
+
+#[test]
+fn encrypt_decrypt() -> Result<(), Error> {
+    for_each_permutation!(check_encrypt_decrypt)
+}
+
+#[test]
+fn rearranged_chunks_fail() -> Result<(), Error> {
+    for_each_permutation!(check_rearranged_chunks_fail)
+}
+
+#[test]
+fn streaming() -> Result<(), Error> {
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (_, shared_secret, nonce) = encryption_variables(&mut rng, 0);
+
+    // a message that is never in memory at once
+    let message = (0..5000u64).map(BlsScalar::from);
+    let mut decrypted_len = 0;
+    let cipher = encrypt_chunks(message, 256, &shared_secret, &nonce)
+        .map(|chunk| chunk.expect("encryption should pass"));
+    for (i, chunk) in decrypt_chunks(cipher, &shared_secret, &nonce).enumerate() {
+        let chunk = chunk?;
+        let expected: Vec<BlsScalar> = (256 * i as u64..)
+            .take(chunk.len())
+            .map(BlsScalar::from)
+            .collect();
+        assert_eq!(chunk, expected);
+        decrypted_len += chunk.len();
+    }
+    assert_eq!(decrypted_len, 5000);
+
+    Ok(())
+}
+
+#[test]
+fn single_chunk() -> Result<(), Error> {
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (message, shared_secret, nonce) =
+        encryption_variables(&mut rng, 3 * CHUNK_LEN + 1);
+
+    let cipher = encrypt_chunks(message.clone(), CHUNK_LEN, &shared_secret, &nonce)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // any chunk decrypts on its own, at its position only
+    assert_eq!(
+        decrypt_chunk(&cipher[2], 2, false, &shared_secret, &nonce)?,
+        message[2 * CHUNK_LEN..3 * CHUNK_LEN]
+    );
+    assert_eq!(
+        decrypt_chunk(&cipher[3], 3, true, &shared_secret, &nonce)?,
+        message[3 * CHUNK_LEN..]
+    );
+    assert_eq!(
+        decrypt_chunk(&cipher[2], 1, false, &shared_secret, &nonce).unwrap_err(),
+        Error::DecryptionFailed
+    );
+    assert_eq!(
+        decrypt_chunk(&cipher[2], 2, true, &shared_secret, &nonce).unwrap_err(),
+        Error::DecryptionFailed
+    );
+
+    Ok(())
+}
+
+#[test]
+fn empty_fails() {
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (message, shared_secret, nonce) = encryption_variables(&mut rng, 3);
+
+    let mut cipher = encrypt_chunks([], CHUNK_LEN, &shared_secret, &nonce);
+    assert_eq!(cipher.next(), Some(Err(Error::EncryptionFailed)));
+    assert_eq!(cipher.next(), None);
+
+    let mut cipher = encrypt_chunks(message, 0, &shared_secret, &nonce);
+    assert_eq!(cipher.next(), Some(Err(Error::EncryptionFailed)));
+    assert_eq!(cipher.next(), None);
+}
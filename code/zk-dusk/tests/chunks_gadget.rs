@@ -0,0 +1,199 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "encryption")]
+#![cfg(feature = "zk")]
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
+
+use std::marker::PhantomData;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::JubJubAffine;
+use dusk_plonk::prelude::Error as PlonkError;
+use dusk_plonk::prelude::*;
+use dusk_safe::Safe;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use zk_dusk::news::{NewableSafe, NewableScalar};
+use zk_dusk::{decrypt_chunk_gadget_with, encrypt_chunks_with, hades};
+
+mod common;
+
+use common::{encryption_variables, for_each_gadget_permutation, PUB_PARAMS};
+
+static LABEL: &[u8] = b"chunk-circuit";
+
+const CHUNK_LEN: usize = 4;
+const CHUNKS: usize = 3;
+
+/// Proves that the chunk at a public index of an encrypted message starts
+/// with a public element, with the scalar permutation `P` and the gadget
+/// permutation `G` over a state of `W` elements.
+#[derive(Debug)]
+struct ChunkCircuit<P, G, const W: usize> {
+    pub cipher: Vec<BlsScalar>,
+    pub index: u64,
+    pub is_last: BlsScalar,
+    pub first: BlsScalar,
+    pub shared_secret: JubJubAffine,
+    pub nonce: BlsScalar,
+    permutation: PhantomData<(P, G)>,
+}
+
+impl<P, G, const W: usize> ChunkCircuit<P, G, W>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    /// Encrypts a random message of `CHUNKS` chunks and returns the circuit
+    /// for the chunk at `index`.
+    pub fn random(rng: &mut StdRng, index: usize) -> Self {
+        let (message, shared_secret, nonce) = encryption_variables(rng, CHUNKS * CHUNK_LEN);
+
+        let cipher = encrypt_chunks_with::<P, W, _>(
+            message.iter().copied(),
+            CHUNK_LEN,
+            &shared_secret,
+            &nonce,
+        )
+        .nth(index)
+        .expect("the message has the chunk")
+        .expect("encryption should pass");
+
+        Self {
+            cipher,
+            index: index as u64,
+            is_last: BlsScalar::from((index == CHUNKS - 1) as u64),
+            first: message[index * CHUNK_LEN],
+            shared_secret,
+            nonce,
+            permutation: PhantomData,
+        }
+    }
+
+    pub fn public_inputs(&self) -> Vec<BlsScalar> {
+        let mut public_inputs = self.cipher.clone();
+        public_inputs.push(BlsScalar::from(self.index));
+        public_inputs.push(self.first);
+        public_inputs
+    }
+}
+
+impl<P, G, const W: usize> Default for ChunkCircuit<P, G, W> {
+    fn default() -> Self {
+        Self {
+            cipher: vec![BlsScalar::zero(); CHUNK_LEN + 1],
+            index: 0,
+            is_last: BlsScalar::zero(),
+            first: BlsScalar::zero(),
+            shared_secret: JubJubAffine::identity(),
+            nonce: BlsScalar::zero(),
+            permutation: PhantomData,
+        }
+    }
+}
+
+impl<P, G, const W: usize> Circuit for ChunkCircuit<P, G, W>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+{
+    fn circuit(&self, composer: &mut Composer) -> Result<(), PlonkError> {
+        // append all variables to the circuit
+        let cipher_wit: Vec<Witness> = self
+            .cipher
+            .iter()
+            .map(|c| composer.append_public(*c))
+            .collect();
+        let index_wit = composer.append_public(BlsScalar::from(self.index));
+        let is_last_wit = composer.append_witness(self.is_last);
+        let secret_wit = composer.append_point(self.shared_secret);
+        let nonce_wit = composer.append_witness(self.nonce);
+
+        // decrypt the chunk with the gadget
+        let chunk = decrypt_chunk_gadget_with::<P, G, W>(
+            composer,
+            &cipher_wit,
+            &index_wit,
+            &is_last_wit,
+            &secret_wit,
+            &nonce_wit,
+        )
+        .expect("decryption should pass");
+
+        // the fact about the chunk
+        assert_eq!(chunk.len(), CHUNK_LEN);
+        composer.assert_equal_constant(chunk[0], 0, Some(self.first));
+
+        Ok(())
+    }
+}
+
+fn check_decrypt_chunk<P, G, const W: usize>() -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    let (prover, verifier) =
+        Compiler::compile::<ChunkCircuit<P, G, W>>(&PUB_PARAMS, LABEL)?;
+
+    for index in 0..CHUNKS {
+        let circuit: ChunkCircuit<P, G, W> =
+            ChunkCircuit::random(&mut rng, index);
+
+        let (proof, public_inputs) = prover.prove(&mut rng, &circuit)?;
+        assert_eq!(public_inputs, circuit.public_inputs());
+        verifier.verify(&proof, &circuit.public_inputs())?;
+    }
+
+    Ok(())
+}
+
+fn check_incorrect_position_fails<P, G, const W: usize>(
+) -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    let (prover, _verifier) =
+        Compiler::compile::<ChunkCircuit<P, G, W>>(&PUB_PARAMS, LABEL)?;
+
+    // another index
+    let mut circuit: ChunkCircuit<P, G, W> = ChunkCircuit::random(&mut rng, 1);
+    circuit.index = 2;
+    assert!(prover.prove(&mut rng, &circuit).is_err());
+
+    // not the last chunk
+    let mut circuit: ChunkCircuit<P, G, W> = ChunkCircuit::random(&mut rng, 1);
+    circuit.is_last = BlsScalar::one();
+    assert!(prover.prove(&mut rng, &circuit).is_err());
+
+    // not a boolean
+    let mut circuit: ChunkCircuit<P, G, W> = ChunkCircuit::random(&mut rng, 1);
+    circuit.is_last = BlsScalar::from(2);
+    assert!(prover.prove(&mut rng, &circuit).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn decrypt_chunk() -> Result<(), PlonkError> {
+    for_each_gadget_permutation!(check_decrypt_chunk)
+}
+
+#[test]
+fn incorrect_position_fails() -> Result<(), PlonkError> {
+    check_incorrect_position_fails::<
+        hades::ScalarPermutation,
+        hades::GadgetPermutation,
+        { hades::WIDTH },
+    >()
+}
@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Fixtures shared by the integration tests, each of which uses a part of
+//! them.
+
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::{JubJubAffine, JubJubScalar, GENERATOR_EXTENDED};
+use ff::Field;
+use rand::rngs::StdRng;
+
+#[cfg(feature = "zk")]
+use dusk_plonk::prelude::PublicParameters;
+#[cfg(feature = "zk")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "zk")]
+use rand::SeedableRng;
+
+/// The public parameters of the circuits of every test, whose labels tell
+/// them apart.
+#[cfg(feature = "zk")]
+#[allow(dead_code)]
+pub static PUB_PARAMS: Lazy<PublicParameters> = Lazy::new(|| {
+    let mut rng = StdRng::seed_from_u64(0xfab);
+
+    // The largest circuit, sealing a message with Rescue, fits in 2^14 gates
+    // plus blinding
+    const CAPACITY: usize = 15;
+    PublicParameters::setup(1 << CAPACITY, &mut rng)
+        .expect("Setup of public params should pass")
+});
+
+/// Runs `check::<P, W>()` with every scalar permutation `P` at its default
+/// width `W`.
+#[allow(unused_macros)]
+macro_rules! for_each_permutation {
+    ($check:ident) => {{
+        use zk_dusk::{anemoi, arion, griffin, hades, rescue};
+
+        $check::<hades::ScalarPermutation, { hades::WIDTH }>()?;
+        $check::<rescue::ScalarPermutation, { rescue::WIDTH }>()?;
+        $check::<griffin::ScalarPermutation, { griffin::WIDTH }>()?;
+        $check::<anemoi::ScalarPermutation, { anemoi::WIDTH }>()?;
+        $check::<arion::ScalarPermutation, { arion::WIDTH }>()
+    }};
+}
+#[allow(unused_imports)]
+pub(crate) use for_each_permutation;
+
+/// Runs `check::<P, G, W>()` with the scalar permutation `P` and the gadget
+/// permutation `G` of every permutation at its default width `W`.
+#[allow(unused_macros)]
+macro_rules! for_each_gadget_permutation {
+    ($check:ident) => {{
+        use zk_dusk::{anemoi, arion, griffin, hades, rescue};
+
+        $check::<
+            hades::ScalarPermutation,
+            hades::GadgetPermutation,
+            { hades::WIDTH },
+        >()?;
+        $check::<
+            rescue::ScalarPermutation,
+            rescue::GadgetPermutation,
+            { rescue::WIDTH },
+        >()?;
+        $check::<
+            griffin::ScalarPermutation,
+            griffin::GadgetPermutation,
+            { griffin::WIDTH },
+        >()?;
+        $check::<
+            anemoi::ScalarPermutation,
+            anemoi::GadgetPermutation,
+            { anemoi::WIDTH },
+        >()?;
+        $check::<
+            arion::ScalarPermutation,
+            arion::GadgetPermutation,
+            { arion::WIDTH },
+        >()
+    }};
+}
+#[allow(unused_imports)]
+pub(crate) use for_each_gadget_permutation;
+
+/// `len` random scalars.
+#[allow(dead_code)]
+pub fn random_scalars(rng: &mut StdRng, len: usize) -> Vec<BlsScalar> {
+    (0..len).map(|_| BlsScalar::random(&mut *rng)).collect()
+}
+
+/// A random message of `message_len` elements, shared secret and nonce.
+#[allow(dead_code)]
+pub fn encryption_variables(
+    rng: &mut StdRng,
+    message_len: usize,
+) -> (Vec<BlsScalar>, JubJubAffine, BlsScalar) {
+    let message = random_scalars(rng, message_len);
+    let shared_secret = GENERATOR_EXTENDED * JubJubScalar::random(&mut *rng);
+    let nonce = BlsScalar::random(&mut *rng);
+
+    (message, shared_secret.into(), nonce)
+}
+
+/// A random secret key and its public key.
+#[allow(dead_code)]
+pub fn key_pair(rng: &mut StdRng) -> (JubJubScalar, JubJubAffine) {
+    let secret_key = JubJubScalar::random(&mut *rng);
+    let public_key = GENERATOR_EXTENDED * secret_key;
+
+    (secret_key, public_key.into())
+}
@@ -5,109 +5,165 @@
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
 #![cfg(feature = "encryption")]
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
 
 use dusk_bls12_381::BlsScalar;
-use dusk_jubjub::{JubJubAffine, JubJubScalar, GENERATOR_EXTENDED};
-use dusk_poseidon::{decrypt, encrypt, Error};
+use dusk_jubjub::{JubJubScalar, GENERATOR_EXTENDED};
+use dusk_safe::{Encryption, Safe};
+use zk_dusk::news::NewableScalar;
+use zk_dusk::{
+    decrypt, decrypt_with, encrypt, encrypt_with, hades, rescue, Error,
+};
 use ff::Field;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 
-fn encryption_variables(
-    rng: &mut StdRng,
-    message_len: usize,
-) -> (Vec<BlsScalar>, JubJubAffine, BlsScalar) {
-    let mut message = Vec::with_capacity(message_len);
-    for _ in 0..message_len {
-        message.push(BlsScalar::random(&mut *rng));
-    }
-    let shared_secret = GENERATOR_EXTENDED * &JubJubScalar::random(&mut *rng);
-    let nonce = BlsScalar::random(&mut *rng);
-
-    (message, shared_secret.into(), nonce)
-}
+mod common;
 
-#[test]
-fn encrypt_decrypt() -> Result<(), Error> {
+use common::{encryption_variables, for_each_permutation};
+
+fn check_encrypt_decrypt<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
     let mut rng = StdRng::seed_from_u64(0x42424242);
     let message_len = 42usize;
 
     let (message, shared_secret, nonce) =
         encryption_variables(&mut rng, message_len);
 
-    let cipher = encrypt(&message, &shared_secret, &nonce)?;
+    let cipher = encrypt_with::<P, W>(&message, &shared_secret, &nonce)?;
 
-    let decrypted_message = decrypt(&cipher, &shared_secret, &nonce)?;
+    let decrypted_message = decrypt_with::<P, W>(&cipher, &shared_secret, &nonce)?;
 
     assert_eq!(decrypted_message, message);
 
     Ok(())
 }
 
-#[test]
-fn incorrect_shared_secret_fails() -> Result<(), Error> {
+fn check_incorrect_shared_secret_fails<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
     let mut rng = StdRng::seed_from_u64(0x42424242);
     let message_len = 21usize;
 
     let (message, shared_secret, nonce) =
         encryption_variables(&mut rng, message_len);
 
-    let cipher = encrypt(&message, &shared_secret, &nonce)?;
+    let cipher = encrypt_with::<P, W>(&message, &shared_secret, &nonce)?;
 
     let wrong_shared_secret =
         GENERATOR_EXTENDED * &JubJubScalar::random(&mut rng);
     assert_ne!(shared_secret, wrong_shared_secret.into());
 
     assert_eq!(
-        decrypt(&cipher, &wrong_shared_secret.into(), &nonce,).unwrap_err(),
+        decrypt_with::<P, W>(&cipher, &wrong_shared_secret.into(), &nonce).unwrap_err(),
         Error::DecryptionFailed
     );
 
     Ok(())
 }
 
-#[test]
-fn incorrect_nonce_fails() -> Result<(), Error> {
+fn check_incorrect_nonce_fails<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
     let mut rng = StdRng::seed_from_u64(0x42424242);
     let message_len = 21usize;
 
     let (message, shared_secret, nonce) =
         encryption_variables(&mut rng, message_len);
 
-    let cipher = encrypt(&message, &shared_secret, &nonce)?;
+    let cipher = encrypt_with::<P, W>(&message, &shared_secret, &nonce)?;
 
     let wrong_nonce = BlsScalar::random(&mut rng);
     assert_ne!(nonce, wrong_nonce);
 
     assert_eq!(
-        decrypt(&cipher, &shared_secret, &wrong_nonce,).unwrap_err(),
+        decrypt_with::<P, W>(&cipher, &shared_secret, &wrong_nonce).unwrap_err(),
         Error::DecryptionFailed
     );
 
     Ok(())
 }
 
-#[test]
-fn incorrect_cipher_fails() -> Result<(), Error> {
+fn check_incorrect_cipher_fails<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
     let mut rng = StdRng::seed_from_u64(0x42424242);
     let message_len = 21usize;
 
     let (message, shared_secret, nonce) =
         encryption_variables(&mut rng, message_len);
 
-    let cipher = encrypt(&message, &shared_secret, &nonce)?;
+    let cipher = encrypt_with::<P, W>(&message, &shared_secret, &nonce)?;
 
     let mut wrong_cipher = cipher.clone();
     wrong_cipher[message_len] += BlsScalar::from(42);
     assert_eq!(
-        decrypt(&wrong_cipher, &shared_secret, &nonce,).unwrap_err(),
+        decrypt_with::<P, W>(&wrong_cipher, &shared_secret, &nonce).unwrap_err(),
         Error::DecryptionFailed
     );
 
     let mut wrong_cipher = cipher.clone();
     wrong_cipher[0] += BlsScalar::from(42);
     assert_eq!(
-        decrypt(&wrong_cipher, &shared_secret, &nonce,).unwrap_err(),
+        decrypt_with::<P, W>(&wrong_cipher, &shared_secret, &nonce).unwrap_err(),
+        Error::DecryptionFailed
+    );
+
+    Ok(())
+}
+
+#[test]
+fn encrypt_decrypt() -> Result<(), Error> {
+    for_each_permutation!(check_encrypt_decrypt)
+}
+
+#[test]
+fn incorrect_shared_secret_fails() -> Result<(), Error> {
+    for_each_permutation!(check_incorrect_shared_secret_fails)
+}
+
+#[test]
+fn incorrect_nonce_fails() -> Result<(), Error> {
+    for_each_permutation!(check_incorrect_nonce_fails)
+}
+
+#[test]
+fn incorrect_cipher_fails() -> Result<(), Error> {
+    for_each_permutation!(check_incorrect_cipher_fails)
+}
+
+#[test]
+fn hades_is_the_default() -> Result<(), Error> {
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (message, shared_secret, nonce) = encryption_variables(&mut rng, 7);
+
+    let cipher = encrypt(&message, &shared_secret, &nonce)?;
+    assert_eq!(
+        cipher,
+        encrypt_with::<hades::ScalarPermutation, { hades::WIDTH }>(
+            &message,
+            &shared_secret,
+            &nonce
+        )?
+    );
+    assert_eq!(decrypt(&cipher, &shared_secret, &nonce)?, message);
+
+    // Another permutation gives another cipher-text, which Hades can't
+    // decrypt
+    let cipher = encrypt_with::<rescue::ScalarPermutation, { rescue::WIDTH }>(
+        &message,
+        &shared_secret,
+        &nonce,
+    )?;
+    assert_eq!(
+        decrypt(cipher, &shared_secret, &nonce).unwrap_err(),
         Error::DecryptionFailed
     );
 
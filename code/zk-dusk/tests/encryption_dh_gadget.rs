@@ -0,0 +1,313 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "encryption")]
+#![cfg(feature = "zk")]
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
+
+use std::marker::PhantomData;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::{JubJubAffine, JubJubScalar, GENERATOR_EXTENDED};
+use dusk_plonk::prelude::Error as PlonkError;
+use dusk_plonk::prelude::*;
+use dusk_safe::{Encryption, Safe};
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use zk_dusk::news::{NewableSafe, NewableScalar};
+use zk_dusk::{
+    decrypt_with, encrypt_to_public_key_gadget_with, encrypt_with, hades,
+    shared_secret_gadget,
+};
+
+mod common;
+
+use common::{for_each_gadget_permutation, PUB_PARAMS};
+
+static LABEL: &[u8] = b"dh-encryption-circuit";
+
+const MESSAGE_LEN: usize = 4;
+
+/// Encrypts a message of `MESSAGE_LEN` elements to the public key of the
+/// receiver with the secret key of the sender, with the gadget permutation
+/// `G` over a state of `W` elements; the public keys of the receiver and of
+/// the sender, the nonce and the cipher-text are the public inputs.
+#[derive(Debug)]
+struct DhEncryptionCircuit<P, G, const W: usize> {
+    pub message: [BlsScalar; MESSAGE_LEN],
+    pub cipher: Vec<BlsScalar>,
+    pub sender_secret_key: JubJubScalar,
+    pub sender_public_key: JubJubAffine,
+    pub receiver_public_key: JubJubAffine,
+    pub nonce: BlsScalar,
+    permutation: PhantomData<(P, G)>,
+}
+
+impl<P, G, const W: usize> DhEncryptionCircuit<P, G, W>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
+    /// Returns the circuit and the secret key of the receiver.
+    pub fn random(rng: &mut StdRng) -> (Self, JubJubScalar) {
+        let mut message = [BlsScalar::zero(); MESSAGE_LEN];
+        message
+            .iter_mut()
+            .for_each(|s| *s = BlsScalar::random(&mut *rng));
+        let sender_secret_key = JubJubScalar::random(&mut *rng);
+        let sender_public_key: JubJubAffine =
+            (GENERATOR_EXTENDED * sender_secret_key).into();
+        let receiver_secret_key = JubJubScalar::random(&mut *rng);
+        let receiver_public_key: JubJubAffine =
+            (GENERATOR_EXTENDED * receiver_secret_key).into();
+        let nonce = BlsScalar::random(&mut *rng);
+
+        // native Diffie-Hellman
+        let shared_secret: JubJubAffine =
+            (receiver_public_key * sender_secret_key).into();
+        let cipher = encrypt_with::<P, W>(&message, &shared_secret, &nonce)
+            .expect("encryption should pass");
+
+        let circuit = Self {
+            message,
+            cipher,
+            sender_secret_key,
+            sender_public_key,
+            receiver_public_key,
+            nonce,
+            permutation: PhantomData,
+        };
+        (circuit, receiver_secret_key)
+    }
+
+    pub fn public_inputs(&self) -> Vec<BlsScalar> {
+        let mut public_inputs = vec![
+            self.receiver_public_key.get_u(),
+            self.receiver_public_key.get_v(),
+            self.nonce,
+            self.sender_public_key.get_u(),
+            self.sender_public_key.get_v(),
+        ];
+        public_inputs.extend(&self.cipher);
+        public_inputs
+    }
+}
+
+impl<P, G, const W: usize> Default for DhEncryptionCircuit<P, G, W> {
+    fn default() -> Self {
+        Self {
+            message: [BlsScalar::zero(); MESSAGE_LEN],
+            cipher: vec![BlsScalar::zero(); MESSAGE_LEN + 1],
+            sender_secret_key: JubJubScalar::zero(),
+            sender_public_key: JubJubAffine::identity(),
+            receiver_public_key: JubJubAffine::identity(),
+            nonce: BlsScalar::zero(),
+            permutation: PhantomData,
+        }
+    }
+}
+
+impl<P, G, const W: usize> Circuit for DhEncryptionCircuit<P, G, W>
+where
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    fn circuit(&self, composer: &mut Composer) -> Result<(), PlonkError> {
+        // append all variables to the circuit
+        let message_wit = self.message.map(|m| composer.append_witness(m));
+        let secret_key_wit = composer.append_witness(self.sender_secret_key);
+        let public_key_wit =
+            composer.append_public_point(self.receiver_public_key);
+        let nonce_wit = composer.append_public(self.nonce);
+
+        // encrypt the message to the public key with the gadget
+        let (sender_public_key_wit, cipher_result) =
+            encrypt_to_public_key_gadget_with::<G, W>(
+                composer,
+                &message_wit,
+                &secret_key_wit,
+                &public_key_wit,
+                &nonce_wit,
+            )
+            .expect("encryption should pass");
+
+        // ensure that the message is encrypted by the published sender
+        composer.assert_equal_public_point(
+            sender_public_key_wit,
+            self.sender_public_key,
+        );
+
+        // ensure that the resulting cipher-text is correct
+        assert_eq!(cipher_result.len(), self.cipher.len());
+        cipher_result
+            .iter()
+            .zip(&self.cipher)
+            .for_each(|(r, c)| composer.assert_equal_constant(*r, 0, Some(*c)));
+
+        Ok(())
+    }
+}
+
+fn check_encrypt_to_public_key<P, G, const W: usize>() -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    let (prover, verifier) =
+        Compiler::compile::<DhEncryptionCircuit<P, G, W>>(&PUB_PARAMS, LABEL)?;
+
+    let (circuit, receiver_secret_key): (DhEncryptionCircuit<P, G, W>, _) =
+        DhEncryptionCircuit::random(&mut rng);
+
+    let (proof, public_inputs) = prover.prove(&mut rng, &circuit)?;
+    assert_eq!(public_inputs, circuit.public_inputs());
+    verifier.verify(&proof, &circuit.public_inputs())?;
+
+    // the receiver decrypts the cipher-text with their secret key and the
+    // public key of the sender
+    let shared_secret: JubJubAffine =
+        (circuit.sender_public_key * receiver_secret_key).into();
+    let message = decrypt_with::<P, W>(&circuit.cipher, &shared_secret, &circuit.nonce)
+        .expect("decryption should pass");
+    assert_eq!(message, circuit.message);
+
+    Ok(())
+}
+
+fn check_incorrect_secret_key_fails<P, G, const W: usize>() -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    let (prover, _verifier) =
+        Compiler::compile::<DhEncryptionCircuit<P, G, W>>(&PUB_PARAMS, LABEL)?;
+
+    let (mut circuit, _): (DhEncryptionCircuit<P, G, W>, _) =
+        DhEncryptionCircuit::random(&mut rng);
+    circuit.sender_secret_key = JubJubScalar::random(&mut rng);
+
+    assert!(prover.prove(&mut rng, &circuit).is_err());
+
+    Ok(())
+}
+
+fn check_incorrect_public_key_fails<P, G, const W: usize>() -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    let (prover, verifier) =
+        Compiler::compile::<DhEncryptionCircuit<P, G, W>>(&PUB_PARAMS, LABEL)?;
+
+    let (circuit, _): (DhEncryptionCircuit<P, G, W>, _) =
+        DhEncryptionCircuit::random(&mut rng);
+    let (proof, _public_inputs) = prover.prove(&mut rng, &circuit)?;
+
+    // the proof doesn't verify for the public key of another receiver
+    let mut public_inputs = circuit.public_inputs();
+    let wrong_public_key: JubJubAffine =
+        (GENERATOR_EXTENDED * JubJubScalar::random(&mut rng)).into();
+    public_inputs[0] = wrong_public_key.get_u();
+    public_inputs[1] = wrong_public_key.get_v();
+    assert!(verifier.verify(&proof, &public_inputs).is_err());
+
+    Ok(())
+}
+
+fn check_incorrect_sender_public_key_fails<P, G, const W: usize>(
+) -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    let (prover, verifier) =
+        Compiler::compile::<DhEncryptionCircuit<P, G, W>>(&PUB_PARAMS, LABEL)?;
+
+    let (mut circuit, _): (DhEncryptionCircuit<P, G, W>, _) =
+        DhEncryptionCircuit::random(&mut rng);
+    let (proof, _public_inputs) = prover.prove(&mut rng, &circuit)?;
+    let wrong_public_key: JubJubAffine =
+        (GENERATOR_EXTENDED * JubJubScalar::random(&mut rng)).into();
+
+    // the proof doesn't verify for another sender
+    let mut public_inputs = circuit.public_inputs();
+    public_inputs[3] = wrong_public_key.get_u();
+    public_inputs[4] = wrong_public_key.get_v();
+    assert!(verifier.verify(&proof, &public_inputs).is_err());
+
+    // and a sender can't be published that doesn't match the secret key
+    circuit.sender_public_key = wrong_public_key;
+    assert!(prover.prove(&mut rng, &circuit).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn shared_secret_matches_native() {
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    for _ in 0..4 {
+        let secret_key = JubJubScalar::random(&mut rng);
+        let public_key: JubJubAffine =
+            (GENERATOR_EXTENDED * JubJubScalar::random(&mut rng)).into();
+        let expected: JubJubAffine = (public_key * secret_key).into();
+
+        let mut composer = Composer::initialized();
+        let secret_key_wit = composer.append_witness(secret_key);
+        let public_key_wit = composer.append_point(public_key);
+        let shared_secret =
+            shared_secret_gadget(&mut composer, &secret_key_wit, &public_key_wit);
+
+        assert_eq!(composer[*shared_secret.x()], expected.get_u());
+        assert_eq!(composer[*shared_secret.y()], expected.get_v());
+    }
+}
+
+#[test]
+fn encrypt_to_public_key() -> Result<(), PlonkError> {
+    for_each_gadget_permutation!(check_encrypt_to_public_key)
+}
+
+#[test]
+fn incorrect_secret_key_fails() -> Result<(), PlonkError> {
+    check_incorrect_secret_key_fails::<
+        hades::ScalarPermutation,
+        hades::GadgetPermutation,
+        { hades::WIDTH },
+    >()
+}
+
+#[test]
+fn incorrect_public_key_fails() -> Result<(), PlonkError> {
+    check_incorrect_public_key_fails::<
+        hades::ScalarPermutation,
+        hades::GadgetPermutation,
+        { hades::WIDTH },
+    >()
+}
+
+#[test]
+fn incorrect_sender_public_key_fails() -> Result<(), PlonkError> {
+    check_incorrect_sender_public_key_fails::<
+        hades::ScalarPermutation,
+        hades::GadgetPermutation,
+        { hades::WIDTH },
+    >()
+}
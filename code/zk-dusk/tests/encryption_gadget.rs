@@ -6,35 +6,45 @@
 
 #![cfg(feature = "encryption")]
 #![cfg(feature = "zk")]
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
+
+use std::marker::PhantomData;
 
 use dusk_bls12_381::BlsScalar;
 use dusk_jubjub::{JubJubAffine, JubJubScalar, GENERATOR_EXTENDED};
 use dusk_plonk::prelude::Error as PlonkError;
 use dusk_plonk::prelude::*;
-use dusk_poseidon::{decrypt_gadget, encrypt, encrypt_gadget};
+use dusk_safe::{Encryption, Safe};
 use ff::Field;
-use once_cell::sync::Lazy;
+use zk_dusk::news::{NewableSafe, NewableScalar};
+use zk_dusk::{decrypt_gadget_with, encrypt_gadget_with, encrypt_with};
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 
-static PUB_PARAMS: Lazy<PublicParameters> = Lazy::new(|| {
-    let mut rng = StdRng::seed_from_u64(0xfab);
+mod common;
+
+use common::{for_each_gadget_permutation, PUB_PARAMS};
 
-    const CAPACITY: usize = 13;
-    PublicParameters::setup(1 << CAPACITY, &mut rng)
-        .expect("Setup of public params should pass")
-});
-static LABEL: &[u8] = b"hash-gadget-tester";
+static LABEL: &[u8] = b"encryption-circuit";
 
+/// Encrypts and decrypts a message of `L` elements with the scalar
+/// permutation `P` and the gadget permutation `G` over a state of `W`
+/// elements.
 #[derive(Debug)]
-struct EncryptionCircuit<const L: usize> {
+struct EncryptionCircuit<P, G, const W: usize, const L: usize> {
     pub message: [BlsScalar; L],
     pub cipher: Vec<BlsScalar>,
     pub shared_secret: JubJubAffine,
     pub nonce: BlsScalar,
+    permutation: PhantomData<(P, G)>,
 }
 
-impl<const L: usize> EncryptionCircuit<L> {
+impl<P, G, const W: usize, const L: usize> EncryptionCircuit<P, G, W, L>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
     pub fn random(rng: &mut StdRng) -> Self {
         let mut message = [BlsScalar::zero(); L];
         message
@@ -43,8 +53,9 @@ impl<const L: usize> EncryptionCircuit<L> {
         let shared_secret =
             GENERATOR_EXTENDED * &JubJubScalar::random(&mut *rng);
         let nonce = BlsScalar::random(&mut *rng);
-        let cipher = encrypt(&message, &shared_secret.into(), &nonce)
-            .expect("encryption should pass");
+        let cipher =
+            encrypt_with::<P, W>(&message, &shared_secret.into(), &nonce)
+                .expect("encryption should pass");
         assert_eq!(message.len() + 1, cipher.len());
 
         Self {
@@ -52,11 +63,14 @@ impl<const L: usize> EncryptionCircuit<L> {
             cipher,
             shared_secret: shared_secret.into(),
             nonce,
+            permutation: PhantomData,
         }
     }
 }
 
-impl<const L: usize> Default for EncryptionCircuit<L> {
+impl<P, G, const W: usize, const L: usize> Default
+    for EncryptionCircuit<P, G, W, L>
+{
     fn default() -> Self {
         let message = [BlsScalar::zero(); L];
         let mut cipher = message.to_vec();
@@ -69,11 +83,17 @@ impl<const L: usize> Default for EncryptionCircuit<L> {
             cipher,
             shared_secret,
             nonce,
+            permutation: PhantomData,
         }
     }
 }
 
-impl<const L: usize> Circuit for EncryptionCircuit<L> {
+impl<P, G, const W: usize, const L: usize> Circuit
+    for EncryptionCircuit<P, G, W, L>
+where
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
     fn circuit(&self, composer: &mut Composer) -> Result<(), PlonkError> {
         // append all variables to the circuit
         let mut message_wit = [Composer::ZERO; L];
@@ -86,8 +106,13 @@ impl<const L: usize> Circuit for EncryptionCircuit<L> {
 
         // encrypt the message with the gadget
         let cipher_result =
-            encrypt_gadget(composer, &message_wit, &secret_wit, &nonce_wit)
-                .expect("encryption should pass");
+            encrypt_gadget_with::<G, W>(
+                composer,
+                &message_wit,
+                &secret_wit,
+                &nonce_wit,
+            )
+            .expect("encryption should pass");
 
         // ensure that the resulting cipher-text is correct
         assert_eq!(cipher_result.len(), self.cipher.len());
@@ -98,8 +123,13 @@ impl<const L: usize> Circuit for EncryptionCircuit<L> {
 
         // decrypt the cipher result with the gadget
         let message_result =
-            decrypt_gadget(composer, &cipher_result, &secret_wit, &nonce_wit)
-                .expect("decryption should pass");
+            decrypt_gadget_with::<G, W>(
+                composer,
+                &cipher_result,
+                &secret_wit,
+                &nonce_wit,
+            )
+            .expect("decryption should pass");
 
         // assert that the decrypted message is the same as in the beginning
         assert_eq!(message_result.len(), L);
@@ -112,17 +142,21 @@ impl<const L: usize> Circuit for EncryptionCircuit<L> {
     }
 }
 
-#[test]
-fn encrypt_decrypt() -> Result<(), PlonkError> {
+fn check_encrypt_decrypt<P, G, const W: usize>() -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
     let mut rng = StdRng::seed_from_u64(0x42424242);
     const MESSAGE_LEN: usize = 4;
 
-    let (prover, verifier) = Compiler::compile::<EncryptionCircuit<MESSAGE_LEN>>(
+    let (prover, verifier) = Compiler::compile::<EncryptionCircuit<P, G, W, MESSAGE_LEN>>(
         &PUB_PARAMS,
         LABEL,
     )?;
 
-    let circuit: EncryptionCircuit<MESSAGE_LEN> =
+    let circuit: EncryptionCircuit<P, G, W, MESSAGE_LEN> =
         EncryptionCircuit::random(&mut rng);
 
     let (proof, _public_inputs) = prover.prove(&mut rng, &circuit)?;
@@ -131,16 +165,20 @@ fn encrypt_decrypt() -> Result<(), PlonkError> {
     verifier.verify(&proof, public_inputs)
 }
 
-#[test]
-fn incorrect_shared_secret_fails() -> Result<(), Error> {
+fn check_incorrect_shared_secret_fails<P, G, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
     let mut rng = StdRng::seed_from_u64(0x42424242);
     const MESSAGE_LEN: usize = 5;
 
     let (prover, _verifier) = Compiler::compile::<
-        EncryptionCircuit<MESSAGE_LEN>,
+        EncryptionCircuit<P, G, W, MESSAGE_LEN>,
     >(&PUB_PARAMS, LABEL)?;
 
-    let mut circuit: EncryptionCircuit<MESSAGE_LEN> =
+    let mut circuit: EncryptionCircuit<P, G, W, MESSAGE_LEN> =
         EncryptionCircuit::random(&mut rng);
 
     let wrong_shared_secret =
@@ -152,16 +190,20 @@ fn incorrect_shared_secret_fails() -> Result<(), Error> {
     Ok(())
 }
 
-#[test]
-fn incorrect_nonce_fails() -> Result<(), Error> {
+fn check_incorrect_nonce_fails<P, G, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
     let mut rng = StdRng::seed_from_u64(0x42424242);
     const MESSAGE_LEN: usize = 6;
 
     let (prover, _verifier) = Compiler::compile::<
-        EncryptionCircuit<MESSAGE_LEN>,
+        EncryptionCircuit<P, G, W, MESSAGE_LEN>,
     >(&PUB_PARAMS, LABEL)?;
 
-    let mut circuit: EncryptionCircuit<MESSAGE_LEN> =
+    let mut circuit: EncryptionCircuit<P, G, W, MESSAGE_LEN> =
         EncryptionCircuit::random(&mut rng);
 
     let wrong_nonce = BlsScalar::random(&mut rng);
@@ -172,16 +214,20 @@ fn incorrect_nonce_fails() -> Result<(), Error> {
     Ok(())
 }
 
-#[test]
-fn incorrect_cipher_fails() -> Result<(), Error> {
+fn check_incorrect_cipher_fails<P, G, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
     let mut rng = StdRng::seed_from_u64(0x42424242);
     const MESSAGE_LEN: usize = 7;
 
     let (prover, _verifier) = Compiler::compile::<
-        EncryptionCircuit<MESSAGE_LEN>,
+        EncryptionCircuit<P, G, W, MESSAGE_LEN>,
     >(&PUB_PARAMS, LABEL)?;
 
-    let mut circuit: EncryptionCircuit<MESSAGE_LEN> =
+    let mut circuit: EncryptionCircuit<P, G, W, MESSAGE_LEN> =
         EncryptionCircuit::random(&mut rng);
 
     let mut wrong_cipher = circuit.cipher.clone();
@@ -193,17 +239,21 @@ fn incorrect_cipher_fails() -> Result<(), Error> {
     Ok(())
 }
 
-#[test]
-fn incorrect_public_input_fails() -> Result<(), Error> {
+fn check_incorrect_public_input_fails<P, G, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
     let mut rng = StdRng::seed_from_u64(0x42424242);
     const MESSAGE_LEN: usize = 8;
 
-    let (prover, verifier) = Compiler::compile::<EncryptionCircuit<MESSAGE_LEN>>(
+    let (prover, verifier) = Compiler::compile::<EncryptionCircuit<P, G, W, MESSAGE_LEN>>(
         &PUB_PARAMS,
         LABEL,
     )?;
 
-    let circuit: EncryptionCircuit<MESSAGE_LEN> =
+    let circuit: EncryptionCircuit<P, G, W, MESSAGE_LEN> =
         EncryptionCircuit::random(&mut rng);
 
     let (proof, _public_inputs) = prover.prove(&mut rng, &circuit)?;
@@ -215,3 +265,29 @@ fn incorrect_public_input_fails() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn encrypt_decrypt() -> Result<(), PlonkError> {
+    for_each_gadget_permutation!(check_encrypt_decrypt)
+}
+
+#[test]
+fn incorrect_shared_secret_fails() -> Result<(), Error> {
+    for_each_gadget_permutation!(check_incorrect_shared_secret_fails)
+}
+
+#[test]
+fn incorrect_nonce_fails() -> Result<(), Error> {
+    for_each_gadget_permutation!(check_incorrect_nonce_fails)
+}
+
+#[test]
+fn incorrect_cipher_fails() -> Result<(), Error> {
+    for_each_gadget_permutation!(check_incorrect_cipher_fails)
+}
+
+#[test]
+fn incorrect_public_input_fails() -> Result<(), Error> {
+    for_each_gadget_permutation!(check_incorrect_public_input_fails)
+}
+
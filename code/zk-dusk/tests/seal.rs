@@ -0,0 +1,249 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "encryption")]
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Error as BytesError;
+use dusk_jubjub::{JubJubAffine, JubJubExtended};
+use dusk_safe::{Encryption, Safe};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use zk_dusk::news::NewableScalar;
+use zk_dusk::{
+    decrypt_with, encrypt, hades, open, open_with, seal, seal_with, Domain,
+    Error, Hash, SealedMessage,
+};
+
+mod common;
+
+use common::{for_each_permutation, key_pair, random_scalars};
+
+fn check_seal_open<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (secret_key, public_key) = key_pair(&mut rng);
+
+    for message_len in [1, W, 3 * W + 2] {
+        let message = random_scalars(&mut rng, message_len);
+
+        let sealed = seal_with::<P, W, _>(&public_key, &message, &mut rng)?;
+        assert_eq!(sealed.cipher.len(), message_len + 1);
+        assert_eq!(open_with::<P, W>(&secret_key, &sealed)?, message);
+    }
+
+    Ok(())
+}
+
+fn check_incorrect_secret_key_fails<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (_, public_key) = key_pair(&mut rng);
+    let (wrong_secret_key, _) = key_pair(&mut rng);
+    let message = random_scalars(&mut rng, 5);
+
+    let sealed = seal_with::<P, W, _>(&public_key, &message, &mut rng)?;
+    assert_eq!(
+        open_with::<P, W>(&wrong_secret_key, &sealed).unwrap_err(),
+        Error::DecryptionFailed
+    );
+
+    Ok(())
+}
+
+fn check_tampered_message_fails<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (secret_key, public_key) = key_pair(&mut rng);
+    let message = random_scalars(&mut rng, 5);
+
+    let sealed = seal_with::<P, W, _>(&public_key, &message, &mut rng)?;
+
+    let mut wrong_sealed = sealed.clone();
+    wrong_sealed.cipher[2] += BlsScalar::one();
+    assert_eq!(
+        open_with::<P, W>(&secret_key, &wrong_sealed).unwrap_err(),
+        Error::DecryptionFailed
+    );
+
+    let mut wrong_sealed = sealed.clone();
+    wrong_sealed.nonce += BlsScalar::one();
+    assert_eq!(
+        open_with::<P, W>(&secret_key, &wrong_sealed).unwrap_err(),
+        Error::DecryptionFailed
+    );
+
+    let mut wrong_sealed = sealed;
+    wrong_sealed.ephemeral_public_key = key_pair(&mut rng).1;
+    assert_eq!(
+        open_with::<P, W>(&secret_key, &wrong_sealed).unwrap_err(),
+        Error::DecryptionFailed
+    );
+
+    Ok(())
+}
+
+fn check_nonce_domain<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (_secret_key, public_key) = key_pair(&mut rng);
+    let message = random_scalars(&mut rng, 4);
+
+    // the nonce is the hash of both public keys in its own domain, and not
+    // the hash of the same input in the domain `Other`
+    let sealed = seal_with::<P, W, _>(&public_key, &message, &mut rng)?;
+    let keys = [
+        sealed.ephemeral_public_key.get_u(),
+        sealed.ephemeral_public_key.get_v(),
+        public_key.get_u(),
+        public_key.get_v(),
+    ];
+    let domain = Domain::from_label(b"zk-dusk-seal-nonce");
+    assert_eq!(sealed.nonce, Hash::<P, W>::digest(domain, &keys)[0]);
+    assert_ne!(sealed.nonce, Hash::<P, W>::digest(Domain::Other, &keys)[0]);
+
+    Ok(())
+}
+
+#[test]
+fn seal_open() -> Result<(), Error> {
+    for_each_permutation!(check_seal_open)
+}
+
+#[test]
+fn incorrect_secret_key_fails() -> Result<(), Error> {
+    for_each_permutation!(check_incorrect_secret_key_fails)
+}
+
+#[test]
+fn tampered_message_fails() -> Result<(), Error> {
+    for_each_permutation!(check_tampered_message_fails)
+}
+
+#[test]
+fn nonce_domain() -> Result<(), Error> {
+    for_each_permutation!(check_nonce_domain)
+}
+
+#[test]
+fn hades_is_the_default() -> Result<(), Error> {
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (secret_key, public_key) = key_pair(&mut rng);
+    let message = random_scalars(&mut rng, 4);
+
+    let sealed = seal(&public_key, &message, &mut rng.clone())?;
+    assert_eq!(
+        sealed,
+        seal_with::<hades::ScalarPermutation, { hades::WIDTH }, _>(
+            &public_key,
+            &message,
+            &mut rng
+        )?
+    );
+    assert_eq!(open(&secret_key, &sealed)?, message);
+
+    // the sealed message is the encryption with the Diffie-Hellman shared
+    // secret of the ephemeral key
+    let shared_secret: JubJubAffine =
+        (sealed.ephemeral_public_key * secret_key).into();
+    assert_eq!(
+        decrypt_with::<hades::ScalarPermutation, { hades::WIDTH }>(
+            &sealed.cipher,
+            &shared_secret,
+            &sealed.nonce
+        )?,
+        message
+    );
+
+    Ok(())
+}
+
+#[test]
+fn serialization() -> Result<(), Error> {
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (secret_key, public_key) = key_pair(&mut rng);
+    let message = random_scalars(&mut rng, 3);
+
+    let sealed = seal(&public_key, &message, &mut rng)?;
+    let bytes = sealed.to_var_bytes();
+    assert_eq!(bytes.len(), 32 + 32 + 4 * 32);
+
+    let deserialized =
+        SealedMessage::from_var_bytes(&bytes).expect("valid bytes");
+    assert_eq!(deserialized, sealed);
+    assert_eq!(open(&secret_key, &deserialized)?, message);
+
+    // the cipher-text has at least the tag, and only whole scalars
+    assert!(matches!(
+        SealedMessage::from_var_bytes(&bytes[..64]),
+        Err(BytesError::BadLength { .. })
+    ));
+    assert!(matches!(
+        SealedMessage::from_var_bytes(&bytes[..bytes.len() - 1]),
+        Err(BytesError::BadLength { .. })
+    ));
+
+    // a scalar that isn't canonical
+    let mut wrong_bytes = bytes.clone();
+    wrong_bytes[64..96].copy_from_slice(&[0xff; 32]);
+    assert!(SealedMessage::from_var_bytes(&wrong_bytes).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn small_order_ephemeral_key_fails() -> Result<(), Error> {
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let (secret_key, public_key) = key_pair(&mut rng);
+    let message = random_scalars(&mut rng, 4);
+    let sealed = seal(&public_key, &message, &mut rng)?;
+
+    // the point (0, -1) of order 2, alone and added to a valid key
+    let torsion =
+        JubJubAffine::from_raw_unchecked(BlsScalar::zero(), -BlsScalar::one());
+    let mixed: JubJubAffine =
+        (JubJubExtended::from(sealed.ephemeral_public_key) + torsion).into();
+
+    for ephemeral_public_key in [torsion, mixed, JubJubAffine::identity()] {
+        // a message sealed as `seal` would, but with the shared secret the
+        // receiver derives from a key that isn't of prime order
+        let shared_secret: JubJubAffine =
+            (JubJubExtended::from(ephemeral_public_key) * secret_key).into();
+        let nonce = Hash::<hades::ScalarPermutation, { hades::WIDTH }>::digest(
+            Domain::from_label(b"zk-dusk-seal-nonce"),
+            &[
+                ephemeral_public_key.get_u(),
+                ephemeral_public_key.get_v(),
+                public_key.get_u(),
+                public_key.get_v(),
+            ],
+        )[0];
+        let forged = SealedMessage {
+            ephemeral_public_key,
+            nonce,
+            cipher: encrypt(&message, &shared_secret, &nonce)?,
+        };
+
+        assert_eq!(open(&secret_key, &forged), Err(Error::DecryptionFailed));
+        assert!(matches!(
+            SealedMessage::from_var_bytes(&forged.to_var_bytes()),
+            Err(BytesError::InvalidData)
+        ));
+    }
+
+    Ok(())
+}
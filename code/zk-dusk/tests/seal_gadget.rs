@@ -0,0 +1,246 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "encryption")]
+#![cfg(feature = "zk")]
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
+
+use std::marker::PhantomData;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::{JubJubAffine, JubJubScalar, GENERATOR_EXTENDED};
+use dusk_plonk::prelude::Error as PlonkError;
+use dusk_plonk::prelude::*;
+use dusk_safe::{Encryption, Safe};
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use zk_dusk::news::{NewableSafe, NewableScalar};
+use zk_dusk::{hades, seal_gadget_with, seal_with, Domain, Hash, SealedMessage};
+
+mod common;
+
+use common::{for_each_gadget_permutation, PUB_PARAMS};
+
+static LABEL: &[u8] = b"seal-circuit";
+
+const MESSAGE_LEN: usize = 4;
+
+/// Proves that a sealed message encrypts the message of a commitment to a
+/// public key, with the scalar permutation `P` and the gadget permutation `G`
+/// over a state of `W` elements. The commitment, the public key and the
+/// sealed message are the public inputs.
+#[derive(Debug)]
+struct SealCircuit<P, G, const W: usize> {
+    pub message: [BlsScalar; MESSAGE_LEN],
+    pub commitment: BlsScalar,
+    pub public_key: JubJubAffine,
+    pub ephemeral_secret_key: JubJubScalar,
+    pub sealed: SealedMessage,
+    permutation: PhantomData<(P, G)>,
+}
+
+impl<P, G, const W: usize> SealCircuit<P, G, W>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+{
+    pub fn random(rng: &mut StdRng) -> Self {
+        let mut message = [BlsScalar::zero(); MESSAGE_LEN];
+        message
+            .iter_mut()
+            .for_each(|s| *s = BlsScalar::random(&mut *rng));
+        let commitment = Hash::<P, W>::digest(Domain::Other, &message)[0];
+        let public_key: JubJubAffine =
+            (GENERATOR_EXTENDED * JubJubScalar::random(&mut *rng)).into();
+
+        // `seal` draws the ephemeral secret key first from the rng
+        let ephemeral_secret_key = JubJubScalar::random(&mut rng.clone());
+        let sealed = seal_with::<P, W, _>(&public_key, &message, rng)
+            .expect("sealing should pass");
+
+        // the nonce the gadget derives is constrained to the one of `seal`,
+        // the hash of both public keys in the domain of the seal nonce
+        let keys = [
+            sealed.ephemeral_public_key.get_u(),
+            sealed.ephemeral_public_key.get_v(),
+            public_key.get_u(),
+            public_key.get_v(),
+        ];
+        let nonce_domain = Domain::from_label(b"zk-dusk-seal-nonce");
+        assert_eq!(sealed.nonce, Hash::<P, W>::digest(nonce_domain, &keys)[0]);
+
+        Self {
+            message,
+            commitment,
+            public_key,
+            ephemeral_secret_key,
+            sealed,
+            permutation: PhantomData,
+        }
+    }
+
+    pub fn public_inputs(&self) -> Vec<BlsScalar> {
+        let mut public_inputs = vec![
+            self.commitment,
+            self.public_key.get_u(),
+            self.public_key.get_v(),
+            self.sealed.ephemeral_public_key.get_u(),
+            self.sealed.ephemeral_public_key.get_v(),
+            self.sealed.nonce,
+        ];
+        public_inputs.extend(&self.sealed.cipher);
+        public_inputs
+    }
+}
+
+impl<P, G, const W: usize> Default for SealCircuit<P, G, W> {
+    fn default() -> Self {
+        Self {
+            message: [BlsScalar::zero(); MESSAGE_LEN],
+            commitment: BlsScalar::zero(),
+            public_key: JubJubAffine::identity(),
+            ephemeral_secret_key: JubJubScalar::zero(),
+            sealed: SealedMessage {
+                ephemeral_public_key: JubJubAffine::identity(),
+                nonce: BlsScalar::zero(),
+                cipher: vec![BlsScalar::zero(); MESSAGE_LEN + 1],
+            },
+            permutation: PhantomData,
+        }
+    }
+}
+
+impl<P, G, const W: usize> Circuit for SealCircuit<P, G, W>
+where
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    fn circuit(&self, composer: &mut Composer) -> Result<(), PlonkError> {
+        // append all variables to the circuit
+        let message_wit = self.message.map(|m| composer.append_witness(m));
+        let ephemeral_secret_key_wit =
+            composer.append_witness(self.ephemeral_secret_key);
+
+        // seal the message of the commitment to the public key with the
+        // gadget
+        let commitment_wit = composer.append_public(self.commitment);
+        let public_key_wit = composer.append_public_point(self.public_key);
+        let sealed = seal_gadget_with::<G, W>(
+            composer,
+            &public_key_wit,
+            &message_wit,
+            &commitment_wit,
+            &ephemeral_secret_key_wit,
+        )
+        .expect("sealing should pass");
+
+        // ensure that the resulting sealed message is correct
+        composer.assert_equal_public_point(
+            sealed.ephemeral_public_key,
+            self.sealed.ephemeral_public_key,
+        );
+        composer.assert_equal_constant(sealed.nonce, 0, Some(self.sealed.nonce));
+        assert_eq!(sealed.cipher.len(), self.sealed.cipher.len());
+        sealed
+            .cipher
+            .iter()
+            .zip(&self.sealed.cipher)
+            .for_each(|(r, c)| composer.assert_equal_constant(*r, 0, Some(*c)));
+
+        Ok(())
+    }
+}
+
+fn check_seal<P, G, const W: usize>() -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    let (prover, verifier) =
+        Compiler::compile::<SealCircuit<P, G, W>>(&PUB_PARAMS, LABEL)?;
+
+    let circuit: SealCircuit<P, G, W> = SealCircuit::random(&mut rng);
+
+    let (proof, public_inputs) = prover.prove(&mut rng, &circuit)?;
+    assert_eq!(public_inputs, circuit.public_inputs());
+    verifier.verify(&proof, &circuit.public_inputs())
+}
+
+fn check_incorrect_commitment_fails<P, G, const W: usize>(
+) -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    let (prover, verifier) =
+        Compiler::compile::<SealCircuit<P, G, W>>(&PUB_PARAMS, LABEL)?;
+
+    // another message can't be proven to be the sealed one
+    let mut circuit: SealCircuit<P, G, W> = SealCircuit::random(&mut rng);
+    circuit.message[0] += BlsScalar::one();
+    assert!(prover.prove(&mut rng, &circuit).is_err());
+
+    // the proof doesn't verify for another commitment
+    let circuit: SealCircuit<P, G, W> = SealCircuit::random(&mut rng);
+    let (proof, _public_inputs) = prover.prove(&mut rng, &circuit)?;
+    let mut public_inputs = circuit.public_inputs();
+    public_inputs[0] += BlsScalar::one();
+    assert!(verifier.verify(&proof, &public_inputs).is_err());
+
+    Ok(())
+}
+
+fn check_incorrect_public_key_fails<P, G, const W: usize>(
+) -> Result<(), PlonkError>
+where
+    P: Safe<BlsScalar, W> + Encryption<BlsScalar, W> + NewableScalar,
+    G: NewableSafe<W>,
+    for<'b> G::T<'b>: Encryption<Witness, W>,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    let (prover, _verifier) =
+        Compiler::compile::<SealCircuit<P, G, W>>(&PUB_PARAMS, LABEL)?;
+
+    // the message isn't sealed to another public key
+    let mut circuit: SealCircuit<P, G, W> = SealCircuit::random(&mut rng);
+    circuit.public_key =
+        (GENERATOR_EXTENDED * JubJubScalar::random(&mut rng)).into();
+    assert!(prover.prove(&mut rng, &circuit).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn seal() -> Result<(), PlonkError> {
+    for_each_gadget_permutation!(check_seal)
+}
+
+#[test]
+fn incorrect_commitment_fails() -> Result<(), PlonkError> {
+    check_incorrect_commitment_fails::<
+        hades::ScalarPermutation,
+        hades::GadgetPermutation,
+        { hades::WIDTH },
+    >()
+}
+
+#[test]
+fn incorrect_public_key_fails() -> Result<(), PlonkError> {
+    check_incorrect_public_key_fails::<
+        hades::ScalarPermutation,
+        hades::GadgetPermutation,
+        { hades::WIDTH },
+    >()
+}
@@ -0,0 +1,175 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+#![feature(generic_const_exprs)]
+
+use dusk_bls12_381::BlsScalar;
+use dusk_safe::Safe;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use zk_dusk::news::NewableScalar;
+use zk_dusk::{hades, Domain, Error, Hash, StreamingHash};
+
+mod common;
+
+use common::{for_each_permutation, random_scalars};
+
+fn check_same_as_hash<P, const W: usize>() -> Result<(), Error>
+where
+    P: Safe<BlsScalar, W> + NewableScalar,
+{
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+
+    for input_len in [1, W - 1, W, 3 * W + 2] {
+        let input = random_scalars(&mut rng, input_len);
+        let expected = Hash::<P, W>::digest(Domain::Other, &input);
+
+        // updated at once, element by element, and in uneven chunks
+        let mut hash = StreamingHash::<P, W>::new(Domain::Other, input_len)?;
+        hash.update(&input)?;
+        assert_eq!(hash.finalize(), expected);
+
+        let mut hash = StreamingHash::<P, W>::new(Domain::Other, input_len)?;
+        for element in input.iter() {
+            hash.update(&[*element])?;
+        }
+        assert_eq!(hash.finalize(), expected);
+
+        let mut hash = StreamingHash::<P, W>::new(Domain::Other, input_len)?;
+        let (first, second) = input.split_at(input_len / 3);
+        hash.update(first)?;
+        hash.update(&[])?;
+        hash.update(second)?;
+        assert_eq!(hash.finalize(), expected);
+
+        // several output elements
+        let mut expected = Hash::<P, W>::new(Domain::Other);
+        expected.update(&input);
+        expected.output_len(2 * W)?;
+        let mut hash = StreamingHash::<P, W>::with_output_len(
+            Domain::Other,
+            input_len,
+            2 * W,
+        )?;
+        hash.update(&input)?;
+        assert_eq!(hash.finalize(), expected.finalize());
+
+        let mut hash = StreamingHash::<P, W>::new(Domain::Other, input_len)?;
+        hash.update(&input)?;
+        assert_eq!(
+            hash.finalize_truncated(),
+            Hash::<P, W>::digest_truncated(Domain::Other, &input)
+        );
+    }
+
+    // the other domains
+    let input = random_scalars(&mut rng, 8);
+    for (domain, input) in [
+        (Domain::Merkle2, &input[..2]),
+        (Domain::Merkle4, &input[..4]),
+        (Domain::Merkle(8), &input[..]),
+        (Domain::Encryption, &input[..3]),
+        (Domain::Custom(42), &input[..5]),
+    ] {
+        let mut hash = StreamingHash::<P, W>::new(domain, input.len())?;
+        hash.update(input)?;
+        assert_eq!(hash.finalize(), Hash::<P, W>::digest(domain, input));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn same_as_hash() -> Result<(), Error> {
+    for_each_permutation!(check_same_as_hash)
+}
+
+#[test]
+fn prefix_reuse() -> Result<(), Error> {
+    type HadesHash = StreamingHash<hades::ScalarPermutation, { hades::WIDTH }>;
+
+    let mut rng = StdRng::seed_from_u64(0x42424242);
+    let prefix = random_scalars(&mut rng, 11);
+    let suffixes = [random_scalars(&mut rng, 5), random_scalars(&mut rng, 5)];
+
+    let mut hash = HadesHash::new(Domain::Other, 16)?;
+    hash.update(&prefix)?;
+    for suffix in suffixes.iter() {
+        let mut with_suffix = hash.clone();
+        with_suffix.update(suffix)?;
+        assert_eq!(
+            with_suffix.finalize(),
+            Hash::<hades::ScalarPermutation, { hades::WIDTH }>::digest(
+                Domain::Other,
+                &[&prefix[..], suffix].concat()
+            )
+        );
+    }
+    assert_eq!(hash.remaining(), 5);
+
+    Ok(())
+}
+
+#[test]
+fn streaming() -> Result<(), Error> {
+    type HadesHash = StreamingHash<hades::ScalarPermutation, { hades::WIDTH }>;
+
+    // an input that is never in memory at once
+    let mut hash = HadesHash::new(Domain::Other, 5000)?;
+    for i in 0..5000u64 {
+        hash.update(&[BlsScalar::from(i)])?;
+    }
+    let input: Vec<BlsScalar> = (0..5000u64).map(BlsScalar::from).collect();
+    assert_eq!(
+        hash.finalize(),
+        Hash::<hades::ScalarPermutation, { hades::WIDTH }>::digest(
+            Domain::Other,
+            &input
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_length_fails() -> Result<(), Error> {
+    type HadesHash = StreamingHash<hades::ScalarPermutation, { hades::WIDTH }>;
+
+    let input = [BlsScalar::one(), BlsScalar::from(2), BlsScalar::from(3)];
+
+    // the lengths the domain doesn't allow
+    assert!(matches!(
+        HadesHash::new(Domain::Merkle4, 3),
+        Err(Error::IOPatternViolation)
+    ));
+    assert!(matches!(
+        HadesHash::with_output_len(Domain::Merkle2, 2, 2),
+        Err(Error::IOPatternViolation)
+    ));
+    assert!(matches!(
+        HadesHash::new(Domain::Other, 0),
+        Err(Error::InvalidIOPattern)
+    ));
+    assert!(matches!(
+        HadesHash::with_output_len(Domain::Other, 3, 0),
+        Err(Error::InvalidIOPattern)
+    ));
+
+    // more input than declared
+    let mut hash = HadesHash::new(Domain::Other, 2)?;
+    assert_eq!(hash.update(&input), Err(Error::IOPatternViolation));
+    assert_eq!(hash.remaining(), 2);
+
+    // less input than declared
+    let mut hash = HadesHash::new(Domain::Other, 4)?;
+    hash.update(&input)?;
+    assert_eq!(hash.clone().try_finalize(), Err(Error::IOPatternViolation));
+    assert_eq!(hash.try_finalize_truncated(), Err(Error::IOPatternViolation));
+
+    Ok(())
+}
@@ -6,14 +6,25 @@ use plonky2::{
     hash::hash_types::RichField,
 };
 use zk_lib::hashes::{
-    anemoi::{anemoi::Anemoi, SPONGE_RATE as SPONGE_RATE_ANE, SPONGE_WIDTH as SPONGE_WIDTH_ANE},
-    arion::{arion::Arion, SPONGE_RATE as SPONGE_RATE_ARI, SPONGE_WIDTH as SPONGE_WIDTH_ARI},
-    griffin::griffin::Griffin,
+    anemoi::{
+        anemoi::Anemoi, AnemoiParameters, SPONGE_RATE as SPONGE_RATE_ANE,
+        SPONGE_WIDTH as SPONGE_WIDTH_ANE,
+    },
+    arion::{
+        arion::Arion, ArionParameters, SPONGE_RATE as SPONGE_RATE_ARI,
+        SPONGE_WIDTH as SPONGE_WIDTH_ARI,
+    },
+    griffin::{
+        griffin::Griffin, GriffinParameters, SPONGE_RATE as SPONGE_RATE_GRI,
+        SPONGE_WIDTH as SPONGE_WIDTH_GRI,
+    },
     mimc::mimc::MiMC,
-    poseidon::{poseidon::Poseidon, SPONGE_RATE, SPONGE_WIDTH},
+    poseidon::{poseidon::Poseidon, PoseidonParameters, SPONGE_RATE, SPONGE_WIDTH},
     rescue_prime::{
-        rescue_prime::Rescue, SPONGE_RATE as SPONGE_RATE_RESC, SPONGE_WIDTH as SPONGE_WIDTH_RESC,
+        rescue_prime::Rescue, RescueParameters, SPONGE_RATE as SPONGE_RATE_RESC,
+        SPONGE_WIDTH as SPONGE_WIDTH_RESC,
     },
+    Width,
 };
 
 // Only can use GoldilocksField field type
@@ -37,64 +48,99 @@ where
 }
 
 fn bench_poseidon(c: &mut Criterion) {
-    let mut input = [GoldilocksField::ZERO; SPONGE_RATE];
-    for i in 0..SPONGE_RATE {
-        input[i] = GoldilocksField(0 as u64);
-    }
-    let id = BenchmarkId::new("Poseidon Hash", SPONGE_WIDTH);
+    poseidon::<8, 4>(c);
+    poseidon::<SPONGE_WIDTH, SPONGE_RATE>(c);
+    poseidon::<16, 12>(c);
+    poseidon::<24, 20>(c);
+}
+
+fn poseidon<const WIDTH: usize, const RATE: usize>(c: &mut Criterion)
+where
+    Width<WIDTH>: PoseidonParameters<WIDTH>,
+{
+    let input = [GoldilocksField::ZERO; RATE];
+    let id = BenchmarkId::new("Poseidon Hash", WIDTH);
     c.bench_with_input(id, &input, |b, &input| {
-        b.iter(|| Poseidon::poseidon_hash::<GoldilocksField, 2>(input))
+        b.iter(|| Poseidon::poseidon_hash_width::<GoldilocksField, 2, WIDTH, RATE>(input))
     });
 }
 
 fn bench_rescue(c: &mut Criterion) {
-    let mut input = [GoldilocksField::ZERO; SPONGE_RATE_RESC];
-    for i in 0..SPONGE_RATE {
-        input[i] = GoldilocksField(0 as u64);
-    }
-    let id = BenchmarkId::new("Rescue Hash", SPONGE_WIDTH);
+    rescue::<8, 4>(c);
+    rescue::<SPONGE_WIDTH_RESC, SPONGE_RATE_RESC>(c);
+    rescue::<16, 12>(c);
+    rescue::<24, 20>(c);
+}
+
+fn rescue<const WIDTH: usize, const RATE: usize>(c: &mut Criterion)
+where
+    Width<WIDTH>: RescueParameters<WIDTH>,
+{
+    let input = [GoldilocksField::ZERO; RATE];
+    let id = BenchmarkId::new("Rescue Hash", WIDTH);
     c.bench_with_input(id, &input, |b, &input| {
         b.iter(|| {
-            Rescue::rescue_hash::<GoldilocksField, SPONGE_RATE_RESC>(input);
+            Rescue::rescue_hash_width::<GoldilocksField, WIDTH, RATE, RATE, 4>(input);
         })
     });
 }
 
 fn bench_griffin(c: &mut Criterion) {
-    let mut input = [GoldilocksField::ZERO; SPONGE_RATE_RESC];
-    for i in 0..SPONGE_RATE_RESC {
-        input[i] = GoldilocksField(0 as u64);
-    }
-    let id = BenchmarkId::new("Griffin Hash", SPONGE_WIDTH_RESC);
+    griffin::<8, 4>(c);
+    griffin::<SPONGE_WIDTH_GRI, SPONGE_RATE_GRI>(c);
+    griffin::<16, 12>(c);
+    griffin::<24, 20>(c);
+}
+
+fn griffin<const WIDTH: usize, const RATE: usize>(c: &mut Criterion)
+where
+    Width<WIDTH>: GriffinParameters<WIDTH>,
+{
+    let input = [GoldilocksField::ZERO; RATE];
+    let id = BenchmarkId::new("Griffin Hash", WIDTH);
     c.bench_with_input(id, &input, |b, &input| {
         b.iter(|| {
-            Griffin::griffin_sponge::<GoldilocksField, SPONGE_RATE_RESC>(input);
+            Griffin::griffin_sponge_width::<GoldilocksField, WIDTH, RATE, 4>(input);
         })
     });
 }
 
 fn bench_anemoi(c: &mut Criterion) {
-    let mut input = [GoldilocksField::ZERO; SPONGE_RATE_ANE];
-    for i in 0..SPONGE_RATE_ANE {
-        input[i] = GoldilocksField(0 as u64);
-    }
-    let id = BenchmarkId::new("Anemoi Hash", SPONGE_WIDTH_ANE);
+    anemoi::<8, 4>(c);
+    anemoi::<SPONGE_WIDTH_ANE, SPONGE_RATE_ANE>(c);
+    anemoi::<16, 12>(c);
+    anemoi::<24, 20>(c);
+}
+
+fn anemoi<const WIDTH: usize, const RATE: usize>(c: &mut Criterion)
+where
+    Width<WIDTH>: AnemoiParameters<WIDTH>,
+{
+    let input = [GoldilocksField::ZERO; RATE];
+    let id = BenchmarkId::new("Anemoi Hash", WIDTH);
     c.bench_with_input(id, &input, |b, &input| {
         b.iter(|| {
-            Anemoi::anemoi_hash::<GoldilocksField, SPONGE_RATE_ANE>(input);
+            Anemoi::anemoi_hash_width::<GoldilocksField, WIDTH, RATE, 4>(input);
         })
     });
 }
 
 fn bench_arion(c: &mut Criterion) {
-    let mut input = [GoldilocksField::ZERO; SPONGE_RATE_ARI];
-    for i in 0..SPONGE_RATE_ARI {
-        input[i] = GoldilocksField(0 as u64);
-    }
-    let id = BenchmarkId::new("Arion Hash", SPONGE_WIDTH_ARI);
+    arion::<8, 4>(c);
+    arion::<SPONGE_WIDTH_ARI, SPONGE_RATE_ARI>(c);
+    arion::<16, 12>(c);
+    arion::<24, 20>(c);
+}
+
+fn arion<const WIDTH: usize, const RATE: usize>(c: &mut Criterion)
+where
+    Width<WIDTH>: ArionParameters<WIDTH>,
+{
+    let input = [GoldilocksField::ZERO; RATE];
+    let id = BenchmarkId::new("Arion Hash", WIDTH);
     c.bench_with_input(id, &input, |b, &input| {
         b.iter(|| {
-            Arion::arion_hash::<GoldilocksField, SPONGE_RATE_ARI>(input);
+            Arion::arion_hash_width::<GoldilocksField, WIDTH, RATE, 4>(input);
         })
     });
 }
@@ -0,0 +1,56 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use criterion::{
+    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId, Criterion,
+    Throughput,
+};
+
+use plonky2::{
+    field::{goldilocks_field::GoldilocksField, types::Sample},
+    plonk::config::PoseidonGoldilocksConfig,
+};
+use starky::config::StarkConfig;
+use zk_lib::starks::{
+    anemoi::AnemoiStark, arion::ArionStark, griffin::GriffinStark, poseidon::PoseidonStark,
+    prove_permutations, rescue_prime::RescueStark, PermutationStark, WIDTH,
+};
+
+type F = GoldilocksField;
+type C = PoseidonGoldilocksConfig;
+
+fn bench_stark<S>(group: &mut BenchmarkGroup<WallTime>, name: &str, stark: S)
+where
+    S: PermutationStark<F, 2>,
+    [(); S::COLUMNS]:,
+    [(); S::PUBLIC_INPUTS]:,
+{
+    let config = StarkConfig::standard_fast_config();
+    let n = S::PERMUTATIONS;
+    let inputs: Vec<[F; WIDTH]> = (0..n).map(|_| F::rand_array()).collect();
+    group.throughput(Throughput::Elements(n as u64));
+    group.bench_with_input(BenchmarkId::new(name, n), &inputs, |b, inputs| {
+        b.iter(|| prove_permutations::<F, C, S, 2>(stark, &config, inputs).unwrap())
+    });
+}
+
+fn starks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("stark_proof_generation");
+    group.sample_size(10);
+    // The numbers of permutations proven at once, whose inputs and outputs are public inputs;
+    // the throughput gives the cost per permutation
+    bench_stark(&mut group, "poseidon", PoseidonStark::<F, 2, 64>::new());
+    bench_stark(&mut group, "poseidon", PoseidonStark::<F, 2, 256>::new());
+    bench_stark(&mut group, "rescue", RescueStark::<F, 2, 64>::new());
+    bench_stark(&mut group, "rescue", RescueStark::<F, 2, 256>::new());
+    bench_stark(&mut group, "griffin", GriffinStark::<F, 2, 64>::new());
+    bench_stark(&mut group, "griffin", GriffinStark::<F, 2, 256>::new());
+    bench_stark(&mut group, "anemoi", AnemoiStark::<F, 2, 64>::new());
+    bench_stark(&mut group, "anemoi", AnemoiStark::<F, 2, 256>::new());
+    bench_stark(&mut group, "arion", ArionStark::<F, 2, 64>::new());
+    bench_stark(&mut group, "arion", ArionStark::<F, 2, 256>::new());
+    group.finish();
+}
+
+criterion_group!(benches, starks);
+criterion_main!(benches);
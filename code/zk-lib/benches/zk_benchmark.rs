@@ -1,16 +1,19 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{
+    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId, Criterion,
+};
 
 use plonky2::{
     field::{goldilocks_field::GoldilocksField, types::Field},
     plonk::config::PoseidonGoldilocksConfig,
 };
 use zk_lib::hashes::{
-    anemoi::{anemoi::Anemoi, SPONGE_RATE as SPONGE_RATE_ANE},
-    arion::{arion::Arion, SPONGE_RATE as SPONGE_RATE_ARI},
-    griffin::{griffin::Griffin, SPONGE_RATE as SPONGE_RATE_GRI},
+    anemoi::{anemoi::Anemoi, AnemoiParameters, SPONGE_RATE as SPONGE_RATE_ANE},
+    arion::{arion::Arion, ArionParameters, SPONGE_RATE as SPONGE_RATE_ARI},
+    griffin::{griffin::Griffin, GriffinParameters, SPONGE_RATE as SPONGE_RATE_GRI},
     mimc::mimc::MiMC,
-    poseidon::{poseidon::Poseidon, SPONGE_RATE as SPONGE_RATE_POS},
-    rescue_prime::{rescue_prime::Rescue, SPONGE_RATE as SPONGE_RATE_RESC},
+    poseidon::{poseidon::Poseidon, PoseidonParameters, SPONGE_RATE as SPONGE_RATE_POS},
+    rescue_prime::{rescue_prime::Rescue, RescueParameters, SPONGE_RATE as SPONGE_RATE_RESC},
+    Width,
 };
 
 fn mimc(c: &mut Criterion) {
@@ -37,6 +40,17 @@ fn arion(c: &mut Criterion) {
     bench_arion(c);
 }
 
+// Proof generation for the state widths 8, 12, 16 and 24, with a capacity of 4
+fn widths(c: &mut Criterion) {
+    let mut group = c.benchmark_group("proof_generation_width");
+    group.sample_size(10);
+    bench_widths::<8, 4>(&mut group);
+    bench_widths::<12, 8>(&mut group);
+    bench_widths::<16, 12>(&mut group);
+    bench_widths::<24, 20>(&mut group);
+    group.finish();
+}
+
 fn bench_mimc(c: &mut Criterion) {
     let mimc = MiMC::<GoldilocksField>::new_from_rng();
     let init_value = [GoldilocksField(1), GoldilocksField(2)];
@@ -253,5 +267,82 @@ fn bench_arion(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, mimc, poseidon, rescue, griffin, anemoi, arion);
+fn bench_widths<const WIDTH: usize, const RATE: usize>(group: &mut BenchmarkGroup<WallTime>)
+where
+    Width<WIDTH>: PoseidonParameters<WIDTH>
+        + RescueParameters<WIDTH>
+        + GriffinParameters<WIDTH>
+        + AnemoiParameters<WIDTH>
+        + ArionParameters<WIDTH>,
+{
+    let input: [GoldilocksField; RATE] = core::array::from_fn(|i| GoldilocksField(i as u64));
+
+    let (data, pw) = Poseidon::circuit_generation_width::<
+        GoldilocksField,
+        PoseidonGoldilocksConfig,
+        WIDTH,
+        RATE,
+        1,
+    >(input);
+    group.bench_function(BenchmarkId::new("poseidon", WIDTH), |b| {
+        b.iter(|| {
+            Poseidon::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw)
+        })
+    });
+
+    let (data, pw) = Rescue::circuit_generation_width::<
+        GoldilocksField,
+        PoseidonGoldilocksConfig,
+        WIDTH,
+        RATE,
+        RATE,
+        4,
+    >(input);
+    group.bench_function(BenchmarkId::new("rescue", WIDTH), |b| {
+        b.iter(|| {
+            Rescue::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw)
+        })
+    });
+
+    let (data, pw) = Griffin::circuit_generation_width::<
+        GoldilocksField,
+        PoseidonGoldilocksConfig,
+        WIDTH,
+        RATE,
+        4,
+    >(input);
+    group.bench_function(BenchmarkId::new("griffin", WIDTH), |b| {
+        b.iter(|| {
+            Griffin::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw)
+        })
+    });
+
+    let (data, pw) = Anemoi::circuit_generation_width::<
+        GoldilocksField,
+        PoseidonGoldilocksConfig,
+        WIDTH,
+        RATE,
+        4,
+    >(input);
+    group.bench_function(BenchmarkId::new("anemoi", WIDTH), |b| {
+        b.iter(|| {
+            Anemoi::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw)
+        })
+    });
+
+    let (data, pw) = Arion::circuit_generation_width::<
+        GoldilocksField,
+        PoseidonGoldilocksConfig,
+        WIDTH,
+        RATE,
+        4,
+    >(input);
+    group.bench_function(BenchmarkId::new("arion", WIDTH), |b| {
+        b.iter(|| {
+            Arion::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw)
+        })
+    });
+}
+
+criterion_group!(benches, mimc, poseidon, rescue, griffin, anemoi, arion, widths);
 criterion_main!(benches);
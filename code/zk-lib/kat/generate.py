@@ -0,0 +1,648 @@
+#!/usr/bin/env python3
+"""Known-answer tests of the zk-lib hashes over Goldilocks.
+
+The vectors are computed without the Rust sources: every constant is derived again from its
+definition, and the permutations come from the references where they are available.
+
+- Poseidon: the permutation of plonky2 (`plonky2::hash::poseidon`). Its round constants are
+  drawn again from ChaCha8 seeded with 0, as plonky2's `generate_constants` does, and the Rust
+  tests compare the width 12 with plonky2 itself, at several lengths. The other widths take
+  their constants from the Grain LFSR of the Poseidon reference (`generate_parameters_grain`).
+- Rescue: Rescue-Prime Optimized, with the constants of `rescue_constants.sage`, which are
+  those of Winterfell's `Rpo256` for the width 12.
+- Griffin: the constants, matrix and number of rounds of `griffin_constants.sage`.
+- Anemoi: `AnemoiPermutation` and `sponge_hash` of the Anemoi authors' `anemoi.sage`, run
+  with `sage_shim.py`. The script does not ship the round function, which follows the paper.
+- Arion: the `ArionHash` class of the Arion authors' `ArionHash.sage`, run with
+  `sage_shim.py`. It draws its constants at random, so the width-12 ones are the only values
+  read from the Rust sources; the other widths are squeezed from SHAKE256.
+- MiMC: the MiMC-2n/n Feistel permutation with x^7, with constants drawn from SHAKE256, and
+  with the constants of `MiMC::new_from_seed(MIMC_SEED)`, drawn again from ChaCha8.
+
+Each hash has vectors of one block of `rate` elements, as absorbed by its `*_hash_width`
+function, and vectors of the SAFE sponge of `crate::sponge` at several input lengths.
+
+    python3 kat/generate.py
+
+rewrites `kat/<hash>.json`.
+"""
+
+import ast
+import contextlib
+import hashlib
+import io
+import json
+import math
+import os
+import random
+import re
+import struct
+
+import sage_shim
+
+P = 2**64 - 2**32 + 1
+# Multiplicative generator of Goldilocks
+G = 7
+
+ROOT = os.path.dirname(os.path.abspath(__file__))
+HASHES = os.path.join(ROOT, "..", "src", "hashes")
+
+WIDTHS = [8, 12, 16, 24]
+CAPACITY = 4
+SECURITY_LEVEL = 128
+OUTPUT_LEN = 4
+
+
+def inverse_exponent(d):
+    return pow(d, -1, P - 1)
+
+
+def mat_vec(matrix, v):
+    return [sum(a * b for a, b in zip(row, v)) % P for row in matrix]
+
+
+def circulant(row):
+    """Sage's `matrix.circulant`: every row is the previous one shifted to the right."""
+    n = len(row)
+    return [[row[(j - i) % n] for j in range(n)] for i in range(n)]
+
+
+def cauchy(n):
+    """The MDS matrix the crate uses where the reference has none: `1 / (x_i - y_j)` with
+    `x_i = i` and `y_j = n + j`."""
+    return [[pow(i - n - j, -1, P) for j in range(n)] for i in range(n)]
+
+
+def shake_elements(seed):
+    """Field elements squeezed from SHAKE256, as in `get_round_constants` of the Griffin and
+    Rescue scripts: little-endian integers of one byte more than the modulus, reduced."""
+    bytes_per_int = (P.bit_length() + 7) // 8 + 1
+    stream, position = b"", 0
+    while True:
+        if position + bytes_per_int > len(stream):
+            stream = hashlib.shake_256(seed.encode()).digest(2 * len(stream) + 1024)
+        chunk = stream[position:position + bytes_per_int]
+        position += bytes_per_int
+        yield int.from_bytes(chunk, "little") % P
+
+
+def take(iterator, n):
+    return [next(iterator) for _ in range(n)]
+
+
+def rust_array(path, name):
+    """The value of the array constant `name` in the Rust file `path`."""
+    with open(os.path.join(HASHES, path)) as f:
+        source = f.read()
+    match = re.search(r"const %s\s*:[^=]*=\s*(\[.*?\]);" % name, source, re.S)
+    if match is None:
+        raise KeyError("%s not found in %s" % (name, path))
+    text = re.sub(r"//[^\n]*", "", match.group(1))
+    return ast.literal_eval(text)
+
+
+# ChaCha8, as `rand_chacha::ChaCha8Rng` with `rand::Rng::gen_range`
+
+
+class ChaCha8:
+    def __init__(self, seed):
+        self.key = struct.unpack("<8I", seed)
+        self.counter = 0
+        self.words = []
+
+    @classmethod
+    def seed_from_u64(cls, state):
+        """`rand_core::SeedableRng::seed_from_u64`: the 32-byte seed is filled by PCG32."""
+        seed = b""
+        for _ in range(8):
+            state = (state * 6364136223846793005 + 11634580027462260723) % 2**64
+            xorshifted = (((state >> 18) ^ state) >> 27) % 2**32
+            rot = state >> 59
+            seed += struct.pack("<I", ((xorshifted >> rot) | (xorshifted << (32 - rot))) % 2**32)
+        return cls(seed)
+
+    def block(self):
+        constants = struct.unpack("<4I", b"expand 32-byte k")
+        counter = [self.counter % 2**32, self.counter >> 32]
+        state = list(constants) + list(self.key) + counter + [0, 0]
+        x = state[:]
+
+        def quarter_round(a, b, c, d):
+            for (i, j, k, rot) in [(a, b, d, 16), (c, d, b, 12), (a, b, d, 8), (c, d, b, 7)]:
+                x[i] = (x[i] + x[j]) % 2**32
+                x[k] ^= x[i]
+                x[k] = ((x[k] << rot) | (x[k] >> (32 - rot))) % 2**32
+
+        for _ in range(4):
+            quarter_round(0, 4, 8, 12)
+            quarter_round(1, 5, 9, 13)
+            quarter_round(2, 6, 10, 14)
+            quarter_round(3, 7, 11, 15)
+            quarter_round(0, 5, 10, 15)
+            quarter_round(1, 6, 11, 12)
+            quarter_round(2, 7, 8, 13)
+            quarter_round(3, 4, 9, 14)
+        self.counter += 1
+        return [(a + b) % 2**32 for a, b in zip(x, state)]
+
+    def next_u64(self):
+        if len(self.words) < 2:
+            self.words += self.block()
+        low, high = self.words[0], self.words[1]
+        self.words = self.words[2:]
+        return low | high << 32
+
+    def gen_range(self, high):
+        """`gen_range(0..high)` on `u64`: widening multiplication with rejection."""
+        zone = ((high << (64 - high.bit_length())) - 1) % 2**64
+        while True:
+            product = self.next_u64() * high
+            if product % 2**64 <= zone:
+                return product >> 64
+
+    def sample(self):
+        """`Sample::sample` of plonky2's `GoldilocksField`."""
+        return self.gen_range(P)
+
+
+# Poseidon
+
+FULL_ROUNDS, PARTIAL_ROUNDS = 8, 22
+# `MDS_MATRIX_CIRC` and `MDS_MATRIX_DIAG` of `plonky2::hash::poseidon_goldilocks`
+PLONKY2_MDS_CIRC = [17, 15, 41, 16, 2, 28, 13, 13, 39, 18, 34, 20]
+PLONKY2_MDS_DIAG = [8] + [0] * 11
+
+
+def grain_round_constants(width):
+    """Round constants of `generate_parameters_grain.sage` for a prime field with x^alpha."""
+    bits = []
+    for value, length in [(1, 2), (0, 4), (P.bit_length(), 12), (width, 12),
+                          (FULL_ROUNDS, 10), (PARTIAL_ROUNDS, 10)]:
+        bits += [(value >> i) & 1 for i in range(length - 1, -1, -1)]
+    bits += [1] * 30
+
+    def clock():
+        new_bit = bits[62] ^ bits[51] ^ bits[38] ^ bits[23] ^ bits[13] ^ bits[0]
+        bits.pop(0)
+        bits.append(new_bit)
+        return new_bit
+
+    for _ in range(160):
+        clock()
+
+    def next_bit():
+        while True:
+            keep, bit = clock(), clock()
+            if keep:
+                return bit
+
+    constants = []
+    while len(constants) < width * (FULL_ROUNDS + PARTIAL_ROUNDS):
+        value = 0
+        for _ in range(P.bit_length()):
+            value = value << 1 | next_bit()
+        if value < P:
+            constants.append(value)
+    return constants
+
+
+def poseidon_parameters(width):
+    if width == 12:
+        rng = ChaCha8.seed_from_u64(0)
+        round_constants = [rng.gen_range(P) for _ in range(width * (FULL_ROUNDS + PARTIAL_ROUNDS))]
+        mds = circulant(PLONKY2_MDS_CIRC)
+        for i, d in enumerate(PLONKY2_MDS_DIAG):
+            mds[i][i] += d
+        return round_constants, mds
+    return grain_round_constants(width), cauchy(width)
+
+
+def poseidon_permutation(width):
+    round_constants, mds = poseidon_parameters(width)
+    half_full_rounds = FULL_ROUNDS // 2
+
+    def permute(state):
+        state = state[:]
+        for r in range(FULL_ROUNDS + PARTIAL_ROUNDS):
+            state = [(x + round_constants[width * r + i]) % P for i, x in enumerate(state)]
+            if half_full_rounds <= r < half_full_rounds + PARTIAL_ROUNDS:
+                state[0] = pow(state[0], 7, P)
+            else:
+                state = [pow(x, 7, P) for x in state]
+            state = mat_vec(mds, state)
+        return state
+
+    return permute
+
+
+def poseidon(width, rate, permute, message):
+    # The first element of the state
+    return permute(message + [0] * (width - rate))[:1]
+
+
+def hash_no_pad(permute, message):
+    """`plonky2::plonk::config::Hasher::hash_no_pad` of `PoseidonHash`: an overwrite-mode
+    sponge of rate 8, squeezing 4 elements."""
+    state = [0] * 12
+    for i in range(0, len(message), 8):
+        chunk = message[i:i + 8]
+        state = permute(chunk + state[len(chunk):])
+    return state[:OUTPUT_LEN]
+
+
+# Rescue-Prime Optimized
+
+RESCUE_ROUNDS = 7
+
+
+def rescue_permutation(width):
+    # `get_round_constants` and `get_mds` of `rescue_constants.sage`
+    round_constants = take(
+        shake_elements("RPO(%i,%i,%i,%i)" % (P, width, CAPACITY, SECURITY_LEVEL)),
+        2 * width * RESCUE_ROUNDS,
+    )
+    rows = {
+        12: [7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8],
+        16: [256, 2, 1073741824, 2048, 16777216, 128, 8, 16, 524288, 4194304, 1, 268435456,
+             1, 1024, 2, 8192],
+    }
+    mds = circulant(rows[width]) if width in rows else cauchy(width)
+    alpha, alpha_inv = 7, inverse_exponent(7)
+
+    def permute(state):
+        for r in range(RESCUE_ROUNDS):
+            for half, exponent in [(0, alpha), (1, alpha_inv)]:
+                state = mat_vec(mds, state)
+                offset = (2 * r + half) * width
+                state = [(x + round_constants[offset + i]) % P for i, x in enumerate(state)]
+                state = [pow(x, exponent, P) for x in state]
+        return state
+
+    return permute
+
+
+def rescue(width, rate, permute, message):
+    # The capacity comes first and the rate is overwritten by the message
+    capacity = width - rate
+    state = permute([0] * capacity + message)
+    return state[capacity:capacity + OUTPUT_LEN]
+
+
+# Griffin
+
+GRIFFIN_D = 7
+
+
+def griffin_rounds(width):
+    """`get_number_of_rounds` of `griffin_constants.sage`."""
+    target = 2 ** (SECURITY_LEVEL // 2)
+    for rounds in range(1, 25):
+        left = math.comb(rounds * (GRIFFIN_D + width) + 1, 1 + width * rounds)
+        right = math.comb(GRIFFIN_D**rounds + 1 + rounds, 1 + rounds)
+        if min(left, right) >= target:
+            break
+    return math.ceil(1.2 * max(6, 1 + rounds))
+
+
+def griffin_matrix(width):
+    """`get_matrix` of `griffin_constants.sage`, for a width multiple of 4."""
+    m4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]
+    blocks = width // 4
+    m1 = [[m4[i % 4][j % 4] if i // 4 == j // 4 else 0 for j in range(width)]
+          for i in range(width)]
+    m2 = [[(2 if i == j else 1) if i % 4 == j % 4 else 0 for j in range(width)]
+          for i in range(width)]
+    assert blocks > 1
+    return [[sum(m1[i][k] * m2[k][j] for k in range(width)) % P for j in range(width)]
+            for i in range(width)]
+
+
+def griffin_permutation(width):
+    rounds = griffin_rounds(width)
+    # `get_round_constants` of `griffin_constants.sage`
+    elements = shake_elements("Griffin(%i,%i,%i,%i)" % (P, width, CAPACITY, SECURITY_LEVEL))
+    alpha, beta = next(elements), next(elements)
+    alphas = [(i - 1) * alpha % P for i in range(2, width)]
+    betas = [(i - 1) ** 2 * beta % P for i in range(2, width)]
+    round_constants = take(elements, width * (rounds - 1))
+    mds = griffin_matrix(width)
+    d_inv = inverse_exponent(GRIFFIN_D)
+
+    def non_linear(x):
+        y = x[:]
+        y[0] = pow(x[0], d_inv, P)
+        y[1] = pow(x[1], GRIFFIN_D, P)
+        for i in range(2, width):
+            # L_i(y_0, y_1, x_{i-1}) = (i - 1) y_0 + y_1 + x_{i-1}, with 0 in place of x_1
+            z = 0 if i == 2 else x[i - 1]
+            l = ((i - 1) * y[0] + y[1] + z) % P
+            y[i] = x[i] * (l * l + alphas[i - 2] * l + betas[i - 2]) % P
+        return y
+
+    def permute(state):
+        state = mat_vec(mds, state)
+        for r in range(rounds):
+            state = mat_vec(mds, non_linear(state))
+            # No constants in the last round
+            if r < rounds - 1:
+                state = [(x + round_constants[width * r + i]) % P for i, x in enumerate(state)]
+        return state
+
+    return permute
+
+
+def griffin(width, rate, permute, message):
+    return permute(message + [0] * (width - rate))[:OUTPUT_LEN]
+
+
+# Anemoi
+
+
+def anemoi_reference():
+    """The namespace of `anemoi.sage`, whose `constants` import is `constants.py`, without the
+    call that writes the BLS12-381 tables."""
+    directory = os.path.join(HASHES, "anemoi", "constanta_gen")
+    names = sage_shim.run(os.path.join(directory, "constants.py"))
+    names = sage_shim.run(os.path.join(directory, "anemoi.sage"),
+                          strip=[r"^from constants import \*$", r"^get_constants\(.*\)\s*$"],
+                          names=names)
+    get_mds = names["get_mds"]
+
+    def get_mds_or_cauchy(field, l):
+        # The circulant search of `get_mds` finds no matrix for 12 columns over Goldilocks,
+        # where the crate uses a Cauchy matrix
+        if l > 8:
+            return sage_shim.Matrix(field, cauchy(l))
+        return get_mds(field, l)
+
+    names["get_mds"] = get_mds_or_cauchy
+    return names
+
+
+def anemoi_permutation(reference, width):
+    columns = width // 2
+    permutation = reference["AnemoiPermutation"](
+        q=P, alpha=7, n_cols=columns, security_level=SECURITY_LEVEL)
+    rounds = permutation.n_rounds
+    c = [[int(x) for x in row] for row in permutation.C]
+    d = [[int(x) for x in row] for row in permutation.D]
+    mds = [[int(x) for x in row] for row in permutation.mat.rows]
+    g, delta = int(permutation.g), int(permutation.delta)
+    alpha_inv = int(permutation.alpha_inv)
+
+    def linear_layer(x, y):
+        x = mat_vec(mds, x)
+        y = mat_vec(mds, y[1:] + y[:1])
+        # Pseudo-Hadamard transform on each (x, y) pair
+        y = [(a + b) % P for a, b in zip(y, x)]
+        x = [(a + b) % P for a, b in zip(x, y)]
+        return x, y
+
+    def permute(state):
+        state = [int(v) % P for v in state]
+        x, y = state[:columns], state[columns:]
+        for r in range(rounds):
+            x = [(a + c[r][i]) % P for i, a in enumerate(x)]
+            y = [(a + d[r][i]) % P for i, a in enumerate(y)]
+            x, y = linear_layer(x, y)
+            # Open Flystel
+            for i in range(columns):
+                x[i] = (x[i] - g * y[i] * y[i]) % P
+                y[i] = (y[i] - pow(x[i], alpha_inv, P)) % P
+                x[i] = (x[i] + g * y[i] * y[i] + delta) % P
+        x, y = linear_layer(x, y)
+        return x + y
+
+    # The interface `sponge_hash` expects
+    permutation.input_size = lambda: width
+    permutation.__class__ = type("AnemoiKat", (permutation.__class__,),
+                                 {"__call__": lambda self, state: permute(state)})
+    return permutation
+
+
+def anemoi(reference):
+    def hash_function(width, rate, permutation, message):
+        # Hirose's sponge of the reference, over one full block
+        digest = reference["sponge_hash"](permutation, rate, OUTPUT_LEN, message)
+        return [int(x) % P for x in digest]
+
+    return hash_function
+
+
+# Arion
+
+ARION_ROUNDS = 8
+
+
+def arion_constants(width):
+    """Constants of `crate::parameters::arion` for the widths other than 12: squeezed from
+    SHAKE256 in the order of `ArionHash`, without the g_i coefficients that have roots."""
+    elements = shake_elements("Arion(%i,%i,%i)" % (P, width, ARION_ROUNDS))
+    constants_g = []
+    while len(constants_g) < ARION_ROUNDS * (width - 1):
+        c_1, c_2 = next(elements), next(elements)
+        if sage_shim.legendre_symbol(c_1 * c_1 - 4 * c_2, P) == -1:
+            constants_g.append([c_1, c_2])
+    constants_h = take(elements, ARION_ROUNDS * (width - 1))
+    constants_aff = [take(elements, width) for _ in range(ARION_ROUNDS)]
+    return constants_g, constants_h, constants_aff
+
+
+def arion_permutation(reference, width):
+    if width == 12:
+        # Drawn at random by the reference, they cannot be derived again
+        constants_g = [pair for row in rust_array("arion/constants.rs", "G_VALUES")
+                       for pair in row]
+        constants_h = [x for row in rust_array("arion/constants.rs", "H_VALUES") for x in row]
+        constants_aff = rust_array("arion/constants.rs", "AFFINE_CONSTANTS")
+    else:
+        constants_g, constants_h, constants_aff = arion_constants(width)
+
+    field = sage_shim.GF(P)
+    # The constructor prints the parameters and writes the constants to files
+    with contextlib.redirect_stdout(io.StringIO()), sage_shim.scratch_directory():
+        arion_hash = reference["ArionHash"](
+            field=field,
+            branches=width,
+            rounds=ARION_ROUNDS,
+            capacity=CAPACITY,
+            constants_g=[[field(x) for x in pair] for pair in constants_g],
+            constants_h=[field(x) for x in constants_h],
+            constants_aff=[[field(x) for x in row] for row in constants_aff],
+        )
+    branches = width - 1
+
+    def permute(state):
+        # The permutation inside `ArionHash.hash`
+        state = arion_hash.matrix * sage_shim.vector(field, state)
+        for r in range(ARION_ROUNDS):
+            state = arion_hash.round_function(
+                state,
+                arion_hash.constants_g[r * branches:(r + 1) * branches],
+                arion_hash.constants_h[r * branches:(r + 1) * branches],
+                sage_shim.vector(field, arion_hash.constants_aff[r]),
+            )
+        return [int(x) for x in state]
+
+    permute.arion_hash = arion_hash
+    return permute
+
+
+def arion(width, rate, permute, message):
+    output = permute(message + [0] * (width - rate))
+    # `ArionHash.hash` returns the first element of the same state
+    assert output[0] == int(permute.arion_hash.hash(message[:]))
+    return output[:OUTPUT_LEN]
+
+
+# MiMC
+
+MIMC_ROUNDS = 41
+# `crate::registry::MIMC_SEED`, "mimc" in ASCII
+MIMC_SEED = 0x6d696d63
+
+
+def mimc_constants():
+    stream = hashlib.shake_256(b"MiMC-Goldilocks").digest(8 * MIMC_ROUNDS)
+    return [int.from_bytes(stream[8 * i:8 * i + 8], "little") % P for i in range(MIMC_ROUNDS)]
+
+
+def mimc_seeded_constants(seed):
+    """The constants of `MiMC::new_from_seed(seed)`."""
+    rng = ChaCha8.seed_from_u64(seed)
+    return [rng.sample() for _ in range(MIMC_ROUNDS)]
+
+
+def mimc(constants, message):
+    x_left, x_right = message
+    for c in constants:
+        x_left, x_right = (x_right + pow(x_left + c, 7, P)) % P, x_left
+    return [x_left]
+
+
+# SAFE sponge of `crate::sponge`
+
+# Domain separator of the sponge vectors, "kat" in ASCII
+SPONGE_DOMAIN = 0x6b6174
+
+
+def sponge_tag(length, squeeze_length):
+    """The tag of the IO-pattern `[Absorb(length), Squeeze(squeeze_length)]`: SHA3-256 of
+    the SAFE encoding of the pattern and of the domain separator, as four 32-bit limbs."""
+    encoding = struct.pack(">II", 0x80000000 + length, squeeze_length)
+    encoding += struct.pack(">Q", SPONGE_DOMAIN)
+    digest = hashlib.sha3_256(encoding).digest()
+    return list(struct.unpack("<4I", digest[:16]))
+
+
+def sponge(width, rate, permute, message):
+    state = [0] * width
+    state[rate:rate + CAPACITY] = sponge_tag(len(message), OUTPUT_LEN)
+    position = 0
+    for element in message:
+        if position == rate:
+            state = permute(state)
+            position = 0
+        state[position] = (state[position] + element) % P
+        position += 1
+    output = []
+    while len(output) < OUTPUT_LEN:
+        state = permute(state)
+        output += state[:min(rate, OUTPUT_LEN - len(output))]
+    return output
+
+
+# Vectors
+
+
+def inputs(rng, length):
+    """The inputs of a length: a counter, random elements, and the largest element."""
+    return [
+        list(range(length)),
+        [rng.randrange(P) for _ in range(length)],
+        [P - 1] * length,
+    ]
+
+
+def hex_elements(elements):
+    return ["0x%016x" % x for x in elements]
+
+
+def vector(width, rate, message, output):
+    return {
+        "width": width,
+        "rate": rate,
+        "input": hex_elements(message),
+        "output": hex_elements(output),
+    }
+
+
+def vectors(hash_function, permutation, rng):
+    """The block vectors and the sponge vectors of a hash, for every width."""
+    blocks, sponges = [], []
+    for width in WIDTHS:
+        rate = width - CAPACITY
+        permute = permutation(width)
+        for message in inputs(rng, rate):
+            blocks.append(vector(width, rate, message,
+                                 hash_function(width, rate, permute, message)))
+        for length in [1, rate - 1, rate, rate + 1, 2 * rate + 3]:
+            message = [rng.randrange(P) for _ in range(length)]
+            sponges.append(vector(width, rate, message, sponge(width, rate, permute, message)))
+    return {"vectors": blocks, "sponge": {"domain": SPONGE_DOMAIN, "vectors": sponges}}
+
+
+def write(name, reference, content):
+    content = dict({"hash": name, "field": "goldilocks", "reference": reference}, **content)
+    with open(os.path.join(ROOT, name + ".json"), "w") as f:
+        json.dump(content, f, indent=2)
+        f.write("\n")
+
+
+def main():
+    rng = random.Random(0x6b6174)
+
+    content = vectors(poseidon, poseidon_permutation, rng)
+    permute = poseidon_permutation(12)
+    content["hash_no_pad"] = [
+        {"input": hex_elements(message), "output": hex_elements(hash_no_pad(permute, message))}
+        for message in [[rng.randrange(P) for _ in range(length)] for length in [0, 1, 7, 8, 9, 20]]
+    ]
+    write("poseidon", "plonky2::hash::poseidon", content)
+
+    write("rescue", "rescue_constants.sage (Rescue-Prime Optimized)",
+          vectors(rescue, rescue_permutation, rng))
+    write("griffin", "griffin_constants.sage", vectors(griffin, griffin_permutation, rng))
+
+    reference = anemoi_reference()
+    write("anemoi", "anemoi.sage",
+          vectors(anemoi(reference), lambda width: anemoi_permutation(reference, width), rng))
+
+    reference = sage_shim.run(os.path.join(HASHES, "arion", "arion-constants", "ArionHash.sage"),
+                              strip=[r"^A = ArionHash\(.*\)\s*$"])
+    write("arion", "ArionHash.sage",
+          vectors(arion, lambda width: arion_permutation(reference, width), rng))
+
+    constants = mimc_constants()
+    seeded_constants = mimc_seeded_constants(MIMC_SEED)
+    mimc_inputs = inputs(rng, 2)
+    write("mimc", "MiMC-2n/n Feistel", {
+        "constants": hex_elements(constants),
+        "vectors": [
+            {"input": hex_elements(message), "output": hex_elements(mimc(constants, message))}
+            for message in mimc_inputs
+        ],
+        "seeded": {
+            "seed": MIMC_SEED,
+            "constants": hex_elements(seeded_constants),
+            "vectors": [
+                {"input": hex_elements(message),
+                 "output": hex_elements(mimc(seeded_constants, message))}
+                for message in mimc_inputs
+            ],
+        },
+    })
+
+
+if __name__ == "__main__":
+    main()
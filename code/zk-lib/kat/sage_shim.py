@@ -0,0 +1,281 @@
+"""The little of Sage that the reference scripts in `src/hashes` need, over prime fields.
+
+`run(path)` executes a Sage script with these definitions in place of `sage.all`, so that the
+classes of the Anemoi and Arion authors can be used as they are, without Sage installed.
+"""
+
+import contextlib
+import copy as _copy
+import itertools
+import math
+import os
+import re
+import tempfile
+
+
+def is_prime(n):
+    if n < 2:
+        return False
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]:
+        if n % p == 0:
+            return n == p
+    d, s = n - 1, 0
+    while d % 2 == 0:
+        d, s = d // 2, s + 1
+    for a in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]:
+        x = pow(a, d, n)
+        if x in (1, n - 1):
+            continue
+        for _ in range(s - 1):
+            x = x * x % n
+            if x == n - 1:
+                break
+        else:
+            return False
+    return True
+
+
+def prime_factors(n):
+    factors, p = [], 2
+    while p * p <= n:
+        if n % p == 0:
+            factors.append(p)
+            while n % p == 0:
+                n //= p
+        p += 1
+    if n > 1:
+        factors.append(n)
+    return factors
+
+
+class Integer(int):
+    def nbits(self):
+        return self.bit_length()
+
+
+class FieldElement:
+    __slots__ = ("field", "value")
+
+    def __init__(self, field, value):
+        self.field = field
+        self.value = int(value) % field.p
+
+    def _other(self, other):
+        return other.value if isinstance(other, FieldElement) else int(other)
+
+    def __add__(self, other):
+        return FieldElement(self.field, self.value + self._other(other))
+
+    __radd__ = __add__
+
+    def __sub__(self, other):
+        return FieldElement(self.field, self.value - self._other(other))
+
+    def __rsub__(self, other):
+        return FieldElement(self.field, self._other(other) - self.value)
+
+    def __mul__(self, other):
+        if isinstance(other, (Vector, Matrix)):
+            return NotImplemented
+        return FieldElement(self.field, self.value * self._other(other))
+
+    __rmul__ = __mul__
+
+    def __neg__(self):
+        return FieldElement(self.field, -self.value)
+
+    def __pow__(self, exponent):
+        return FieldElement(self.field, pow(self.value, int(exponent), self.field.p))
+
+    def __eq__(self, other):
+        return self.value == self._other(other) % self.field.p
+
+    def __hash__(self):
+        return hash(self.value)
+
+    def __int__(self):
+        return self.value
+
+    __index__ = __int__
+
+    def __repr__(self):
+        return str(self.value)
+
+    def to_bytes(self, byteorder):
+        return self.value.to_bytes((self.field.p.bit_length() + 7) // 8, byteorder)
+
+
+class GF:
+    def __init__(self, p):
+        p = int(p)
+        if not is_prime(p):
+            raise ValueError("only prime fields are supported")
+        self.p = p
+
+    def __call__(self, value):
+        return FieldElement(self, int(value))
+
+    def characteristic(self):
+        return Integer(self.p)
+
+    cardinality = characteristic
+
+    def is_prime_field(self):
+        return True
+
+    def one(self):
+        return self(1)
+
+    def multiplicative_generator(self):
+        factors = prime_factors(self.p - 1)
+        g = 2
+        while any(pow(g, (self.p - 1) // q, self.p) == 1 for q in factors):
+            g += 1
+        return self(g)
+
+    def random_element(self):
+        raise NotImplementedError("the shim has no randomness, constants must be given")
+
+
+FiniteField = GF
+
+
+class Vector(list):
+    def __init__(self, field, entries):
+        super().__init__(field(x) for x in entries)
+        self.field = field
+
+    def __add__(self, other):
+        return Vector(self.field, [a + b for a, b in zip(self, other)])
+
+    def __copy__(self):
+        return Vector(self.field, self)
+
+
+def vector(field, entries):
+    return Vector(field, list(entries))
+
+
+class Matrix:
+    def __init__(self, field, rows, cols=None, entries=None):
+        if cols is not None:
+            rows = entries
+        self.field = field
+        self.rows = [[field(x) if field else x for x in row] for row in rows]
+
+    def nrows(self):
+        return len(self.rows)
+
+    def is_square(self):
+        return all(len(row) == len(self.rows) for row in self.rows)
+
+    def __getitem__(self, index):
+        if isinstance(index, tuple):
+            return self.rows[index[0]][index[1]]
+        return self.rows[index]
+
+    def __setitem__(self, index, value):
+        self.rows[index[0]][index[1]] = self.field(value) if self.field else value
+
+    def change_ring(self, field):
+        return Matrix(field, [[int(x) for x in row] for row in self.rows])
+
+    def transpose(self):
+        return Matrix(self.field, [list(col) for col in zip(*self.rows)])
+
+    def __mul__(self, other):
+        if isinstance(other, Vector):
+            return Vector(self.field, [sum((a * b for a, b in zip(row, other)), self.field(0))
+                                       for row in self.rows])
+        raise NotImplementedError
+
+    def __str__(self):
+        return "\n".join("[" + " ".join(str(x) for x in row) + "]" for row in self.rows)
+
+    @staticmethod
+    def circulant(row):
+        row = list(row)
+        field = getattr(row[0], "field", None) if row else None
+        n = len(row)
+        return Matrix(field, [[row[(j - i) % n] for j in range(n)] for i in range(n)])
+
+
+matrix = Matrix
+
+
+def identity_matrix(field, n):
+    return Matrix(field, [[int(i == j) for j in range(n)] for i in range(n)])
+
+
+def gcd(a, b):
+    return math.gcd(int(a), int(b))
+
+
+def xgcd(a, b):
+    a, b = int(a), int(b)
+    x0, x1, y0, y1 = 1, 0, 0, 1
+    while b:
+        q, a, b = a // b, b, a % b
+        x0, x1 = x1, x0 - q * x1
+        y0, y1 = y1, y0 - q * y1
+    return a, x0, y0
+
+
+def inverse_mod(a, m):
+    return pow(int(a), -1, int(m))
+
+
+def legendre_symbol(a, p):
+    value = pow(int(a), (int(p) - 1) // 2, int(p))
+    return -1 if value == int(p) - 1 else value
+
+
+def binomial(n, k):
+    return Integer(math.comb(int(n), int(k)))
+
+
+def flatten(items):
+    if isinstance(items, (list, tuple)):
+        return [x for item in items for x in flatten(item)]
+    return [items]
+
+
+def copy(value):
+    return _copy.copy(value)
+
+
+NAMES = {
+    name: value for name, value in globals().items()
+    if name in [
+        "is_prime", "Integer", "GF", "FiniteField", "vector", "matrix", "Matrix",
+        "identity_matrix", "gcd", "xgcd", "inverse_mod", "legendre_symbol", "binomial",
+        "flatten", "copy", "itertools",
+    ]
+}
+
+
+@contextlib.contextmanager
+def scratch_directory():
+    """Runs the body in a temporary directory, as the reference scripts write their tables
+    to the current one."""
+    cwd = os.getcwd()
+    with tempfile.TemporaryDirectory() as directory:
+        os.chdir(directory)
+        try:
+            yield
+        finally:
+            os.chdir(cwd)
+
+
+def run(path, strip=(), names=None):
+    """Executes the Sage script `path`, without its `sage.all` import and the top-level calls
+    matching the regular expressions of `strip`, and returns its namespace."""
+    with open(path) as f:
+        source = f.read()
+    source = re.sub(r"^from sage\.all import \*$", "", source, flags=re.M)
+    for pattern in strip:
+        source = re.sub(pattern, "", source, flags=re.M | re.S)
+    namespace = dict(NAMES, **(names or {}))
+    with scratch_directory():
+        exec(compile(source, path, "exec"), namespace)
+    return namespace
@@ -0,0 +1,297 @@
+// Command-line interface to the hashes of zk-lib
+//
+// `zk-hash hash` hashes field elements, given in hex on stdin or in a file, with the plain
+// implementation of a hash, selected by name or ID in the registry. `zk-hash prove` proves the
+// hash with the circuit of the hash and writes the proof and the verifier data, which
+// `zk-hash verify` reads back, after checking that it is the verifier data of the circuit of the
+// hash it is given. `zk-hash stats` prints the size of the circuit and of its proofs.
+
+use std::{
+    fs,
+    io::{self, Read},
+    process::ExitCode,
+};
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use plonky2::{
+    field::{
+        goldilocks_field::GoldilocksField,
+        types::{Field, Field64, PrimeField64},
+    },
+    plonk::{
+        circuit_data::{CircuitData, VerifierCircuitData},
+        config::PoseidonGoldilocksConfig,
+        proof::ProofWithPublicInputs,
+    },
+    util::serialization::DefaultGateSerializer,
+};
+use zk_lib::registry::{HashAlgorithm, HashFunction};
+
+type F = GoldilocksField;
+type C = PoseidonGoldilocksConfig;
+
+const USAGE: &str = "\
+Usage:
+  zk-hash hash --alg <ALG> [FILE]
+  zk-hash prove --alg <ALG> [FILE] --proof <PATH> --verifier <PATH>
+  zk-hash verify --alg <ALG> --proof <PATH> --verifier <PATH>
+  zk-hash stats --alg <ALG>
+
+ALG is one of poseidon, rescue, griffin, anemoi, arion and mimc, or its numeric ID. The input
+is 8 field elements in hex (2 for mimc), separated by spaces, commas or new lines, read from
+FILE or from stdin.";
+
+fn parse_algorithm(name: &str) -> Result<HashAlgorithm> {
+    let algorithm = match name.parse::<u8>() {
+        Ok(id) => HashAlgorithm::from_id(id),
+        Err(_) => name.parse(),
+    };
+    algorithm.map_err(|_| anyhow!("unknown hash algorithm `{}`", name))
+}
+
+/// Options of a command: `--name value` pairs and positional arguments.
+struct Options {
+    named: Vec<(String, String)>,
+    positional: Vec<String>,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Self> {
+        let mut named = Vec::new();
+        let mut positional = Vec::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value of --{}", name))?;
+                named.push((name.to_string(), value.clone()));
+            } else {
+                positional.push(arg.clone());
+            }
+        }
+        Ok(Self { named, positional })
+    }
+
+    fn get(&self, name: &str) -> Result<&str> {
+        self.named
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+            .ok_or_else(|| anyhow!("missing --{}", name))
+    }
+
+    fn hasher(&self) -> Result<Box<dyn HashFunction<F, C>>> {
+        Ok(parse_algorithm(self.get("alg")?)?.hasher())
+    }
+
+    /// The input, from the file given as positional argument or from stdin.
+    fn input(&self) -> Result<Vec<F>> {
+        let text = match self.positional.as_slice() {
+            [] => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                text
+            }
+            [path] => fs::read_to_string(path).with_context(|| format!("cannot read {}", path))?,
+            _ => bail!("too many arguments"),
+        };
+        parse_elements(&text)
+    }
+}
+
+fn parse_element(word: &str) -> Result<F> {
+    let digits = word.strip_prefix("0x").unwrap_or(word);
+    let value = u64::from_str_radix(digits, 16)
+        .with_context(|| format!("invalid hex element `{}`", word))?;
+    ensure!(value < F::ORDER, "non-canonical field element `{}`", word);
+    Ok(F::from_canonical_u64(value))
+}
+
+fn parse_elements(text: &str) -> Result<Vec<F>> {
+    text.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|word| !word.is_empty())
+        .map(parse_element)
+        .collect()
+}
+
+fn format_elements(elements: &[F]) -> String {
+    elements
+        .iter()
+        .map(|element| format!("0x{:016x}", element.to_canonical_u64()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn verifier_bytes(data: &CircuitData<F, C, 2>) -> Result<Vec<u8>> {
+    data.verifier_data()
+        .to_bytes(&DefaultGateSerializer)
+        .map_err(|_| anyhow!("cannot serialize the verifier data"))
+}
+
+fn hash(options: &Options) -> Result<()> {
+    let output = options.hasher()?.hash(&options.input()?)?;
+    println!("{}", format_elements(&output));
+    Ok(())
+}
+
+fn prove(options: &Options) -> Result<()> {
+    let (proof_path, verifier_path) = (options.get("proof")?, options.get("verifier")?);
+    let (data, proof) = options.hasher()?.prove(&options.input()?)?;
+    data.verify(proof.clone())?;
+
+    fs::write(proof_path, proof.to_bytes())
+        .with_context(|| format!("cannot write {}", proof_path))?;
+    fs::write(verifier_path, verifier_bytes(&data)?)
+        .with_context(|| format!("cannot write {}", verifier_path))?;
+    println!("{}", format_elements(&proof.public_inputs));
+    Ok(())
+}
+
+fn verify(options: &Options) -> Result<()> {
+    let hasher = options.hasher()?;
+    let (proof_path, verifier_path) = (options.get("proof")?, options.get("verifier")?);
+    let bytes =
+        fs::read(verifier_path).with_context(|| format!("cannot read {}", verifier_path))?;
+    let data = VerifierCircuitData::<F, C, 2>::from_bytes(bytes, &DefaultGateSerializer)
+        .map_err(|_| anyhow!("invalid verifier data in {}", verifier_path))?;
+
+    // Any circuit verifies with its own verifier data, which must be that of the hash
+    let (circuit, _) = hasher.circuit(&vec![F::ZERO; hasher.input_len()])?;
+    ensure!(
+        data.verifier_only.circuit_digest == circuit.verifier_only.circuit_digest
+            && data.verifier_only.constants_sigmas_cap
+                == circuit.verifier_only.constants_sigmas_cap,
+        "{} is not the verifier data of {}",
+        verifier_path,
+        hasher.algorithm()
+    );
+
+    let bytes = fs::read(proof_path).with_context(|| format!("cannot read {}", proof_path))?;
+    let proof = ProofWithPublicInputs::from_bytes(bytes, &data.common)
+        .with_context(|| format!("invalid proof in {}", proof_path))?;
+
+    let public_inputs = proof.public_inputs.clone();
+    data.verify(proof).context("invalid proof")?;
+    println!(
+        "valid {} proof of {}",
+        hasher.algorithm(),
+        format_elements(&public_inputs)
+    );
+    Ok(())
+}
+
+fn stats(options: &Options) -> Result<()> {
+    let hasher = options.hasher()?;
+    let (data, proof) = hasher.prove(&vec![F::ZERO; hasher.input_len()])?;
+    let common = &data.common;
+
+    let algorithm = hasher.algorithm();
+    println!("algorithm: {} (ID {})", algorithm, algorithm.id());
+    println!("rows: {} (2^{})", common.degree(), common.degree_bits());
+    println!("gate types: {}", common.gates.len());
+    for gate in &common.gates {
+        println!("  {:?}", gate);
+    }
+    println!("public inputs: {}", common.num_public_inputs);
+    println!("proof size: {} bytes", proof.to_bytes().len());
+    println!("verifier data size: {} bytes", verifier_bytes(&data)?.len());
+    Ok(())
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let (command, args) = args
+        .split_first()
+        .ok_or_else(|| anyhow!("missing command"))?;
+    let options = Options::parse(args)?;
+    match command.as_str() {
+        "hash" => hash(&options),
+        "prove" => prove(&options),
+        "verify" => verify(&options),
+        "stats" => stats(&options),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => bail!("unknown command `{}`", command),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {:#}\n\n{}", error, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_elements() {
+        let input = parse_elements("0x1 2,a\n 0xffffffff00000000 0 0 0 0").unwrap();
+        assert_eq!(input[..3], [F::ONE, F::TWO, F::from_canonical_u64(10)]);
+        assert_eq!(input[3], F::NEG_ONE);
+        assert_eq!(
+            format_elements(&input[..2]),
+            "0x0000000000000001 0x0000000000000002"
+        );
+
+        assert!(parse_elements("1 2 3 4 5 6 7 g").is_err());
+        assert!(parse_elements("1 2 3 4 5 6 7 0xffffffff00000001").is_err());
+
+        assert_eq!(parse_algorithm("Griffin").unwrap(), HashAlgorithm::Griffin);
+        assert_eq!(parse_algorithm("6").unwrap(), HashAlgorithm::MiMC);
+        assert!(parse_algorithm("sha256").is_err());
+        assert!(parse_algorithm("7").is_err());
+    }
+
+    fn command(args: &[&str]) -> Result<()> {
+        run(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_proofs() {
+        let dir = std::env::temp_dir().join(format!("zk-hash-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        for (i, algorithm) in HashAlgorithm::ALL.into_iter().enumerate() {
+            let hasher = algorithm.hasher::<F, C>();
+            let input: Vec<F> = (0..hasher.input_len())
+                .map(F::from_canonical_usize)
+                .collect();
+            assert!(hasher.hash(&input[1..]).is_err());
+
+            let [input_path, proof_path, verifier_path] = ["input", "proof", "verifier"]
+                .map(|file| dir.join(format!("{}.{}", algorithm, file)).display().to_string());
+            fs::write(&input_path, format_elements(&input)).unwrap();
+            let name = algorithm.name();
+            let other = HashAlgorithm::ALL[(i + 1) % HashAlgorithm::ALL.len()].name();
+            let files = ["--proof", &proof_path, "--verifier", &verifier_path];
+            command(&[&["prove", "--alg", name, &input_path], &files[..]].concat()).unwrap();
+
+            let verifier = VerifierCircuitData::<F, C, 2>::from_bytes(
+                fs::read(&verifier_path).unwrap(),
+                &DefaultGateSerializer,
+            )
+            .unwrap();
+            let proof = ProofWithPublicInputs::<F, C, 2>::from_bytes(
+                fs::read(&proof_path).unwrap(),
+                &verifier.common,
+            )
+            .unwrap();
+            assert_eq!(proof.public_inputs, hasher.public_inputs(&input).unwrap());
+
+            // The proof verifies as a proof of the hash, and of no other hash
+            command(&[&["verify", "--alg", name], &files[..]].concat()).unwrap();
+            assert!(command(&[&["verify", "--alg", other], &files[..]].concat()).is_err());
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
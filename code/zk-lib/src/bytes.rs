@@ -0,0 +1,169 @@
+// Hashing of byte strings
+//
+// Bytes are packed little-endian, 7 per Goldilocks element so that every packed value is
+// canonical, after an element holding the length of the string. The encoding is injective
+// and is absorbed by the `Sponge` with the `BYTES_DOMAIN` separator. In the circuit, every
+// byte target is range-checked before being packed.
+
+use plonky2::{
+    field::extension::Extendable,
+    hash::hash_types::{HashOut, HashOutTarget, RichField},
+    iop::target::Target,
+    plonk::circuit_builder::CircuitBuilder,
+};
+
+use crate::{
+    hashes::Permutation,
+    sponge::{hash_to_digest, hash_to_digest_circuit},
+};
+
+/// Number of bytes packed in a field element.
+pub const BYTES_PER_ELEMENT: usize = 7;
+
+/// Domain separator of the byte hashing, `"bytes"` in ASCII.
+pub const BYTES_DOMAIN: u64 = 0x62_79_74_65_73;
+
+/// Number of elements of the encoding of `len` bytes.
+pub fn encoded_len(len: usize) -> usize {
+    1 + len.div_ceil(BYTES_PER_ELEMENT)
+}
+
+/// Encodes `bytes` as the length followed by the bytes packed in chunks of
+/// `BYTES_PER_ELEMENT`, the last chunk being padded with zeros.
+pub fn encode<F: RichField>(bytes: &[u8]) -> Vec<F> {
+    let mut elements = Vec::with_capacity(encoded_len(bytes.len()));
+    elements.push(F::from_canonical_usize(bytes.len()));
+    for chunk in bytes.chunks(BYTES_PER_ELEMENT) {
+        let packed = chunk
+            .iter()
+            .rev()
+            .fold(0u64, |acc, byte| (acc << 8) | *byte as u64);
+        elements.push(F::from_canonical_u64(packed));
+    }
+    elements
+}
+
+/// Encodes byte targets as [`encode`] does, after checking that each of them is a byte.
+pub fn encode_circuit<F: RichField + Extendable<2>>(
+    builder: &mut CircuitBuilder<F, 2>,
+    bytes: &[Target],
+) -> Vec<Target> {
+    let base = F::from_canonical_u64(1 << 8);
+
+    let mut elements = Vec::with_capacity(encoded_len(bytes.len()));
+    elements.push(builder.constant(F::from_canonical_usize(bytes.len())));
+    for chunk in bytes.chunks(BYTES_PER_ELEMENT) {
+        let mut packed = builder.zero();
+        for byte in chunk.iter().rev() {
+            builder.range_check(*byte, 8);
+            packed = builder.mul_const_add(base, packed, *byte);
+        }
+        elements.push(packed);
+    }
+    elements
+}
+
+/// Hashes `bytes` with the permutation `H` over a state of `WIDTH` elements, `RATE` of
+/// which absorb the encoding.
+pub fn hash_bytes<H, F, const WIDTH: usize, const RATE: usize>(bytes: &[u8]) -> HashOut<F>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    hash_to_digest::<H, F, WIDTH, RATE>(&[&encode(bytes)], BYTES_DOMAIN).into()
+}
+
+/// Circuit of [`hash_bytes`] over byte targets, which are range-checked.
+pub fn hash_bytes_circuit<H, F, const WIDTH: usize, const RATE: usize>(
+    builder: &mut CircuitBuilder<F, 2>,
+    bytes: &[Target],
+) -> HashOutTarget
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    let elements = encode_circuit(builder, bytes);
+    hash_to_digest_circuit::<H, F, WIDTH, RATE>(builder, &[&elements], BYTES_DOMAIN)
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::{
+        field::{goldilocks_field::GoldilocksField, types::Field},
+        iop::witness::{PartialWitness, WitnessWrite},
+        plonk::{circuit_data::CircuitConfig, config::PoseidonGoldilocksConfig},
+    };
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+    use crate::hashes::{griffin::griffin::Griffin, poseidon::poseidon::Poseidon};
+
+    type F = GoldilocksField;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode::<F>(&[]), [F::ZERO]);
+        assert_eq!(
+            encode::<F>(&[1, 2, 3, 4, 5, 6, 7, 8]),
+            [
+                F::from_canonical_u64(8),
+                F::from_canonical_u64(0x07_06_05_04_03_02_01),
+                F::from_canonical_u64(8)
+            ]
+        );
+        // Trailing zeros are not lost in the padding
+        assert_ne!(encode::<F>(&[1, 2]), encode::<F>(&[1, 2, 0]));
+        assert_ne!(
+            hash_bytes::<Griffin, F, 12, 8>(&[1, 2]),
+            hash_bytes::<Griffin, F, 12, 8>(&[1, 2, 0])
+        );
+        assert_eq!(encode::<F>(&[0xff; 7])[1].0, (1 << 56) - 1);
+    }
+
+    fn check_hash_bytes<H: Permutation<12>>() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let inputs: Vec<Vec<u8>> = [0, 1, 6, 7, 8, 20, 55, 56, 57, 100]
+            .iter()
+            .map(|len| (0..*len).map(|_| rng.gen()).collect())
+            .collect();
+
+        let mut builder = CircuitBuilder::<F, 2>::new(CircuitConfig::standard_recursion_config());
+        let mut pw = PartialWitness::new();
+        let mut expected = Vec::new();
+        for input in &inputs {
+            let targets = builder.add_virtual_targets(input.len());
+            let hash = hash_bytes_circuit::<H, F, 12, 8>(&mut builder, &targets);
+            builder.register_public_inputs(&hash.elements);
+
+            let bytes: Vec<F> = input.iter().map(|b| F::from_canonical_u8(*b)).collect();
+            pw.set_target_arr(&targets, &bytes);
+            expected.extend(hash_bytes::<H, F, 12, 8>(input).elements);
+        }
+
+        let data = builder.build::<PoseidonGoldilocksConfig>();
+        let proof = data.prove(pw).unwrap();
+        assert_eq!(proof.public_inputs, expected);
+        assert!(data.verify(proof).is_ok());
+    }
+
+    #[test]
+    fn test_hash_bytes_circuit() {
+        check_hash_bytes::<Griffin>();
+        check_hash_bytes::<Poseidon>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_byte_range_check() {
+        let mut builder = CircuitBuilder::<F, 2>::new(CircuitConfig::standard_recursion_config());
+        let targets = builder.add_virtual_targets(2);
+        hash_bytes_circuit::<Griffin, F, 12, 8>(&mut builder, &targets);
+
+        let mut pw = PartialWitness::new();
+        pw.set_target(targets[0], F::ONE);
+        pw.set_target(targets[1], F::from_canonical_u64(256));
+        let data = builder.build::<PoseidonGoldilocksConfig>();
+        let _ = data.prove(pw);
+    }
+}
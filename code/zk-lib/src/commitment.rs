@@ -0,0 +1,227 @@
+// Hiding commitments
+//
+// A commitment to a value of field elements is the `Digest` squeezed by the `Sponge` after
+// absorbing a random blinding of `BLINDING_LEN` elements and then the value, under the
+// `COMMITMENT_DOMAIN` separator. The blinding makes it hiding and the sponge makes it binding.
+// `OpeningTarget` proves the knowledge of an opening in a circuit, and relations on the
+// committed value: a range, or the equality of the values behind two commitments.
+
+use core::marker::PhantomData;
+
+use plonky2::{
+    field::extension::Extendable,
+    hash::hash_types::RichField,
+    iop::{
+        target::Target,
+        witness::{PartialWitness, WitnessWrite},
+    },
+    plonk::circuit_builder::CircuitBuilder,
+};
+
+use crate::{
+    digest::{Digest, DigestTarget},
+    hashes::Permutation,
+    sponge::{hash_to_digest, hash_to_digest_circuit},
+};
+
+/// Number of elements of a blinding, 256 bits.
+pub const BLINDING_LEN: usize = 4;
+
+/// Domain separator of the commitments, `"commit"` in ASCII.
+pub const COMMITMENT_DOMAIN: u64 = 0x63_6f_6d_6d_69_74;
+
+/// A uniformly random blinding.
+pub fn random_blinding<F: RichField>() -> [F; BLINDING_LEN] {
+    F::rand_array()
+}
+
+/// Commitment to `value` with `blinding`, with the permutation `H` over a state of `WIDTH`
+/// elements, `RATE` of which absorb the blinding and the value.
+pub fn commit<H, F, const WIDTH: usize, const RATE: usize>(
+    value: &[F],
+    blinding: &[F; BLINDING_LEN],
+) -> Digest<F>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    hash_to_digest::<H, F, WIDTH, RATE>(&[blinding, value], COMMITMENT_DOMAIN)
+}
+
+/// Checks that `value` and `blinding` open `commitment`.
+pub fn open<H, F, const WIDTH: usize, const RATE: usize>(
+    commitment: &Digest<F>,
+    value: &[F],
+    blinding: &[F; BLINDING_LEN],
+) -> bool
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    commit::<H, F, WIDTH, RATE>(value, blinding) == *commitment
+}
+
+/// Circuit of [`commit`].
+pub fn commit_circuit<H, F, const WIDTH: usize, const RATE: usize>(
+    builder: &mut CircuitBuilder<F, 2>,
+    value: &[Target],
+    blinding: &[Target; BLINDING_LEN],
+) -> DigestTarget
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    hash_to_digest_circuit::<H, F, WIDTH, RATE>(builder, &[blinding, value], COMMITMENT_DOMAIN)
+}
+
+/// Knowledge of the opening of a commitment to a value of `len` elements. The value and the
+/// blinding are private witnesses; the commitment is left to the caller, to be registered as
+/// a public input or connected to other targets.
+#[derive(Debug, Clone)]
+pub struct OpeningTarget<H> {
+    pub value: Vec<Target>,
+    pub blinding: [Target; BLINDING_LEN],
+    pub commitment: DigestTarget,
+    _permutation: PhantomData<H>,
+}
+
+impl<H> OpeningTarget<H> {
+    pub fn new<F, const WIDTH: usize, const RATE: usize>(
+        builder: &mut CircuitBuilder<F, 2>,
+        len: usize,
+    ) -> Self
+    where
+        H: Permutation<WIDTH>,
+        F: RichField + Extendable<2>,
+    {
+        let value = builder.add_virtual_targets(len);
+        let blinding = builder.add_virtual_target_arr();
+        let commitment = commit_circuit::<H, F, WIDTH, RATE>(builder, &value, &blinding);
+        Self {
+            value,
+            blinding,
+            commitment,
+            _permutation: PhantomData,
+        }
+    }
+
+    pub fn set_witness<F: RichField>(
+        &self,
+        pw: &mut PartialWitness<F>,
+        value: &[F],
+        blinding: &[F; BLINDING_LEN],
+    ) {
+        pw.set_target_arr(&self.value, value);
+        pw.set_target_arr(&self.blinding, blinding);
+    }
+
+    /// Constrains every element of the committed value to be below `2^bits`.
+    pub fn range_check<F>(&self, builder: &mut CircuitBuilder<F, 2>, bits: usize)
+    where
+        F: RichField + Extendable<2>,
+    {
+        for element in &self.value {
+            builder.range_check(*element, bits);
+        }
+    }
+
+    /// Constrains the values committed to by `self` and `other` to be equal.
+    pub fn connect_values<F, G>(&self, builder: &mut CircuitBuilder<F, 2>, other: &OpeningTarget<G>)
+    where
+        F: RichField + Extendable<2>,
+    {
+        assert_eq!(self.value.len(), other.value.len());
+        for (left, right) in self.value.iter().zip(&other.value) {
+            builder.connect(*left, *right);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::{
+        field::{goldilocks_field::GoldilocksField, types::Field},
+        plonk::{circuit_data::CircuitConfig, config::PoseidonGoldilocksConfig},
+    };
+
+    use super::*;
+    use crate::{
+        hashes::{griffin::griffin::Griffin, rescue_prime::rescue_prime::Rescue},
+        prf::prf,
+    };
+
+    type F = GoldilocksField;
+
+    #[test]
+    fn test_commit_open() {
+        let value = [F::ONE, F::TWO, F::NEG_ONE];
+        let blinding = random_blinding();
+        let commitment = commit::<Griffin, F, 12, 8>(&value, &blinding);
+        assert!(open::<Griffin, F, 12, 8>(&commitment, &value, &blinding));
+        assert!(!open::<Griffin, F, 12, 8>(
+            &commitment,
+            &value[1..],
+            &blinding
+        ));
+        assert!(!open::<Griffin, F, 12, 8>(
+            &commitment,
+            &value,
+            &random_blinding()
+        ));
+
+        // Hiding: the same value under another blinding gives another commitment
+        assert_ne!(
+            commitment,
+            commit::<Griffin, F, 12, 8>(&value, &random_blinding())
+        );
+        // The commitments are separated from the PRF keyed with the blinding
+        assert_ne!(commitment, prf::<Griffin, F, 12, 8>(&blinding, &value));
+    }
+
+    fn opening_circuit<H: Permutation<12>>(values: [&[F]; 2], bits: usize) -> (Vec<F>, Vec<F>) {
+        let mut builder = CircuitBuilder::<F, 2>::new(CircuitConfig::standard_recursion_config());
+        let openings = values.map(|value| {
+            let opening = OpeningTarget::<H>::new::<F, 12, 8>(&mut builder, value.len());
+            builder.register_public_inputs(&opening.commitment.elements);
+            opening
+        });
+        openings[0].range_check(&mut builder, bits);
+        openings[0].connect_values(&mut builder, &openings[1]);
+
+        let mut pw = PartialWitness::new();
+        let mut expected = Vec::new();
+        for (opening, value) in openings.iter().zip(values) {
+            let blinding = random_blinding();
+            opening.set_witness(&mut pw, value, &blinding);
+            expected.extend(commit::<H, F, 12, 8>(value, &blinding).elements());
+        }
+
+        let data = builder.build::<PoseidonGoldilocksConfig>();
+        let proof = data.prove(pw).unwrap();
+        let public_inputs = proof.public_inputs.clone();
+        assert!(data.verify(proof).is_ok());
+        (public_inputs, expected)
+    }
+
+    #[test]
+    fn test_opening_circuit() {
+        let value = [F::from_canonical_u64(200), F::from_canonical_u64(3)];
+        let (commitments, expected) = opening_circuit::<Griffin>([&value, &value], 8);
+        assert_eq!(commitments, expected);
+        let (commitments, expected) = opening_circuit::<Rescue>([&value, &value], 8);
+        assert_eq!(commitments, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_opening_range() {
+        let value = [F::from_canonical_u64(256)];
+        opening_circuit::<Griffin>([&value, &value], 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_opening_equality() {
+        opening_circuit::<Griffin>([&[F::ONE], &[F::TWO]], 8);
+    }
+}
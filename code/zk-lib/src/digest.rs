@@ -0,0 +1,204 @@
+// 256-bit digests
+//
+// A `Digest` is the output of the sponges: four Goldilocks elements, 256 bits, giving 128 bits
+// of collision resistance. It is encoded as the 32 little-endian bytes of its canonical
+// elements, displayed in hex, and serialized as the hex string in human-readable formats and
+// as the bytes otherwise. Decoding rejects non-canonical elements, so every digest has a
+// single encoding.
+
+use core::{fmt, str::FromStr};
+
+use plonky2::hash::hash_types::{HashOut, HashOutTarget, RichField, NUM_HASH_OUT_ELTS};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Number of bytes of an encoded digest.
+pub const DIGEST_BYTES: usize = 8 * NUM_HASH_OUT_ELTS;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error {
+    /// An encoding that is not `DIGEST_BYTES` bytes, or `2 * DIGEST_BYTES` hex digits, long.
+    InvalidLength,
+    /// A character that is not a hex digit.
+    InvalidHex,
+    /// An element that is not below the order of the field.
+    NonCanonical,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidLength => write!(f, "a digest is {} bytes long", DIGEST_BYTES),
+            Error::InvalidHex => write!(f, "invalid hex digit"),
+            Error::NonCanonical => write!(f, "non-canonical field element"),
+        }
+    }
+}
+
+/// A digest of four field elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Digest<F: RichField>(pub [F; NUM_HASH_OUT_ELTS]);
+
+impl<F: RichField> Digest<F> {
+    pub fn elements(&self) -> &[F; NUM_HASH_OUT_ELTS] {
+        &self.0
+    }
+
+    /// The little-endian bytes of the canonical elements.
+    pub fn to_bytes(&self) -> [u8; DIGEST_BYTES] {
+        let mut bytes = [0; DIGEST_BYTES];
+        for (chunk, element) in bytes.chunks_exact_mut(8).zip(self.0) {
+            chunk.copy_from_slice(&element.to_canonical_u64().to_le_bytes());
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != DIGEST_BYTES {
+            return Err(Error::InvalidLength);
+        }
+        let mut elements = [F::ZERO; NUM_HASH_OUT_ELTS];
+        for (element, chunk) in elements.iter_mut().zip(bytes.chunks_exact(8)) {
+            let value = u64::from_le_bytes(chunk.try_into().unwrap());
+            if value >= F::ORDER {
+                return Err(Error::NonCanonical);
+            }
+            *element = F::from_canonical_u64(value);
+        }
+        Ok(Self(elements))
+    }
+}
+
+impl<F: RichField> From<HashOut<F>> for Digest<F> {
+    fn from(hash: HashOut<F>) -> Self {
+        Self(hash.elements)
+    }
+}
+
+impl<F: RichField> From<Digest<F>> for HashOut<F> {
+    fn from(digest: Digest<F>) -> Self {
+        Self { elements: digest.0 }
+    }
+}
+
+/// In-circuit twin of [`Digest`].
+pub type DigestTarget = HashOutTarget;
+
+impl<F: RichField> TryFrom<&[F]> for Digest<F> {
+    type Error = Error;
+
+    fn try_from(elements: &[F]) -> Result<Self, Error> {
+        elements
+            .try_into()
+            .map(Self)
+            .map_err(|_| Error::InvalidLength)
+    }
+}
+
+impl<F: RichField> fmt::LowerHex for Digest<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        for byte in self.to_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: RichField> fmt::Display for Digest<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:x}", self)
+    }
+}
+
+impl<F: RichField> FromStr for Digest<F> {
+    type Err = Error;
+
+    /// Parses the hex encoding of a digest, with or without a `0x` prefix.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let hex = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+        if hex.len() != 2 * DIGEST_BYTES {
+            return Err(Error::InvalidLength);
+        }
+        let digit = |c: u8| (c as char).to_digit(16).ok_or(Error::InvalidHex);
+        let bytes = hex
+            .chunks_exact(2)
+            .map(|pair| Ok((digit(pair[0])? << 4 | digit(pair[1])?) as u8))
+            .collect::<Result<Vec<u8>, Error>>()?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+impl<F: RichField> Serialize for Digest<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+impl<'de, F: RichField> Deserialize<'de> for Digest<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let hex = String::deserialize(deserializer)?;
+            hex.parse().map_err(de::Error::custom)
+        } else {
+            let bytes = Vec::<u8>::deserialize(deserializer)?;
+            Self::from_bytes(&bytes).map_err(de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{
+        goldilocks_field::GoldilocksField,
+        types::{Field, Field64, Sample},
+    };
+
+    use super::*;
+
+    type F = GoldilocksField;
+
+    #[test]
+    fn test_digest_encoding() {
+        let digest = Digest([F::ONE, F::TWO, F::NEG_ONE, F::ZERO]);
+        let hex = digest.to_string();
+        assert_eq!(
+            hex,
+            "0100000000000000020000000000000000000000ffffffff0000000000000000"
+        );
+        assert_eq!(format!("{:#x}", digest), format!("0x{}", hex));
+        assert_eq!(hex.parse(), Ok(digest));
+        assert_eq!(format!("0x{}", hex).parse(), Ok(digest));
+        assert_eq!(Digest::from_bytes(&digest.to_bytes()), Ok(digest));
+
+        let digest = Digest(F::rand_array());
+        assert_eq!(digest.to_string().parse(), Ok(digest));
+        assert_eq!(Digest::from(HashOut::from(digest)), digest);
+    }
+
+    #[test]
+    fn test_digest_decoding_errors() {
+        assert_eq!(Digest::<F>::from_bytes(&[0; 31]), Err(Error::InvalidLength));
+        assert_eq!("00".parse::<Digest<F>>(), Err(Error::InvalidLength));
+        assert_eq!("zz".repeat(32).parse::<Digest<F>>(), Err(Error::InvalidHex));
+
+        // The order of the field is not a canonical element
+        let mut bytes = [0; DIGEST_BYTES];
+        bytes[8..16].copy_from_slice(&F::ORDER.to_le_bytes());
+        assert_eq!(Digest::<F>::from_bytes(&bytes), Err(Error::NonCanonical));
+    }
+
+    #[test]
+    fn test_digest_serde() {
+        let digest = Digest::<F>(F::rand_array());
+        let json = serde_json::to_string(&digest).unwrap();
+        assert_eq!(json, format!("\"{}\"", digest));
+        assert_eq!(serde_json::from_str::<Digest<F>>(&json).unwrap(), digest);
+        assert!(serde_json::from_str::<Digest<F>>("\"00\"").is_err());
+    }
+}
@@ -0,0 +1,366 @@
+// Proofs of hash chains y = H^n(x) with cyclic recursion
+//
+// Every proof of the chain circuit applies `steps` iterations of the hash to the output of
+// the proof it recursively verifies, or to the initial value for the first proof. The proof
+// of `n` iterations has the size of a single proof, whatever `n`. Its public inputs are the
+// initial value, the output, the number of iterations, and the verifier data of the circuit,
+// which `HashChain::verify` checks against the circuit itself.
+//
+// One iteration maps `x` in `F^RATE` to the rate of the permutation of `x` followed by a
+// zero capacity, which is the sponge hash of `x` squeezed to `RATE` elements.
+
+use core::marker::PhantomData;
+
+use anyhow::{ensure, Result};
+use hashbrown::HashMap;
+use plonky2::{
+    field::extension::Extendable,
+    gates::{gate::GateRef, noop::NoopGate},
+    hash::hash_types::RichField,
+    iop::{
+        target::{BoolTarget, Target},
+        witness::{PartialWitness, WitnessWrite},
+    },
+    plonk::{
+        circuit_builder::CircuitBuilder,
+        circuit_data::{CircuitConfig, CircuitData, CommonCircuitData, VerifierCircuitTarget},
+        config::{AlgebraicHasher, GenericConfig},
+        proof::{ProofWithPublicInputs, ProofWithPublicInputsTarget},
+    },
+    recursion::{
+        cyclic_recursion::check_cyclic_proof_verifier_data, dummy_circuit::cyclic_base_proof,
+    },
+};
+
+use crate::hashes::Permutation;
+
+/// Bound on the number of circuits built to find the common data of the chain circuit.
+const MAX_COMMON_DATA_ROUNDS: usize = 8;
+
+/// One iteration of the chain.
+pub fn iteration<H, F, const WIDTH: usize, const RATE: usize>(x: [F; RATE]) -> [F; RATE]
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    let mut state = [F::ZERO; WIDTH];
+    state[..RATE].copy_from_slice(&x);
+    H::permute(&mut state);
+    state[..RATE].try_into().unwrap()
+}
+
+/// `H^n(x)`, computed natively.
+pub fn iterate<H, F, const WIDTH: usize, const RATE: usize>(x: [F; RATE], n: usize) -> [F; RATE]
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    (0..n).fold(x, |y, _| iteration::<H, F, WIDTH, RATE>(y))
+}
+
+fn iteration_circuit<H, F, const WIDTH: usize, const RATE: usize>(
+    builder: &mut CircuitBuilder<F, 2>,
+    x: &[Target],
+) -> Vec<Target>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    let mut state = [builder.zero(); WIDTH];
+    state[..RATE].copy_from_slice(x);
+    H::permute_circuit(&mut state, builder);
+    state[..RATE].to_vec()
+}
+
+/// Common data of a circuit verifying a proof of a circuit verifying an empty proof, from
+/// which the common data of the chain circuit is searched.
+fn common_data_for_recursion<F, C>() -> CommonCircuitData<F, 2>
+where
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F>,
+    C::Hasher: AlgebraicHasher<F>,
+{
+    let config = CircuitConfig::standard_recursion_config();
+    let mut data = CircuitBuilder::<F, 2>::new(config.clone()).build::<C>();
+    for _ in 0..2 {
+        let mut builder = CircuitBuilder::<F, 2>::new(config.clone());
+        let proof = builder.add_virtual_proof_with_pis(&data.common);
+        let verifier_data =
+            builder.add_virtual_verifier_data(data.common.config.fri_config.cap_height);
+        builder.verify_proof::<C>(&proof, &verifier_data, &data.common);
+        data = builder.build::<C>();
+    }
+    data.common
+}
+
+/// Targets of the chain circuit set by the prover.
+struct ChainTargets {
+    initial: Vec<Target>,
+    condition: BoolTarget,
+    inner_proof: ProofWithPublicInputsTarget<2>,
+    verifier_data: VerifierCircuitTarget,
+}
+
+/// Adds the chain circuit to `builder`. With `cyclic`, the inner proof is a proof of the
+/// circuit itself, or a dummy one for the first proof of the chain, and `common_data` must be
+/// the common data of the circuit being built. Without it, the inner proof is verified
+/// against free verifier data, which gives the same gates and can be built from any
+/// `common_data`.
+fn chain_circuit<H, F, C, const WIDTH: usize, const RATE: usize>(
+    builder: &mut CircuitBuilder<F, 2>,
+    steps: usize,
+    common_data: &mut CommonCircuitData<F, 2>,
+    cyclic: bool,
+) -> Result<ChainTargets>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F> + 'static,
+    C::Hasher: AlgebraicHasher<F>,
+{
+    let initial = builder.add_virtual_targets(RATE);
+    builder.register_public_inputs(&initial);
+    let input = builder.add_virtual_targets(RATE);
+    let mut output = input.clone();
+    for _ in 0..steps {
+        output = iteration_circuit::<H, F, WIDTH, RATE>(builder, &output);
+    }
+    builder.register_public_inputs(&output);
+    let iterations = builder.add_virtual_public_input();
+
+    let verifier_data = builder.add_verifier_data_public_inputs();
+    common_data.num_public_inputs = builder.num_public_inputs();
+
+    let condition = builder.add_virtual_bool_target_safe();
+    let inner_proof = builder.add_virtual_proof_with_pis(common_data);
+    let inner_initial = &inner_proof.public_inputs[..RATE];
+    let inner_output = &inner_proof.public_inputs[RATE..2 * RATE];
+    let inner_iterations = inner_proof.public_inputs[2 * RATE];
+
+    // Without an inner proof, the initial value of the dummy proof is not checked, so it can
+    // be connected to ours in every case
+    for i in 0..RATE {
+        builder.connect(initial[i], inner_initial[i]);
+        let x = builder.select(condition, inner_output[i], initial[i]);
+        builder.connect(input[i], x);
+    }
+    let steps = builder.constant(F::from_canonical_usize(steps));
+    let total = builder.mul_add(condition.target, inner_iterations, steps);
+    builder.connect(iterations, total);
+
+    if cyclic {
+        builder.conditionally_verify_cyclic_proof_or_dummy::<C>(
+            condition,
+            &inner_proof,
+            common_data,
+        )?;
+    } else {
+        let other_proof = builder.add_virtual_proof_with_pis(common_data);
+        let other_verifier_data =
+            builder.add_virtual_verifier_data(common_data.config.fri_config.cap_height);
+        builder.conditionally_verify_proof::<C>(
+            condition,
+            &inner_proof,
+            &verifier_data,
+            &other_proof,
+            &other_verifier_data,
+            common_data,
+        );
+    }
+    // The dummy circuit of the first proof is padded with no-ops
+    builder.add_gate_to_gate_set(GateRef::new(NoopGate));
+
+    Ok(ChainTargets {
+        initial,
+        condition,
+        inner_proof,
+        verifier_data,
+    })
+}
+
+/// Circuit proving `steps` iterations of the permutation `H` of `WIDTH` elements on top of
+/// the proof it verifies, on values of `RATE` elements.
+pub struct HashChain<H, F, C, const WIDTH: usize, const RATE: usize>
+where
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F>,
+{
+    pub data: CircuitData<F, C, 2>,
+    common_data: CommonCircuitData<F, 2>,
+    targets: ChainTargets,
+    steps: usize,
+    _permutation: PhantomData<H>,
+}
+
+impl<H, F, C, const WIDTH: usize, const RATE: usize> HashChain<H, F, C, WIDTH, RATE>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F> + 'static,
+    C::Hasher: AlgebraicHasher<F>,
+{
+    /// Builds the circuit for `steps` iterations per proof.
+    pub fn new(steps: usize) -> Result<Self> {
+        assert!(steps > 0 && RATE < WIDTH);
+
+        // The chain circuit verifies proofs of its own common data, which depends on the
+        // size of the verifier: iterate until the common data is a fixed point
+        let mut common_data = common_data_for_recursion::<F, C>();
+        let mut rounds = 0;
+        loop {
+            let mut builder = CircuitBuilder::new(CircuitConfig::standard_recursion_config());
+            chain_circuit::<H, F, C, WIDTH, RATE>(&mut builder, steps, &mut common_data, false)?;
+            let data = builder.build::<C>();
+            if data.common == common_data {
+                break;
+            }
+            common_data = data.common;
+            rounds += 1;
+            ensure!(
+                rounds < MAX_COMMON_DATA_ROUNDS,
+                "the common data of the chain circuit does not converge"
+            );
+        }
+
+        let mut builder = CircuitBuilder::new(CircuitConfig::standard_recursion_config());
+        let targets =
+            chain_circuit::<H, F, C, WIDTH, RATE>(&mut builder, steps, &mut common_data, true)?;
+        Ok(Self {
+            data: builder.build::<C>(),
+            common_data,
+            targets,
+            steps,
+            _permutation: PhantomData,
+        })
+    }
+
+    /// Proves the first `steps` iterations from `x`.
+    pub fn prove_first(&self, x: [F; RATE]) -> Result<ProofWithPublicInputs<F, C, 2>> {
+        let mut pw = PartialWitness::new();
+        pw.set_target_arr(&self.targets.initial, &x);
+        pw.set_bool_target(self.targets.condition, false);
+        let dummy = cyclic_base_proof(
+            &self.common_data,
+            &self.data.verifier_only,
+            x.into_iter().enumerate().collect::<HashMap<_, _>>(),
+        );
+        pw.set_proof_with_pis_target(&self.targets.inner_proof, &dummy);
+        pw.set_verifier_data_target(&self.targets.verifier_data, &self.data.verifier_only);
+        self.data.prove(pw)
+    }
+
+    /// Proves `steps` more iterations on top of `previous`.
+    pub fn prove_next(
+        &self,
+        previous: &ProofWithPublicInputs<F, C, 2>,
+    ) -> Result<ProofWithPublicInputs<F, C, 2>> {
+        let mut pw = PartialWitness::new();
+        pw.set_target_arr(&self.targets.initial, &Self::initial(previous));
+        pw.set_bool_target(self.targets.condition, true);
+        pw.set_proof_with_pis_target(&self.targets.inner_proof, previous);
+        pw.set_verifier_data_target(&self.targets.verifier_data, &self.data.verifier_only);
+        self.data.prove(pw)
+    }
+
+    /// Proves `n` iterations from `x`. `n` must be a positive multiple of the number of
+    /// steps per proof.
+    pub fn prove(&self, x: [F; RATE], n: usize) -> Result<ProofWithPublicInputs<F, C, 2>> {
+        ensure!(
+            n > 0 && n % self.steps == 0,
+            "{} iterations are not a multiple of {} steps",
+            n,
+            self.steps
+        );
+        let mut proof = self.prove_first(x)?;
+        for _ in 1..n / self.steps {
+            proof = self.prove_next(&proof)?;
+        }
+        Ok(proof)
+    }
+
+    /// Verifies a proof of the chain, including the verifier data in its public inputs.
+    pub fn verify(&self, proof: &ProofWithPublicInputs<F, C, 2>) -> Result<()> {
+        check_cyclic_proof_verifier_data(proof, &self.data.verifier_only, &self.data.common)?;
+        self.data.verify(proof.clone())
+    }
+
+    pub fn initial(proof: &ProofWithPublicInputs<F, C, 2>) -> [F; RATE] {
+        proof.public_inputs[..RATE].try_into().unwrap()
+    }
+
+    pub fn output(proof: &ProofWithPublicInputs<F, C, 2>) -> [F; RATE] {
+        proof.public_inputs[RATE..2 * RATE].try_into().unwrap()
+    }
+
+    pub fn iterations(proof: &ProofWithPublicInputs<F, C, 2>) -> u64 {
+        proof.public_inputs[2 * RATE].to_canonical_u64()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::{
+        field::{goldilocks_field::GoldilocksField, types::Field},
+        plonk::config::PoseidonGoldilocksConfig,
+    };
+
+    use super::*;
+    use crate::hashes::{
+        griffin::griffin::Griffin,
+        poseidon::{poseidon::Poseidon, SPONGE_RATE},
+    };
+
+    type F = GoldilocksField;
+    type C = PoseidonGoldilocksConfig;
+
+    fn input() -> [F; SPONGE_RATE] {
+        core::array::from_fn(|i| F::from_canonical_usize(i + 1))
+    }
+
+    #[test]
+    fn test_iteration() {
+        // An iteration is the sponge hash squeezed to the whole rate
+        let x = input();
+        assert_eq!(
+            iteration::<Griffin, F, 12, 8>(x),
+            Griffin::griffin_sponge::<F, 8>(x)
+        );
+        assert_eq!(
+            iteration::<Poseidon, F, 12, 8>(x)[0],
+            Poseidon::poseidon_hash::<F, 2>(x)
+        );
+    }
+
+    #[test]
+    fn test_griffin_chain() {
+        let chain = HashChain::<Griffin, F, C, 12, 8>::new(2).unwrap();
+        let x = input();
+        let proof = chain.prove(x, 6).unwrap();
+        chain.verify(&proof).unwrap();
+
+        let mut y = x;
+        for _ in 0..6 {
+            y = Griffin::griffin_sponge::<F, 8>(y);
+        }
+        assert_eq!(HashChain::<Griffin, F, C, 12, 8>::initial(&proof), x);
+        assert_eq!(HashChain::<Griffin, F, C, 12, 8>::output(&proof), y);
+        assert_eq!(HashChain::<Griffin, F, C, 12, 8>::iterations(&proof), 6);
+        assert!(chain.prove(x, 5).is_err());
+    }
+
+    #[test]
+    fn test_poseidon_chain() {
+        let chain = HashChain::<Poseidon, F, C, 12, 8>::new(1).unwrap();
+        let x = input();
+        let proof = chain.prove(x, 2).unwrap();
+        chain.verify(&proof).unwrap();
+
+        let y = iterate::<Poseidon, F, 12, 8>(x, 2);
+        assert_eq!(
+            y[0],
+            Poseidon::poseidon_hash::<F, 2>(iteration::<Poseidon, F, 12, 8>(x))
+        );
+        assert_eq!(HashChain::<Poseidon, F, C, 12, 8>::output(&proof), y);
+        assert_eq!(HashChain::<Poseidon, F, C, 12, 8>::iterations(&proof), 2);
+    }
+}
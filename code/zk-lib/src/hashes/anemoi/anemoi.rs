@@ -1,10 +1,6 @@
 // Implementation of the Anemoi hash funciton and zk circuit
 
-use self::{
-    mds::MDS_MATRIX,
-    round_constants::{C, D},
-    sbox::{ALPHA_INV, BETA, DELTA},
-};
+use self::sbox::{ALPHA_INV, BETA, DELTA};
 use plonky2::{
     field::extension::Extendable,
     hash::hash_types::RichField,
@@ -21,21 +17,23 @@ use plonky2::{
 };
 
 use super::*;
+use crate::hashes::{Permutation, Width};
 
 pub struct Anemoi;
 
 impl Anemoi {
-    fn linear_layer<F: RichField + Extendable<2>>(state: &mut [F; SPONGE_WIDTH]) {
-        let mut x = [F::ZERO; NUM_COLUMNS];
-        x.copy_from_slice(&state[..NUM_COLUMNS]);
-        let mut y = [F::ZERO; NUM_COLUMNS];
-        y.copy_from_slice(&state[NUM_COLUMNS..]);
+    fn linear_layer<F: RichField + Extendable<2>, const WIDTH: usize>(state: &mut [F; WIDTH])
+    where
+        Width<WIDTH>: AnemoiParameters<WIDTH>,
+    {
+        let num_columns = WIDTH / 2;
+        let mds_matrix = Width::<WIDTH>::MDS_MATRIX;
 
         // MDS_MATRIX * x
-        let mut x_vec = [F::ZERO; NUM_COLUMNS];
-        for i in 0..NUM_COLUMNS {
-            for j in 0..NUM_COLUMNS {
-                let ct = MDS_MATRIX[i][j];
+        let mut x_vec = [F::ZERO; WIDTH];
+        for i in 0..num_columns {
+            for j in 0..num_columns {
+                let ct = mds_matrix[i * num_columns + j];
                 let mut temp = state[j].clone();
                 temp.mul_assign(F::from_canonical_usize(ct));
                 x_vec[i].add_assign(temp);
@@ -43,18 +41,14 @@ impl Anemoi {
         }
 
         // MDS_MATRIX * y
-        let mut y_vec = [F::ZERO; NUM_COLUMNS];
-        let mut y_rotated = [F::ZERO; NUM_COLUMNS];
-        for i in 0..NUM_COLUMNS {
-            if i != NUM_COLUMNS - 1 {
-                y_rotated[i] = y[i + 1];
-            } else {
-                y_rotated[i] = y[0];
-            }
+        let mut y_vec = [F::ZERO; WIDTH];
+        let mut y_rotated = [F::ZERO; WIDTH];
+        for i in 0..num_columns {
+            y_rotated[i] = state[num_columns + (i + 1) % num_columns];
         }
-        for i in 0..NUM_COLUMNS {
-            for j in 0..NUM_COLUMNS {
-                let ct = MDS_MATRIX[i][j];
+        for i in 0..num_columns {
+            for j in 0..num_columns {
+                let ct = mds_matrix[i * num_columns + j];
                 let mut temp = y_rotated[j].clone();
                 temp.mul_assign(F::from_canonical_usize(ct));
                 y_vec[i].add_assign(temp);
@@ -62,13 +56,13 @@ impl Anemoi {
         }
 
         // Pseudo-Hadamard transform P
-        for i in 0..NUM_COLUMNS {
+        for i in 0..num_columns {
             y_vec[i] += x_vec[i];
             x_vec[i] += y_vec[i];
         }
 
-        state[..NUM_COLUMNS].copy_from_slice(&x_vec);
-        state[NUM_COLUMNS..].copy_from_slice(&y_vec);
+        state[..num_columns].copy_from_slice(&x_vec[..num_columns]);
+        state[num_columns..].copy_from_slice(&y_vec[..num_columns]);
     }
 
     fn sbox_exp_comp<F: RichField + Extendable<2>>(x: F) -> F {
@@ -76,20 +70,27 @@ impl Anemoi {
         F::mul(x, x)
     }
 
-    fn evaluate_sbox<F: RichField + Extendable<2>>(state: &mut [F; SPONGE_WIDTH]) {
-        for i in 0..NUM_COLUMNS {
-            state[i] -= F::from_canonical_usize(BETA) * Self::sbox_exp_comp(state[NUM_COLUMNS + i]);
-            state[NUM_COLUMNS + i] -= state[i].exp_u64(ALPHA_INV as u64);
-            state[i] += F::from_canonical_usize(BETA) * Self::sbox_exp_comp(state[NUM_COLUMNS + i])
+    fn evaluate_sbox<F: RichField + Extendable<2>, const WIDTH: usize>(state: &mut [F; WIDTH]) {
+        let num_columns = WIDTH / 2;
+        for i in 0..num_columns {
+            state[i] -= F::from_canonical_usize(BETA) * Self::sbox_exp_comp(state[num_columns + i]);
+            state[num_columns + i] -= state[i].exp_u64(ALPHA_INV as u64);
+            state[i] += F::from_canonical_usize(BETA) * Self::sbox_exp_comp(state[num_columns + i])
                 + F::from_canonical_usize(DELTA);
         }
     }
 
-    fn anemoi_permutation<F: RichField + Extendable<2>>(state: &mut [F; SPONGE_WIDTH]) {
-        for j in 0..NUMBER_OF_ROUNDS {
-            for i in 0..NUM_COLUMNS {
-                state[i] += F::from_canonical_usize(C[j][i]);
-                state[NUM_COLUMNS + i] += F::from_canonical_usize(D[j][i]);
+    fn anemoi_permutation<F: RichField + Extendable<2>, const WIDTH: usize>(state: &mut [F; WIDTH])
+    where
+        Width<WIDTH>: AnemoiParameters<WIDTH>,
+    {
+        let num_columns = WIDTH / 2;
+        let c = Width::<WIDTH>::C;
+        let d = Width::<WIDTH>::D;
+        for j in 0..Width::<WIDTH>::NUMBER_OF_ROUNDS {
+            for i in 0..num_columns {
+                state[i] += F::from_canonical_usize(c[j * num_columns + i]);
+                state[num_columns + i] += F::from_canonical_usize(d[j * num_columns + i]);
             }
             Self::linear_layer(state);
             Self::evaluate_sbox(state);
@@ -101,11 +102,27 @@ impl Anemoi {
     pub fn anemoi_hash<F: RichField + Extendable<2>, const H: usize>(
         input: [F; SPONGE_RATE],
     ) -> Vec<F> {
+        Self::anemoi_hash_width::<F, SPONGE_WIDTH, SPONGE_RATE, H>(input)
+    }
+
+    /// Anemoi hash over a state of `WIDTH` elements, `RATE` of which absorb the input.
+    pub fn anemoi_hash_width<
+        F: RichField + Extendable<2>,
+        const WIDTH: usize,
+        const RATE: usize,
+        const H: usize,
+    >(
+        input: [F; RATE],
+    ) -> Vec<F>
+    where
+        Width<WIDTH>: AnemoiParameters<WIDTH>,
+    {
+        assert!(RATE < WIDTH);
         assert!(input.len() != 0);
 
-        let mut state = [F::ZERO; SPONGE_WIDTH];
+        let mut state = [F::ZERO; WIDTH];
 
-        let sigma = if input.len() % SPONGE_RATE == 0 {
+        let sigma = if input.len() % RATE == 0 {
             F::ONE
         } else {
             F::ZERO
@@ -116,14 +133,14 @@ impl Anemoi {
         for &element in input.iter() {
             state[i] += element;
             i += 1;
-            if i % SPONGE_RATE == 0 {
+            if i % RATE == 0 {
                 Self::anemoi_permutation(&mut state);
                 i = 0;
             }
         }
 
         // We then add sigma to the last capacity register of the capacity.
-        state[SPONGE_WIDTH - 1] += sigma;
+        state[WIDTH - 1] += sigma;
 
         // Squeezing
         let mut digest = Vec::new();
@@ -132,7 +149,7 @@ impl Anemoi {
             digest.push(state[pos]);
             pos += 1;
 
-            if pos == SPONGE_RATE {
+            if pos == RATE {
                 pos = 0;
                 Self::anemoi_permutation(&mut state);
             }
@@ -144,40 +161,40 @@ impl Anemoi {
      *      ZERO-KNOWLEDGE PROOF        *
      ***********************************
      */
-    fn linear_layer_circuit<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn linear_layer_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
-    ) {
-        let mut x = [Target::default(); NUM_COLUMNS];
-        x.copy_from_slice(&state[..NUM_COLUMNS]);
-        let mut y = [Target::default(); NUM_COLUMNS];
-        y.copy_from_slice(&state[NUM_COLUMNS..]);
+    ) where
+        Width<WIDTH>: AnemoiParameters<WIDTH>,
+    {
+        let num_columns = WIDTH / 2;
+        let mds_matrix = Width::<WIDTH>::MDS_MATRIX;
 
         // mds_matrix * x
-        let mut x_vec = [Target::default(); NUM_COLUMNS];
-        for i in 0..NUM_COLUMNS {
-            let mut sum = Target::default();
-            for j in 0..NUM_COLUMNS {
-                sum = builder.mul_const_add(F::from_canonical_usize(MDS_MATRIX[i][j]), x[j], sum);
+        let mut x_vec = [Target::default(); WIDTH];
+        for i in 0..num_columns {
+            let mut sum = builder.zero();
+            for j in 0..num_columns {
+                sum = builder.mul_const_add(
+                    F::from_canonical_usize(mds_matrix[i * num_columns + j]),
+                    state[j],
+                    sum,
+                );
             }
             x_vec[i] = sum;
         }
 
         // mds_matrix * y
-        let mut y_vec = [Target::default(); NUM_COLUMNS];
-        let mut y_rotated = [Target::default(); NUM_COLUMNS];
-        for i in 0..NUM_COLUMNS {
-            if i != NUM_COLUMNS - 1 {
-                y_rotated[i] = y[i + 1];
-            } else {
-                y_rotated[i] = y[0];
-            }
+        let mut y_vec = [Target::default(); WIDTH];
+        let mut y_rotated = [Target::default(); WIDTH];
+        for i in 0..num_columns {
+            y_rotated[i] = state[num_columns + (i + 1) % num_columns];
         }
-        for i in 0..NUM_COLUMNS {
-            let mut sum = Target::default();
-            for j in 0..NUM_COLUMNS {
+        for i in 0..num_columns {
+            let mut sum = builder.zero();
+            for j in 0..num_columns {
                 sum = builder.mul_const_add(
-                    F::from_canonical_usize(MDS_MATRIX[i][j]),
+                    F::from_canonical_usize(mds_matrix[i * num_columns + j]),
                     y_rotated[j],
                     sum,
                 );
@@ -186,13 +203,13 @@ impl Anemoi {
         }
 
         // Pseudo-Hadamard transform P
-        for i in 0..NUM_COLUMNS {
+        for i in 0..num_columns {
             y_vec[i] = builder.add(y_vec[i], x_vec[i]);
             x_vec[i] = builder.add(x_vec[i], y_vec[i]);
         }
 
-        state[..NUM_COLUMNS].copy_from_slice(&x_vec);
-        state[NUM_COLUMNS..].copy_from_slice(&y_vec);
+        state[..num_columns].copy_from_slice(&x_vec[..num_columns]);
+        state[num_columns..].copy_from_slice(&y_vec[..num_columns]);
     }
 
     fn sbox_exp_comp_circuit<F: RichField + Extendable<2>>(
@@ -203,34 +220,43 @@ impl Anemoi {
         builder.square(x)
     }
 
-    fn evaluate_sbox_circuit<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn evaluate_sbox_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
     ) {
-        for i in 0..NUM_COLUMNS {
-            let exp = Self::sbox_exp_comp_circuit(state[NUM_COLUMNS + i], builder);
+        let num_columns = WIDTH / 2;
+        for i in 0..num_columns {
+            let exp = Self::sbox_exp_comp_circuit(state[num_columns + i], builder);
             let op1 = builder.mul_const(F::from_canonical_usize(BETA), exp);
             state[i] = builder.sub(state[i], op1);
 
             //let exp = builder.exp_u64(state[i], ALPHA_INV as u64);
             let exp = builder.exp_inv(state[i]);
-            state[NUM_COLUMNS + i] = builder.sub(state[NUM_COLUMNS + i], exp);
+            state[num_columns + i] = builder.sub(state[num_columns + i], exp);
 
-            let exp = Self::sbox_exp_comp_circuit(state[NUM_COLUMNS + i], builder);
+            let exp = Self::sbox_exp_comp_circuit(state[num_columns + i], builder);
             let op1 = builder.mul_const_add(F::from_canonical_usize(BETA), exp, state[i]);
             state[i] = builder.add_const(op1, F::from_canonical_usize(DELTA));
         }
     }
 
-    fn anemoi_permutation_circuit<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn anemoi_permutation_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
-    ) {
-        for j in 0..NUMBER_OF_ROUNDS {
-            for i in 0..NUM_COLUMNS {
-                state[i] = builder.add_const(state[i], F::from_canonical_usize(C[j][i]));
-                state[NUM_COLUMNS + i] =
-                    builder.add_const(state[NUM_COLUMNS + i], F::from_canonical_usize(D[j][i]));
+    ) where
+        Width<WIDTH>: AnemoiParameters<WIDTH>,
+    {
+        let num_columns = WIDTH / 2;
+        let c = Width::<WIDTH>::C;
+        let d = Width::<WIDTH>::D;
+        for j in 0..Width::<WIDTH>::NUMBER_OF_ROUNDS {
+            for i in 0..num_columns {
+                state[i] =
+                    builder.add_const(state[i], F::from_canonical_usize(c[j * num_columns + i]));
+                state[num_columns + i] = builder.add_const(
+                    state[num_columns + i],
+                    F::from_canonical_usize(d[j * num_columns + i]),
+                );
             }
             Self::linear_layer_circuit(state, builder);
             Self::evaluate_sbox_circuit(state, builder);
@@ -246,13 +272,32 @@ impl Anemoi {
     >(
         x: [F; SPONGE_RATE],
     ) -> (CircuitData<F, C, 2>, PartialWitness<F>) {
+        Self::circuit_generation_width::<F, C, SPONGE_WIDTH, SPONGE_RATE, H>(x)
+    }
+
+    /// Circuit generation for the Anemoi hash over a state of `WIDTH` elements, `RATE` of
+    /// which absorb the input
+    pub fn circuit_generation_width<
+        F: RichField + Extendable<2>,
+        C: GenericConfig<2, F = F>,
+        const WIDTH: usize,
+        const RATE: usize,
+        const H: usize,
+    >(
+        x: [F; RATE],
+    ) -> (CircuitData<F, C, 2>, PartialWitness<F>)
+    where
+        Width<WIDTH>: AnemoiParameters<WIDTH>,
+    {
+        assert!(RATE < WIDTH);
+
         let config = CircuitConfig::standard_recursion_config();
         let mut builder = CircuitBuilder::<F, 2>::new(config);
-        let input = builder.add_virtual_target_arr::<SPONGE_RATE>();
-        let mut state = builder.add_virtual_target_arr::<SPONGE_WIDTH>();
+        let input = builder.add_virtual_target_arr::<RATE>();
+        let mut state = builder.add_virtual_target_arr::<WIDTH>();
 
-        for i in 0..SPONGE_WIDTH {
-            state[i] = Target::default();
+        for i in 0..WIDTH {
+            state[i] = builder.zero();
         }
 
         // The arithmetic circuit
@@ -261,18 +306,18 @@ impl Anemoi {
         for &element in input.iter() {
             state[i] = builder.add(state[i], element);
             i += 1;
-            if i % SPONGE_RATE == 0 {
+            if i % RATE == 0 {
                 Self::anemoi_permutation_circuit(&mut state, &mut builder);
                 i = 0;
             }
         }
 
-        if input.len() % SPONGE_RATE == 0 {
+        if input.len() % RATE == 0 {
             let sigma = builder.one();
-            state[SPONGE_WIDTH - 1] = builder.add(state[SPONGE_WIDTH - 1], sigma);
+            state[WIDTH - 1] = builder.add(state[WIDTH - 1], sigma);
         } else {
             let sigma = builder.zero();
-            state[SPONGE_WIDTH - 1] = builder.add(state[SPONGE_WIDTH - 1], sigma);
+            state[WIDTH - 1] = builder.add(state[WIDTH - 1], sigma);
         }
 
         // Squeezing
@@ -282,7 +327,7 @@ impl Anemoi {
             digest.push(state[pos]);
             pos += 1;
 
-            if pos == SPONGE_RATE {
+            if pos == RATE {
                 pos = 0;
                 Self::anemoi_permutation_circuit(&mut state, &mut builder);
             }
@@ -331,6 +376,22 @@ impl Anemoi {
     }
 }
 
+impl<const WIDTH: usize> Permutation<WIDTH> for Anemoi
+where
+    Width<WIDTH>: AnemoiParameters<WIDTH>,
+{
+    fn permute<F: RichField + Extendable<2>>(state: &mut [F; WIDTH]) {
+        Self::anemoi_permutation(state);
+    }
+
+    fn permute_circuit<F: RichField + Extendable<2>>(
+        state: &mut [Target; WIDTH],
+        builder: &mut CircuitBuilder<F, 2>,
+    ) {
+        Self::anemoi_permutation_circuit(state, builder);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use plonky2::{
@@ -338,7 +399,8 @@ mod test {
         plonk::config::PoseidonGoldilocksConfig,
     };
 
-    use super::{Anemoi, SPONGE_RATE};
+    use super::{Anemoi, AnemoiParameters, Width, SPONGE_RATE};
+    use crate::hashes::kat;
 
     #[test]
     fn test_anemoi_hash() {
@@ -358,4 +420,58 @@ mod test {
             Anemoi::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw);
         Anemoi::proof_verification::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &proof);
     }
+
+    fn check_width<const WIDTH: usize, const RATE: usize>()
+    where
+        Width<WIDTH>: AnemoiParameters<WIDTH>,
+    {
+        let input: [GoldilocksField; RATE] =
+            core::array::from_fn(|i| GoldilocksField(i as u64 + 1));
+        let output = Anemoi::anemoi_hash_width::<GoldilocksField, WIDTH, RATE, 4>(input);
+
+        let (data, pw) = Anemoi::circuit_generation_width::<
+            GoldilocksField,
+            PoseidonGoldilocksConfig,
+            WIDTH,
+            RATE,
+            4,
+        >(input);
+        let proof =
+            Anemoi::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw);
+        assert_eq!(proof.public_inputs, output);
+        assert!(data.verify(proof).is_ok());
+    }
+
+    #[test]
+    fn test_anemoi_widths() {
+        check_width::<8, 4>();
+        check_width::<16, 12>();
+        check_width::<24, 20>();
+    }
+
+    fn check_kat<const WIDTH: usize, const RATE: usize>()
+    where
+        Width<WIDTH>: AnemoiParameters<WIDTH>,
+    {
+        kat::check_kat::<Anemoi, _, WIDTH, RATE>(
+            include_str!("../../../kat/anemoi.json"),
+            "anemoi",
+            Anemoi::anemoi_hash_width::<GoldilocksField, WIDTH, RATE, 4>,
+            Anemoi::circuit_generation_width::<
+                GoldilocksField,
+                PoseidonGoldilocksConfig,
+                WIDTH,
+                RATE,
+                4,
+            >,
+        );
+    }
+
+    #[test]
+    fn test_anemoi_kat() {
+        check_kat::<8, 4>();
+        check_kat::<12, 8>();
+        check_kat::<16, 12>();
+        check_kat::<24, 20>();
+    }
 }
@@ -9,3 +9,21 @@ pub const MDS_MATRIX: [[usize; NUM_COLUMNS]; NUM_COLUMNS] = [
     [3, 4, 5, 6, 1, 1],
     [1, 3, 4, 5, 6, 1],
 ];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{goldilocks_field::GoldilocksField, types::PrimeField64};
+
+    use super::*;
+    use crate::parameters::anemoi::mds_matrix;
+
+    #[test]
+    fn test_anemoi_mds() {
+        let generated = mds_matrix::<GoldilocksField>(NUM_COLUMNS);
+        let generated: Vec<Vec<usize>> = generated
+            .iter()
+            .map(|row| row.iter().map(|x| x.to_canonical_u64() as usize).collect())
+            .collect();
+        assert_eq!(generated, MDS_MATRIX.map(|row| row.to_vec()));
+    }
+}
@@ -19,7 +19,8 @@ use self::sbox::ALPHA_INV;
 pub mod anemoi;
 mod mds;
 mod round_constants;
-mod sbox;
+pub(crate) mod sbox;
+mod widths;
 
 /// The number of rounds for SPONGE_RATE = 4
 pub const NUMBER_OF_ROUNDS: usize = 10;
@@ -39,6 +40,19 @@ pub const NUM_COLUMNS: usize = 6;
 /// Four elements (32-bytes) are returned as digest.
 pub const DIGEST_SIZE: usize = 4;
 
+/// Constants of the Anemoi permutation for a state width, implemented for
+/// [`Width`](super::Width) 8, 12, 16 and 24. The state is split in two rows of `WIDTH / 2`
+/// columns and the tables are flattened row after row.
+pub trait AnemoiParameters<const WIDTH: usize> {
+    const NUMBER_OF_ROUNDS: usize;
+    /// Additive constants C, `WIDTH / 2` per round.
+    const C: &'static [usize];
+    /// Additive constants D, `WIDTH / 2` per round.
+    const D: &'static [usize];
+    /// The `WIDTH / 2 x WIDTH / 2` MDS matrix applied to each row.
+    const MDS_MATRIX: &'static [usize];
+}
+
 trait CircuitBuilderExtensionsAnemoi<F: RichField + Extendable<D>, const D: usize> {
     fn exp_inv(&mut self, x: Target) -> Target;
     fn exp_inv_extension(&mut self, x: ExtensionTarget<D>) -> ExtensionTarget<D>;
@@ -129,3 +143,19 @@ impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D>
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parameters::anemoi::{number_of_rounds, round_bounds};
+
+    #[test]
+    fn test_number_of_rounds() {
+        let alpha = sbox::ALPHA as u64;
+        assert!(NUMBER_OF_ROUNDS >= round_bounds(64, NUM_COLUMNS, alpha, 128).minimum());
+        assert_eq!(
+            NUMBER_OF_ROUNDS,
+            number_of_rounds(64, NUM_COLUMNS, alpha, 128)
+        );
+    }
+}
@@ -167,3 +167,31 @@ pub const D: [[usize; NUM_COLUMNS]; NUMBER_OF_ROUNDS] = [
         4251266975385335495,
     ],
 ];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{
+        goldilocks_field::GoldilocksField,
+        types::{Field, PrimeField64},
+    };
+
+    use super::*;
+    use crate::hashes::anemoi::sbox::{ALPHA, DELTA};
+    use crate::parameters::anemoi::round_constants;
+
+    #[test]
+    fn test_anemoi_constants() {
+        let (c, d) =
+            round_constants::<GoldilocksField>(NUMBER_OF_ROUNDS, NUM_COLUMNS, ALPHA as u64);
+        let to_usize = |m: Vec<Vec<GoldilocksField>>| -> Vec<Vec<usize>> {
+            m.iter()
+                .map(|row| row.iter().map(|x| x.to_canonical_u64() as usize).collect())
+                .collect()
+        };
+        assert_eq!(to_usize(c), C.map(|row| row.to_vec()));
+        assert_eq!(to_usize(d), D.map(|row| row.to_vec()));
+
+        let delta = GoldilocksField::MULTIPLICATIVE_GROUP_GENERATOR.inverse();
+        assert_eq!(delta.to_canonical_u64() as usize, DELTA);
+    }
+}
@@ -0,0 +1,856 @@
+// Anemoi constants for the widths other than 12, generated with `crate::parameters::anemoi`
+// as in `anemoi.sage`. The number of rounds follows `get_n_rounds` for each width. Width 24
+// uses a Cauchy linear layer, as no small circulant MDS matrix could be found (see
+// `parameters::anemoi::mds_matrix`).
+
+use super::{mds, round_constants, AnemoiParameters, NUMBER_OF_ROUNDS};
+use crate::hashes::{flatten, Width};
+
+impl AnemoiParameters<8> for Width<8> {
+    const NUMBER_OF_ROUNDS: usize = 11;
+    const C: &'static [usize] = &flatten::<_, 4, 11, 44>(&C_8);
+    const D: &'static [usize] = &flatten::<_, 4, 11, 44>(&D_8);
+    const MDS_MATRIX: &'static [usize] = &flatten::<_, 4, 4, 16>(&MDS_MATRIX_8);
+}
+
+impl AnemoiParameters<12> for Width<12> {
+    const NUMBER_OF_ROUNDS: usize = NUMBER_OF_ROUNDS;
+    const C: &'static [usize] = &flatten::<_, 6, 10, 60>(&round_constants::C);
+    const D: &'static [usize] = &flatten::<_, 6, 10, 60>(&round_constants::D);
+    const MDS_MATRIX: &'static [usize] = &flatten::<_, 6, 6, 36>(&mds::MDS_MATRIX);
+}
+
+impl AnemoiParameters<16> for Width<16> {
+    const NUMBER_OF_ROUNDS: usize = 9;
+    const C: &'static [usize] = &flatten::<_, 8, 9, 72>(&C_16);
+    const D: &'static [usize] = &flatten::<_, 8, 9, 72>(&D_16);
+    const MDS_MATRIX: &'static [usize] = &flatten::<_, 8, 8, 64>(&MDS_MATRIX_16);
+}
+
+impl AnemoiParameters<24> for Width<24> {
+    const NUMBER_OF_ROUNDS: usize = 9;
+    const C: &'static [usize] = &flatten::<_, 12, 9, 108>(&C_24);
+    const D: &'static [usize] = &flatten::<_, 12, 9, 108>(&D_24);
+    const MDS_MATRIX: &'static [usize] = &flatten::<_, 12, 12, 144>(&MDS_MATRIX_24);
+}
+
+pub const C_8: [[usize; 4]; 11] = [
+    [
+        135,
+        11838461599927962280,
+        6695425721971157745,
+        5019557931754813304,
+    ],
+    [
+        2495927434367559449,
+        13492968121013574904,
+        10098914474676720416,
+        4843826562618834151,
+    ],
+    [
+        6426088485207136111,
+        5831914601985236665,
+        3131753839109558676,
+        3931787235364236402,
+    ],
+    [
+        15630856188639316257,
+        1985318265374982878,
+        2310933915074061319,
+        82460173033028635,
+    ],
+    [
+        35751876663066701,
+        12218846398244870934,
+        12158495329324236791,
+        14193345342118923896,
+    ],
+    [
+        10762311532495310736,
+        1602790612750887683,
+        10558955589715575707,
+        4268644503140521285,
+    ],
+    [
+        13776040199363511613,
+        2645507812358398727,
+        11189896514172536637,
+        2832927452123329016,
+    ],
+    [
+        7954822014402935159,
+        4221275062851635374,
+        4146348742796636803,
+        11984179805579125028,
+    ],
+    [
+        16207264356032897204,
+        14947325633951015074,
+        12682075457336921621,
+        3428661900037965523,
+    ],
+    [
+        11395204924706988113,
+        1445298492587981366,
+        1648821176782737788,
+        15250803325479081487,
+    ],
+    [
+        16536231370017439012,
+        7360252512197717923,
+        2705763861182069829,
+        10717289112464514560,
+    ],
+];
+
+pub const D_8: [[usize; 4]; 11] = [
+    [
+        2635249152773512181,
+        6157005413239783064,
+        68462008871283793,
+        1116237429717582885,
+    ],
+    [
+        5762606100895777574,
+        8442941448080101767,
+        4103380275331552543,
+        1571935574336309811,
+    ],
+    [
+        13733991889676916174,
+        4823112666993325466,
+        1177444377705952741,
+        4701120985023274000,
+    ],
+    [
+        8470567618884092005,
+        4955068425572651685,
+        4335176548860035390,
+        4830346017881646239,
+    ],
+    [
+        1762538920950224372,
+        5628928103070337343,
+        4623069507738008464,
+        9381562731595339102,
+    ],
+    [
+        7562502807542755678,
+        8533020617751225684,
+        16543678068304218972,
+        12977010192791808083,
+    ],
+    [
+        12515775684509917073,
+        11515282027457697246,
+        667419133445556099,
+        13480837351873576332,
+    ],
+    [
+        4309793999236275086,
+        10706285777637868360,
+        9685851931171175053,
+        1800582135601722490,
+    ],
+    [
+        168687491574439534,
+        9038787499445450463,
+        5828029796419662274,
+        17745003519597934030,
+    ],
+    [
+        16776936635835678651,
+        16957068933669564963,
+        16215084091452626649,
+        14093965381797029560,
+    ],
+    [
+        14988442143969286076,
+        15942502016102458046,
+        10342505838675115216,
+        2630930231605619159,
+    ],
+];
+
+pub const MDS_MATRIX_8: [[usize; 4]; 4] =
+    [[1, 8, 7, 7], [49, 56, 8, 15], [49, 49, 1, 8], [8, 15, 7, 8]];
+
+pub const C_16: [[usize; 8]; 9] = [
+    [
+        135,
+        11838461599927962280,
+        6695425721971157745,
+        5019557931754813304,
+        2904638783509415261,
+        17086431532787116882,
+        4972076613235101111,
+        18272986603539534184,
+    ],
+    [
+        2495927434367559449,
+        13492968121013574904,
+        10098914474676720416,
+        4843826562618834151,
+        17539093704093074863,
+        8553726820257998916,
+        13567687516272615404,
+        71502677948365123,
+    ],
+    [
+        6426088485207136111,
+        5831914601985236665,
+        3131753839109558676,
+        3931787235364236402,
+        15904007449688076222,
+        9797250005833249614,
+        439796863147211135,
+        13521513955094368969,
+    ],
+    [
+        15630856188639316257,
+        1985318265374982878,
+        2310933915074061319,
+        82460173033028635,
+        18020935870943136160,
+        6084573595352436610,
+        8933695584932696929,
+        1522306714865425656,
+    ],
+    [
+        35751876663066701,
+        12218846398244870934,
+        12158495329324236791,
+        14193345342118923896,
+        5719028597539960093,
+        4952589849070338459,
+        11914478338842961321,
+        476213383944498273,
+    ],
+    [
+        10762311532495310736,
+        1602790612750887683,
+        10558955589715575707,
+        4268644503140521285,
+        11257154188468233463,
+        15424512470177117790,
+        4585327954385160538,
+        18164824272807563486,
+    ],
+    [
+        13776040199363511613,
+        2645507812358398727,
+        11189896514172536637,
+        2832927452123329016,
+        11496711359337291663,
+        2261696610080146603,
+        863755243828208151,
+        17475182553166584714,
+    ],
+    [
+        7954822014402935159,
+        4221275062851635374,
+        4146348742796636803,
+        11984179805579125028,
+        8628002987794654845,
+        16956140286558224010,
+        15814388260515662959,
+        6419071341279627984,
+    ],
+    [
+        16207264356032897204,
+        14947325633951015074,
+        12682075457336921621,
+        3428661900037965523,
+        3223466965214343391,
+        15171824165606685438,
+        5349731667796432038,
+        12198156760545595440,
+    ],
+];
+
+pub const D_16: [[usize; 8]; 9] = [
+    [
+        2635249152773512181,
+        6157005413239783064,
+        68462008871283793,
+        1116237429717582885,
+        9681424646190483573,
+        1572301186541881797,
+        13445232714005727478,
+        6797634508126127921,
+    ],
+    [
+        5762606100895777574,
+        8442941448080101767,
+        4103380275331552543,
+        1571935574336309811,
+        6500565011114264933,
+        12117770057182054231,
+        4225529061383363529,
+        7674324165704249260,
+    ],
+    [
+        13733991889676916174,
+        4823112666993325466,
+        1177444377705952741,
+        4701120985023274000,
+        8906703494650828230,
+        17402517980698866867,
+        13585607215614105519,
+        6718816111377230723,
+    ],
+    [
+        8470567618884092005,
+        4955068425572651685,
+        4335176548860035390,
+        4830346017881646239,
+        15002184011095468174,
+        17668393665407633869,
+        7611313963174586998,
+        17144905035752451737,
+    ],
+    [
+        1762538920950224372,
+        5628928103070337343,
+        4623069507738008464,
+        9381562731595339102,
+        11587352351734674030,
+        6976741463753333320,
+        1032428261712648992,
+        6539143249459321956,
+    ],
+    [
+        7562502807542755678,
+        8533020617751225684,
+        16543678068304218972,
+        12977010192791808083,
+        12198882173423234671,
+        12522068315620399922,
+        7223426177429719801,
+        854414299668090119,
+    ],
+    [
+        12515775684509917073,
+        11515282027457697246,
+        667419133445556099,
+        13480837351873576332,
+        14377983554391253389,
+        1298796665622389253,
+        5441397676971727932,
+        2104316790126071865,
+    ],
+    [
+        4309793999236275086,
+        10706285777637868360,
+        9685851931171175053,
+        1800582135601722490,
+        9124511682535551038,
+        13608476841787401127,
+        18007267193346117207,
+        7110186147340633923,
+    ],
+    [
+        168687491574439534,
+        9038787499445450463,
+        5828029796419662274,
+        17745003519597934030,
+        9773170880078026308,
+        17877355940958649279,
+        13595805820749673010,
+        495722717314803782,
+    ],
+];
+
+pub const MDS_MATRIX_16: [[usize; 8]; 8] = [
+    [1, 2, 3, 5, 7, 8, 8, 9],
+    [9, 1, 2, 3, 5, 7, 8, 8],
+    [8, 9, 1, 2, 3, 5, 7, 8],
+    [8, 8, 9, 1, 2, 3, 5, 7],
+    [7, 8, 8, 9, 1, 2, 3, 5],
+    [5, 7, 8, 8, 9, 1, 2, 3],
+    [3, 5, 7, 8, 8, 9, 1, 2],
+    [2, 3, 5, 7, 8, 8, 9, 1],
+];
+
+pub const C_24: [[usize; 12]; 9] = [
+    [
+        135,
+        11838461599927962280,
+        6695425721971157745,
+        5019557931754813304,
+        2904638783509415261,
+        17086431532787116882,
+        4972076613235101111,
+        18272986603539534184,
+        3370574598173730207,
+        13827963590946687244,
+        8348099868274638803,
+        17055287704188579870,
+    ],
+    [
+        2495927434367559449,
+        13492968121013574904,
+        10098914474676720416,
+        4843826562618834151,
+        17539093704093074863,
+        8553726820257998916,
+        13567687516272615404,
+        71502677948365123,
+        6348046189183514009,
+        8683622794110277321,
+        2332074279700255916,
+        1556717538028704214,
+    ],
+    [
+        6426088485207136111,
+        5831914601985236665,
+        3131753839109558676,
+        3931787235364236402,
+        15904007449688076222,
+        9797250005833249614,
+        439796863147211135,
+        13521513955094368969,
+        18246230152015811562,
+        13337099589505559538,
+        18316746883826677326,
+        3257251748551922803,
+    ],
+    [
+        15630856188639316257,
+        1985318265374982878,
+        2310933915074061319,
+        82460173033028635,
+        18020935870943136160,
+        6084573595352436610,
+        8933695584932696929,
+        1522306714865425656,
+        1956112227602885479,
+        13075885790208132967,
+        13213585686419432708,
+        14597686223141745784,
+    ],
+    [
+        35751876663066701,
+        12218846398244870934,
+        12158495329324236791,
+        14193345342118923896,
+        5719028597539960093,
+        4952589849070338459,
+        11914478338842961321,
+        476213383944498273,
+        7358250607545896767,
+        1206853769260862028,
+        13906001870354200209,
+        10220308615713568336,
+    ],
+    [
+        10762311532495310736,
+        1602790612750887683,
+        10558955589715575707,
+        4268644503140521285,
+        11257154188468233463,
+        15424512470177117790,
+        4585327954385160538,
+        18164824272807563486,
+        10891707228581004352,
+        2921962996035653136,
+        1139254292135491940,
+        18247024092709559612,
+    ],
+    [
+        13776040199363511613,
+        2645507812358398727,
+        11189896514172536637,
+        2832927452123329016,
+        11496711359337291663,
+        2261696610080146603,
+        863755243828208151,
+        17475182553166584714,
+        4006891809252766998,
+        9018301402336404214,
+        4826968296017734448,
+        4653794932473641023,
+    ],
+    [
+        7954822014402935159,
+        4221275062851635374,
+        4146348742796636803,
+        11984179805579125028,
+        8628002987794654845,
+        16956140286558224010,
+        15814388260515662959,
+        6419071341279627984,
+        11873348981444287261,
+        15869995849954107003,
+        10785278425309673965,
+        4807555348403594424,
+    ],
+    [
+        16207264356032897204,
+        14947325633951015074,
+        12682075457336921621,
+        3428661900037965523,
+        3223466965214343391,
+        15171824165606685438,
+        5349731667796432038,
+        12198156760545595440,
+        2398728968097555603,
+        18314830933327941229,
+        17443153692706106161,
+        16899340942554155691,
+    ],
+];
+
+pub const D_24: [[usize; 12]; 9] = [
+    [
+        2635249152773512181,
+        6157005413239783064,
+        68462008871283793,
+        1116237429717582885,
+        9681424646190483573,
+        1572301186541881797,
+        13445232714005727478,
+        6797634508126127921,
+        15976170656458410191,
+        15135644589366390517,
+        2057023330320555577,
+        8909639278273332128,
+    ],
+    [
+        5762606100895777574,
+        8442941448080101767,
+        4103380275331552543,
+        1571935574336309811,
+        6500565011114264933,
+        12117770057182054231,
+        4225529061383363529,
+        7674324165704249260,
+        1138327691808315751,
+        10622733306284686673,
+        15119171324915463090,
+        12489242695282746872,
+    ],
+    [
+        13733991889676916174,
+        4823112666993325466,
+        1177444377705952741,
+        4701120985023274000,
+        8906703494650828230,
+        17402517980698866867,
+        13585607215614105519,
+        6718816111377230723,
+        17077736392582175242,
+        870690770206946507,
+        16698324597568862117,
+        18231001643747527399,
+    ],
+    [
+        8470567618884092005,
+        4955068425572651685,
+        4335176548860035390,
+        4830346017881646239,
+        15002184011095468174,
+        17668393665407633869,
+        7611313963174586998,
+        17144905035752451737,
+        4766170563358829165,
+        4588029066099099942,
+        15573715495351197505,
+        15103244144112346065,
+    ],
+    [
+        1762538920950224372,
+        5628928103070337343,
+        4623069507738008464,
+        9381562731595339102,
+        11587352351734674030,
+        6976741463753333320,
+        1032428261712648992,
+        6539143249459321956,
+        608640487929638055,
+        1606072659194210926,
+        6706463223913762608,
+        1166198081311966219,
+    ],
+    [
+        7562502807542755678,
+        8533020617751225684,
+        16543678068304218972,
+        12977010192791808083,
+        12198882173423234671,
+        12522068315620399922,
+        7223426177429719801,
+        854414299668090119,
+        17662245409139617232,
+        16841330186143873626,
+        7459863945869925931,
+        4266317789068244766,
+    ],
+    [
+        12515775684509917073,
+        11515282027457697246,
+        667419133445556099,
+        13480837351873576332,
+        14377983554391253389,
+        1298796665622389253,
+        5441397676971727932,
+        2104316790126071865,
+        12716974199910340396,
+        6430468733129000901,
+        13087122159851128957,
+        11059376908345871016,
+    ],
+    [
+        4309793999236275086,
+        10706285777637868360,
+        9685851931171175053,
+        1800582135601722490,
+        9124511682535551038,
+        13608476841787401127,
+        18007267193346117207,
+        7110186147340633923,
+        18198667871788795126,
+        10897399680433638157,
+        16660668788830002941,
+        8828373823962758884,
+    ],
+    [
+        168687491574439534,
+        9038787499445450463,
+        5828029796419662274,
+        17745003519597934030,
+        9773170880078026308,
+        17877355940958649279,
+        13595805820749673010,
+        495722717314803782,
+        14777243078564850192,
+        948685914515674786,
+        10924995206934637540,
+        8526610568821522554,
+    ],
+];
+
+pub const MDS_MATRIX_24: [[usize; 12]; 12] = [
+    [
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+    ],
+    [
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+    ],
+    [
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+    ],
+    [
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+    ],
+    [
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+    ],
+    [
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+    ],
+    [
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+    ],
+    [
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+    ],
+    [
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+    ],
+    [
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+    ],
+    [
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+    ],
+    [
+        18446744069414584320,
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+    ],
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{goldilocks_field::GoldilocksField, types::PrimeField64};
+
+    use super::*;
+    use crate::hashes::anemoi::sbox::ALPHA;
+    use crate::parameters::anemoi::{mds_matrix, number_of_rounds, round_bounds, round_constants};
+
+    fn check_width<const WIDTH: usize>()
+    where
+        Width<WIDTH>: AnemoiParameters<WIDTH>,
+    {
+        let rounds = Width::<WIDTH>::NUMBER_OF_ROUNDS;
+        let (c, d) = round_constants::<GoldilocksField>(rounds, WIDTH / 2, ALPHA as u64);
+        let to_usize = |m: Vec<Vec<GoldilocksField>>| -> Vec<usize> {
+            m.iter()
+                .flatten()
+                .map(|x| x.to_canonical_u64() as usize)
+                .collect()
+        };
+        assert_eq!(to_usize(c), Width::<WIDTH>::C);
+        assert_eq!(to_usize(d), Width::<WIDTH>::D);
+        assert_eq!(
+            to_usize(mds_matrix::<GoldilocksField>(WIDTH / 2)),
+            Width::<WIDTH>::MDS_MATRIX
+        );
+    }
+
+    fn check_rounds<const WIDTH: usize>()
+    where
+        Width<WIDTH>: AnemoiParameters<WIDTH>,
+    {
+        let rounds = Width::<WIDTH>::NUMBER_OF_ROUNDS;
+        let alpha = ALPHA as u64;
+        assert!(rounds >= round_bounds(64, WIDTH / 2, alpha, 128).minimum());
+        assert_eq!(rounds, number_of_rounds(64, WIDTH / 2, alpha, 128));
+    }
+
+    #[test]
+    fn test_anemoi_widths() {
+        check_width::<8>();
+        check_width::<16>();
+        check_width::<24>();
+    }
+
+    #[test]
+    fn test_number_of_rounds() {
+        check_rounds::<8>();
+        check_rounds::<12>();
+        check_rounds::<16>();
+        check_rounds::<24>();
+    }
+}
@@ -1,6 +1,5 @@
 // Implementation of the Arion hash function and zero-knowledge circuit
 
-use arion::constants::{AFFINE_CONSTANTS, G_VALUES, H_VALUES};
 use plonky2::{
     field::extension::Extendable,
     hash::hash_types::RichField,
@@ -17,40 +16,41 @@ use plonky2::{
 };
 
 use super::*;
+use crate::hashes::{Permutation, Width};
 
 pub struct Arion;
 
 impl Arion {
-    fn mul_matrix<F: RichField + Extendable<2>>(state: &mut [F; SPONGE_WIDTH]) {
-        let mut w = [F::ZERO; SPONGE_WIDTH];
+    fn mul_matrix<F: RichField + Extendable<2>, const WIDTH: usize>(state: &mut [F; WIDTH]) {
+        let mut w = [F::ZERO; WIDTH];
         let mut sigma = F::ZERO;
         for val in &mut *state {
             sigma += *val;
         }
 
         let mut sum = F::ZERO;
-        for i in 0..SPONGE_WIDTH {
+        for i in 0..WIDTH {
             sum += F::from_canonical_usize(i) * state[i];
         }
 
         w[0] = sigma + sum;
 
         let mut i = 1;
-        while i < SPONGE_WIDTH {
-            w[i] = w[i - 1] - (sigma + F::from_canonical_usize(SPONGE_WIDTH) * state[i - 1]);
+        while i < WIDTH {
+            w[i] = w[i - 1] - sigma + F::from_canonical_usize(WIDTH) * state[i - 1];
             i += 1;
         }
 
         state.copy_from_slice(&w);
     }
 
-    fn affine_layer<F: RichField + Extendable<2>>(
-        state: &mut [F; SPONGE_WIDTH],
-        constants_aff: &[u64; SPONGE_WIDTH],
+    fn affine_layer<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [F; WIDTH],
+        constants_aff: &[u64],
     ) {
         Self::mul_matrix(state);
-        let mut inner = [F::ZERO; SPONGE_WIDTH];
-        for i in 0..SPONGE_WIDTH {
+        let mut inner = [F::ZERO; WIDTH];
+        for i in 0..WIDTH {
             inner[i] += state[i] + F::from_canonical_u64(constants_aff[i]);
         }
         state.copy_from_slice(&inner);
@@ -63,20 +63,20 @@ impl Arion {
         *x = F::mul(x6, *x)
     }
 
-    fn gtds<F: RichField + Extendable<2>>(
-        state: &mut [F; SPONGE_WIDTH],
-        constants_g: &[[u64; 2]; SPONGE_WIDTH - 1],
-        constants_h: &[u64; SPONGE_WIDTH - 1],
+    fn gtds<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [F; WIDTH],
+        constants_g: &[[u64; 2]],
+        constants_h: &[u64],
     ) {
-        let mut output = [F::ZERO; SPONGE_WIDTH];
+        let mut output = [F::ZERO; WIDTH];
         output.copy_from_slice(state);
 
-        output[SPONGE_WIDTH - 1] = output[SPONGE_WIDTH - 1].exp_u64(E as u64);
+        output[WIDTH - 1] = output[WIDTH - 1].exp_u64(E as u64);
 
-        let mut sigma = state[SPONGE_WIDTH - 1].clone();
-        sigma += output[SPONGE_WIDTH - 1];
+        let mut sigma = state[WIDTH - 1].clone();
+        sigma += output[WIDTH - 1];
 
-        for i in (0..SPONGE_WIDTH - 1).rev() {
+        for i in (0..WIDTH - 1).rev() {
             Self::s_box(&mut output[i]);
 
             // Evaluate g and h
@@ -103,26 +103,48 @@ impl Arion {
         state.copy_from_slice(&output);
     }
 
-    fn arion_permutation<F: RichField + Extendable<2>>(state: &mut [F; SPONGE_WIDTH]) {
+    fn arion_permutation<F: RichField + Extendable<2>, const WIDTH: usize>(state: &mut [F; WIDTH])
+    where
+        Width<WIDTH>: ArionParameters<WIDTH>,
+    {
+        let g_values = Width::<WIDTH>::G_VALUES.chunks(WIDTH - 1);
+        let h_values = Width::<WIDTH>::H_VALUES.chunks(WIDTH - 1);
+        let affine_constants = Width::<WIDTH>::AFFINE_CONSTANTS.chunks(WIDTH);
+
         Self::mul_matrix(state);
-        Self::affine_layer(state, &[0u64; SPONGE_WIDTH]);
-        for r in 0..NUMBER_OF_ROUNDS {
-            Self::gtds(state, &G_VALUES[r], &H_VALUES[r]);
-            Self::affine_layer(state, &AFFINE_CONSTANTS[r]);
+        for ((g, h), affine) in g_values.zip(h_values).zip(affine_constants) {
+            Self::gtds(state, g, h);
+            Self::affine_layer(state, affine);
         }
     }
 
     pub fn arion_hash<F: RichField + Extendable<2>, const L: usize>(
         input: [F; SPONGE_RATE],
     ) -> [F; L] {
-        let mut state = [F::ZERO; SPONGE_WIDTH];
+        Self::arion_hash_width::<F, SPONGE_WIDTH, SPONGE_RATE, L>(input)
+    }
 
+    /// Arion hash over a state of `WIDTH` elements, `RATE` of which absorb the input.
+    pub fn arion_hash_width<
+        F: RichField + Extendable<2>,
+        const WIDTH: usize,
+        const RATE: usize,
+        const L: usize,
+    >(
+        input: [F; RATE],
+    ) -> [F; L]
+    where
+        Width<WIDTH>: ArionParameters<WIDTH>,
+    {
+        let mut state = [F::ZERO; WIDTH];
+
+        assert!(RATE < WIDTH);
         assert!(input.len() > 0);
-        assert!(input.len() % SPONGE_RATE == 0);
+        assert!(input.len() % RATE == 0);
 
         let mut absorb_index = 0;
         while absorb_index < input.len() {
-            for i in 0..SPONGE_RATE {
+            for i in 0..RATE {
                 state[i] += input[absorb_index];
                 absorb_index += 1;
             }
@@ -143,45 +165,41 @@ impl Arion {
      ***********************************
      */
 
-    fn mul_matrix_circuit<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn mul_matrix_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
     ) {
-        let mut w = [Target::default(); SPONGE_WIDTH];
-        let mut sigma = Target::default();
+        let mut w = [Target::default(); WIDTH];
+        let mut sigma = builder.zero();
         for val in &mut *state {
             sigma = builder.add(sigma, *val);
         }
 
-        let mut sum = Target::default();
-        for i in 0..SPONGE_WIDTH {
+        let mut sum = builder.zero();
+        for i in 0..WIDTH {
             sum = builder.mul_const_add(F::from_canonical_usize(i), state[i], sum);
         }
         w[0] = builder.add(sigma, sum);
 
         let mut i = 1;
-        while i < SPONGE_WIDTH {
-            let op1 =
-                builder.mul_const_add(F::from_canonical_usize(SPONGE_WIDTH), state[i - 1], sigma);
-            w[i] = builder.sub(w[i - 1], op1);
+        while i < WIDTH {
+            let op1 = builder.sub(w[i - 1], sigma);
+            w[i] = builder.mul_const_add(F::from_canonical_usize(WIDTH), state[i - 1], op1);
             i += 1;
         }
 
         state.copy_from_slice(&w);
     }
 
-    fn affine_layer_circit<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
-        constants_aff: &[u64; SPONGE_WIDTH],
+    fn affine_layer_circit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
+        constants_aff: &[u64],
         builder: &mut CircuitBuilder<F, 2>,
     ) {
         Self::mul_matrix_circuit(state, builder);
-        let mut inner = [Target::default(); SPONGE_WIDTH];
-        for i in 0..SPONGE_WIDTH {
-            let op1 = builder.add_const(state[i], F::from_canonical_u64(constants_aff[i]));
-            inner[i] = builder.add(inner[i], op1);
+        for i in 0..WIDTH {
+            state[i] = builder.add_const(state[i], F::from_canonical_u64(constants_aff[i]));
         }
-        state.copy_from_slice(&inner);
     }
 
     fn s_box_circuit<F: RichField + Extendable<2>>(
@@ -195,23 +213,22 @@ impl Arion {
         *x = builder.mul(x6, *x);
     }
 
-    fn gtds_circuit<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn gtds_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
-        constants_g: &[[u64; 2]; SPONGE_WIDTH - 1],
-        constants_h: &[u64; SPONGE_WIDTH - 1],
+        constants_g: &[[u64; 2]],
+        constants_h: &[u64],
     ) {
-        let mut output = [Target::default(); SPONGE_WIDTH];
+        let mut output = [Target::default(); WIDTH];
         output.copy_from_slice(state);
 
-        //output[SPONGE_WIDTH - 1] = builder.exp_u64(output[SPONGE_WIDTH - 1], E as u64);
-        output[SPONGE_WIDTH - 1] = builder.exp_inv(output[SPONGE_WIDTH - 1]);
+        //output[WIDTH - 1] = builder.exp_u64(output[WIDTH - 1], E as u64);
+        output[WIDTH - 1] = builder.exp_inv(output[WIDTH - 1]);
 
-        let mut sigma = state[SPONGE_WIDTH - 1].clone();
-        sigma = builder.add(sigma, output[SPONGE_WIDTH - 1]);
+        let mut sigma = state[WIDTH - 1].clone();
+        sigma = builder.add(sigma, output[WIDTH - 1]);
 
-        let mut pos = 20;
-        for i in (0..(SPONGE_WIDTH - 1)).rev() {
+        for i in (0..(WIDTH - 1)).rev() {
             Self::s_box_circuit(&mut output[i], builder);
 
             // Evaluate h and g
@@ -227,24 +244,25 @@ impl Arion {
 
             sigma = builder.add(sigma, output[i]);
             sigma = builder.add(sigma, state[i]);
-
-            if pos > 0 {
-                pos -= 2;
-            }
         }
 
         state.copy_from_slice(&output);
     }
 
-    fn arion_permutation_circuit<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn arion_permutation_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
-    ) {
+    ) where
+        Width<WIDTH>: ArionParameters<WIDTH>,
+    {
+        let g_values = Width::<WIDTH>::G_VALUES.chunks(WIDTH - 1);
+        let h_values = Width::<WIDTH>::H_VALUES.chunks(WIDTH - 1);
+        let affine_constants = Width::<WIDTH>::AFFINE_CONSTANTS.chunks(WIDTH);
+
         Self::mul_matrix_circuit(state, builder);
-        Self::affine_layer_circit(state, &[0u64; SPONGE_WIDTH], builder);
-        for r in 0..NUMBER_OF_ROUNDS {
-            Self::gtds_circuit(state, builder, &G_VALUES[r], &H_VALUES[r]);
-            Self::affine_layer_circit(state, &AFFINE_CONSTANTS[r], builder);
+        for ((g, h), affine) in g_values.zip(h_values).zip(affine_constants) {
+            Self::gtds_circuit(state, builder, g, h);
+            Self::affine_layer_circit(state, affine, builder);
         }
     }
 
@@ -255,19 +273,38 @@ impl Arion {
     >(
         x: [F; SPONGE_RATE],
     ) -> (CircuitData<F, C, 2>, PartialWitness<F>) {
+        Self::circuit_generation_width::<F, C, SPONGE_WIDTH, SPONGE_RATE, L>(x)
+    }
+
+    /// Circuit generation for the Arion hash over a state of `WIDTH` elements, `RATE` of which
+    /// absorb the input
+    pub fn circuit_generation_width<
+        F: RichField + Extendable<2>,
+        C: GenericConfig<2, F = F>,
+        const WIDTH: usize,
+        const RATE: usize,
+        const L: usize,
+    >(
+        x: [F; RATE],
+    ) -> (CircuitData<F, C, 2>, PartialWitness<F>)
+    where
+        Width<WIDTH>: ArionParameters<WIDTH>,
+    {
+        assert!(RATE < WIDTH);
+
         let config = CircuitConfig::standard_recursion_config();
         let mut builder = CircuitBuilder::<F, 2>::new(config);
-        let input = builder.add_virtual_target_arr::<SPONGE_RATE>();
-        let mut state = builder.add_virtual_target_arr::<SPONGE_WIDTH>();
+        let input = builder.add_virtual_target_arr::<RATE>();
+        let mut state = builder.add_virtual_target_arr::<WIDTH>();
 
-        for i in 0..SPONGE_WIDTH {
-            state[i] = Target::default();
+        for i in 0..WIDTH {
+            state[i] = builder.zero();
         }
 
         // The arithmetic circuit
         let mut absorb_index = 0;
         while absorb_index < input.len() {
-            for i in 0..SPONGE_RATE {
+            for i in 0..RATE {
                 state[i] = builder.add(state[i], input[absorb_index]);
                 absorb_index += 1;
             }
@@ -322,6 +359,22 @@ impl Arion {
     }
 }
 
+impl<const WIDTH: usize> Permutation<WIDTH> for Arion
+where
+    Width<WIDTH>: ArionParameters<WIDTH>,
+{
+    fn permute<F: RichField + Extendable<2>>(state: &mut [F; WIDTH]) {
+        Self::arion_permutation(state);
+    }
+
+    fn permute_circuit<F: RichField + Extendable<2>>(
+        state: &mut [Target; WIDTH],
+        builder: &mut CircuitBuilder<F, 2>,
+    ) {
+        Self::arion_permutation_circuit(state, builder);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use plonky2::{
@@ -329,7 +382,8 @@ mod tests {
         plonk::config::PoseidonGoldilocksConfig,
     };
 
-    use super::{Arion, SPONGE_RATE};
+    use super::{Arion, ArionParameters, Width, SPONGE_RATE};
+    use crate::hashes::kat;
 
     #[test]
     fn arion_test() {
@@ -354,4 +408,58 @@ mod tests {
             Arion::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw);
         Arion::proof_verification::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &proof);
     }
+
+    fn check_width<const WIDTH: usize, const RATE: usize>()
+    where
+        Width<WIDTH>: ArionParameters<WIDTH>,
+    {
+        let input: [GoldilocksField; RATE] =
+            core::array::from_fn(|i| GoldilocksField(i as u64 + 1));
+        let output = Arion::arion_hash_width::<GoldilocksField, WIDTH, RATE, 4>(input);
+
+        let (data, pw) = Arion::circuit_generation_width::<
+            GoldilocksField,
+            PoseidonGoldilocksConfig,
+            WIDTH,
+            RATE,
+            4,
+        >(input);
+        let proof =
+            Arion::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw);
+        assert_eq!(proof.public_inputs, output);
+        assert!(data.verify(proof).is_ok());
+    }
+
+    #[test]
+    fn test_arion_widths() {
+        check_width::<8, 4>();
+        check_width::<16, 12>();
+        check_width::<24, 20>();
+    }
+
+    fn check_kat<const WIDTH: usize, const RATE: usize>()
+    where
+        Width<WIDTH>: ArionParameters<WIDTH>,
+    {
+        kat::check_kat::<Arion, _, WIDTH, RATE>(
+            include_str!("../../../kat/arion.json"),
+            "arion",
+            Arion::arion_hash_width::<GoldilocksField, WIDTH, RATE, 4>,
+            Arion::circuit_generation_width::<
+                GoldilocksField,
+                PoseidonGoldilocksConfig,
+                WIDTH,
+                RATE,
+                4,
+            >,
+        );
+    }
+
+    #[test]
+    fn test_arion_kat() {
+        check_kat::<8, 4>();
+        check_kat::<12, 8>();
+        check_kat::<16, 12>();
+        check_kat::<24, 20>();
+    }
 }
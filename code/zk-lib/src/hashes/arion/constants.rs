@@ -328,3 +328,32 @@ pub const AFFINE_CONSTANTS: [[u64; SPONGE_WIDTH]; NUMBER_OF_ROUNDS] = [
         14144504619476557901,
     ],
 ];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{
+        goldilocks_field::GoldilocksField,
+        types::{Field, Field64},
+    };
+
+    use super::*;
+    use crate::parameters::arion::is_irreducible_quadratic;
+
+    // The shipped constants were drawn at random by the reference script and cannot be
+    // regenerated, only checked against the constraints they must satisfy.
+    #[test]
+    fn test_arion_constants() {
+        for pair in G_VALUES.iter().flatten() {
+            let [c_1, c_2] = pair.map(GoldilocksField::from_canonical_u64);
+            assert!(is_irreducible_quadratic(c_1, c_2));
+        }
+        let all_canonical = G_VALUES
+            .iter()
+            .flatten()
+            .flatten()
+            .chain(H_VALUES.iter().flatten())
+            .chain(AFFINE_CONSTANTS.iter().flatten())
+            .all(|x| *x < GoldilocksField::ORDER);
+        assert!(all_canonical);
+    }
+}
@@ -12,4 +12,19 @@ pub const MATRIX: [[u64; 12]; 12] = [
     [4,   5,  6,  7,  8,  9, 10, 11, 12, 1,  2,  3],
     [3,   4,  5,  6,  7,  8,  9, 10, 11, 12, 1,  2],
     [2,   3,  4,  5,  6,  7,  8,  9, 10, 11, 12, 1],
-];
\ No newline at end of file
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::goldilocks_field::GoldilocksField;
+
+    use super::*;
+    use crate::hashes::arion::SPONGE_WIDTH;
+    use crate::parameters::{arion::matrix, mds::to_u64_matrix};
+
+    #[test]
+    fn test_arion_matrix() {
+        let generated = matrix::<GoldilocksField>(SPONGE_WIDTH);
+        assert_eq!(to_u64_matrix(&generated), MATRIX.map(|row| row.to_vec()));
+    }
+}
@@ -14,6 +14,7 @@ use plonky2::{
 pub mod arion;
 mod constants;
 mod mds;
+mod widths;
 
 pub const NUMBER_OF_ROUNDS: usize = 8;
 
@@ -32,6 +33,17 @@ pub const D_2: usize = 161;
 
 pub const E: usize = 458304200482349921;
 
+/// Constants of the Arion permutation for a state width, implemented for
+/// [`Width`](super::Width) 8, 12, 16 and 24. The tables are flattened round after round.
+pub trait ArionParameters<const WIDTH: usize> {
+    /// Coefficients of the g_i polynomials, `WIDTH - 1` pairs per round.
+    const G_VALUES: &'static [[u64; 2]];
+    /// Constants of the h_i polynomials, `WIDTH - 1` per round.
+    const H_VALUES: &'static [u64];
+    /// Affine round constants, `WIDTH` per round.
+    const AFFINE_CONSTANTS: &'static [u64];
+}
+
 trait CircuitBuilderExtensionsArion<F: RichField + Extendable<D>, const D: usize> {
     fn exp_inv(&mut self, x: Target) -> Target;
     fn exp_inv_extension(&mut self, x: ExtensionTarget<D>) -> ExtensionTarget<D>;
@@ -127,3 +139,15 @@ impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D>
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parameters::arion::round_bounds;
+
+    #[test]
+    fn test_number_of_rounds() {
+        let bounds = round_bounds(64, SPONGE_WIDTH, D_1 as u64, D_2 as u64, 128);
+        assert!(NUMBER_OF_ROUNDS >= bounds.minimum());
+    }
+}
@@ -0,0 +1,1362 @@
+// Arion constants for the widths other than 12, for the same 8 rounds. The shipped width-12
+// constants cannot be regenerated, these ones are squeezed from SHAKE256 with
+// `crate::parameters::arion`.
+
+use super::{constants, ArionParameters, NUMBER_OF_ROUNDS};
+use crate::hashes::{flatten, Width};
+
+impl ArionParameters<8> for Width<8> {
+    const G_VALUES: &'static [[u64; 2]] = &flatten::<_, 7, 8, 56>(&G_VALUES_8);
+    const H_VALUES: &'static [u64] = &flatten::<_, 7, 8, 56>(&H_VALUES_8);
+    const AFFINE_CONSTANTS: &'static [u64] = &flatten::<_, 8, 8, 64>(&AFFINE_CONSTANTS_8);
+}
+
+impl ArionParameters<12> for Width<12> {
+    const G_VALUES: &'static [[u64; 2]] = &flatten::<_, 11, 8, 88>(&constants::G_VALUES);
+    const H_VALUES: &'static [u64] = &flatten::<_, 11, 8, 88>(&constants::H_VALUES);
+    const AFFINE_CONSTANTS: &'static [u64] = &flatten::<_, 12, 8, 96>(&constants::AFFINE_CONSTANTS);
+}
+
+impl ArionParameters<16> for Width<16> {
+    const G_VALUES: &'static [[u64; 2]] = &flatten::<_, 15, 8, 120>(&G_VALUES_16);
+    const H_VALUES: &'static [u64] = &flatten::<_, 15, 8, 120>(&H_VALUES_16);
+    const AFFINE_CONSTANTS: &'static [u64] = &flatten::<_, 16, 8, 128>(&AFFINE_CONSTANTS_16);
+}
+
+impl ArionParameters<24> for Width<24> {
+    const G_VALUES: &'static [[u64; 2]] = &flatten::<_, 23, 8, 184>(&G_VALUES_24);
+    const H_VALUES: &'static [u64] = &flatten::<_, 23, 8, 184>(&H_VALUES_24);
+    const AFFINE_CONSTANTS: &'static [u64] = &flatten::<_, 24, 8, 192>(&AFFINE_CONSTANTS_24);
+}
+
+pub const G_VALUES_8: [[[u64; 2]; 7]; NUMBER_OF_ROUNDS] = [
+    [
+        [7510361994460570995, 16204605231285281722],
+        [11682858005791619733, 13810143975087639442],
+        [10004448628261741366, 7840109755031293015],
+        [13317548612054987918, 12053651247427748193],
+        [197306748044207590, 696074603018069469],
+        [2935879891594036382, 293341298309808354],
+        [8369085406776838523, 15388804307684096733],
+    ],
+    [
+        [4649578173583402447, 8618387434595555542],
+        [11644021986584651981, 4670239361480659924],
+        [3791481571357949810, 6950372534502619831],
+        [13120710011309186356, 13684443732588044494],
+        [3160851796275050635, 12776292542011625453],
+        [16530251911017026344, 12687294485411580912],
+        [3932942015956113244, 12282536909345731813],
+    ],
+    [
+        [4807324597714316580, 824719313050841037],
+        [15716066378389901537, 2328086349156434249],
+        [5496192158394104133, 12713496004034653276],
+        [17490087751350634266, 7329745003503180853],
+        [7124184996490269702, 5411261252485735897],
+        [7406163850368259461, 6433272819012421714],
+        [438964109391716642, 13561595685847490376],
+    ],
+    [
+        [7996775427197047295, 16044669366212315512],
+        [17236267354899304792, 6629872381228715699],
+        [14085797959219031387, 12545480344853399728],
+        [1410817049138636533, 16362597349224730094],
+        [83564394637582794, 12875015254091682214],
+        [3273739506878920944, 3967633256529407302],
+        [1504127888512418718, 13530699005544763093],
+    ],
+    [
+        [2174990902616917957, 6470400833094469906],
+        [1395487443697857055, 7803066230725490511],
+        [1187057921009222446, 15761724071960347847],
+        [1491044895090383127, 7679590289980159222],
+        [14350495911836086196, 15045211487927624197],
+        [6470092298989037474, 781811037794673707],
+        [824203039320353093, 7690493591712898876],
+    ],
+    [
+        [16730070909724354728, 14672845934206765781],
+        [12233689294813276594, 10252976828797201142],
+        [12888139277385973770, 10011469528034529540],
+        [5621063947419436708, 8097087500828694158],
+        [6517299896510680143, 16694368107627561604],
+        [8620446100340406184, 6588427785746713153],
+        [8770129189723004179, 7662648196102396716],
+    ],
+    [
+        [12363800842282586472, 6036577539124159202],
+        [15276175652414948094, 7817961460096855630],
+        [7166845519617372600, 2750278969003135781],
+        [15958490820806303982, 16020504446876226168],
+        [6607737842959407011, 11455699087779919476],
+        [14875734168324628815, 10183622631976103646],
+        [7376197782209087656, 1600473015355185345],
+    ],
+    [
+        [12352465325042217422, 5959297503664826487],
+        [4470090660529084390, 14187164270736510119],
+        [7942531858822609328, 8301204220033969608],
+        [7428878057647510221, 993417373278365438],
+        [15096403905387593496, 5887711041267066097],
+        [16383408643578253922, 5932807055990530790],
+        [12532782204867499093, 1228516955922365691],
+    ],
+];
+
+pub const H_VALUES_8: [[u64; 7]; NUMBER_OF_ROUNDS] = [
+    [
+        1280956036290408335,
+        6555908407705575134,
+        11425688251065208142,
+        15706795028129125377,
+        14789084490283191170,
+        5645344978091342352,
+        15299420156536256733,
+    ],
+    [
+        3493490269695742103,
+        12745271083941774832,
+        13639809452742024301,
+        12516143985801754679,
+        5135729137256659792,
+        13907445865115573999,
+        17765619680254953460,
+    ],
+    [
+        8937338728790323944,
+        18119208181393081960,
+        15211184461388760808,
+        6856889076676335748,
+        17391461444361319098,
+        12683255992591545605,
+        8788541790944002434,
+    ],
+    [
+        17052447928420217923,
+        17947047473328128192,
+        530474813546981086,
+        5789828221397981729,
+        10060884395692880163,
+        9531769200154740359,
+        5511406551191983422,
+    ],
+    [
+        12788343672626218165,
+        253229509755374902,
+        17594882439242799091,
+        2654952500565903126,
+        9325714650044263776,
+        12201184902381289310,
+        18185618035781457089,
+    ],
+    [
+        18407234324200818391,
+        5620031036550886863,
+        10163428008256949977,
+        6731795079681519866,
+        14277519270224954402,
+        2821705307125814879,
+        7275111824074017840,
+    ],
+    [
+        7226064460084497581,
+        6782568753254391722,
+        5079462218086744267,
+        12621129646277298132,
+        16565403893034737818,
+        12354937017625097049,
+        8226842460583867196,
+    ],
+    [
+        801814755270295245,
+        2411996795123837762,
+        3389321414034809996,
+        10803120751570470262,
+        14279218022850713886,
+        4139820700062546673,
+        14288889980834155108,
+    ],
+];
+
+pub const AFFINE_CONSTANTS_8: [[u64; 8]; NUMBER_OF_ROUNDS] = [
+    [
+        2007706956219937807,
+        5786568457567663143,
+        13131616246326788589,
+        11849288874498533102,
+        17249709851227342563,
+        16970873110854928813,
+        5722696914914752311,
+        15111369615569960944,
+    ],
+    [
+        14667671394749463755,
+        5386588804797063763,
+        11056355252494853353,
+        7238541405206339048,
+        5889599965433164524,
+        10091036641656373945,
+        1062893712124600486,
+        7825504161061346378,
+    ],
+    [
+        2395778519173920071,
+        324778166519527427,
+        15046476440380104405,
+        13492893371234047275,
+        10121143666100654856,
+        18224970547436434275,
+        1707611279338874467,
+        10235860535125189387,
+    ],
+    [
+        12868457803113141077,
+        5808169830154062522,
+        5850375635619749173,
+        4719568674572580331,
+        12876449168094848906,
+        6067931072393020147,
+        7862250073160014811,
+        18355765194431540392,
+    ],
+    [
+        10899798446537784103,
+        2708344095059616332,
+        2912930333996086678,
+        9209928445302217600,
+        14385138533633611823,
+        3661114171290478056,
+        15884637828866882522,
+        3047973870041456730,
+    ],
+    [
+        10060457743849740013,
+        4342198527098280722,
+        4731212290100341886,
+        14559364782285541397,
+        3216198173151206683,
+        6501407688490872475,
+        5533214124848385568,
+        17132549307965345236,
+    ],
+    [
+        8286865813645757277,
+        958933348472961710,
+        14763148812696305250,
+        7215102711442479474,
+        17514476025770580251,
+        2305506808473663204,
+        4894522618389750532,
+        2213353504928076867,
+    ],
+    [
+        13333936546916473385,
+        15144538999112543273,
+        13443614710975069579,
+        17075089193415686188,
+        12375304452020133815,
+        3106389834997492436,
+        7361276523700606244,
+        14766293410916266083,
+    ],
+];
+
+pub const G_VALUES_16: [[[u64; 2]; 15]; NUMBER_OF_ROUNDS] = [
+    [
+        [969407668302145718, 10531338892164794616],
+        [11424961183124600970, 5693822745591239770],
+        [545029123745236407, 8241213721750529129],
+        [5615442831668137243, 8351081753532107413],
+        [9341074678273300767, 1588998460986847782],
+        [7475295972854825288, 14760347563776119820],
+        [17746041029503291562, 12836362637065978629],
+        [10632870456015616411, 8294295300713378732],
+        [3244284254393072402, 15571273866892550183],
+        [338814444571087611, 2699462075645708093],
+        [12522612124630108173, 13438410320868656472],
+        [14004964050104203868, 11791648872437059891],
+        [13660196160306701386, 11849443409922792774],
+        [4761000578525903558, 10776710711829735954],
+        [12088668171793096952, 1760539177501724230],
+    ],
+    [
+        [3472629273641587197, 3071486387152569992],
+        [7613193320059948129, 8473966097593829538],
+        [16639441768132347300, 18355606469495400083],
+        [14417906925166865128, 9925559433630999624],
+        [15790360432819516638, 15159429752695878576],
+        [4515053828442278584, 3521719608889713581],
+        [16119273654299195224, 17270221885241708207],
+        [2064640033722701766, 17337504937532138456],
+        [16069749427491979692, 7443574872321006388],
+        [7453252056772824793, 2979837662859913423],
+        [13504128385633364554, 12396525641716380139],
+        [12157148586677619846, 16055654483379240484],
+        [4061080306064637627, 8459667283737487428],
+        [2131175058227456919, 10049803037584239310],
+        [2177405645122222010, 5443830072666499917],
+    ],
+    [
+        [9529814308188054989, 8585979316015050686],
+        [17552375523769433352, 1506718976533226071],
+        [2910040304616019701, 7021316060622444544],
+        [6295259089398391770, 13340676154780297498],
+        [14119688707794158312, 5874586029611210261],
+        [17154209677516052000, 13236093004239432686],
+        [12883096492596586522, 18128039852597478166],
+        [7438511619428744262, 14258874427069219007],
+        [5346732250880011099, 10928904268872680564],
+        [928900838831145158, 12999204116647163820],
+        [12147237538304982609, 1991577025839746355],
+        [4695743857775199606, 6949278295729301146],
+        [14165244917159062964, 11730089179235141798],
+        [4657738756110023908, 2445446821007762028],
+        [10243323265056066980, 4738129281559395429],
+    ],
+    [
+        [12514124361945866109, 3200605790831350177],
+        [1070320147791405091, 13930459128903021471],
+        [10004085214710160735, 17446611913800709341],
+        [1163804401853651813, 180266928996444960],
+        [17931097649174617124, 12239586643071275559],
+        [5907836317771156348, 11515172483397157281],
+        [13144649713862439824, 17423784985086222168],
+        [2635597053874358244, 11412678215286837070],
+        [11054455847972309534, 10877557649017302609],
+        [14429115746689861128, 18112930160567229678],
+        [7114325764956053722, 5405595098011705106],
+        [15229282636276671314, 550007139928613561],
+        [17992452507359088291, 2427321299591558587],
+        [1663424389387048022, 16503041614260101914],
+        [7665925255262479238, 5582771000464439230],
+    ],
+    [
+        [10148976159653595852, 4122596579989465413],
+        [1784578859954045527, 1567347985860636296],
+        [13898614504515430401, 15226468423009029812],
+        [13032265015696469743, 1460695975885257338],
+        [6230984270345120575, 2597551395584745858],
+        [1998917920644679530, 7257365347731882530],
+        [17069675557743357539, 1072496272456410825],
+        [5840833147738699202, 6653090660600652156],
+        [16832413309283725931, 388699275498581238],
+        [8736126654454991384, 5259702912466336769],
+        [17144978787184679785, 11654109485422739111],
+        [12718482307742566994, 7901405558006350322],
+        [10680245454447808285, 6459452702958082006],
+        [10623206028949320137, 12940879018771974856],
+        [8205165386668247053, 11854735694184477486],
+    ],
+    [
+        [16776375876808248124, 4811458224915676043],
+        [252090083687831349, 14060154169558936436],
+        [6142898632893284294, 7500080126440357164],
+        [11072391359287887148, 8093799022172386731],
+        [6897408249809920962, 12431420339669753259],
+        [7814863473556188591, 17519451858615593201],
+        [15989343997318165607, 12487642570913706843],
+        [6192801550654430220, 5117278366198093767],
+        [5713734616105480104, 651268343750596064],
+        [1092223655980616499, 11406363006321689954],
+        [5176881614197128631, 12809659050107121161],
+        [12585225267035047460, 16119974170368755454],
+        [8813004391703167427, 13800050355564237762],
+        [2136970830192531025, 12031711888753082495],
+        [3432607164185274016, 11295493033206034671],
+    ],
+    [
+        [8159545793175024686, 15303259634113751372],
+        [12358265019135857695, 14372419535187788090],
+        [14963625593399565883, 2250962306466186461],
+        [5263930543989589967, 1938251969854918494],
+        [1834191872407402426, 13176546730321443001],
+        [12636763289843286942, 15899451602610199646],
+        [1514173629618844547, 1030792943296126116],
+        [5756802228015326077, 11355134442725376303],
+        [8450473550502289310, 14576069844572800863],
+        [4248224738989508048, 13741428447012138596],
+        [4236740032916668895, 5153365951314027231],
+        [3198630892407847450, 4474267667920761957],
+        [6033326725917307075, 721879642964667817],
+        [8438893988819106852, 13563885076124629809],
+        [17086340214898590465, 13289125561072110470],
+    ],
+    [
+        [9578973205621415192, 17138211942294330957],
+        [8304281312050539776, 3648812838386811697],
+        [7661820335734811321, 17800741392066480985],
+        [10892947747772227149, 17343769669028126056],
+        [3241151038352031054, 17818920099397658755],
+        [5859078015311596837, 11521295784754293123],
+        [14438365229583420624, 14282758598903964984],
+        [6193835604589957469, 16980691110913040017],
+        [15661244566482085321, 10623414607189721321],
+        [13318771152803436946, 2162944161561488481],
+        [6809480306204935708, 680177662342315832],
+        [18346171859086197576, 1676979369635955258],
+        [2740354623666797742, 3363626727073697214],
+        [9531312058417703376, 2189032959238940052],
+        [14776579694070851005, 8322377923612032193],
+    ],
+];
+
+pub const H_VALUES_16: [[u64; 15]; NUMBER_OF_ROUNDS] = [
+    [
+        4169332039624590773,
+        4863655448561999193,
+        11186181950762345977,
+        2779900022009587356,
+        7163620769527431039,
+        4793805902082665992,
+        16773129489416705860,
+        16338726233691663307,
+        15370906584270843776,
+        16766418715000136190,
+        8972383580839549380,
+        6332028363465357388,
+        16739433661855091415,
+        11291650976424573323,
+        8129865680728510728,
+    ],
+    [
+        17485949445514005733,
+        6603885313241563267,
+        16144408693480271651,
+        15002674225381686504,
+        17947273218480598865,
+        8403542549608670239,
+        11205946932107179273,
+        16866067479868694387,
+        353570687934372156,
+        16332822885683851516,
+        9756243048240345559,
+        10582191144223412358,
+        4992703826240748939,
+        7895627684851577556,
+        8067616333746924938,
+    ],
+    [
+        14558747278978132897,
+        15368161057852527562,
+        3984553971746045949,
+        11205207347556296353,
+        1033832597282279456,
+        9488878730382654167,
+        14507105089578229775,
+        6807156343268631090,
+        2238105005323298542,
+        15867812039248347241,
+        8542818625912268493,
+        12452410454120148145,
+        5688402179694622614,
+        3423931931924067999,
+        13967472827295175880,
+    ],
+    [
+        4963963139866348360,
+        13793833837096715305,
+        7742595964832719216,
+        13815110819947902177,
+        6412604571291771061,
+        14934925610230303944,
+        16397633012781064879,
+        4713485576567834917,
+        3874544059689417793,
+        14032746378409329184,
+        18326267438810234655,
+        3524385092967565227,
+        7228326766506625100,
+        12881877346796385635,
+        3733287708912266147,
+    ],
+    [
+        8613971612831647224,
+        7332029930718673574,
+        6563021865054682105,
+        14950359318784652050,
+        9602995259431073269,
+        13319988344744934522,
+        7976726686837098710,
+        5710658200488518147,
+        17630304056880565,
+        12825010554304346960,
+        6231716010615233588,
+        16356561120377928345,
+        254092510272423239,
+        10885227584596737017,
+        8010701522871954816,
+    ],
+    [
+        2544993954380907042,
+        3156834198803644810,
+        1649863136218089434,
+        4301854563794715213,
+        4285404722161937347,
+        15717767271905621119,
+        13398384941291644848,
+        8761935212363187070,
+        12876233915588331527,
+        14575586809963103238,
+        7059544478580611776,
+        7254559777581902671,
+        16023520378864250286,
+        1241864646210995890,
+        5842659076017720716,
+    ],
+    [
+        11949737898984390073,
+        3120082286158817407,
+        9923398512504050386,
+        8895175190825007607,
+        14157816961267043895,
+        12960424779408568833,
+        7557039761857758291,
+        3869784152960897694,
+        2859903900218545121,
+        7405554666327448148,
+        15508649822395474271,
+        17257781691727872965,
+        2444913960540606839,
+        9700966417320247535,
+        9518775820730490170,
+    ],
+    [
+        14115636764296565904,
+        838546250560346365,
+        17210152051190558539,
+        1336378059352721968,
+        7244036379439590475,
+        4312633557388957574,
+        11513597954711230376,
+        8322149986970909558,
+        18364304584418781800,
+        17604444269378392249,
+        7006119802193698060,
+        9676428169861294135,
+        4143809658722589634,
+        8703375502671325528,
+        10074982180890893346,
+    ],
+];
+
+pub const AFFINE_CONSTANTS_16: [[u64; 16]; NUMBER_OF_ROUNDS] = [
+    [
+        17243763823717122595,
+        2492170292903702470,
+        8424224987091476873,
+        11965829460664990898,
+        8945263727028913863,
+        1736058071719045050,
+        9338502018096087316,
+        6300074192760231265,
+        8349409643927144674,
+        15249280806513308558,
+        2398281637167879932,
+        10282554683502207985,
+        14188995918628486553,
+        15347933638655939396,
+        3679194219160525303,
+        11761369338324550622,
+    ],
+    [
+        8885942984957021538,
+        2913434473202069746,
+        722714070577144307,
+        2697871317665619997,
+        11798255641961042317,
+        8036148063772409357,
+        5522379534829247835,
+        1868493455408622693,
+        17053888538518514036,
+        14256382910159424313,
+        8735559156722742335,
+        17872581781429286871,
+        17035926734632023901,
+        3831370012794066977,
+        13942997495318516157,
+        14871174698326415319,
+    ],
+    [
+        2590697587702177924,
+        14004234484833941881,
+        1011126688225396486,
+        3115082704186972401,
+        13217476832571016929,
+        11849191001909801099,
+        9240485911809396258,
+        2433583064139716283,
+        15295867672318842463,
+        12078826109608882966,
+        14780555311543841456,
+        10528958654415824382,
+        2839467500851017788,
+        4436996965773424861,
+        157816264330489787,
+        6997135988424202999,
+    ],
+    [
+        11995949416330710042,
+        13418515940737371928,
+        91894775253189018,
+        7456792981446018458,
+        5910783206693147416,
+        17609959280122927724,
+        11774862071436706236,
+        15997363262209272781,
+        900219520050185030,
+        972965307064443212,
+        957485881901594623,
+        18085828968826578381,
+        15719844989955926711,
+        7153892917045035404,
+        11991786787371829469,
+        264158789460956456,
+    ],
+    [
+        5923676971726618357,
+        12885588473394739659,
+        15292224287589439378,
+        14851390983906739739,
+        6559686124177291452,
+        2652749780086780798,
+        1088807920181269161,
+        11370224886421420128,
+        6203211425061512130,
+        4439778313159880014,
+        13844305768903021156,
+        10561135094255250506,
+        15270267843444881613,
+        7984554610325937303,
+        13476128895612270217,
+        3621370755920430596,
+    ],
+    [
+        685192349328724483,
+        4625835599667923067,
+        17753999430230160930,
+        4692613547660806060,
+        14420133155680896705,
+        9781937790940112335,
+        18250254985049532592,
+        18028158184338295763,
+        18385541369342171563,
+        4886414414673950437,
+        5473120952850578220,
+        5604704698823743722,
+        3238153550731304676,
+        9856817717083180221,
+        12019163116256597448,
+        1790031404037960208,
+    ],
+    [
+        6580048320355332272,
+        8297632870661948387,
+        7471265232145169429,
+        9627182858320199959,
+        6993957098662318527,
+        651695220898238126,
+        10206036245191373609,
+        8670919643721504418,
+        5209527872966097486,
+        4787094978715236413,
+        12029250514224742556,
+        6542455888706127054,
+        958935468715001969,
+        11351159133239464704,
+        9183733004385744197,
+        14792193063167235844,
+    ],
+    [
+        13773636495116377954,
+        1020187290587121351,
+        10765336560899342715,
+        2029114604865673539,
+        2606406659923409952,
+        13319130058203237731,
+        5418674241772522304,
+        9445054098776246280,
+        14806765488944707137,
+        16439151264838256606,
+        4895213804213701537,
+        7294471137919231966,
+        940778206920204822,
+        13875970476579504649,
+        16256520973314719133,
+        5063093380365135366,
+    ],
+];
+
+pub const G_VALUES_24: [[[u64; 2]; 23]; NUMBER_OF_ROUNDS] = [
+    [
+        [3221447789302157778, 6755064158896877202],
+        [8512546092265000160, 10501896416572436757],
+        [15289111985636603649, 7502086773606955161],
+        [2360265143048085821, 15022481786832730360],
+        [13627564299983983199, 14537390060602276316],
+        [8298418124650854442, 5206158042422599232],
+        [442491980672496702, 12828095384946648769],
+        [1255819641965864371, 889735590450507528],
+        [8485609270608353824, 17333248710820913088],
+        [13504888607855516039, 11247886235193864157],
+        [4624688847901979091, 3984057055759438461],
+        [10206146810275699996, 17595364713611478037],
+        [12954511676800040695, 16167920482102563725],
+        [1191459712419932782, 17836365184001696300],
+        [12947613134650971800, 10903881418667879059],
+        [6103639967100434642, 3699426902408498305],
+        [13553052038748273605, 1461983717183221523],
+        [14198351371688718392, 1235579536266056084],
+        [11708705848126410682, 11227989900982050597],
+        [8118236047143998723, 1187273811351547155],
+        [16538300792899074176, 10064448979052481415],
+        [15815660459372512598, 17195080033495393571],
+        [5546366313710300104, 15223880663909766598],
+    ],
+    [
+        [8852606141716815702, 1716294926747445207],
+        [117799694832627349, 11881028038853314387],
+        [3737110706429858986, 61154423215480171],
+        [13469267730427665754, 7640802847380570396],
+        [11651221447871244606, 9306547098513823089],
+        [11770625244706696930, 1892761233849185937],
+        [3192245157618066166, 2654872390229033588],
+        [1697712100942578748, 10258756582144312473],
+        [3920187932005071187, 18254937369047019332],
+        [7412154553738570016, 6841313195266157241],
+        [1416393620510321014, 16761488012346996421],
+        [4313798483787791169, 8707283111651436863],
+        [1231776717490304398, 5185192928086992688],
+        [14011244042127605583, 13470032421229057573],
+        [6871737091235216688, 14243632324293000712],
+        [5777663394809645215, 14207889836474529992],
+        [16258613218794673896, 3939334304312283758],
+        [4623228585487661024, 15135400383361286905],
+        [1393283420605518506, 7478526766287677009],
+        [17705625494861643747, 13197706479144475423],
+        [3486434020437893149, 5926372298597397810],
+        [3838191372914836571, 7249303084336958820],
+        [2142299907417111764, 13232554614799584659],
+    ],
+    [
+        [8203240029263601061, 10664281676205701719],
+        [291968191508760063, 9585784194602638691],
+        [12166392436088822600, 6560608708018445195],
+        [6377284463418653914, 17422259468926913181],
+        [8614383191062267175, 13251489827422947290],
+        [11715046761223569624, 3149833233585987710],
+        [2453009700884086489, 9529956047606655190],
+        [2793462748629083141, 10423949265690983002],
+        [2445377753687015911, 3891634740487744080],
+        [7550039921002627807, 3176960216144210833],
+        [9894508840935798494, 8458759586743333230],
+        [9121530147551426872, 5708270793639360294],
+        [6195021706412972269, 13898492614130329737],
+        [5616602240758700317, 720458956479955291],
+        [3409562989135617474, 16708513901459722307],
+        [8981570355419295248, 7812257053770130813],
+        [13746091404597005527, 323847191523387290],
+        [16566027762854846269, 7286524174209743690],
+        [16944180029696977195, 14349591873514152399],
+        [8504712647386023285, 6484344023506382275],
+        [6433061428385413179, 2981179802149099451],
+        [2636402429542788220, 6475874650770888212],
+        [7671153348334658176, 15740261889722804482],
+    ],
+    [
+        [13962458211285840567, 1712703136793466632],
+        [5928082320307403873, 11616274150338798469],
+        [1203896159726419203, 18293235788981810057],
+        [13961119106469473132, 17588016101925876133],
+        [2341473749377576592, 16219623784648810353],
+        [15618864770882716645, 17161771328098101461],
+        [13236479230497499736, 4442253674900589180],
+        [3804017491246517327, 2522263377470877055],
+        [14318203317489962624, 11579886551655919973],
+        [7005648217459276248, 15946202325677668171],
+        [15109144543934170963, 10178515740983321375],
+        [3347336866055659381, 13431069514470140296],
+        [13995045374226254272, 1176711809093177089],
+        [7402345073772048396, 2422939867874422174],
+        [3570086334369486599, 2188259654972378329],
+        [11304475685814760849, 274508391792785407],
+        [14242648053833729392, 6827716198534569573],
+        [16655013057875213193, 10939959261566230459],
+        [14861268311946690466, 9273974368740117034],
+        [11880174955059701589, 12463998079987302448],
+        [14587322902742063171, 7465397154307911300],
+        [2097255550227941342, 11901379971373757339],
+        [15620766132275671934, 11340669663034009167],
+    ],
+    [
+        [17571161679446995379, 8076777648128385104],
+        [2150425538423248673, 1205925623735853504],
+        [7799314606659161003, 12073706955795741375],
+        [13529816889527029695, 17958072459539294988],
+        [1347596704497647445, 14647910372773730516],
+        [6282831749438784803, 997369252691265281],
+        [461039807863916345, 7038473535835937230],
+        [4041197264517685576, 358352175810356961],
+        [13392333551090217420, 4688624519215079662],
+        [260260672811702533, 5513174277329750620],
+        [8647303296977104823, 4365245625510411974],
+        [9179291563023769576, 4616272572973687236],
+        [7236832570004938521, 13757018152923407633],
+        [13424918525698777964, 15045878832059956781],
+        [5592348196275548025, 13925884655986672563],
+        [2253841990379560497, 8177525413685958270],
+        [17069054809079818080, 7688575384199923336],
+        [3354903693957657989, 16907479243532717508],
+        [11844501690982226238, 2250232435338404706],
+        [9660531360174887395, 2281527495438382733],
+        [15589522278474091588, 17527403453354152815],
+        [14153687114529724182, 12211856500192893793],
+        [11251869755605706204, 8205545518376655538],
+    ],
+    [
+        [4920538515786135551, 11632245704920123674],
+        [17488168691866752236, 12943198334853249626],
+        [4352863286595244689, 7652634400830167322],
+        [1334268318257723223, 14623686443726861724],
+        [396986986172566182, 10800827907120743380],
+        [14527492869620452286, 17372100388471356029],
+        [4179652084654575818, 6822049420811818754],
+        [5734045863178171164, 8635070621180524578],
+        [12957523882104284513, 14938535223379819958],
+        [6118198736846718045, 6026431209184553959],
+        [3693879631202039233, 2046466964586005945],
+        [11586856833095449889, 13788590840613342552],
+        [8892264819764286816, 8901876971156255888],
+        [304345809452563679, 12180122030211398712],
+        [10648777199110255777, 13666504733450627225],
+        [8139628580025331002, 5332838026042815654],
+        [12236053348550613030, 5586833373214430188],
+        [12770411880157519290, 16555927002113897908],
+        [16692766305669075030, 13051745289025500098],
+        [96520111891193841, 17411411465394892658],
+        [17419449654663257851, 16795602659881503000],
+        [16182525142851470468, 8144768116490049739],
+        [17549490687223645154, 5357032030482100446],
+    ],
+    [
+        [8307914816185733135, 13836808117017662002],
+        [9361617372075938806, 12905729289308180035],
+        [2314619296232674116, 17504784404532041022],
+        [3564620945493844029, 4345866606499474588],
+        [5085435021440210691, 14453223017897715483],
+        [99691530311099940, 16741828085851114682],
+        [8176036041695691571, 13912094061338203719],
+        [17134420665534568185, 14757483142057756544],
+        [1055370027202191290, 12958807621586335040],
+        [3709639888644736154, 11947908259893142706],
+        [15521308277733388233, 17005508480434371309],
+        [12700496500780416758, 14502661452018831707],
+        [10492238678101286434, 8233030898144852894],
+        [16732461170096852517, 10904649261945151088],
+        [17072764139535097995, 11726460875901722265],
+        [16046448494090659477, 11186257920251646692],
+        [5991546448401838967, 7952666552113486178],
+        [13308229380709020474, 11311123411758419064],
+        [17910859572801717540, 14545425395592350],
+        [10412072711573430915, 9034248909314522342],
+        [8275278362310638335, 16211913816293023809],
+        [10380484541634107201, 13091243661934757970],
+        [7614727544304398346, 11034860301710054431],
+    ],
+    [
+        [3310380534987301787, 9244073100701813745],
+        [12539589167593493731, 8722012305283196287],
+        [5127775691488525480, 4654122567632177436],
+        [1386888180167440522, 12550553347267636312],
+        [11713614993634154474, 15442225670738037492],
+        [5263164842223794201, 14015199508832248151],
+        [13467816418092314597, 15410536312781109519],
+        [8324631397588841650, 6656390656122348958],
+        [5782585444529369252, 13595416953893274861],
+        [17908800594887393159, 17980569383684020025],
+        [2625803140760838202, 13543585389733150724],
+        [15037999396201315801, 10991733406968554575],
+        [11225103263452101398, 11306854014931040819],
+        [17098373761972656710, 16141187854994682236],
+        [6702299421362482968, 18250905811659078808],
+        [15812925448857275483, 13189683813940718064],
+        [667069975075661119, 3247940154216298088],
+        [4521316300100718063, 11279346953258577518],
+        [3469969159854041523, 12077847413998956324],
+        [3524250808961803555, 3059089182883990325],
+        [9670871806895980554, 16026620157197337522],
+        [14617180787239540716, 443580480538636594],
+        [3440242499167273060, 15698144149175116737],
+    ],
+];
+
+pub const H_VALUES_24: [[u64; 23]; NUMBER_OF_ROUNDS] = [
+    [
+        14300550939379972390,
+        7187236844930562834,
+        5266856798808167439,
+        10039130908734456994,
+        6883125478679889585,
+        12842935173557313932,
+        16726807188874589691,
+        644428633043809296,
+        13743724480073786120,
+        13874778042437875359,
+        3118188168397538751,
+        3369023522550554843,
+        8836222838376738344,
+        11678788146268696732,
+        3851973629726206443,
+        13400101178089090712,
+        16140615289032775475,
+        7457427312425411628,
+        17136543738643679808,
+        10568616707779754251,
+        16026488958382665787,
+        7318804220519754096,
+        7232264199243864666,
+    ],
+    [
+        3627587579040074531,
+        4917615733013594821,
+        5497875340630638821,
+        7061794019087701439,
+        13090318508612378066,
+        6141073403082675074,
+        13972560465884558117,
+        1087402961311615259,
+        3548245378334296531,
+        499649065468359617,
+        3196235684502983324,
+        9553388527821278049,
+        480758174699350085,
+        12179258756225722124,
+        659629641792571760,
+        4179419593266682718,
+        796536442996801823,
+        8072193267737649755,
+        18379356301061527504,
+        2249806283958477625,
+        10351560650673945251,
+        14617995848241003813,
+        4324642627036208469,
+    ],
+    [
+        14958144585492545984,
+        9442205321938572658,
+        9431626299044752982,
+        3392866213950988673,
+        14251780458839056886,
+        16851612597726054052,
+        2309749437977017370,
+        8211243275010249966,
+        6649595985212809258,
+        4476312970212871638,
+        10226374205251041819,
+        15134853513159109335,
+        11804378122071306196,
+        17824783630795646264,
+        9592636552968869897,
+        2611184605699705634,
+        15987704422159345635,
+        13442497821308394104,
+        4166017802945885054,
+        17352201477080745770,
+        2766124954410425025,
+        16952507290815500503,
+        7794903009165220847,
+    ],
+    [
+        9530194956382358786,
+        17998048030328539586,
+        12589901473525971496,
+        9601862248435736633,
+        14471225316698456071,
+        6852623439412689027,
+        4920554811663677942,
+        6037101628496329440,
+        5975421143410825891,
+        17096576502529758032,
+        6329782453215885640,
+        11254907847706738504,
+        16479605276878696460,
+        5013191215939414520,
+        7165628629536734826,
+        11634931228075472592,
+        5069025333481045444,
+        3717747894297756387,
+        15107279977920576436,
+        7934979106425029801,
+        14488382148607171198,
+        3467333991281475036,
+        11919571804950852409,
+    ],
+    [
+        486099096549261868,
+        5667784884911913816,
+        3604703773670845420,
+        146387949826374523,
+        15457002116386986739,
+        16794106173128565693,
+        16323045170297422628,
+        17408073206560000537,
+        17271188092344315906,
+        1212039088346539637,
+        1877902736099968833,
+        12615417661764145748,
+        6341395249585445217,
+        17130138358521518279,
+        4673820292666135881,
+        18046700958842630474,
+        10523240609970338160,
+        14783269695654525838,
+        10952447057390994241,
+        3193986222911088530,
+        5437865413160904688,
+        6471981687827866866,
+        12460766425903797220,
+    ],
+    [
+        14783651367324741150,
+        1603640040421162531,
+        6976983186988981086,
+        1043999040661018903,
+        14597059294287759975,
+        13224817378420739299,
+        8201159955953918134,
+        5414433236926123042,
+        18087309681280880854,
+        10811690689333861282,
+        17740691648426667602,
+        1131575770318336490,
+        288227601682510124,
+        10932365252381642657,
+        1502863289030059568,
+        4052997827847917302,
+        6140907704608341966,
+        1800438955834844029,
+        7825411003121207617,
+        11055717753717688073,
+        7494388645337473063,
+        1174982350654034043,
+        15581576438683294459,
+    ],
+    [
+        13772599568951747740,
+        7382291253449070060,
+        6730393218703388571,
+        3584661498568867222,
+        2133996213663319853,
+        15270231159740941647,
+        7373827097776082764,
+        12197487943129762640,
+        2228332090835593805,
+        3104689293311154682,
+        10737629143248744909,
+        4250008049370223879,
+        26263086929858450,
+        12130884924304519612,
+        13657015904896606829,
+        16635118192739935112,
+        8570874682412859394,
+        12609428876154273933,
+        5142803450404212993,
+        11326961513871332060,
+        15620096236928634649,
+        15452415637586542389,
+        8590761305226681673,
+    ],
+    [
+        12691146393596208631,
+        3407873265429372002,
+        4444434393349004621,
+        270632461189358096,
+        11448655661516393519,
+        3776300588139233726,
+        5992968469529765709,
+        18234635895270602630,
+        18195133444035490689,
+        3286110870575283241,
+        1249881503933344254,
+        8245791941795472723,
+        10238736250422445804,
+        11784138753158812695,
+        17772513972131011693,
+        11191430782736843114,
+        4661674642064118872,
+        2902716068631017426,
+        11531706090663727054,
+        242811703360873842,
+        5611198457763870165,
+        18118636396745334641,
+        10548777292177367201,
+    ],
+];
+
+pub const AFFINE_CONSTANTS_24: [[u64; 24]; NUMBER_OF_ROUNDS] = [
+    [
+        11198486992709299361,
+        13936728712508508180,
+        10594925111227303377,
+        11674920338191894457,
+        106267671524614382,
+        9349707469518003769,
+        14233491185195026007,
+        17705275006535330511,
+        18290681113965924685,
+        2689418005784966237,
+        5163526096956370989,
+        10168640442099337760,
+        13466307207438701098,
+        15723272509523866780,
+        5397980114060727734,
+        11142867391857432787,
+        656357463545703391,
+        17381147939942925930,
+        3635447979748525254,
+        14480391538489477992,
+        7363992911666512690,
+        4267621234690147203,
+        18280498953667686678,
+        14769579268826489600,
+    ],
+    [
+        7260259082403015147,
+        843420868344318170,
+        10591203583691688153,
+        908432380351959215,
+        8670413097545753767,
+        11850189531189930920,
+        10650231407268076418,
+        18091569951958701719,
+        6463065578950511200,
+        16423198422284847744,
+        972732703124458148,
+        16252666132655999107,
+        6417898251552067712,
+        8132929792492554832,
+        2507681461254822007,
+        1564793666866293404,
+        14168944996979057936,
+        15513273629792976488,
+        561936859948619022,
+        9246867868460739593,
+        3758287711476174542,
+        16911686101764319025,
+        5144736424746318425,
+        15987410391221098581,
+    ],
+    [
+        12872039473166583858,
+        7364144741410750863,
+        10303352918902556879,
+        18385568325825231976,
+        4345306598872822893,
+        16446597116255008776,
+        12742880854342142901,
+        10206744591406130510,
+        15582952136188712442,
+        4871558342559521300,
+        16590853747150743789,
+        14101984411540670568,
+        18437276476960230276,
+        15196324695672251843,
+        15821823360818476637,
+        2338918665512600720,
+        10350758363535526128,
+        9602849322220497668,
+        10856413498871814278,
+        17962823010774661933,
+        11918195711822746416,
+        13672960555783362847,
+        6619564608297389477,
+        10943789274462460304,
+    ],
+    [
+        5099043225739958418,
+        9141998546486323073,
+        13704738026038751943,
+        13449397252487697028,
+        6715664111152234004,
+        930342742296592427,
+        2975277290567423466,
+        17780383428211954799,
+        2633002805051807557,
+        297087627307543173,
+        4279251505867636324,
+        3706907272574510544,
+        15616943777732339810,
+        5199108093562743951,
+        17196154974281320858,
+        3634882304435776989,
+        7710839246712615552,
+        12189984611182478271,
+        15146326948478610472,
+        15496120362639735889,
+        3028416508171099138,
+        9502665898148425394,
+        3892720039943940520,
+        12713640399401670692,
+    ],
+    [
+        3510329062068281814,
+        9626809938142657889,
+        5808625344965389851,
+        18368486776685462371,
+        14835428600049926443,
+        1214107516515796526,
+        15002124578344913988,
+        6064180619072002493,
+        12875204795744872960,
+        10763256407087672835,
+        14813076227177911508,
+        4216766782742073029,
+        13021673942070667170,
+        8346179015759100616,
+        12191663101838668140,
+        4359970001159165147,
+        7154136644615124348,
+        14275916965687163983,
+        6153992056162873505,
+        16334532280277463334,
+        9406516405745351740,
+        7769569114682846739,
+        2877535917418637780,
+        2302205018514937632,
+    ],
+    [
+        6394634608443782748,
+        6011002171641025388,
+        4144469337660767650,
+        311089790449563435,
+        14013105991633318458,
+        1644366422161773258,
+        17589997115081476909,
+        6014862744446787631,
+        11882279111115168714,
+        964146092550706512,
+        11411077673437822706,
+        13831603674135386184,
+        7476690030876856140,
+        13783997375077904492,
+        10465067170732926951,
+        14130618723377759662,
+        250087994089893859,
+        14080385108089974143,
+        9899451264514998976,
+        5773068799222850409,
+        1395484806487872263,
+        5775313673570911259,
+        4736058464206609138,
+        3308252338141950184,
+    ],
+    [
+        8313188169357511436,
+        2143125440565207550,
+        15346267506127746109,
+        15969673226258278435,
+        4663064951280299031,
+        2796423057262352555,
+        12979110466945375931,
+        11223191082828891269,
+        12250403622015222898,
+        13051643882605714908,
+        16542031366411491240,
+        948312894651082090,
+        589054276478592515,
+        8764400797794722448,
+        13987754482169709393,
+        11366447952691331647,
+        9393412163009835945,
+        6095708214854917826,
+        13987673237139797674,
+        12462406152663370794,
+        14193324935349047047,
+        3460981180697686512,
+        10789553237692257739,
+        10693634891046439586,
+    ],
+    [
+        6580462211227439229,
+        6521947949547409984,
+        4920340520083228542,
+        15500181374595172799,
+        1185989501448612900,
+        16935634961438691207,
+        559888984614211385,
+        16847249828229513754,
+        7213821022184948438,
+        13788193064096477983,
+        14497354257228439758,
+        5024977470042873148,
+        17565489103158936535,
+        2630770619464567320,
+        15165105859210158952,
+        3803619085039987775,
+        12885465078050682460,
+        15179209592283292891,
+        13427336919961416673,
+        14094585084298168591,
+        3609099561993457213,
+        11534362323064488028,
+        220618617261054965,
+        13737671634313192149,
+    ],
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{goldilocks_field::GoldilocksField, types::PrimeField64};
+
+    use super::*;
+    use crate::hashes::arion::{D_1, D_2};
+    use crate::parameters::arion::{constants, round_bounds};
+
+    fn check_width<const WIDTH: usize>()
+    where
+        Width<WIDTH>: ArionParameters<WIDTH>,
+    {
+        let generated = constants::<GoldilocksField>(WIDTH, NUMBER_OF_ROUNDS);
+        let to_u64 = |x: &GoldilocksField| x.to_canonical_u64();
+        let g_values: Vec<[u64; 2]> = generated
+            .g_values
+            .iter()
+            .flatten()
+            .map(|pair| [to_u64(&pair[0]), to_u64(&pair[1])])
+            .collect();
+        let h_values: Vec<u64> = generated.h_values.iter().flatten().map(to_u64).collect();
+        let affine_constants: Vec<u64> = generated
+            .affine_constants
+            .iter()
+            .flatten()
+            .map(to_u64)
+            .collect();
+        assert_eq!(g_values, Width::<WIDTH>::G_VALUES);
+        assert_eq!(h_values, Width::<WIDTH>::H_VALUES);
+        assert_eq!(affine_constants, Width::<WIDTH>::AFFINE_CONSTANTS);
+    }
+
+    fn check_rounds<const WIDTH: usize>()
+    where
+        Width<WIDTH>: ArionParameters<WIDTH>,
+    {
+        let bounds = round_bounds(64, WIDTH, D_1 as u64, D_2 as u64, 128);
+        assert!(NUMBER_OF_ROUNDS >= bounds.minimum());
+    }
+
+    #[test]
+    fn test_arion_widths() {
+        check_width::<8>();
+        check_width::<16>();
+        check_width::<24>();
+    }
+
+    // Every width shares the rounds of width 12
+    #[test]
+    fn test_number_of_rounds() {
+        check_rounds::<8>();
+        check_rounds::<12>();
+        check_rounds::<16>();
+        check_rounds::<24>();
+    }
+}
@@ -107,4 +107,30 @@ pub const ROUND_CONSTANTS: [usize; 84] = [
     16185142423537834886,
     7347466071671954145,
     8853088384766122033,
-];
\ No newline at end of file
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{goldilocks_field::GoldilocksField, types::PrimeField64};
+
+    use super::*;
+    use crate::hashes::griffin::{NUMBER_OF_ROUNDS, SPONGE_CAPACITY, SPONGE_WIDTH};
+    use crate::parameters::griffin::constants;
+
+    #[test]
+    fn test_griffin_constants() {
+        let generated =
+            constants::<GoldilocksField>(SPONGE_WIDTH, SPONGE_CAPACITY, 128, NUMBER_OF_ROUNDS);
+        let to_u64 = |v: &[GoldilocksField]| -> Vec<u64> {
+            v.iter().map(|x| x.to_canonical_u64()).collect()
+        };
+        let shipped = |v: &[usize]| -> Vec<u64> { v.iter().map(|x| *x as u64).collect() };
+
+        assert_eq!(to_u64(&generated.alphas), shipped(&ALPHAS));
+        assert_eq!(to_u64(&generated.betas), shipped(&BETAS));
+        assert_eq!(
+            to_u64(&generated.round_constants),
+            shipped(&ROUND_CONSTANTS)
+        );
+    }
+}
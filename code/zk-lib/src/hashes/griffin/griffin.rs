@@ -15,11 +15,8 @@ use plonky2::{
     },
 };
 
-use self::{
-    constants::{ALPHAS, BETAS, ROUND_CONSTANTS},
-    mds::MDS_MATRIX,
-};
 use super::*;
+use crate::hashes::{Permutation, Width};
 
 pub struct Griffin;
 
@@ -36,7 +33,10 @@ impl Griffin {
         F::mul(x6, x)
     }
 
-    fn non_linear_layer<F: RichField + Extendable<2>>(state: &mut [F; SPONGE_WIDTH]) {
+    fn non_linear_layer<F: RichField + Extendable<2>, const WIDTH: usize>(state: &mut [F; WIDTH])
+    where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
         fn li<F: RichField + Extendable<2>>(z0: &F, z1: &F, z2: &F, i: usize) -> F {
             let prod1 = z0.mul(F::from_canonical_usize(i - 1));
             let prod2 = prod1.add(*z1);
@@ -44,6 +44,11 @@ impl Griffin {
             prod3
         }
 
+        let alphas = Width::<WIDTH>::ALPHAS;
+        let betas = Width::<WIDTH>::BETAS;
+        // L_i takes the input x_{i-1}, not the output y_{i-1}
+        let input = *state;
+
         state[0] = state[0].exp_u64(D_INV as u64);
         state[1] = Self::sbox(state[1]);
 
@@ -51,23 +56,29 @@ impl Griffin {
 
         state[2] = state[2]
             * (F::square(&l)
-                + F::from_canonical_usize(ALPHAS[0]) * l
-                + F::from_canonical_usize(BETAS[0]));
+                + F::from_canonical_usize(alphas[0]) * l
+                + F::from_canonical_usize(betas[0]));
 
-        for i in 3..SPONGE_WIDTH {
-            l = li(&state[0], &state[1], &state[i - 1], i);
+        for i in 3..WIDTH {
+            l = li(&state[0], &state[1], &input[i - 1], i);
             state[i] = state[i]
                 * (F::square(&l)
-                    + F::from_canonical_usize(ALPHAS[i - 2]) * l
-                    + F::from_canonical_usize(BETAS[i - 2]));
+                    + F::from_canonical_usize(alphas[i - 2]) * l
+                    + F::from_canonical_usize(betas[i - 2]));
         }
     }
 
-    fn linear_layer<F: RichField + Extendable<2>>(state: &[F; SPONGE_WIDTH]) -> [F; SPONGE_WIDTH] {
-        let mut new_state: [F; SPONGE_WIDTH] = [F::ZERO; SPONGE_WIDTH];
-        for i in 0..SPONGE_WIDTH {
-            for j in 0..SPONGE_WIDTH {
-                let ct = MDS_MATRIX[i][j];
+    fn linear_layer<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &[F; WIDTH],
+    ) -> [F; WIDTH]
+    where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        let mds_matrix = &Width::<WIDTH>::MDS_MATRIX;
+        let mut new_state: [F; WIDTH] = [F::ZERO; WIDTH];
+        for i in 0..WIDTH {
+            for j in 0..WIDTH {
+                let ct = mds_matrix[i][j];
                 let mut temp = state[j].clone();
                 temp.mul_assign(F::from_canonical_u64(ct));
                 new_state[i].add_assign(temp)
@@ -76,12 +87,15 @@ impl Griffin {
         new_state
     }
 
-    fn additive_constants_layer<F: RichField + Extendable<2>>(
-        state: &mut [F; SPONGE_WIDTH],
+    fn additive_constants_layer<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [F; WIDTH],
         round: usize,
-    ) {
-        for j in 0..SPONGE_WIDTH {
-            state[j] += F::from_canonical_u64(ROUND_CONSTANTS[round * SPONGE_WIDTH + j] as u64);
+    ) where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        let round_constants = Width::<WIDTH>::ROUND_CONSTANTS;
+        for j in 0..WIDTH {
+            state[j] += F::from_canonical_u64(round_constants[round * WIDTH + j] as u64);
         }
     }
 
@@ -89,7 +103,12 @@ impl Griffin {
      * GRIFFIN HASH AND COMPRESION FUNCTION *
      **************************************** */
 
-    fn griffin_permutation<F: RichField + Extendable<2>>(state: &mut [F; SPONGE_WIDTH]) {
+    fn griffin_permutation<F: RichField + Extendable<2>, const WIDTH: usize>(state: &mut [F; WIDTH])
+    where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        // Initial linear layer, without constants
+        *state = Self::linear_layer(state);
         for i in 0..(NUMBER_OF_ROUNDS - 1) {
             Self::non_linear_layer(state);
             *state = Self::linear_layer(state);
@@ -104,15 +123,31 @@ impl Griffin {
     pub fn griffin_sponge<F: RichField + Extendable<2>, const L: usize>(
         input: [F; SPONGE_RATE],
     ) -> [F; L] {
-        let mut state = [F::ZERO; SPONGE_WIDTH];
+        Self::griffin_sponge_width::<F, SPONGE_WIDTH, SPONGE_RATE, L>(input)
+    }
 
+    /// Griffin sponge over a state of `WIDTH` elements, `RATE` of which absorb the input.
+    pub fn griffin_sponge_width<
+        F: RichField + Extendable<2>,
+        const WIDTH: usize,
+        const RATE: usize,
+        const L: usize,
+    >(
+        input: [F; RATE],
+    ) -> [F; L]
+    where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        let mut state = [F::ZERO; WIDTH];
+
+        assert!(RATE < WIDTH);
         assert!(input.len() > 0);
-        assert!(input.len() % SPONGE_RATE == 0);
+        assert!(input.len() % RATE == 0);
 
         // Absorbing
         let mut absorb_index = 0;
         while absorb_index < input.len() {
-            for i in 0..SPONGE_RATE {
+            for i in 0..RATE {
                 state[i] += input[absorb_index];
                 absorb_index += 1;
             }
@@ -120,10 +155,10 @@ impl Griffin {
         }
 
         // Squeezing
-        let mut output = [F::ZERO; SPONGE_RATE];
+        let mut output = [F::ZERO; RATE];
         let mut squeeze_index = 0;
         while squeeze_index < L {
-            for i in 0..SPONGE_RATE {
+            for i in 0..RATE {
                 output[i] = state[i];
                 squeeze_index += 1;
             }
@@ -153,10 +188,12 @@ impl Griffin {
         builder.mul(x6, x)
     }
 
-    fn non_linear_layer_circuit<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn non_linear_layer_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
-    ) {
+    ) where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
         fn li<F: RichField + Extendable<2>>(
             builder: &mut CircuitBuilder<F, 2>,
             z0: &Target,
@@ -168,57 +205,73 @@ impl Griffin {
             builder.add(first_op, *z2)
         }
 
+        let alphas = Width::<WIDTH>::ALPHAS;
+        let betas = Width::<WIDTH>::BETAS;
+        // L_i takes the input x_{i-1}, not the output y_{i-1}
+        let input = *state;
+
         state[0] = builder.exp_inv(state[0]);
         state[1] = Self::sbox_circuit(state[1], builder);
 
-        let mut l = li(builder, &state[0], &state[1], &Target::default(), 2);
+        let zero = builder.zero();
+        let mut l = li(builder, &state[0], &state[1], &zero, 2);
 
         let exp = builder.square(l);
-        let op1 = builder.mul_const_add(F::from_canonical_usize(ALPHAS[0]), l, exp);
-        let op2 = builder.add_const(op1, F::from_canonical_usize(BETAS[0]));
+        let op1 = builder.mul_const_add(F::from_canonical_usize(alphas[0]), l, exp);
+        let op2 = builder.add_const(op1, F::from_canonical_usize(betas[0]));
         state[2] = builder.mul(state[2], op2);
 
-        for i in 3..SPONGE_WIDTH {
-            l = li(builder, &state[0], &state[1], &state[i - 1], i);
+        for i in 3..WIDTH {
+            l = li(builder, &state[0], &state[1], &input[i - 1], i);
             let exp = builder.square(l);
-            let op1 = builder.mul_const_add(F::from_canonical_usize(ALPHAS[i - 2]), l, exp);
-            let op2 = builder.add_const(op1, F::from_canonical_usize(BETAS[i - 2]));
+            let op1 = builder.mul_const_add(F::from_canonical_usize(alphas[i - 2]), l, exp);
+            let op2 = builder.add_const(op1, F::from_canonical_usize(betas[i - 2]));
             state[i] = builder.mul(state[i], op2);
         }
     }
 
-    fn linear_layer_circuit<F: RichField + Extendable<2>>(
-        state: &[Target; SPONGE_WIDTH],
+    fn linear_layer_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &[Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
-    ) -> [Target; SPONGE_WIDTH] {
-        let mut new_state = [Target::default(); SPONGE_WIDTH];
-        for i in 0..12 {
-            let mut sum = Target::default();
-            for j in 0..12 {
-                sum = builder.mul_const_add(F::from_canonical_u64(MDS_MATRIX[i][j]), state[j], sum);
+    ) -> [Target; WIDTH]
+    where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        let mds_matrix = &Width::<WIDTH>::MDS_MATRIX;
+        let mut new_state = [Target::default(); WIDTH];
+        for i in 0..WIDTH {
+            let mut sum = builder.zero();
+            for j in 0..WIDTH {
+                sum = builder.mul_const_add(F::from_canonical_u64(mds_matrix[i][j]), state[j], sum);
             }
             new_state[i] = sum;
         }
         new_state
     }
 
-    fn additive_constants_layer_circuit<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn additive_constants_layer_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
         round: usize,
-    ) {
-        for j in 0..SPONGE_WIDTH {
+    ) where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        let round_constants = Width::<WIDTH>::ROUND_CONSTANTS;
+        for j in 0..WIDTH {
             state[j] = builder.add_const(
                 state[j],
-                F::from_canonical_usize(ROUND_CONSTANTS[round * SPONGE_WIDTH + j]),
+                F::from_canonical_usize(round_constants[round * WIDTH + j]),
             );
         }
     }
 
-    fn griffin_permutation_circuit<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn griffin_permutation_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
-    ) {
+    ) where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        *state = Self::linear_layer_circuit(state, builder);
         for i in 0..(NUMBER_OF_ROUNDS - 1) {
             Self::non_linear_layer_circuit(state, builder);
             *state = Self::linear_layer_circuit(state, builder);
@@ -237,34 +290,52 @@ impl Griffin {
     >(
         x: [F; SPONGE_RATE],
     ) -> (CircuitData<F, C, 2>, PartialWitness<F>) {
+        Self::circuit_generation_width::<F, C, SPONGE_WIDTH, SPONGE_RATE, L>(x)
+    }
+
+    /// Circuit generation for the Griffin hash over a state of `WIDTH` elements, `RATE` of
+    /// which absorb the input
+    pub fn circuit_generation_width<
+        F: RichField + Extendable<2>,
+        C: GenericConfig<2, F = F>,
+        const WIDTH: usize,
+        const RATE: usize,
+        const L: usize,
+    >(
+        x: [F; RATE],
+    ) -> (CircuitData<F, C, 2>, PartialWitness<F>)
+    where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        assert!(RATE < WIDTH);
         assert!(x.len() > 0);
-        assert!(x.len() % SPONGE_RATE == 0);
+        assert!(x.len() % RATE == 0);
 
         let config = CircuitConfig::standard_recursion_config();
         let mut builder = CircuitBuilder::<F, 2>::new(config);
-        let input = builder.add_virtual_target_arr::<SPONGE_RATE>();
-        let mut state = builder.add_virtual_target_arr::<SPONGE_WIDTH>();
+        let input = builder.add_virtual_target_arr::<RATE>();
+        let mut state = builder.add_virtual_target_arr::<WIDTH>();
 
-        for i in 0..SPONGE_WIDTH {
-            state[i] = Target::default();
+        for i in 0..WIDTH {
+            state[i] = builder.zero();
         }
 
         // The arithmetic circuit
         // Absorbing
         let mut absorb_index = 0;
         while absorb_index < x.len() {
-            for i in 0..SPONGE_RATE {
+            for i in 0..RATE {
                 state[i] = builder.add(state[i], input[absorb_index]);
                 absorb_index += 1;
             }
             Self::griffin_permutation_circuit(&mut state, &mut builder);
         }
 
-        let mut output = [Target::default(); SPONGE_RATE];
+        let mut output = [Target::default(); RATE];
         // Squeezing
         let mut squeeze_index = 0;
         while squeeze_index < L {
-            for i in 0..SPONGE_RATE {
+            for i in 0..RATE {
                 output[i] = state[i];
                 squeeze_index += 1;
             }
@@ -319,6 +390,22 @@ impl Griffin {
     }
 }
 
+impl<const WIDTH: usize> Permutation<WIDTH> for Griffin
+where
+    Width<WIDTH>: GriffinParameters<WIDTH>,
+{
+    fn permute<F: RichField + Extendable<2>>(state: &mut [F; WIDTH]) {
+        Self::griffin_permutation(state);
+    }
+
+    fn permute_circuit<F: RichField + Extendable<2>>(
+        state: &mut [Target; WIDTH],
+        builder: &mut CircuitBuilder<F, 2>,
+    ) {
+        Self::griffin_permutation_circuit(state, builder);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use plonky2::{
@@ -327,6 +414,7 @@ mod test {
     };
 
     use super::*;
+    use crate::hashes::kat;
 
     #[test]
     fn test_griffin_hash() {
@@ -348,4 +436,58 @@ mod test {
             Griffin::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw);
         Griffin::proof_verification::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &proof);
     }
+
+    fn check_width<const WIDTH: usize, const RATE: usize>()
+    where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        let input: [GoldilocksField; RATE] =
+            core::array::from_fn(|i| GoldilocksField(i as u64 + 1));
+        let output = Griffin::griffin_sponge_width::<GoldilocksField, WIDTH, RATE, 4>(input);
+
+        let (data, pw) = Griffin::circuit_generation_width::<
+            GoldilocksField,
+            PoseidonGoldilocksConfig,
+            WIDTH,
+            RATE,
+            4,
+        >(input);
+        let proof =
+            Griffin::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw);
+        assert_eq!(proof.public_inputs, output);
+        assert!(data.verify(proof).is_ok());
+    }
+
+    #[test]
+    fn test_griffin_widths() {
+        check_width::<8, 4>();
+        check_width::<16, 12>();
+        check_width::<24, 20>();
+    }
+
+    fn check_kat<const WIDTH: usize, const RATE: usize>()
+    where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        kat::check_kat::<Griffin, _, WIDTH, RATE>(
+            include_str!("../../../kat/griffin.json"),
+            "griffin",
+            Griffin::griffin_sponge_width::<GoldilocksField, WIDTH, RATE, 4>,
+            Griffin::circuit_generation_width::<
+                GoldilocksField,
+                PoseidonGoldilocksConfig,
+                WIDTH,
+                RATE,
+                4,
+            >,
+        );
+    }
+
+    #[test]
+    fn test_griffin_kat() {
+        check_kat::<8, 4>();
+        check_kat::<12, 8>();
+        check_kat::<16, 12>();
+        check_kat::<24, 20>();
+    }
 }
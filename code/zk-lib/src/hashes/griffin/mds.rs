@@ -11,4 +11,22 @@ pub const MDS_MATRIX: [[u64; 12]; 12] = [
     [4, 6, 1, 1, 4, 6, 1, 1, 8, 12, 2, 2],
     [1, 3, 5, 7, 1, 3, 5, 7, 2, 6, 10, 14],
     [1, 1, 4, 6, 1, 1, 4, 6, 2, 2, 8, 12],
-];
\ No newline at end of file
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::goldilocks_field::GoldilocksField;
+
+    use super::*;
+    use crate::hashes::griffin::SPONGE_WIDTH;
+    use crate::parameters::{griffin::mds_matrix, mds::to_u64_matrix};
+
+    #[test]
+    fn test_griffin_mds() {
+        let generated = mds_matrix::<GoldilocksField>(SPONGE_WIDTH);
+        assert_eq!(
+            to_u64_matrix(&generated),
+            MDS_MATRIX.map(|row| row.to_vec())
+        );
+    }
+}
@@ -14,6 +14,7 @@ use plonky2::{
 mod constants;
 pub mod griffin;
 mod mds;
+mod widths;
 
 pub const SPONGE_RATE: usize = 8;
 pub const SPONGE_CAPACITY: usize = 4;
@@ -24,6 +25,18 @@ pub const D_INV: u64 = 10540996611094048183;
 
 pub const NUMBER_OF_ROUNDS: usize = 8;
 
+/// Constants of the Griffin permutation for a state width, implemented for
+/// [`Width`](super::Width) 8, 12, 16 and 24.
+pub trait GriffinParameters<const WIDTH: usize> {
+    /// The `alpha_i` of the non-linear layer, for branches 2 to `WIDTH - 1`.
+    const ALPHAS: &'static [usize];
+    /// The `beta_i` of the non-linear layer, for branches 2 to `WIDTH - 1`.
+    const BETAS: &'static [usize];
+    /// The `WIDTH * (NUMBER_OF_ROUNDS - 1)` additive round constants.
+    const ROUND_CONSTANTS: &'static [usize];
+    const MDS_MATRIX: [[u64; WIDTH]; WIDTH];
+}
+
 trait CircuitBuilderExtensionsGriff<F: RichField + Extendable<D>, const D: usize> {
     fn exp_inv(&mut self, x: Target) -> Target;
     fn exp_inv_extension(&mut self, x: ExtensionTarget<D>) -> ExtensionTarget<D>;
@@ -116,3 +129,15 @@ impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D>
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parameters::griffin::{number_of_rounds, round_bounds};
+
+    #[test]
+    fn test_number_of_rounds() {
+        assert!(NUMBER_OF_ROUNDS >= round_bounds(SPONGE_WIDTH, D, 128).minimum());
+        assert_eq!(NUMBER_OF_ROUNDS, number_of_rounds(SPONGE_WIDTH, D, 128));
+    }
+}
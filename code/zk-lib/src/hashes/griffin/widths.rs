@@ -0,0 +1,641 @@
+// Griffin constants for the widths other than 12, generated for a capacity of 4 with
+// `crate::parameters::griffin` as in `griffin_constants.sage`.
+
+use super::{constants, mds, GriffinParameters};
+use crate::hashes::Width;
+
+impl GriffinParameters<8> for Width<8> {
+    const ALPHAS: &'static [usize] = &ALPHAS_8;
+    const BETAS: &'static [usize] = &BETAS_8;
+    const ROUND_CONSTANTS: &'static [usize] = &ROUND_CONSTANTS_8;
+    const MDS_MATRIX: [[u64; 8]; 8] = MDS_MATRIX_8;
+}
+
+impl GriffinParameters<12> for Width<12> {
+    const ALPHAS: &'static [usize] = &constants::ALPHAS;
+    const BETAS: &'static [usize] = &constants::BETAS;
+    const ROUND_CONSTANTS: &'static [usize] = &constants::ROUND_CONSTANTS;
+    const MDS_MATRIX: [[u64; 12]; 12] = mds::MDS_MATRIX;
+}
+
+impl GriffinParameters<16> for Width<16> {
+    const ALPHAS: &'static [usize] = &ALPHAS_16;
+    const BETAS: &'static [usize] = &BETAS_16;
+    const ROUND_CONSTANTS: &'static [usize] = &ROUND_CONSTANTS_16;
+    const MDS_MATRIX: [[u64; 16]; 16] = MDS_MATRIX_16;
+}
+
+impl GriffinParameters<24> for Width<24> {
+    const ALPHAS: &'static [usize] = &ALPHAS_24;
+    const BETAS: &'static [usize] = &BETAS_24;
+    const ROUND_CONSTANTS: &'static [usize] = &ROUND_CONSTANTS_24;
+    const MDS_MATRIX: [[u64; 24]; 24] = MDS_MATRIX_24;
+}
+
+pub const ALPHAS_8: [usize; 6] = [
+    6303398607380181568,
+    12606797214760363136,
+    463451752725960383,
+    6766850360106141951,
+    13070248967486323519,
+    926903505451920766,
+];
+
+pub const BETAS_8: [usize; 6] = [
+    5698628486727258041,
+    4347769877494447843,
+    14394168241716153727,
+    17391079509977791372,
+    13338503682279360778,
+    2236440758620861945,
+];
+
+pub const ROUND_CONSTANTS_8: [usize; 56] = [
+    9692712401870945221,
+    7618007584389424767,
+    5248032629877155397,
+    3331263627507477698,
+    860199187432911550,
+    10360526140302824670,
+    5014858186237911359,
+    4161019260461204222,
+    2649891723669882704,
+    15035697086627576083,
+    14140087988207356741,
+    357780579603925138,
+    273712483418536090,
+    348552596175072640,
+    11116926243792475367,
+    2475357435469270767,
+    9513699262061178678,
+    11735848814479196467,
+    12888397717055708631,
+    15194236579723079985,
+    14734897209064082180,
+    9352307275330595094,
+    2536293522055086772,
+    1551701365424645656,
+    17180574791560887028,
+    10973179380721509279,
+    15451549433162538377,
+    11230437049044589131,
+    14416448585168854586,
+    13520950449774622599,
+    14110026253178816443,
+    7562226163074683487,
+    15625584526294513461,
+    12868717640985007163,
+    5045176603305276542,
+    6821445918259551845,
+    15049718154108882541,
+    676731535772312475,
+    14779363889066167393,
+    17108914943169063073,
+    17529530613938644968,
+    13801329800663243071,
+    12666329335088484031,
+    10289051774796875319,
+    46795987162557096,
+    8590445841426612555,
+    7174111149249058757,
+    5820086182616968416,
+    18362920096257427776,
+    18336590902193839311,
+    17082524670299631881,
+    2963587252058675526,
+    2307961039727424150,
+    17730937419471724169,
+    13943985318970238834,
+    8435322757080491462,
+];
+
+pub const MDS_MATRIX_8: [[u64; 8]; 8] = [
+    [10, 14, 2, 6, 5, 7, 1, 3],
+    [8, 12, 2, 2, 4, 6, 1, 1],
+    [2, 6, 10, 14, 1, 3, 5, 7],
+    [2, 2, 8, 12, 1, 1, 4, 6],
+    [5, 7, 1, 3, 10, 14, 2, 6],
+    [4, 6, 1, 1, 8, 12, 2, 2],
+    [1, 3, 5, 7, 2, 6, 10, 14],
+    [1, 1, 4, 6, 2, 2, 8, 12],
+];
+
+pub const ALPHAS_16: [usize; 14] = [
+    18030149750860539994,
+    17613555432306495667,
+    17196961113752451340,
+    16780366795198407013,
+    16363772476644362686,
+    15947178158090318359,
+    15530583839536274032,
+    15113989520982229705,
+    14697395202428185378,
+    14280800883874141051,
+    13864206565320096724,
+    13447612246766052397,
+    13031017928212008070,
+    12614423609657963743,
+];
+
+pub const BETAS_16: [usize; 14] = [
+    9559261164796803665,
+    1343556520358046018,
+    12246374205512895701,
+    5374226081432184072,
+    17620600286945079773,
+    12092008683222414162,
+    7235195339678771560,
+    3050160256314151967,
+    17983647502543139704,
+    15142168939536566129,
+    12972468636709015563,
+    11474546594060488006,
+    10648402811590983458,
+    10494037289300501919,
+];
+
+pub const ROUND_CONSTANTS_16: [usize; 112] = [
+    15895623202587418121,
+    6245819979719555770,
+    14007388521660693729,
+    17508126106799047826,
+    15282006842288167657,
+    6389306178036346232,
+    4294785060887535,
+    8926823142746677334,
+    4364877111862701624,
+    10628464693298833631,
+    5982168522523672763,
+    6084159050868610228,
+    10982822841954826426,
+    11083304242470540844,
+    5378997362458072058,
+    7660535768818258252,
+    8727628214141727783,
+    17975901142060312685,
+    4725571673371830974,
+    11972414371499741687,
+    8795486165806118518,
+    766480591620771794,
+    14481375349444578295,
+    6997282878614092968,
+    300984304880835870,
+    15028155524316501714,
+    6290657695781340455,
+    8019688219427292136,
+    5237485063014668893,
+    15194470036912950113,
+    10572634606993915142,
+    6355541115900178801,
+    15876108994517321047,
+    1795468968100748437,
+    2834417458041497976,
+    9970613442844036148,
+    9463376790431565034,
+    13950891497178656526,
+    14741573913785125266,
+    3282161637383030113,
+    10952688863998210890,
+    17682309628598365210,
+    6946983534198818230,
+    702506996194985715,
+    1996780517317703192,
+    6673862078475826338,
+    9960545048033951484,
+    1182940038118338272,
+    13026680159275777742,
+    10705205595434653775,
+    6334133964582004264,
+    13501681300990166147,
+    6115616498020039786,
+    14371844814876403317,
+    10016742250943073950,
+    13618571181104496480,
+    3871995619650212660,
+    6831014938224096493,
+    2667742273409044255,
+    5286129593669211899,
+    11608933533252346898,
+    4252469172114960863,
+    8971866555061065787,
+    6306803529706583830,
+    9845367972420152789,
+    12080949855813886481,
+    2844408636523698901,
+    5074931233638019719,
+    2499083262844221644,
+    4544651430524031207,
+    1112117247062064628,
+    8610578101769581304,
+    13068116560206702989,
+    1608799597439317700,
+    18311703603542030809,
+    16688502255910328831,
+    3539055226751009555,
+    16275012725049837915,
+    5972037128379296319,
+    5408530470720996520,
+    15946648342971262804,
+    13773141161728287029,
+    12416408544927841857,
+    7066572958513833617,
+    9750146913440445760,
+    6646651928845524280,
+    1324435069662916738,
+    18375173399889141399,
+    10017027370784510865,
+    12390781440015067756,
+    8783054816277215310,
+    12722850783648254700,
+    4680118093160026676,
+    14832180266890479279,
+    8858589970022343273,
+    16099558422130375820,
+    2048480761046071490,
+    8626868979831395298,
+    5576874521439171354,
+    7166712786961288975,
+    14402096059195710639,
+    15054196131144692155,
+    9112314078894747342,
+    16334478889368746285,
+    17020940918982596522,
+    13732827253344856810,
+    8949221786565199835,
+    6048965174182974138,
+    1061456514705786419,
+    10925616933639571156,
+    3012361922962395496,
+    6337248085282441393,
+];
+
+pub const MDS_MATRIX_16: [[u64; 16]; 16] = [
+    [10, 14, 2, 6, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3],
+    [8, 12, 2, 2, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1],
+    [2, 6, 10, 14, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7],
+    [2, 2, 8, 12, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6],
+    [5, 7, 1, 3, 10, 14, 2, 6, 5, 7, 1, 3, 5, 7, 1, 3],
+    [4, 6, 1, 1, 8, 12, 2, 2, 4, 6, 1, 1, 4, 6, 1, 1],
+    [1, 3, 5, 7, 2, 6, 10, 14, 1, 3, 5, 7, 1, 3, 5, 7],
+    [1, 1, 4, 6, 2, 2, 8, 12, 1, 1, 4, 6, 1, 1, 4, 6],
+    [5, 7, 1, 3, 5, 7, 1, 3, 10, 14, 2, 6, 5, 7, 1, 3],
+    [4, 6, 1, 1, 4, 6, 1, 1, 8, 12, 2, 2, 4, 6, 1, 1],
+    [1, 3, 5, 7, 1, 3, 5, 7, 2, 6, 10, 14, 1, 3, 5, 7],
+    [1, 1, 4, 6, 1, 1, 4, 6, 2, 2, 8, 12, 1, 1, 4, 6],
+    [5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 10, 14, 2, 6],
+    [4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 8, 12, 2, 2],
+    [1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 2, 6, 10, 14],
+    [1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 2, 2, 8, 12],
+];
+
+pub const ALPHAS_24: [usize; 22] = [
+    7165083796199929416,
+    14330167592399858832,
+    3048507319185203927,
+    10213591115385133343,
+    17378674911585062759,
+    6097014638370407854,
+    13262098434570337270,
+    1980438161355682365,
+    9145521957555611781,
+    16310605753755541197,
+    5028945480540886292,
+    12194029276740815708,
+    912369003526160803,
+    8077452799726090219,
+    15242536595926019635,
+    3960876322711364730,
+    11125960118911294146,
+    18291043915111223562,
+    7009383641896568657,
+    14174467438096498073,
+    2892807164881843168,
+    10057890961081772584,
+];
+
+pub const BETAS_24: [usize; 22] = [
+    4309428553064824138,
+    17237714212259296552,
+    1891368838754248600,
+    13610624640793433245,
+    15501993479547681845,
+    7565475355016994400,
+    8247814336615955231,
+    17549010424344564338,
+    17022319548788237400,
+    6667741709946974417,
+    4932020977235359710,
+    11815157350653393279,
+    8870406760786490803,
+    14544513277049236603,
+    10390732830027046358,
+    14855809489134504389,
+    9492999184957026375,
+    12749045986909196637,
+    6177205825576430854,
+    8224222770373313347,
+    443352751885259795,
+    1281339839526854519,
+];
+
+pub const ROUND_CONSTANTS_24: [usize; 168] = [
+    13240298964247984756,
+    17333401045608821106,
+    16838610673378931001,
+    3598296859403037906,
+    17005965841783681840,
+    13088024962607103449,
+    2600191787617097416,
+    15910205699991472815,
+    10371186010937729009,
+    6913267115300028059,
+    2437085101121552843,
+    6341875898008082165,
+    6937750249790387220,
+    16722135135764116689,
+    12936656788907339639,
+    1744687143917249609,
+    10916453340148031910,
+    6698376913915335113,
+    15245506126609173425,
+    12270323507999062619,
+    9722164842890199156,
+    4184907470635786601,
+    1112991919064231090,
+    17588525487921421884,
+    10540849637616824023,
+    11276642558361847370,
+    5204956530259018535,
+    16500407121170411440,
+    7588171561668080252,
+    9567455650970485961,
+    9198460588103344995,
+    1366758968446583350,
+    13758773616826304687,
+    16231833134336950026,
+    10381119458208798325,
+    3321969150368424719,
+    10032458716847487757,
+    3712318914098666003,
+    10327073584667999944,
+    4263421789915914187,
+    11554273906368371299,
+    1748545915613939602,
+    6488037311200468383,
+    6639670388406537706,
+    2652869377079191261,
+    13021353279098491998,
+    5032008249101183743,
+    4865670618143067767,
+    16562060545307348225,
+    17213858368924210953,
+    16627910674785220674,
+    3960831901125534388,
+    12327982986580152851,
+    11247298071090974052,
+    12022164088231914926,
+    4221478826655244303,
+    2736782801232800738,
+    2240243065082342633,
+    16046514056678859750,
+    7932094884679437970,
+    1707984852365252807,
+    366190882676910195,
+    6004960493331350723,
+    15647430688607578550,
+    10657290118745377745,
+    5292911667781345744,
+    14536380422020317109,
+    9374789936319766242,
+    490883393315682929,
+    11882042823367789545,
+    9408516302676754242,
+    3994412375906561124,
+    15846253555946213436,
+    16115837126489495238,
+    17648464566444252546,
+    7651307419547669645,
+    16642689808168298947,
+    7659589014862605304,
+    9112341552189462485,
+    5651883657364812904,
+    7740663967582479400,
+    11725542321379150913,
+    18425109218478702473,
+    12636452979087592843,
+    6526495275314461920,
+    2198144605981466565,
+    7466894428246903469,
+    12533280688808720556,
+    448318327627558831,
+    3014365434169569671,
+    15928525902539523961,
+    17810065347362748047,
+    3033722336308099844,
+    12550509252775613766,
+    4786827965553941510,
+    11490294809510641975,
+    7503923933550720878,
+    1048962783061709685,
+    2826252403053456642,
+    5965698800501523723,
+    16576099592486834399,
+    3750085000630287369,
+    11337447046541374200,
+    5534937462872247308,
+    12047906499844879770,
+    2026807169278509065,
+    6174367792728677240,
+    3863524785288526421,
+    7020463056013962806,
+    8965066607096650050,
+    17503158940034098619,
+    6286580764390577476,
+    13489769196834742451,
+    3210911046773634787,
+    11098251001045423530,
+    841439480251119519,
+    1930245985486292949,
+    7050317448449356077,
+    3991815834578939351,
+    17162373394349584596,
+    16327309174311817516,
+    13920679475029528420,
+    14986377445600266078,
+    7511038449679293521,
+    14571755905878208105,
+    3298465889621998567,
+    16261138184112936070,
+    11398031037638485972,
+    11534327906034863168,
+    1484642285407012421,
+    16966965670201091344,
+    14944572891864918385,
+    3753065828958522892,
+    12526266465614719602,
+    8398576267781908350,
+    12166905199550712075,
+    4967459265075804866,
+    17877336667299291868,
+    11771108431443376297,
+    11806824190617314658,
+    7271056146978505601,
+    16164763718420322450,
+    1262885928886471005,
+    16362010856402701714,
+    3657950612850436145,
+    4145339502434642007,
+    5585152090372785400,
+    14066652239904345774,
+    5244491337836445437,
+    13616849186199417909,
+    7833398420468012906,
+    3692871664896456580,
+    11322440521415455927,
+    9842475122029812162,
+    17359985433798222653,
+    15749425179078596171,
+    14219500373810474444,
+    16982151154284885075,
+    16201639245158626700,
+    9947975796449398545,
+    3360668894700279556,
+    9183469329217068338,
+    11991194521456081621,
+    15562513590626782732,
+    5229936090467214767,
+    18300976847079254981,
+    17864988338414805535,
+    9453960453523866013,
+];
+
+pub const MDS_MATRIX_24: [[u64; 24]; 24] = [
+    [
+        10, 14, 2, 6, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3,
+    ],
+    [
+        8, 12, 2, 2, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1,
+    ],
+    [
+        2, 6, 10, 14, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7,
+    ],
+    [
+        2, 2, 8, 12, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6,
+    ],
+    [
+        5, 7, 1, 3, 10, 14, 2, 6, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3,
+    ],
+    [
+        4, 6, 1, 1, 8, 12, 2, 2, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1,
+    ],
+    [
+        1, 3, 5, 7, 2, 6, 10, 14, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7,
+    ],
+    [
+        1, 1, 4, 6, 2, 2, 8, 12, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6,
+    ],
+    [
+        5, 7, 1, 3, 5, 7, 1, 3, 10, 14, 2, 6, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3,
+    ],
+    [
+        4, 6, 1, 1, 4, 6, 1, 1, 8, 12, 2, 2, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1,
+    ],
+    [
+        1, 3, 5, 7, 1, 3, 5, 7, 2, 6, 10, 14, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7,
+    ],
+    [
+        1, 1, 4, 6, 1, 1, 4, 6, 2, 2, 8, 12, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6,
+    ],
+    [
+        5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 10, 14, 2, 6, 5, 7, 1, 3, 5, 7, 1, 3,
+    ],
+    [
+        4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 8, 12, 2, 2, 4, 6, 1, 1, 4, 6, 1, 1,
+    ],
+    [
+        1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 2, 6, 10, 14, 1, 3, 5, 7, 1, 3, 5, 7,
+    ],
+    [
+        1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 2, 2, 8, 12, 1, 1, 4, 6, 1, 1, 4, 6,
+    ],
+    [
+        5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 10, 14, 2, 6, 5, 7, 1, 3,
+    ],
+    [
+        4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 8, 12, 2, 2, 4, 6, 1, 1,
+    ],
+    [
+        1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 2, 6, 10, 14, 1, 3, 5, 7,
+    ],
+    [
+        1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 2, 2, 8, 12, 1, 1, 4, 6,
+    ],
+    [
+        5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 10, 14, 2, 6,
+    ],
+    [
+        4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 8, 12, 2, 2,
+    ],
+    [
+        1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7, 2, 6, 10, 14,
+    ],
+    [
+        1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 1, 1, 4, 6, 2, 2, 8, 12,
+    ],
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{goldilocks_field::GoldilocksField, types::PrimeField64};
+
+    use super::*;
+    use crate::hashes::griffin::{D, NUMBER_OF_ROUNDS, SPONGE_CAPACITY};
+    use crate::parameters::{
+        griffin::{constants, mds_matrix, number_of_rounds, round_bounds},
+        mds::to_u64_matrix,
+    };
+
+    fn check_width<const WIDTH: usize>()
+    where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        let generated = constants::<GoldilocksField>(WIDTH, SPONGE_CAPACITY, 128, NUMBER_OF_ROUNDS);
+        let to_usize = |v: &[GoldilocksField]| -> Vec<usize> {
+            v.iter().map(|x| x.to_canonical_u64() as usize).collect()
+        };
+        assert_eq!(to_usize(&generated.alphas), Width::<WIDTH>::ALPHAS);
+        assert_eq!(to_usize(&generated.betas), Width::<WIDTH>::BETAS);
+        assert_eq!(
+            to_usize(&generated.round_constants),
+            Width::<WIDTH>::ROUND_CONSTANTS
+        );
+        assert_eq!(
+            to_u64_matrix(&mds_matrix::<GoldilocksField>(WIDTH)),
+            Width::<WIDTH>::MDS_MATRIX.map(|row| row.to_vec())
+        );
+    }
+
+    fn check_rounds<const WIDTH: usize>()
+    where
+        Width<WIDTH>: GriffinParameters<WIDTH>,
+    {
+        assert!(NUMBER_OF_ROUNDS >= round_bounds(WIDTH, D, 128).minimum());
+        assert_eq!(NUMBER_OF_ROUNDS, number_of_rounds(WIDTH, D, 128));
+    }
+
+    #[test]
+    fn test_griffin_widths() {
+        check_width::<8>();
+        check_width::<16>();
+        check_width::<24>();
+    }
+
+    // Every width shares the rounds of width 12
+    #[test]
+    fn test_number_of_rounds() {
+        check_rounds::<8>();
+        check_rounds::<12>();
+        check_rounds::<16>();
+        check_rounds::<24>();
+    }
+}
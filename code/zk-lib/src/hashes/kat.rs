@@ -0,0 +1,137 @@
+// Known-answer tests of the hashes
+//
+// The vectors in `kat/` are computed by `kat/generate.py` from the reference implementation of
+// each hash, over Goldilocks. Every vector gives the width and rate of the state, the input
+// block and the expected output, all in hex. The `sponge` vectors go through the SAFE sponge
+// of `crate::sponge` with inputs of several lengths.
+
+use core::fmt::Debug;
+
+use plonky2::{
+    field::{goldilocks_field::GoldilocksField, types::Field},
+    iop::witness::PartialWitness,
+    plonk::{circuit_data::CircuitData, config::PoseidonGoldilocksConfig},
+};
+use serde_json::Value;
+
+use super::Permutation;
+use crate::sponge::{Call, Sponge};
+
+type F = GoldilocksField;
+type C = PoseidonGoldilocksConfig;
+
+/// A vector of a KAT file. `width` and `rate` are zero in the MiMC file, which has no state.
+pub(crate) struct Vector {
+    pub width: usize,
+    pub rate: usize,
+    pub input: Vec<F>,
+    pub output: Vec<F>,
+}
+
+pub(crate) fn elements(value: &Value) -> Vec<F> {
+    value
+        .as_array()
+        .expect("an array of elements")
+        .iter()
+        .map(|element| {
+            let digits = element.as_str().expect("an element in hex");
+            let value = u64::from_str_radix(digits.trim_start_matches("0x"), 16).unwrap();
+            F::from_canonical_u64(value)
+        })
+        .collect()
+}
+
+/// The parsed KAT file `json`, which must be the file of the hash `name`.
+pub(crate) fn parse(json: &str, name: &str) -> Value {
+    let file: Value = serde_json::from_str(json).expect("a valid KAT file");
+    assert_eq!(file["hash"], name);
+    assert_eq!(file["field"], "goldilocks");
+    file
+}
+
+/// The vectors of the KAT file `json` with a state of `width` elements. All the vectors when
+/// `width` is zero.
+pub(crate) fn vectors(json: &str, name: &str, width: usize) -> Vec<Vector> {
+    let file = parse(json, name);
+    select(&file["vectors"], name, width)
+}
+
+fn select(vectors: &Value, name: &str, width: usize) -> Vec<Vector> {
+    let vectors: Vec<Vector> = vectors
+        .as_array()
+        .expect("a list of vectors")
+        .iter()
+        .map(|vector| Vector {
+            width: vector["width"].as_u64().unwrap_or(0) as usize,
+            rate: vector["rate"].as_u64().unwrap_or(0) as usize,
+            input: elements(&vector["input"]),
+            output: elements(&vector["output"]),
+        })
+        .filter(|vector| width == 0 || vector.width == width)
+        .collect();
+    assert!(!vectors.is_empty(), "no {} vector of width {}", name, width);
+    vectors
+}
+
+/// Checks the `sponge` vectors of `json` with a state of `WIDTH` elements: each input is
+/// absorbed at once by a [`Sponge`] over `H`, which then squeezes the output.
+pub(crate) fn check_sponge<H: Permutation<WIDTH>, const WIDTH: usize, const RATE: usize>(
+    json: &str,
+    name: &str,
+) {
+    let file = parse(json, name);
+    let domain_sep = file["sponge"]["domain"]
+        .as_u64()
+        .expect("a domain separator");
+    for vector in select(&file["sponge"]["vectors"], name, WIDTH) {
+        assert_eq!(vector.rate, RATE);
+        let iopattern = [
+            Call::Absorb(vector.input.len()),
+            Call::Squeeze(vector.output.len()),
+        ];
+        let mut sponge = Sponge::<H, F, WIDTH, RATE>::start(iopattern, domain_sep).unwrap();
+        sponge.absorb(&vector.input).unwrap();
+        assert_eq!(sponge.squeeze(vector.output.len()).unwrap(), vector.output);
+        sponge.finish().unwrap();
+    }
+}
+
+/// Checks the vectors of `json` with a state of `WIDTH` elements, or all of them when `WIDTH`
+/// is zero: `hash` must give the output of each input block, and the circuit built by
+/// `circuit` must prove it in its last public inputs.
+pub(crate) fn check_vectors<O, const WIDTH: usize, const RATE: usize>(
+    json: &str,
+    name: &str,
+    hash: impl Fn([F; RATE]) -> O,
+    circuit: impl Fn([F; RATE]) -> (CircuitData<F, C, 2>, PartialWitness<F>),
+) where
+    O: Debug,
+    Vec<F>: PartialEq<O>,
+{
+    for vector in vectors(json, name, WIDTH) {
+        assert_eq!(vector.rate, if WIDTH == 0 { 0 } else { RATE });
+        let input: [F; RATE] = vector.input.try_into().unwrap();
+        assert_eq!(vector.output, hash(input));
+
+        let (data, pw) = circuit(input);
+        let proof = data.prove(pw).unwrap();
+        let outputs = proof.public_inputs.len() - vector.output.len();
+        assert_eq!(proof.public_inputs[outputs..], vector.output);
+    }
+}
+
+/// Checks the vectors and the `sponge` vectors of the hash `H` with a state of `WIDTH`
+/// elements, as [`check_vectors`] and [`check_sponge`] do.
+pub(crate) fn check_kat<H, O, const WIDTH: usize, const RATE: usize>(
+    json: &str,
+    name: &str,
+    hash: impl Fn([F; RATE]) -> O,
+    circuit: impl Fn([F; RATE]) -> (CircuitData<F, C, 2>, PartialWitness<F>),
+) where
+    H: Permutation<WIDTH>,
+    O: Debug,
+    Vec<F>: PartialEq<O>,
+{
+    check_vectors::<O, WIDTH, RATE>(json, name, hash, circuit);
+    check_sponge::<H, WIDTH, RATE>(json, name);
+}
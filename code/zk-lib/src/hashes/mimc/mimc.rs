@@ -8,6 +8,8 @@ use plonky2::plonk::circuit_builder::CircuitBuilder;
 use plonky2::plonk::circuit_data::{CircuitConfig, CircuitData};
 use plonky2::plonk::config::GenericConfig;
 use plonky2::plonk::proof::ProofWithPublicInputs;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 
 const ROUNDS: usize = 41;
 
@@ -38,6 +40,15 @@ where
         }
     }
 
+    /// Creates a new MiMC struct whose constants are drawn from a generator seeded with `seed`,
+    /// so that the same seed always gives the same hash
+    pub fn new_from_seed(seed: u64) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        Self {
+            constants: (0..ROUNDS).map(|_| F::sample(&mut rng)).collect(),
+        }
+    }
+
     /* **********************************
        **********************************
        *     MiMC HASH FUNCTION     *
@@ -144,6 +155,7 @@ mod tests {
     };
 
     use super::*;
+    use crate::{hashes::kat, registry::MIMC_SEED};
 
     #[test]
     fn permute_mimc() {
@@ -160,4 +172,35 @@ mod tests {
         let proof = MiMC::proof_generation(&data, &pw);
         MiMC::proof_verification(&data, &proof);
     }
+
+    #[test]
+    fn mimc_kat() {
+        let json = include_str!("../../../kat/mimc.json");
+        let constants = kat::elements(&kat::parse(json, "mimc")["constants"]);
+        let mimc = MiMC::<GoldilocksField>::new(constants);
+
+        type C = PoseidonGoldilocksConfig;
+
+        // The input is public as well as the hash
+        kat::check_vectors::<_, 0, 2>(
+            json,
+            "mimc",
+            |input| [mimc.permute_rounds(input)],
+            |input| mimc.circuit_generation::<C, 2>(input),
+        );
+    }
+
+    #[test]
+    fn mimc_seeded_kat() {
+        let json = include_str!("../../../kat/mimc.json");
+        let seeded = &kat::parse(json, "mimc")["seeded"];
+        assert_eq!(seeded["seed"].as_u64(), Some(MIMC_SEED));
+        let mimc = MiMC::<GoldilocksField>::new_from_seed(MIMC_SEED);
+        assert_eq!(mimc.constants, kat::elements(&seeded["constants"]));
+
+        for vector in seeded["vectors"].as_array().unwrap() {
+            let input: [GoldilocksField; 2] = kat::elements(&vector["input"]).try_into().unwrap();
+            assert_eq!(kat::elements(&vector["output"]), [mimc.permute_rounds(input)]);
+        }
+    }
 }
\ No newline at end of file
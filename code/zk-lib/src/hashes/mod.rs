@@ -3,4 +3,43 @@ pub mod poseidon;
 pub mod rescue_prime;
 pub mod griffin;
 pub mod anemoi;
-pub mod arion;
\ No newline at end of file
+pub mod arion;
+
+#[cfg(test)]
+pub(crate) mod kat;
+
+use plonky2::{
+    field::extension::Extendable, hash::hash_types::RichField, iop::target::Target,
+    plonk::circuit_builder::CircuitBuilder,
+};
+
+/// State width of a permutation. Each hash implements its parameter trait for the widths it
+/// ships constants for (8, 12, 16 and 24), so an unsupported width does not compile.
+pub struct Width<const WIDTH: usize>;
+
+/// Permutation of a state of `WIDTH` elements, natively and in a circuit. Implemented by every
+/// hash for the widths it supports, so constructions such as the
+/// [`Sponge`](crate::sponge::Sponge) can be written once for all of them.
+pub trait Permutation<const WIDTH: usize> {
+    fn permute<F: RichField + Extendable<2>>(state: &mut [F; WIDTH]);
+
+    fn permute_circuit<F: RichField + Extendable<2>>(
+        state: &mut [Target; WIDTH],
+        builder: &mut CircuitBuilder<F, 2>,
+    );
+}
+
+/// Copies a `M x N` table into a flat array of `K = M * N` entries, row after row, to expose
+/// tables of different shapes as slices.
+pub(crate) const fn flatten<T: Copy, const N: usize, const M: usize, const K: usize>(
+    table: &[[T; N]; M],
+) -> [T; K] {
+    assert!(N * M == K);
+    let mut flat = [table[0][0]; K];
+    let mut i = 0;
+    while i < K {
+        flat[i] = table[i / N][i % N];
+        i += 1;
+    }
+    flat
+}
@@ -98,4 +98,19 @@ pub const ALL_ROUND_CONSTANTS: [u64; MAX_WIDTH * N_ROUNDS]  = [
     0xf3c12fe54d5c653b, 0x40b9e922ed9771e2, 0x551f5b0fbe7b1840, 0x25032aa7c4cb1811,
     0xaaed34074b164346, 0x8ffd96bbf9c9c81d, 0x70fc91eb5937085c, 0x7f795e2a5f915440,
     0x4543d9df5476d3cb, 0xf172d73e004fc90d, 0xdfd1c4febcc81238, 0xbc8dfb627fe558fc,
-];
\ No newline at end of file
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{goldilocks_field::GoldilocksField, types::PrimeField64};
+
+    use super::*;
+    use crate::parameters::poseidon::plonky2_round_constants;
+
+    #[test]
+    fn test_poseidon_constants() {
+        let generated = plonky2_round_constants::<GoldilocksField>(MAX_WIDTH * N_ROUNDS);
+        let generated: Vec<u64> = generated.iter().map(|x| x.to_canonical_u64()).collect();
+        assert_eq!(generated, ALL_ROUND_CONSTANTS);
+    }
+}
@@ -19,4 +19,22 @@ pub const MDS_MATRIX: [[u64; 12]; 12] = [
     [16, 2, 28, 13, 13, 39, 18, 34, 20, 17, 15, 41],
     [41, 16, 2, 28, 13, 13, 39, 18, 34, 20, 17, 15],
     [15, 41, 16, 2, 28, 13, 13, 39, 18, 34, 20, 17],
-];
\ No newline at end of file
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::goldilocks_field::GoldilocksField;
+
+    use super::*;
+    use crate::parameters::{
+        mds::{is_mds, to_u64_matrix},
+        poseidon::circulant_plus_diagonal,
+    };
+
+    #[test]
+    fn test_poseidon_mds() {
+        let matrix = circulant_plus_diagonal::<GoldilocksField>(&MDS_MATRIX_CIRC, &MDS_MATRIX_DIAG);
+        assert_eq!(to_u64_matrix(&matrix), MDS_MATRIX.map(|row| row.to_vec()));
+        assert!(is_mds(&matrix));
+    }
+}
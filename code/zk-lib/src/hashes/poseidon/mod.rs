@@ -1,6 +1,7 @@
 pub mod poseidon;
 mod constants;
 mod mds;
+mod widths;
 
 pub const SPONGE_RATE: usize = 8;
 pub const SPONGE_CAPACITY: usize = 4;
@@ -11,4 +12,34 @@ pub const N_FULL_ROUNDS_TOTAL: usize = 2 * HALF_N_FULL_ROUNDS;
 pub const N_PARTIAL_ROUNDS: usize = 22;
 pub const N_ROUNDS: usize = N_FULL_ROUNDS_TOTAL + N_PARTIAL_ROUNDS; // 30
 pub const MAX_WIDTH: usize = 12;
-pub const N_CONSTANTS: usize = MAX_WIDTH * N_ROUNDS;
\ No newline at end of file
+pub const N_CONSTANTS: usize = MAX_WIDTH * N_ROUNDS;
+
+/// Constants of the Poseidon permutation for a state width, implemented for
+/// [`Width`](super::Width) 8, 12, 16 and 24.
+pub trait PoseidonParameters<const WIDTH: usize> {
+    /// The `WIDTH * N_ROUNDS` round constants.
+    const ROUND_CONSTANTS: &'static [u64];
+    const MDS_MATRIX: [[u64; WIDTH]; WIDTH];
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parameters::poseidon::{is_secure, number_of_rounds};
+
+    #[test]
+    fn test_number_of_rounds() {
+        assert!(is_secure(
+            64,
+            SPONGE_WIDTH,
+            7,
+            128,
+            N_FULL_ROUNDS_TOTAL,
+            N_PARTIAL_ROUNDS
+        ));
+        assert_eq!(
+            number_of_rounds(64, SPONGE_WIDTH, 7, 128),
+            (N_FULL_ROUNDS_TOTAL, N_PARTIAL_ROUNDS)
+        );
+    }
+}
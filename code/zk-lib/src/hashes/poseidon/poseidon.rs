@@ -15,8 +15,8 @@ use plonky2::{
     },
 };
 
-use self::{constants::ALL_ROUND_CONSTANTS, mds::MDS_MATRIX};
 use super::*;
+use crate::hashes::{Permutation, Width};
 
 pub struct Poseidon;
 
@@ -27,14 +27,16 @@ impl Poseidon {
      **********************************
      **********************************
      */
-    fn constant_layer<F: RichField + Extendable<D>, const D: usize>(
-        state: &mut [F; SPONGE_WIDTH],
+    fn constant_layer<F: RichField + Extendable<D>, const D: usize, const WIDTH: usize>(
+        state: &mut [F; WIDTH],
         round_ctr: usize,
-    ) {
-        for i in 0..SPONGE_WIDTH {
-            // SPONGE_WIDTH * round_ctr calculates the starting index in the ALL_ROUND_CONSTANTS array for the constants corresponding to the current round. Since each round has SPONGE_WIDTH number of constants, multiplying SPONGE_WIDTH by round_ctr gives the starting index for the constants of the current round.
+    ) where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
+        for i in 0..WIDTH {
+            // WIDTH * round_ctr calculates the starting index in the ROUND_CONSTANTS array for the constants corresponding to the current round. Since each round has WIDTH number of constants, multiplying WIDTH by round_ctr gives the starting index for the constants of the current round.
             // Adding i to this starting index allows the function to access the appropriate constant for the current state element state[i]
-            let round_constant = ALL_ROUND_CONSTANTS[i + SPONGE_WIDTH * round_ctr];
+            let round_constant = Width::<WIDTH>::ROUND_CONSTANTS[i + WIDTH * round_ctr];
             state[i] = state[i].add(F::from_canonical_u64(round_constant));
         }
     }
@@ -48,18 +50,22 @@ impl Poseidon {
     }
 
     fn sbox_layer<F: RichField + Extendable<D>, const D: usize>(state: &mut [F]) {
-        for i in 0..SPONGE_WIDTH {
+        for i in 0..state.len() {
             state[i] = Self::sbox_monomial(state[i]);
         }
     }
 
-    fn mds_layer<F: RichField + Extendable<D>, const D: usize>(
-        state: &[F; SPONGE_WIDTH],
-    ) -> [F; SPONGE_WIDTH] {
-        let mut new_state: [F; SPONGE_WIDTH] = [F::ZERO; SPONGE_WIDTH];
-        for i in 0..SPONGE_WIDTH {
-            for j in 0..SPONGE_WIDTH {
-                let ct = MDS_MATRIX[i][j];
+    fn mds_layer<F: RichField + Extendable<D>, const D: usize, const WIDTH: usize>(
+        state: &[F; WIDTH],
+    ) -> [F; WIDTH]
+    where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
+        let mds_matrix = &Width::<WIDTH>::MDS_MATRIX;
+        let mut new_state: [F; WIDTH] = [F::ZERO; WIDTH];
+        for i in 0..WIDTH {
+            for j in 0..WIDTH {
+                let ct = mds_matrix[i][j];
                 let mut temp = state[j].clone();
                 temp.mul_assign(F::from_canonical_u64(ct));
                 new_state[i].add_assign(temp)
@@ -68,41 +74,70 @@ impl Poseidon {
         new_state
     }
 
-    fn full_rounds<F: RichField + Extendable<D>, const D: usize>(
-        state: &mut [F; SPONGE_WIDTH],
+    fn full_rounds<F: RichField + Extendable<D>, const D: usize, const WIDTH: usize>(
+        state: &mut [F; WIDTH],
         round_ctr: &mut usize,
-    ) {
+    ) where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
         for _ in 0..HALF_N_FULL_ROUNDS {
-            Self::constant_layer(state, *round_ctr);
-            Self::sbox_layer(state);
-            *state = Self::mds_layer(state);
+            Self::constant_layer::<F, D, WIDTH>(state, *round_ctr);
+            Self::sbox_layer::<F, D>(state);
+            *state = Self::mds_layer::<F, D, WIDTH>(state);
             *round_ctr += 1;
         }
     }
 
-    fn partial_rounds<F: RichField + Extendable<D>, const D: usize>(
-        state: &mut [F; SPONGE_WIDTH],
+    fn partial_rounds<F: RichField + Extendable<D>, const D: usize, const WIDTH: usize>(
+        state: &mut [F; WIDTH],
         round_ctr: &mut usize,
-    ) {
+    ) where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
         for _ in 0..N_PARTIAL_ROUNDS {
-            Self::constant_layer(state, *round_ctr);
-            state[0] = Self::sbox_monomial(state[0]);
-            *state = Self::mds_layer(state);
+            Self::constant_layer::<F, D, WIDTH>(state, *round_ctr);
+            state[0] = Self::sbox_monomial::<F, D>(state[0]);
+            *state = Self::mds_layer::<F, D, WIDTH>(state);
             *round_ctr += 1;
         }
     }
 
+    fn poseidon_permutation<F: RichField + Extendable<D>, const D: usize, const WIDTH: usize>(
+        state: &mut [F; WIDTH],
+    ) where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
+        let mut round_ctr = 0;
+        Self::full_rounds::<F, D, WIDTH>(state, &mut round_ctr);
+        Self::partial_rounds::<F, D, WIDTH>(state, &mut round_ctr);
+        Self::full_rounds::<F, D, WIDTH>(state, &mut round_ctr);
+    }
+
     pub fn poseidon_hash<F: RichField + Extendable<D>, const D: usize>(
         input: [F; SPONGE_RATE],
     ) -> F {
-        let mut round_ctr = 0;
-        let mut state = [F::ZERO; SPONGE_WIDTH];
-        state[..SPONGE_RATE].copy_from_slice(&input);
-        state[SPONGE_RATE..].fill(F::ZERO);
+        Self::poseidon_hash_width::<F, D, SPONGE_WIDTH, SPONGE_RATE>(input)
+    }
+
+    /// Poseidon hash over a state of `WIDTH` elements, the first `RATE` of which hold the input.
+    pub fn poseidon_hash_width<
+        F: RichField + Extendable<D>,
+        const D: usize,
+        const WIDTH: usize,
+        const RATE: usize,
+    >(
+        input: [F; RATE],
+    ) -> F
+    where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
+        assert!(RATE < WIDTH);
+
+        let mut state = [F::ZERO; WIDTH];
+        state[..RATE].copy_from_slice(&input);
+        state[RATE..].fill(F::ZERO);
 
-        Self::full_rounds(&mut state, &mut round_ctr);
-        Self::partial_rounds(&mut state, &mut round_ctr);
-        Self::full_rounds(&mut state, &mut round_ctr);
+        Self::poseidon_permutation::<F, D, WIDTH>(&mut state);
 
         state[0]
     }
@@ -112,17 +147,19 @@ impl Poseidon {
      *      ZERO-KNOWLEDGE PROOF      *
      **********************************
      **********************************/
-    fn constant_layer_circuit<F: RichField + Extendable<D>, const D: usize>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn constant_layer_circuit<F: RichField + Extendable<D>, const D: usize, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         round_ctr: usize,
         builder: &mut CircuitBuilder<F, D>,
-    ) {
-        for i in 0..SPONGE_WIDTH {
-            //let round_constant = round_constants[i + SPONGE_WIDTH * round_ctr];
+    ) where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
+        for i in 0..WIDTH {
+            //let round_constant = round_constants[i + WIDTH * round_ctr];
             //state[i] = builder.add(state[i], round_constant);
             state[i] = builder.add_const(
                 state[i],
-                F::from_canonical_u64(ALL_ROUND_CONSTANTS[i + SPONGE_WIDTH * round_ctr]),
+                F::from_canonical_u64(Width::<WIDTH>::ROUND_CONSTANTS[i + WIDTH * round_ctr]),
             );
         }
     }
@@ -138,35 +175,41 @@ impl Poseidon {
         builder.mul(x6, x)
     }
 
-    fn sbox_layer_circuit<F: RichField + Extendable<D>, const D: usize>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn sbox_layer_circuit<F: RichField + Extendable<D>, const D: usize, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, D>,
     ) {
-        for i in 0..SPONGE_WIDTH {
+        for i in 0..WIDTH {
             state[i] = Self::sbox_monomial_cicruit(state[i], builder);
         }
     }
 
-    fn mds_layer_circuit<F: RichField + Extendable<D>, const D: usize>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn mds_layer_circuit<F: RichField + Extendable<D>, const D: usize, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, D>,
-    ) -> [Target; SPONGE_WIDTH] {
-        let mut new_state = [Target::default(); SPONGE_WIDTH];
-        for i in 0..12 {
-            let mut sum = Target::default();
-            for j in 0..12 {
-                sum = builder.mul_const_add(F::from_canonical_u64(MDS_MATRIX[i][j]), state[j], sum);
+    ) -> [Target; WIDTH]
+    where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
+        let mds_matrix = &Width::<WIDTH>::MDS_MATRIX;
+        let mut new_state = [Target::default(); WIDTH];
+        for i in 0..WIDTH {
+            let mut sum = builder.zero();
+            for j in 0..WIDTH {
+                sum = builder.mul_const_add(F::from_canonical_u64(mds_matrix[i][j]), state[j], sum);
             }
             new_state[i] = sum;
         }
         new_state
     }
 
-    fn full_rounds_circuit<F: RichField + Extendable<D>, const D: usize>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn full_rounds_circuit<F: RichField + Extendable<D>, const D: usize, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         round_ctr: &mut usize,
         builder: &mut CircuitBuilder<F, D>,
-    ) {
+    ) where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
         for _ in 0..HALF_N_FULL_ROUNDS {
             Self::constant_layer_circuit(state, *round_ctr, builder);
             Self::sbox_layer_circuit(state, builder);
@@ -175,11 +218,13 @@ impl Poseidon {
         }
     }
 
-    fn partial_rounds_circuit<F: RichField + Extendable<D>, const D: usize>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn partial_rounds_circuit<F: RichField + Extendable<D>, const D: usize, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         round_ctr: &mut usize,
         builder: &mut CircuitBuilder<F, D>,
-    ) {
+    ) where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
         for _ in 0..N_PARTIAL_ROUNDS {
             Self::constant_layer_circuit(state, *round_ctr, builder);
             state[0] = Self::sbox_monomial_cicruit(state[0], builder);
@@ -188,11 +233,13 @@ impl Poseidon {
         }
     }
 
-    fn poseidon_permutation<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn poseidon_permutation_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
         round_ctr: &mut usize,
-    ) {
+    ) where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
         Self::full_rounds_circuit(state, round_ctr, builder);
         Self::partial_rounds_circuit(state, round_ctr, builder);
         Self::full_rounds_circuit(state, round_ctr, builder);
@@ -206,15 +253,34 @@ impl Poseidon {
     >(
         x: [F; SPONGE_RATE],
     ) -> (CircuitData<F, C, 2>, PartialWitness<F>) {
+        Self::circuit_generation_width::<F, C, SPONGE_WIDTH, SPONGE_RATE, L>(x)
+    }
+
+    /// Generates the circuit for the Poseidon hash over a state of `WIDTH` elements, the first
+    /// `RATE` of which hold the input
+    pub fn circuit_generation_width<
+        F: RichField + Extendable<2>,
+        C: GenericConfig<2, F = F>,
+        const WIDTH: usize,
+        const RATE: usize,
+        const L: usize,
+    >(
+        x: [F; RATE],
+    ) -> (CircuitData<F, C, 2>, PartialWitness<F>)
+    where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
+        assert!(RATE < WIDTH);
+
         let config = CircuitConfig::standard_recursion_config();
         let mut builder = CircuitBuilder::<F, 2>::new(config);
 
-        let input = builder.add_virtual_target_arr::<SPONGE_RATE>();
-        let mut state = builder.add_virtual_target_arr::<SPONGE_WIDTH>();
+        let input = builder.add_virtual_target_arr::<RATE>();
+        let mut state = builder.add_virtual_target_arr::<WIDTH>();
         let mut round_ctr = 0;
 
-        for i in 0..SPONGE_WIDTH {
-            state[i] = Target::default();
+        for i in 0..WIDTH {
+            state[i] = builder.zero();
         }
 
         // The arithmetic circuit
@@ -222,8 +288,8 @@ impl Poseidon {
         for &element in input.iter() {
             state[i] = builder.add(state[i], element);
             i += 1;
-            if i % SPONGE_RATE == 0 {
-                Self::poseidon_permutation(&mut state, &mut builder, &mut round_ctr);
+            if i % RATE == 0 {
+                Self::poseidon_permutation_circuit(&mut state, &mut builder, &mut round_ctr);
                 i = 0;
             }
         }
@@ -274,14 +340,32 @@ impl Poseidon {
     }
 }
 
+impl<const WIDTH: usize> Permutation<WIDTH> for Poseidon
+where
+    Width<WIDTH>: PoseidonParameters<WIDTH>,
+{
+    fn permute<F: RichField + Extendable<2>>(state: &mut [F; WIDTH]) {
+        Self::poseidon_permutation::<F, 2, WIDTH>(state);
+    }
+
+    fn permute_circuit<F: RichField + Extendable<2>>(
+        state: &mut [Target; WIDTH],
+        builder: &mut CircuitBuilder<F, 2>,
+    ) {
+        Self::poseidon_permutation_circuit(state, builder, &mut 0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use plonky2::{
         field::{goldilocks_field::GoldilocksField, types::Field},
-        plonk::config::PoseidonGoldilocksConfig,
+        hash::poseidon::PoseidonHash,
+        plonk::config::{Hasher, PoseidonGoldilocksConfig},
     };
 
     use super::*;
+    use crate::hashes::kat;
 
     #[test]
     fn poseidon_hash() {
@@ -303,4 +387,87 @@ mod tests {
             Poseidon::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw);
         Poseidon::proof_verification::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &proof);
     }
+
+    fn check_width<const WIDTH: usize, const RATE: usize>()
+    where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
+        let input: [GoldilocksField; RATE] = core::array::from_fn(|i| GoldilocksField(i as u64 + 1));
+        let output = Poseidon::poseidon_hash_width::<GoldilocksField, 2, WIDTH, RATE>(input);
+
+        let (data, pw) = Poseidon::circuit_generation_width::<
+            GoldilocksField,
+            PoseidonGoldilocksConfig,
+            WIDTH,
+            RATE,
+            1,
+        >(input);
+        let proof =
+            Poseidon::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw);
+        assert_eq!(proof.public_inputs, [output]);
+        assert!(data.verify(proof).is_ok());
+    }
+
+    #[test]
+    fn poseidon_widths() {
+        check_width::<8, 4>();
+        check_width::<16, 12>();
+        check_width::<24, 20>();
+    }
+
+    fn check_kat<const WIDTH: usize, const RATE: usize>()
+    where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
+        kat::check_kat::<Poseidon, _, WIDTH, RATE>(
+            include_str!("../../../kat/poseidon.json"),
+            "poseidon",
+            |input| {
+                [Poseidon::poseidon_hash_width::<
+                    GoldilocksField,
+                    2,
+                    WIDTH,
+                    RATE,
+                >(input)]
+            },
+            Poseidon::circuit_generation_width::<
+                GoldilocksField,
+                PoseidonGoldilocksConfig,
+                WIDTH,
+                RATE,
+                1,
+            >,
+        );
+    }
+
+    #[test]
+    fn poseidon_kat() {
+        check_kat::<8, 4>();
+        check_kat::<12, 8>();
+        check_kat::<16, 12>();
+        check_kat::<24, 20>();
+    }
+
+    #[test]
+    fn poseidon_plonky2() {
+        // The width 12 is the permutation of plonky2
+        let json = include_str!("../../../kat/poseidon.json");
+        for vector in kat::vectors(json, "poseidon", SPONGE_WIDTH) {
+            let expected = PoseidonHash::hash_no_pad(&vector.input).elements[0];
+            assert_eq!(vector.output, [expected]);
+            let input: [GoldilocksField; SPONGE_RATE] = vector.input.try_into().unwrap();
+            assert_eq!(
+                Poseidon::poseidon_hash::<GoldilocksField, 2>(input),
+                expected
+            );
+        }
+
+        // Inputs of other lengths go through the overwrite-mode sponge of plonky2
+        let file = kat::parse(json, "poseidon");
+        for vector in file["hash_no_pad"].as_array().unwrap() {
+            let input = kat::elements(&vector["input"]);
+            let expected = PoseidonHash::hash_no_pad(&input).elements;
+            assert_eq!(kat::elements(&vector["output"]), expected);
+        }
+    }
 }
@@ -0,0 +1,2537 @@
+// Poseidon constants for the widths other than 12, for the same 8 full and 22 partial
+// rounds. Unlike the plonky2 constants of width 12, they follow the Poseidon paper: the
+// round constants are drawn from the Grain LFSR and the MDS matrix is a Cauchy matrix
+// (see `crate::parameters::poseidon`).
+
+use super::{constants, mds, PoseidonParameters};
+use crate::hashes::Width;
+
+impl PoseidonParameters<8> for Width<8> {
+    const ROUND_CONSTANTS: &'static [u64] = &ROUND_CONSTANTS_8;
+    const MDS_MATRIX: [[u64; 8]; 8] = MDS_MATRIX_8;
+}
+
+impl PoseidonParameters<12> for Width<12> {
+    const ROUND_CONSTANTS: &'static [u64] = &constants::ALL_ROUND_CONSTANTS;
+    const MDS_MATRIX: [[u64; 12]; 12] = mds::MDS_MATRIX;
+}
+
+impl PoseidonParameters<16> for Width<16> {
+    const ROUND_CONSTANTS: &'static [u64] = &ROUND_CONSTANTS_16;
+    const MDS_MATRIX: [[u64; 16]; 16] = MDS_MATRIX_16;
+}
+
+impl PoseidonParameters<24> for Width<24> {
+    const ROUND_CONSTANTS: &'static [u64] = &ROUND_CONSTANTS_24;
+    const MDS_MATRIX: [[u64; 24]; 24] = MDS_MATRIX_24;
+}
+
+pub const ROUND_CONSTANTS_8: [u64; 240] = [
+    15949291268843349465,
+    14644164809401934923,
+    18420360874837380316,
+    4756469047455716334,
+    8685499049481102115,
+    3799221349720045367,
+    13676397835037157930,
+    6566439050423619635,
+    17428268347612331188,
+    2833135872454503769,
+    4767009016213040191,
+    2797635963551733652,
+    5312339450141126694,
+    5356668452102813289,
+    1234059326449530173,
+    7724302552453704877,
+    14868588146468890290,
+    12825281145595371185,
+    13097885453579304196,
+    7905326782341128063,
+    14167525334039893569,
+    2082169701994688927,
+    12190787523818595537,
+    12602917751946636,
+    14890907856876319003,
+    16552240149997473409,
+    5634093690795187558,
+    4883714163685656967,
+    12440776365164557866,
+    3923800234666204307,
+    9858064884105950259,
+    16040043470428402038,
+    5226594323142090582,
+    1243120476974621208,
+    12100812801659301173,
+    11228203327983058121,
+    13891617888374767564,
+    5742893160230537107,
+    3763472116988983643,
+    2466655769425769160,
+    6254574254498162968,
+    14183251225809189357,
+    11565357354521717084,
+    17300657704266685688,
+    310485250821938281,
+    16853586468012618118,
+    1978800426240373849,
+    6948188224235462572,
+    1486402152218690509,
+    5669161690283398991,
+    17943970877073781734,
+    17926851897715769433,
+    13052837496695000666,
+    18138113741095562305,
+    94277733998400326,
+    10891359798487446420,
+    18280773820738154043,
+    13714589910668449566,
+    10639034072771185213,
+    14148790895768484219,
+    18341268649720100165,
+    3096672942770686236,
+    12277596046563557393,
+    400461754528604020,
+    12955488253560265444,
+    11773677676764285572,
+    4833837465239476573,
+    17645852643693996619,
+    6605134696140007471,
+    588040525114200273,
+    11001741536026769411,
+    17917086578469406776,
+    14893530806420712543,
+    727997185253761138,
+    3443873847340254325,
+    13095911531247069692,
+    8330737046680948619,
+    6014364575875986011,
+    16851679856681761121,
+    17817965496543149594,
+    12823640325246269760,
+    13685256787930775147,
+    4682652317564502291,
+    4233879762155685988,
+    11097258179564187322,
+    10804761421745472094,
+    16425553662684325570,
+    10228342822391192470,
+    9655649918592982429,
+    15982037224205216016,
+    2789340379599583566,
+    18446634980590558767,
+    10126403438970932053,
+    16987881356213159169,
+    3276286096405673238,
+    3687894116707946994,
+    5114517305918572341,
+    14991840896855067187,
+    6905420609119477701,
+    5291651548570869410,
+    17562609231968650695,
+    9667605565853693715,
+    13789802377508767704,
+    4732612214544179724,
+    14723209762776543479,
+    9416793489058151660,
+    10253725536659176653,
+    1714527036602866046,
+    15323890982677230130,
+    11730381972445282021,
+    12216033376705242022,
+    2072934925475504801,
+    16432743296706583079,
+    1287600597097751716,
+    10482065724875379357,
+    3057917794534811538,
+    4460508886913832366,
+    4574242228824269567,
+    15497000860205437868,
+    5258779806711233780,
+    2917690869176376459,
+    5552343643601973481,
+    10031668054487390843,
+    16702846882359561935,
+    2597411547338924301,
+    9410807915094873680,
+    4038132370496189774,
+    7514851419529898000,
+    16733481957374600673,
+    12700052193129163508,
+    1271853891477855996,
+    3521154498147528034,
+    1112717710392299742,
+    18436629675246557921,
+    4666660747304286620,
+    17140737263918422602,
+    10050632638543068589,
+    4307574521534980089,
+    18215752451612116735,
+    13480683264439855572,
+    15555612733894467605,
+    2859638725042112817,
+    14083955561040135701,
+    13687444950227705291,
+    13942956444831714793,
+    13618865289968068724,
+    6824181772510810610,
+    13456233547779206111,
+    4539269965952894262,
+    3277758063669404111,
+    16563706468587629579,
+    14829270292839622012,
+    16149299494464346121,
+    12729070118060987573,
+    2876331595276499461,
+    5896403089525423552,
+    10054746017635131337,
+    15891729401440579521,
+    16686953925604982841,
+    11488450620635904896,
+    5319968881416215882,
+    6866530901595465606,
+    5603564167992047509,
+    262316557217489340,
+    2327175406659849217,
+    11157393233775540666,
+    7173103141864251273,
+    6841693016933379637,
+    4813399736209008072,
+    13236902135375776760,
+    974177555869830927,
+    353051917785987980,
+    13008662916818917899,
+    1646935213262692017,
+    701346762003107102,
+    8522515238742904717,
+    17082894626802242899,
+    818193631549863677,
+    4354134903774900392,
+    3125162783175126037,
+    7939145240040161135,
+    13921901388219906947,
+    11058979495887741568,
+    6810363166932110582,
+    9937044286652006583,
+    1912910437194324315,
+    10167077539441507575,
+    16655767813717537030,
+    5991613036521347162,
+    11632505203258666311,
+    15737012069006248518,
+    14639613963426293324,
+    9604751035609530679,
+    17541542306182196454,
+    1015064463500134038,
+    4057433516940758902,
+    17752487478858501829,
+    16536027494361526705,
+    5418825649035372052,
+    7416877752114204045,
+    5298568465954566493,
+    16956613523505180500,
+    3230856115770679244,
+    2960635815920771091,
+    1674467516137385144,
+    16959180593939909097,
+    6894998595391552502,
+    12412712984870068318,
+    18094737559774241307,
+    10930414073408006485,
+    632205812023241520,
+    4896184719192102012,
+    18046601795562835805,
+    4874799261050045371,
+    14805515569601384764,
+    14962150510141817503,
+    14603649285241593324,
+    761417572415388640,
+    1226185771089878470,
+    3868252963893629278,
+    10213206374844377176,
+    12513523831859935725,
+    5930658500326631305,
+    12194321302721369377,
+    11321798037787734142,
+    8980154259883873756,
+    11736938192888407579,
+    13192414074373038918,
+    2476169510837310467,
+    1533959397343817152,
+    12606670949879920962,
+    7925350173351919368,
+    9072593798739179107,
+    12001755003233916850,
+    16756686135965682097,
+    15550825404583733749,
+    8694668369540076298,
+    15457222476085186130,
+    5108598208140922837,
+    3620213722474373078,
+];
+
+pub const MDS_MATRIX_8: [[u64; 8]; 8] = [
+    [
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+    ],
+    [
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+    ],
+    [
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+    ],
+    [
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+    ],
+    [
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+    ],
+    [
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+    ],
+    [
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+    ],
+    [
+        18446744069414584320,
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+    ],
+];
+
+pub const ROUND_CONSTANTS_16: [u64; 480] = [
+    1579613653969377219,
+    15509510893087893340,
+    10090715174060125222,
+    5625716564419252202,
+    3006005019077469174,
+    18314693207014427912,
+    10170571510627764565,
+    2027625550790675754,
+    3983470257916202094,
+    3423470109396435354,
+    3450488264035752368,
+    3151070045406026687,
+    13462781804006123550,
+    13288575772684627216,
+    13745549378090937523,
+    7780139165529418388,
+    11699769881923825909,
+    4349260652212695338,
+    8228348357294662898,
+    12037145371429260559,
+    7743383997471469184,
+    7842006993218978420,
+    18369471830829996810,
+    10157815501749214163,
+    11128005290408113568,
+    12272917402811871099,
+    106820976709770637,
+    6178857985076893256,
+    14036787039817180193,
+    1659458828486927362,
+    15938183887757779615,
+    10060334973073197601,
+    12437259172286625042,
+    3143835545170851304,
+    14361575850007152549,
+    11810209965918630788,
+    17667700303515849362,
+    13451932870729425780,
+    17496644301504593329,
+    1778669202480014256,
+    11124488067381312952,
+    14273583775934080755,
+    13257379154210125715,
+    15182093233826554989,
+    5343815959848316783,
+    5696193414571279556,
+    6089787229781325813,
+    3717595218375990594,
+    12355842859741995537,
+    14160921043125059199,
+    13682913322779819091,
+    12343504462597575239,
+    14249643564311509087,
+    15849758561085927402,
+    12186564686511703262,
+    690520822232718983,
+    684200944081655198,
+    6008958449718781068,
+    3098079696536837500,
+    12083506578988585239,
+    6489163969976034646,
+    1186411428667285248,
+    11192474480021157091,
+    17095617549195818058,
+    2949845317987848448,
+    6935557324168256069,
+    2055390472619727748,
+    12399309956582731760,
+    3088058410657255303,
+    8790959064194804235,
+    4582664136559626757,
+    14821962908959377092,
+    2002685046256118388,
+    15689593639089632368,
+    10512046881800463697,
+    11973738501993105496,
+    13009070935614847359,
+    12908033183314664096,
+    9530849385991780809,
+    17528314872631229800,
+    17869527288639567155,
+    7829055113315023688,
+    3256047469251174543,
+    3015723851705964382,
+    5177282273995529875,
+    16035152896984012190,
+    15766898731627159621,
+    6714370142978148338,
+    5906664861467821209,
+    2197120128337974909,
+    9400421283898153864,
+    16740473708727660723,
+    7287335615015370599,
+    12399217337488717232,
+    12655834778591227242,
+    7516917516498792203,
+    17291898461659961460,
+    3483661079725019706,
+    18038566855851505564,
+    6166154478921479976,
+    8824538844032254328,
+    17138720803294251067,
+    4158286797471309733,
+    1589482450994236426,
+    4836112672767737407,
+    6108317238076822824,
+    11174425919404088598,
+    2755155124184744585,
+    13202523778257173956,
+    6643835433125335951,
+    2748965361441888477,
+    14297205492001640506,
+    12595977886586773319,
+    257624298448087449,
+    2744326799038764281,
+    9097808034015176417,
+    5523099006222423939,
+    7256201493271212720,
+    9738621014989498753,
+    237412858668409460,
+    6405904454922718574,
+    16343835012250148340,
+    9342245617122491936,
+    824922755340087111,
+    5398764608665671277,
+    16030622638137836774,
+    4921899302518525668,
+    10676667276121395674,
+    16304445044992359788,
+    587812931475326787,
+    7627897473303434798,
+    2633542348821978290,
+    5714662808802647771,
+    15665352327749112015,
+    8041781683128153420,
+    13172746155287932121,
+    6611413049825339003,
+    9076340683484238287,
+    12295614633782496176,
+    11414162089917699064,
+    13658053783093222498,
+    16216654076620539925,
+    17782598995085730495,
+    18362903988365127450,
+    11596821426909532371,
+    13499421621575350806,
+    15256312875610823807,
+    1558929959921731273,
+    17567284148478398281,
+    4615393526180652407,
+    6198179740924369841,
+    469814068331072205,
+    17798678474794258808,
+    817083808502648104,
+    14774436095957558125,
+    1664085021810752365,
+    17331520554042242856,
+    4923727656063208445,
+    12524553567247559769,
+    4797104341172537550,
+    13345113629665892994,
+    642668562516629016,
+    3133520952027333240,
+    6046747970997187790,
+    17144687310247274003,
+    12443242853141076418,
+    14702927491068357423,
+    5932201780999956928,
+    11171949680719086864,
+    4412268618713572334,
+    5994417449683224805,
+    1465179914775512104,
+    15701641935056047059,
+    2827237761280830851,
+    2752080883912700821,
+    13455663567943251815,
+    937454209447998663,
+    3202228914341339837,
+    11149119703269974290,
+    1772990248789020510,
+    14501952604574004582,
+    3013816056537702097,
+    5875063541294223982,
+    2804887644629198447,
+    6409198439063697124,
+    14604613908231202451,
+    11090727477783650503,
+    18122027779429419163,
+    10701932224849706923,
+    3741118519646615682,
+    3485709611611060388,
+    9609155506117735884,
+    4305043647745349801,
+    16194824328878457781,
+    15193165739362621330,
+    2725200989981874006,
+    7498869609443066087,
+    15190853184290119974,
+    17113082950606094483,
+    2947200854654307805,
+    4295801703361782,
+    17584490400109323382,
+    12182475738081705041,
+    7061380184320148985,
+    951911123967877170,
+    14069091521560743531,
+    5944776136353812271,
+    9235035768250666072,
+    4912044394200156460,
+    13262289396813769447,
+    4569173350311842435,
+    3313950595516115854,
+    5064056249751485646,
+    11294353796532748504,
+    11299626102617005222,
+    1142912004276743199,
+    10499277250996955817,
+    299102599250526638,
+    3900343901749893610,
+    12180478937691525855,
+    16138522519364596399,
+    3844390763466930665,
+    3723071721054113424,
+    7414755752345373961,
+    8452825997292315145,
+    13039056909375628677,
+    16194581543355504907,
+    2926602609140176303,
+    14409602422904754576,
+    14124635711995472733,
+    7479802206662129254,
+    9487227248120885968,
+    12124544873980081169,
+    12365709197476421637,
+    5443611073912343902,
+    16740724915922390578,
+    3509538820442601661,
+    5746089409826397662,
+    12791034456990622176,
+    11112561873260314763,
+    11132958570548616703,
+    5984791778033835567,
+    13102964290500923490,
+    7669947704246313473,
+    15346866814456781398,
+    11313601524190971243,
+    16481765282394971448,
+    446599493808229385,
+    17331089755711768153,
+    13751117613840177776,
+    2824543388311983244,
+    6249067301896178454,
+    16844875038279414387,
+    2056475852180418320,
+    15954361292830292630,
+    17889766689134197404,
+    7863602142082432989,
+    4143688524677178002,
+    6772916079277628209,
+    5937082263681126541,
+    7555776711547732150,
+    844037442989043343,
+    11862492865561796038,
+    9020384047192219355,
+    4190904251623792310,
+    15998457138245749625,
+    3256833383943868131,
+    731836888733187725,
+    16490743862529999494,
+    3067692567956921265,
+    5376866043520593509,
+    17949892521399489702,
+    14503802206906456092,
+    18064885649485791110,
+    3524185009307820504,
+    8287853693072616667,
+    9762274047622685957,
+    10397520299257277942,
+    3446204872907264746,
+    4019717440759910382,
+    1258797801844447565,
+    17224097678010410816,
+    4304231657996548607,
+    10689745146900986238,
+    4365724581757155851,
+    10155039336396914895,
+    16417589735161468981,
+    12424804061289028123,
+    4869981690811998940,
+    1515939859869273340,
+    8712822797836242339,
+    4288644743923867084,
+    17215606384659741146,
+    11063754689017299617,
+    9634555401181673374,
+    17421410320241964350,
+    9581248560178770457,
+    9027336601486639305,
+    8700191307954012043,
+    13448160007450037696,
+    11662197716735170331,
+    7984257083703717357,
+    731631587951951987,
+    3476195855686597375,
+    1334185254779028320,
+    700630316428088262,
+    8664895252280626800,
+    5838137406279192614,
+    10464941677582810370,
+    2839760456502586192,
+    16316856213097499092,
+    12553986898497142051,
+    8393092476558410479,
+    8471452676755415167,
+    6447975344018878505,
+    14920463804124481384,
+    7774573504827250640,
+    2208036245002930934,
+    11160897180757541961,
+    76873303551506202,
+    4493337907087241214,
+    6491079181198767425,
+    2082058643090790305,
+    6689069862662999375,
+    14223690571761533602,
+    15904115113120678446,
+    9977132407814160719,
+    12753965884758121822,
+    5620181501964557895,
+    8980877372905320224,
+    3997577336938585155,
+    10677919260948572200,
+    7148963197719463568,
+    17735957892791641118,
+    8364308237606298152,
+    17473424258518891026,
+    14671843748319986102,
+    5491395452150289665,
+    10931303521082508232,
+    8008876444600607702,
+    11361083456829421776,
+    11174912361856530910,
+    13297524484339762587,
+    2591104713473175878,
+    16146774066946829098,
+    5173004665047661564,
+    16238798177520742291,
+    16682988689330633258,
+    8284061672908749234,
+    7148703443369646086,
+    16746639615254321750,
+    11923413014868247029,
+    11755571601087934038,
+    11333136470821863608,
+    17702964327595811562,
+    18296297475957468367,
+    3178258370401376690,
+    5848568333102857439,
+    15868141856890681764,
+    1335081109681254173,
+    10756694318966954055,
+    2876067209613444711,
+    17671797885779717660,
+    14759300084323317101,
+    11910627279503920197,
+    14492666910199048288,
+    4263557762402097327,
+    4322898685421951233,
+    9124789841631481091,
+    7310287501595810549,
+    13893879127556001668,
+    2829674190350079516,
+    54071996013129207,
+    12544400410457922027,
+    16953729453315900726,
+    7098218306524829316,
+    1656177239630603131,
+    4856600382060295709,
+    5255117950937432597,
+    4703251331529179663,
+    7456475215388365026,
+    9215376510642647033,
+    3875550169903185629,
+    9540504521320675993,
+    4331591643208406316,
+    13062636054891919956,
+    427198460895983969,
+    5789161390291742462,
+    12856722421567411569,
+    5376505982489075377,
+    10896642443752363820,
+    17148386107276776952,
+    13824694317674924884,
+    11838351676895903779,
+    17148867116095202569,
+    7208553114412233615,
+    13582241037392197131,
+    2036152746900159093,
+    1693317454801169901,
+    10299724087907229965,
+    17634419930215198699,
+    16551834625381203725,
+    15618263414285495209,
+    13105614891035054839,
+    17438293340790598536,
+    9759114741399160608,
+    3448971608284371224,
+    11962286574027998216,
+    1141404081842530425,
+    15271656395879289319,
+    11160217667234375878,
+    12547625657764390468,
+    9040262864279695377,
+    748479184907777137,
+    11220631500208898494,
+    8869501244989761780,
+    13786631712890251885,
+    16588375428074140134,
+    14767355165053632437,
+    4410900339274029743,
+    12546659423788430004,
+    2114628217230343247,
+    2133538739909289268,
+    4773205784438014703,
+    8265894638361252667,
+    5223389730290959856,
+    15619942046564449370,
+    5791281865406255970,
+    13985845210862515943,
+    12032892788021167219,
+    18401103207753875985,
+    3476764791793480456,
+    11649142238174500197,
+    8712351138970364289,
+    14692696050737161630,
+    4894674695969854213,
+    2908121399062178661,
+    6790272183398006673,
+    9010435624898204894,
+    1832701308114390292,
+    3652118343199330870,
+    8089229871466232201,
+    17286971688453404540,
+    4800206276340946007,
+    8115672005831708306,
+    3055380174500960813,
+    16964379100395406620,
+    8088842627823299006,
+    9946304708154620091,
+    15102071546045770121,
+    10301544459319808780,
+    13671123212713835095,
+    16303299764147105030,
+    18171760739689040135,
+    8727304300353130088,
+    15139868272914210324,
+    4557766052329107027,
+    9475155160371266250,
+    7742629784879183824,
+    9095972977211974416,
+    13458232220369596755,
+    13105609530042700354,
+    16669216811073554379,
+    2570887763900098793,
+    6612428420501784008,
+    8453152447539682822,
+    11139961051779113096,
+    16788480021697334394,
+    13519543246350118727,
+    1239913882469702364,
+    7376358710321275355,
+    590020934466687607,
+    5282297971102479724,
+    16056472633876071086,
+    18042098699416501308,
+    8876330831862382235,
+    691356767650698921,
+    6798676828266712040,
+    4195043074986594226,
+    3562802698084250644,
+];
+
+pub const MDS_MATRIX_16: [[u64; 16]; 16] = [
+    [
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+    ],
+    [
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+    ],
+    [
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+    ],
+    [
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+    ],
+    [
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+    ],
+    [
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+    ],
+    [
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+    ],
+    [
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+    ],
+    [
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+    ],
+    [
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+    ],
+    [
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+    ],
+    [
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+    ],
+    [
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+    ],
+    [
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+    ],
+    [
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+    ],
+    [
+        18446744069414584320,
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+    ],
+];
+
+pub const ROUND_CONSTANTS_24: [u64; 720] = [
+    13290748480961376802,
+    9042221506542336346,
+    13952680807265797262,
+    4029328701726734202,
+    14176397708212147829,
+    8193520726965807429,
+    3168680228077219790,
+    9030983862287025901,
+    261037193951032085,
+    4332454456730493739,
+    10408489479008517404,
+    11281742826724614161,
+    14234925890336684000,
+    1449811009151328690,
+    559459582788050793,
+    17983883129546450923,
+    16398603566318883716,
+    9496546145821046262,
+    14656063653132870686,
+    9818102413386831247,
+    2251526620032686930,
+    12045994985773608839,
+    14549002837972255626,
+    4785759839935399476,
+    4833716847521761088,
+    10567686437573986896,
+    1542299236165075438,
+    9473551023816464062,
+    16274174442034696871,
+    10180055339685800768,
+    2206793761787596771,
+    10403720330905190399,
+    10827731156610943823,
+    3466118054503910196,
+    7845937573899893582,
+    2028340364641305184,
+    16220927781264328683,
+    17560921853672101670,
+    3060181879696213545,
+    6232267223045753121,
+    3322523289160933123,
+    10648774574915422547,
+    6390670846386466531,
+    16387061591734308195,
+    12902750490669454389,
+    2130656179209583583,
+    7902183377680710673,
+    1605285708016623479,
+    926961938853635955,
+    15022009423542326821,
+    5446925824448875001,
+    8371331420430550485,
+    6334864647802606671,
+    7677974691252314880,
+    2476343867561813290,
+    13735615057211218337,
+    2151383919961293078,
+    14683959870541965433,
+    10598776624349755649,
+    14632722870883290706,
+    3372054793472501113,
+    1514901905763803893,
+    2281556590847996183,
+    4335910299511426036,
+    6888156622547532195,
+    4869150155788896608,
+    12106122565583814928,
+    382083344656095442,
+    15447084953012834917,
+    1101055000763802000,
+    15722593768318858957,
+    16717122862254656812,
+    9940623148263084501,
+    9467379678376134567,
+    16446372568921278189,
+    4459802133578476810,
+    9362907246383971596,
+    1483417484296368935,
+    13678989001990323803,
+    1740438608471395507,
+    15765017224035513971,
+    14930597856035022190,
+    7038986974768468465,
+    6157176144016721373,
+    16574610081155312696,
+    223546060781743393,
+    3033955114990922782,
+    16349709820380750330,
+    10688939117386991794,
+    14122467057669343077,
+    7686919526020602373,
+    7953971638805518428,
+    14453280170922687591,
+    5644512623914291170,
+    7957777733404357497,
+    11572475101808279532,
+    9627569334536724326,
+    7432609322494824550,
+    13310838684574264037,
+    17656313559195161896,
+    13964486408734902201,
+    10342250385571471184,
+    18008871850248094244,
+    11754330027140282357,
+    14026217786152257177,
+    11926017634619034036,
+    11063845660214238115,
+    4917339027503975555,
+    6761146533093248159,
+    10577965008894433463,
+    17471860651922113238,
+    17123673355870125266,
+    7862222318398516234,
+    17684874526530464686,
+    76789375955426425,
+    14026128884839462821,
+    12846166908963375045,
+    17643105769199488671,
+    18431000799001480559,
+    7279660542465717170,
+    3087041577942122282,
+    10200107774030017444,
+    7851882663885286709,
+    9928149536190498756,
+    17745669175247933648,
+    17249073210518974931,
+    10276356903956148696,
+    10599263971515089065,
+    14674611287863998274,
+    11442877467313309174,
+    11065511496702380967,
+    2876154758173304984,
+    11275106319405561120,
+    4838651127390977706,
+    17338825013815469479,
+    15043234311331351720,
+    8666754534119999865,
+    17468417683690188686,
+    439387219713716329,
+    14262642333432722838,
+    10174772909267604457,
+    13283160425994629576,
+    6623343380634369781,
+    18236152255312899528,
+    9117583921606488373,
+    2318006249843488615,
+    1734925617831435960,
+    10191121775942877091,
+    16886364895571062246,
+    8770856188237241233,
+    14766217225120009423,
+    17837887452519819298,
+    4946551866392005092,
+    12834247727752779384,
+    3956022514260355082,
+    14230683021070007209,
+    11732172622826570049,
+    9540644038724414812,
+    1743243963643217672,
+    14595833487374221513,
+    6785303088430037456,
+    11057886004448597858,
+    15525903806613814612,
+    9716718448761522719,
+    3118648450237020797,
+    16374395437719865074,
+    17005533845562329564,
+    11373372404236173143,
+    14574383989932801008,
+    4758485526685682684,
+    3167559196553390307,
+    10916422758956113732,
+    6715011595753052745,
+    13861852435522919576,
+    14057457309104075879,
+    8812308741776552389,
+    11244231977989553313,
+    129712693465705286,
+    11836080281649174750,
+    957972427214000659,
+    11255811286455618632,
+    17097192067183029993,
+    2630350777786799431,
+    4113248724389387702,
+    11351523681214420881,
+    16734996360480870685,
+    402781529083466692,
+    1969408982284028986,
+    2164788748354132535,
+    6356186309989773039,
+    7728571280114615619,
+    1544823459524514529,
+    273260192698582021,
+    9701253874124018534,
+    16556576327387160247,
+    3071619453411081608,
+    10847396331858688692,
+    11471708852973066056,
+    10582231904655166507,
+    7255861805847723662,
+    5295722918827528763,
+    254807296215737510,
+    5096612675450016430,
+    14325304972919332160,
+    16685131601701811471,
+    579031143658823450,
+    15001038304381100401,
+    16129240440725841563,
+    13107897255272592422,
+    12372143854345675654,
+    10432414055139571408,
+    8514831696098540553,
+    13357969903159227820,
+    8216280779020609121,
+    12263700057188959595,
+    18360222277274244298,
+    686179954342050975,
+    5418743198020404615,
+    15509094458911744369,
+    7591089639564211232,
+    10482922852605935002,
+    1543857559063085815,
+    5722909592121142369,
+    3777743781379222429,
+    808463339883661494,
+    2085254178743426522,
+    11057448475952738076,
+    6436441338883721366,
+    7518749060167602869,
+    13798949068860536116,
+    9080569055004174460,
+    3948772925001356607,
+    7810488220573286114,
+    5269436801069471866,
+    16807690483901761644,
+    2235642619515309710,
+    5259253220075988593,
+    15685169160979610448,
+    5918793315368796097,
+    8301836282321285689,
+    18063672726592084586,
+    2422122633684024677,
+    9011716104791791752,
+    10287516961973565185,
+    8073879403434438227,
+    11179411271898419805,
+    6059347659309625280,
+    1831867535561862895,
+    16239829209792751997,
+    9952677909656380834,
+    10375056400115201088,
+    8776936794930407819,
+    3179211738887722301,
+    9685409718793351859,
+    3930366729634310327,
+    14214822024458122852,
+    6587641913878428447,
+    1554276714542200088,
+    15595904618325373209,
+    7570821851344032515,
+    9391287258935156553,
+    4221545910640619292,
+    11195362223485524489,
+    4683499294229410240,
+    16055576363676375857,
+    9207488300022578550,
+    15307201615922140141,
+    11258695503190951207,
+    7868023753612506450,
+    11674307298164810020,
+    7896236803900326443,
+    3568714872413127311,
+    5617048029949989917,
+    3406424148591394481,
+    15659130254004899401,
+    2036236827684420147,
+    9334692053859147399,
+    4670329024001775624,
+    6068122061963680119,
+    13033204865234587351,
+    7932934595603278988,
+    13609561644227565959,
+    6910828183427187303,
+    7488988818003311228,
+    15086095295320591927,
+    16523873093274168845,
+    16175925244418400636,
+    4549547802352235220,
+    10029783808485121183,
+    15119217055013994459,
+    1872177839163914016,
+    797321827131649071,
+    4226713483937846307,
+    2501737718389372179,
+    12586699748989420049,
+    1677077162577193529,
+    13455706321254637854,
+    10937874139365461975,
+    6972731660317132978,
+    6923268361048357928,
+    11627931927861239577,
+    13087063134902816994,
+    16923981731900594251,
+    10124698975736644826,
+    7930893599811279108,
+    9491535554283431808,
+    9731418907571782196,
+    11961742287713201024,
+    4406915736269813635,
+    4235558225252982051,
+    6180925375935460282,
+    3486775903553450041,
+    8134061854818648345,
+    15872801495929911513,
+    12154664691880299109,
+    12436725877480203229,
+    11279375934079770343,
+    1421707809358590163,
+    16492984895082073284,
+    15052938285971930764,
+    295660088391095584,
+    17138236062571986826,
+    7049574641734997103,
+    6065486277825966535,
+    8173340577147814437,
+    6428159922826264929,
+    8430335998027299012,
+    10509180890766933770,
+    11593874046938299977,
+    5645443164207145159,
+    9185425373129851025,
+    11247590037967446337,
+    2286990658165612148,
+    2436257297986758180,
+    16758054495284943769,
+    449000295064004218,
+    5286223823225246727,
+    4728988128519715130,
+    4872836892240889960,
+    8625967424323758864,
+    14291741089545078485,
+    4256932351670608866,
+    8255921240632656409,
+    9273154111303792856,
+    4866574458236865341,
+    17851198860288717688,
+    10515578408088093689,
+    989601721227073007,
+    3318859256227177459,
+    3639765587653231987,
+    12207250128671781552,
+    17184182793916195761,
+    10931825862882928582,
+    1045669925825984678,
+    734855840691928688,
+    6073676176496335644,
+    8575030922484011819,
+    14702170895334515870,
+    1603597690223280444,
+    1609589173114950976,
+    6833887665861052995,
+    14684580579930295067,
+    12313391093259907960,
+    7262832538852795255,
+    15237510871838120612,
+    4905776633439333854,
+    13771781267681085022,
+    11012466190831123103,
+    7780727506534105705,
+    14736672063985368035,
+    17135741466704711684,
+    15285382017614809052,
+    12926955332841589949,
+    5105797911174618489,
+    10260656009622521756,
+    829533036383024409,
+    2192532883890005533,
+    11710240057349848150,
+    8837680471021546283,
+    4898771104281683402,
+    2244562231644147468,
+    4376722790468586155,
+    2667207772204634737,
+    15111483591195625494,
+    5017434565751998854,
+    16210462313681816043,
+    11789960307791311852,
+    17498387707190079456,
+    8771200812035791109,
+    13053781888096000694,
+    18093547961790904979,
+    2626780862710801622,
+    6383016450591812580,
+    6988594908528268424,
+    2459091979166829198,
+    26191612560563027,
+    7296567806267879779,
+    4510092567328037324,
+    13660596094610046295,
+    5002130124976170084,
+    10372840036610412263,
+    4131323231238971086,
+    12904695816541491129,
+    2793036212732305113,
+    10630026355419950536,
+    13195970378284078106,
+    12826036524037111157,
+    8241657900064778301,
+    10291770279862225962,
+    15983803700221481271,
+    7805713203538676752,
+    8038612731364829651,
+    11373764980316588496,
+    6545558859985696356,
+    4573834136956770054,
+    3014345732936052446,
+    10596842208549329553,
+    16169975402451463557,
+    8211042999086876151,
+    556106600446492926,
+    12634689115419263259,
+    17685638924558587727,
+    2702339952887880382,
+    17915425491319855257,
+    14783902719493379780,
+    1648228726383166714,
+    3345859234009461301,
+    16666112408994332158,
+    6048934515707873228,
+    5021612257064369360,
+    1410750911814048953,
+    12336525235596819062,
+    14244348939553820444,
+    12752256303071291561,
+    13016816240118413816,
+    1618840887288244052,
+    9629217014653256154,
+    13266077691144049981,
+    14719678604933469594,
+    16102129453889299702,
+    13420049344308806919,
+    9946560226137207013,
+    16326774670283905964,
+    15317822305540338803,
+    6380943727781782939,
+    2362990043101141067,
+    8847851091901908899,
+    9543798483305402516,
+    17456364093122594436,
+    16550456662435235503,
+    10475323986594875227,
+    15243713283960780391,
+    14212918701054771604,
+    15327310205365063722,
+    16856440888446696420,
+    2368461788091639213,
+    4713590652810209112,
+    5133767694905187340,
+    5802521406136289434,
+    14827946870540117102,
+    10850955517985275114,
+    5708613495483689858,
+    12013707537731874301,
+    1190182105687445201,
+    18113847544559372945,
+    4862635472729655814,
+    14743868354327649713,
+    8670965842561805978,
+    4553736675797522941,
+    8228679667297353661,
+    15958992244393384379,
+    1282978668053041947,
+    7076257408461728415,
+    7553801690219087585,
+    3762135028538624178,
+    12668992554126023442,
+    17262518647370334781,
+    3650209100206065546,
+    11185453290205484017,
+    10714083704550632605,
+    9829702752688221688,
+    10931138761551850987,
+    2090814981316730316,
+    17812566266670269376,
+    18059537854430057610,
+    4110636887577506737,
+    13268599773788114151,
+    6357712109960060589,
+    62262600045206036,
+    110973013506812386,
+    9795348466424302190,
+    4760774128913774079,
+    388881585180680250,
+    12111783848248547052,
+    12726750653526279831,
+    12430488718247539191,
+    12563513080860800550,
+    12776391207973367848,
+    13197905933926079383,
+    11901113335195913227,
+    10840374920417569711,
+    9855291260857702656,
+    12620051669502230064,
+    2003186835892995743,
+    3993216402308716195,
+    13023712663508014,
+    4926843276926509871,
+    2197463234169447661,
+    6995423589729972589,
+    7773809676193628350,
+    8231430747095433468,
+    4407627499432394646,
+    12324668311334582433,
+    14848100077876386623,
+    15331803136792113016,
+    16210088023867936720,
+    17696051298910628788,
+    9131894856710166002,
+    9644648263797889712,
+    16147012230774008793,
+    15561125863263215054,
+    12613189906433550777,
+    12493376560650701974,
+    6189095273396297602,
+    9105772223902788917,
+    2309327508844924986,
+    17213538643426858893,
+    8585387311454270455,
+    1734833753917955036,
+    9763976043199226071,
+    7514545594640321746,
+    1226435054631333856,
+    8815221003282661832,
+    10658542157502575050,
+    525205781297883318,
+    5347132944591864603,
+    417204392797696977,
+    17749480659541174833,
+    802676702347630828,
+    3399317822181362573,
+    498087767304515897,
+    17964250241097779197,
+    18407843895271407857,
+    2034705135105749297,
+    16468916372094062391,
+    5349976238204277325,
+    11058050304693553353,
+    12684738528636676810,
+    13597202061847143828,
+    9799849493620627146,
+    16375184664288259840,
+    13652718715076174173,
+    3650526964046809386,
+    750559023394283630,
+    6681691784417346717,
+    1434889630174007236,
+    7503604685761761265,
+    14633064046615872125,
+    11723784036854003811,
+    6567515974099726909,
+    18280752060081184405,
+    10436889915121873255,
+    11698622227755093201,
+    3875598801194192743,
+    16504196721553279898,
+    15200839928525745349,
+    560610448316580325,
+    1066521405746633496,
+    10186978030979389174,
+    17450778048171507370,
+    948152712418322172,
+    3939462260505055960,
+    11991662849712549948,
+    17280402793014730193,
+    9090662070226262143,
+    1994502056633536032,
+    6442260501036485718,
+    17722508508545957098,
+    9908367486506269895,
+    1348930474741308758,
+    14380318149481941529,
+    320728131337910304,
+    1748297423991376815,
+    18417671903288573481,
+    9028644349465164736,
+    17787097392350917830,
+    16543199685510703655,
+    2577044698958281187,
+    10898085704502691517,
+    7834583221846087849,
+    4001439359742135961,
+    5226253651136119234,
+    13075943906647514727,
+    13981886171007889309,
+    515685235347419866,
+    7075907446038373721,
+    11052289268655381678,
+    520282389582800429,
+    7574157057319289938,
+    6903828158775778934,
+    6729521535276869796,
+    14908813693922732391,
+    2730005316860650623,
+    1212332446486293142,
+    1906250809934068800,
+    219186395581192050,
+    13959167077040537157,
+    5830089926728430265,
+    9947760106288569000,
+    8475373643620751672,
+    13766302261104132272,
+    5672103097295754979,
+    7399090596525000438,
+    17215002450256497302,
+    7414913480047203796,
+    16971180848097882002,
+    8105174575684222671,
+    4037651090089516251,
+    5147209822837010655,
+    272466428151830510,
+    8326103537301549529,
+    6185157654513043380,
+    14904467190096952869,
+    6699154336723988545,
+    14936320338135314982,
+    15281435164516006525,
+    18027692599609892740,
+    12661259722161024745,
+    10830700263284062458,
+    15865680017534367169,
+    8918573767897252982,
+    2155871784480007599,
+    1183759625556688793,
+    12679858258091600811,
+    6315045769268747030,
+    5770815962829944991,
+    11776955671873066710,
+    11922518976591627883,
+    18337990906468961477,
+    15934634791736597560,
+    2380525323940795846,
+    1628048669822340666,
+    3913375695336568892,
+    18185533083026702080,
+    3318763195913828046,
+    13587321757463773225,
+    8216814738385566047,
+    15272808771384335438,
+    12246942827039870677,
+    6427113868632444875,
+    14240929616543862684,
+    7054004669221810554,
+    12457629499145047798,
+    15555213362756237597,
+    10006361341917635159,
+    7785386073038914045,
+    12613307184124662873,
+    16798209396925720442,
+    9694751247866127033,
+    7112290309520694879,
+    6289075779098765025,
+    14143311563760467545,
+    18314062087439834442,
+    2306647458845448209,
+    6976996054898635224,
+    6875044741797285651,
+    6930132119620228750,
+    11575339725234765904,
+    5003734651286006789,
+    1453282138280820379,
+    11195315527375368831,
+    14447373175492400439,
+    12833272375772866612,
+    15961203251497891370,
+    7042650990187575588,
+    7480432622936247010,
+    10574721790674884041,
+    16582831545273096741,
+    202108445821600918,
+    6532804965593997282,
+    18112050974868335716,
+    4583973540850875170,
+    411518755734764046,
+    7031164098182014958,
+    17392664166376501479,
+    7756290447372141585,
+    15040963911500614226,
+    3314000569177605699,
+    10767291573790812933,
+    17202336893086663386,
+    3612074109227540530,
+    8528813601444843092,
+    17572249418749431376,
+    10135122147556860327,
+    1877481367694973920,
+    17658484567410536108,
+    10466534757715464752,
+    7608742244395000402,
+    3412764470914179963,
+    1828251943971596242,
+    3563676574185874294,
+    8107367849340155489,
+    9070476725888250725,
+    15996283222185555224,
+    17649824713884329942,
+    3168410209207272992,
+    2316649909853341243,
+    5529214780350079539,
+    8837074116629373495,
+    16814431652107395701,
+    9975115629247120961,
+    17600414179084209025,
+    6682778887204063807,
+    9021465207437547867,
+    2472215957462619157,
+    3235150741778262762,
+    11145711336496127400,
+    8964060733109161931,
+    10990597054072207822,
+    14011296985901813845,
+];
+
+pub const MDS_MATRIX_24: [[u64; 24]; 24] = [
+    [
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+        2144970240629602828,
+        7127151117728362124,
+        6558842335791852203,
+        3609145578798505628,
+        17269292320303015109,
+    ],
+    [
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+        2144970240629602828,
+        7127151117728362124,
+        6558842335791852203,
+        3609145578798505628,
+    ],
+    [
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+        2144970240629602828,
+        7127151117728362124,
+        6558842335791852203,
+    ],
+    [
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+        2144970240629602828,
+        7127151117728362124,
+    ],
+    [
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+        2144970240629602828,
+    ],
+    [
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+    ],
+    [
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+    ],
+    [
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+    ],
+    [
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+    ],
+    [
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+    ],
+    [
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+    ],
+    [
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+    ],
+    [
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+    ],
+    [
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+    ],
+    [
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+    ],
+    [
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+    ],
+    [
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+    ],
+    [
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+    ],
+    [
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+    ],
+    [
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+    ],
+    [
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+    ],
+    [
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+    ],
+    [
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+    ],
+    [
+        18446744069414584320,
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+    ],
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{goldilocks_field::GoldilocksField, types::PrimeField64};
+
+    use super::*;
+    use crate::hashes::poseidon::{N_FULL_ROUNDS_TOTAL, N_PARTIAL_ROUNDS};
+    use crate::parameters::{
+        mds::to_u64_matrix,
+        poseidon::{grain_round_constants, is_secure, mds_matrix, number_of_rounds},
+    };
+
+    fn check_width<const WIDTH: usize>()
+    where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
+        let generated =
+            grain_round_constants::<GoldilocksField>(WIDTH, N_FULL_ROUNDS_TOTAL, N_PARTIAL_ROUNDS);
+        let generated: Vec<u64> = generated.iter().map(|x| x.to_canonical_u64()).collect();
+        assert_eq!(generated, Width::<WIDTH>::ROUND_CONSTANTS);
+        assert_eq!(
+            to_u64_matrix(&mds_matrix::<GoldilocksField>(WIDTH)),
+            Width::<WIDTH>::MDS_MATRIX.map(|row| row.to_vec())
+        );
+    }
+
+    fn check_rounds<const WIDTH: usize>()
+    where
+        Width<WIDTH>: PoseidonParameters<WIDTH>,
+    {
+        assert!(is_secure(
+            64,
+            WIDTH,
+            7,
+            128,
+            N_FULL_ROUNDS_TOTAL,
+            N_PARTIAL_ROUNDS
+        ));
+        assert_eq!(
+            number_of_rounds(64, WIDTH, 7, 128),
+            (N_FULL_ROUNDS_TOTAL, N_PARTIAL_ROUNDS)
+        );
+    }
+
+    #[test]
+    fn test_poseidon_widths() {
+        check_width::<8>();
+        check_width::<16>();
+        check_width::<24>();
+    }
+
+    // Every width shares the rounds of width 12
+    #[test]
+    fn test_number_of_rounds() {
+        check_rounds::<8>();
+        check_rounds::<12>();
+        check_rounds::<16>();
+        check_rounds::<24>();
+    }
+}
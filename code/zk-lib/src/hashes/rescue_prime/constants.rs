@@ -168,4 +168,25 @@ pub const ROUND_CONSTANTS: [u64; 168] = [
     9643968136937729763,
     3611348709641382851,
     18256379591337759196,
-];
\ No newline at end of file
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{goldilocks_field::GoldilocksField, types::PrimeField64};
+
+    use super::*;
+    use crate::hashes::rescue_prime::{NUMBER_OF_ROUNDS, SPONGE_CAPACITY, SPONGE_WIDTH};
+    use crate::parameters::rescue_prime::round_constants;
+
+    #[test]
+    fn test_rescue_constants() {
+        let generated = round_constants::<GoldilocksField>(
+            SPONGE_WIDTH,
+            SPONGE_CAPACITY,
+            128,
+            NUMBER_OF_ROUNDS,
+        );
+        let generated: Vec<u64> = generated.iter().map(|x| x.to_canonical_u64()).collect();
+        assert_eq!(generated, ROUND_CONSTANTS);
+    }
+}
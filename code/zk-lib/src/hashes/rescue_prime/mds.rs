@@ -13,4 +13,20 @@ pub const MDS_MATRIX: [[u64; 12]; 12] = [
     [26, 13, 10, 9, 7, 6, 22, 21, 8, 7, 23, 8],
     [8, 26, 13, 10, 9, 7, 6, 22, 21, 8, 7, 23],
     [23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8, 7],
-];
\ No newline at end of file
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{goldilocks_field::GoldilocksField, types::Field};
+
+    use super::*;
+    use crate::parameters::mds::{circulant, is_mds, to_u64_matrix};
+
+    #[test]
+    fn test_rescue_mds() {
+        let circ = MDS_MATRIX_CIRC.map(GoldilocksField::from_canonical_u64);
+        let matrix = circulant(&circ);
+        assert_eq!(to_u64_matrix(&matrix), MDS_MATRIX.map(|row| row.to_vec()));
+        assert!(is_mds(&matrix));
+    }
+}
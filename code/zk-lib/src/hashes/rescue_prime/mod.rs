@@ -8,6 +8,7 @@ use plonky2::{
 mod constants;
 mod mds;
 pub mod rescue_prime;
+mod widths;
 
 pub const ALPHA: usize = 7;
 pub const ALPHA_INV: usize = 10540996611094048183;
@@ -19,6 +20,14 @@ pub const SPONGE_WIDTH: usize = SPONGE_RATE + SPONGE_CAPACITY;
 // Number of rounds calculated using the sage code provided in the rescue-prime paper
 pub const NUMBER_OF_ROUNDS: usize = 7;
 
+/// Constants of the Rescue-prime permutation for a state width, implemented for
+/// [`Width`](super::Width) 8, 12, 16 and 24.
+pub trait RescueParameters<const WIDTH: usize> {
+    /// The `2 * WIDTH * NUMBER_OF_ROUNDS` round constants.
+    const ROUND_CONSTANTS: &'static [u64];
+    const MDS_MATRIX: [[u64; WIDTH]; WIDTH];
+}
+
 trait CircuitBuilderExtensions<F: RichField + Extendable<D>, const D: usize> {
     fn exp_inv(&mut self, x: Target) -> Target;
     fn exp_inv_extension(&mut self, x: ExtensionTarget<D>) -> ExtensionTarget<D>;
@@ -101,3 +110,17 @@ impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D>
         })
     }     
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parameters::rescue_prime::round_bounds;
+
+    // RPO takes 7 rounds, a 40% margin over the 5 rounds sanity minimum of the reference script
+    // instead of 50%.
+    #[test]
+    fn test_number_of_rounds() {
+        let bounds = round_bounds(64, SPONGE_WIDTH, SPONGE_CAPACITY, ALPHA as u64, 128);
+        assert!(NUMBER_OF_ROUNDS >= bounds.minimum());
+    }
+}
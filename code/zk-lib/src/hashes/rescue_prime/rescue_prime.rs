@@ -15,8 +15,8 @@ use plonky2::{
     },
 };
 
-use self::{constants::ROUND_CONSTANTS, mds::MDS_MATRIX};
 use super::*;
+use crate::hashes::{Permutation, Width};
 
 pub struct Rescue;
 
@@ -25,11 +25,15 @@ impl Rescue {
      *    HASH FUNCTION     *
      ************************ */
 
-    fn mds_layer<F: RichField + Extendable<2>>(state: &[F; SPONGE_WIDTH]) -> [F; SPONGE_WIDTH] {
-        let mut new_state: [F; SPONGE_WIDTH] = [F::ZERO; SPONGE_WIDTH];
-        for i in 0..SPONGE_WIDTH {
-            for j in 0..SPONGE_WIDTH {
-                let ct = MDS_MATRIX[i][j];
+    fn mds_layer<F: RichField + Extendable<2>, const WIDTH: usize>(state: &[F; WIDTH]) -> [F; WIDTH]
+    where
+        Width<WIDTH>: RescueParameters<WIDTH>,
+    {
+        let mds_matrix = &Width::<WIDTH>::MDS_MATRIX;
+        let mut new_state: [F; WIDTH] = [F::ZERO; WIDTH];
+        for i in 0..WIDTH {
+            for j in 0..WIDTH {
+                let ct = mds_matrix[i][j];
                 let mut temp = state[j].clone();
                 temp.mul_assign(F::from_canonical_u64(ct));
                 new_state[i].add_assign(temp)
@@ -38,17 +42,21 @@ impl Rescue {
         new_state
     }
 
-    fn rescue_permutation<F: RichField + Extendable<2>>(state: &mut [F; SPONGE_WIDTH]) {
+    fn rescue_permutation<F: RichField + Extendable<2>, const WIDTH: usize>(state: &mut [F; WIDTH])
+    where
+        Width<WIDTH>: RescueParameters<WIDTH>,
+    {
+        let round_constants = Width::<WIDTH>::ROUND_CONSTANTS;
         for i in 0..NUMBER_OF_ROUNDS {
             // MDS
             *state = Self::mds_layer(state);
 
             // Constants
-            for j in 0..SPONGE_WIDTH {
-                state[j] += F::from_canonical_u64(ROUND_CONSTANTS[i * 2 * SPONGE_WIDTH + j]);
+            for j in 0..WIDTH {
+                state[j] += F::from_canonical_u64(round_constants[i * 2 * WIDTH + j]);
             }
             // S-Box
-            for j in 0..SPONGE_WIDTH {
+            for j in 0..WIDTH {
                 state[j] = state[j].exp_u64(ALPHA as u64);
             }
 
@@ -56,12 +64,11 @@ impl Rescue {
             *state = Self::mds_layer(state);
 
             // Constants
-            for j in 0..SPONGE_WIDTH {
-                state[j] +=
-                    F::from_canonical_u64(ROUND_CONSTANTS[i * 2 * SPONGE_WIDTH + SPONGE_WIDTH + j]);
+            for j in 0..WIDTH {
+                state[j] += F::from_canonical_u64(round_constants[i * 2 * WIDTH + WIDTH + j]);
             }
             // Inverse S-Box
-            for j in 0..SPONGE_WIDTH {
+            for j in 0..WIDTH {
                 state[j] = state[j].exp_u64(ALPHA_INV as u64);
             }
         }
@@ -70,24 +77,42 @@ impl Rescue {
     pub fn rescue_hash<F: RichField + Extendable<2>, const L: usize>(
         input: [F; L],
     ) -> [F; SPONGE_RATE / 2] {
+        Self::rescue_hash_width::<F, SPONGE_WIDTH, SPONGE_RATE, L, { SPONGE_RATE / 2 }>(input)
+    }
+
+    /// Rescue-prime hash over a state of `WIDTH` elements, `RATE` of which are overwritten by
+    /// the input. The `H` output elements follow the capacity.
+    pub fn rescue_hash_width<
+        F: RichField + Extendable<2>,
+        const WIDTH: usize,
+        const RATE: usize,
+        const L: usize,
+        const H: usize,
+    >(
+        input: [F; L],
+    ) -> [F; H]
+    where
+        Width<WIDTH>: RescueParameters<WIDTH>,
+    {
+        assert!(RATE < WIDTH && H <= RATE);
         assert!(input.len() > 0);
-        assert!(input.len() % SPONGE_RATE == 0);
+        assert!(input.len() % RATE == 0);
 
-        let mut state = [F::ZERO; SPONGE_WIDTH];
+        let capacity = WIDTH - RATE;
+        let mut state = [F::ZERO; WIDTH];
 
         let mut counter = 0;
         while counter < input.len() {
-            for i in 0..SPONGE_RATE {
-                state[i + SPONGE_CAPACITY] = input[counter]; // Overwrite mode
+            for i in 0..RATE {
+                state[i + capacity] = input[counter]; // Overwrite mode
                 counter += 1;
             }
-            // state[SPONGE_RATE..].copy_from_slice(&input[..SPONGE_RATE]);
             Self::rescue_permutation(&mut state);
         }
 
-        let mut output = [F::ZERO; SPONGE_RATE / 2];
-        for i in SPONGE_CAPACITY..(SPONGE_CAPACITY + SPONGE_RATE / 2) {
-            output[i - SPONGE_CAPACITY] = state[i]
+        let mut output = [F::ZERO; H];
+        for i in capacity..(capacity + H) {
+            output[i - capacity] = state[i]
         }
         output
     }
@@ -98,39 +123,46 @@ impl Rescue {
      *********************************
      ********************************* */
 
-    fn mds_layer_circuit<F: RichField + Extendable<2>>(
-        state: &[Target; SPONGE_WIDTH],
+    fn mds_layer_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &[Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
-    ) -> [Target; SPONGE_WIDTH] {
-        let mut new_state = [Target::default(); SPONGE_WIDTH];
-        for i in 0..12 {
-            let mut sum = Target::default();
-            for j in 0..12 {
-                sum = builder.mul_const_add(F::from_canonical_u64(MDS_MATRIX[i][j]), state[j], sum);
+    ) -> [Target; WIDTH]
+    where
+        Width<WIDTH>: RescueParameters<WIDTH>,
+    {
+        let mds_matrix = &Width::<WIDTH>::MDS_MATRIX;
+        let mut new_state = [Target::default(); WIDTH];
+        for i in 0..WIDTH {
+            let mut sum = builder.zero();
+            for j in 0..WIDTH {
+                sum = builder.mul_const_add(F::from_canonical_u64(mds_matrix[i][j]), state[j], sum);
             }
             new_state[i] = sum;
         }
         new_state
     }
 
-    fn rescue_permutation_circuit<F: RichField + Extendable<2>>(
-        state: &mut [Target; SPONGE_WIDTH],
+    fn rescue_permutation_circuit<F: RichField + Extendable<2>, const WIDTH: usize>(
+        state: &mut [Target; WIDTH],
         builder: &mut CircuitBuilder<F, 2>,
-    ) {
+    ) where
+        Width<WIDTH>: RescueParameters<WIDTH>,
+    {
+        let round_constants = Width::<WIDTH>::ROUND_CONSTANTS;
         for i in 0..NUMBER_OF_ROUNDS {
             // MDS
             *state = Self::mds_layer_circuit(state, builder);
 
             // Constants
-            for j in 0..SPONGE_WIDTH {
+            for j in 0..WIDTH {
                 state[j] = builder.add_const(
                     state[j],
-                    F::from_canonical_u64(ROUND_CONSTANTS[i * 2 * SPONGE_WIDTH + j]),
+                    F::from_canonical_u64(round_constants[i * 2 * WIDTH + j]),
                 );
             }
 
             // S-box
-            for j in 0..SPONGE_WIDTH {
+            for j in 0..WIDTH {
                 //state[j] = builder.exp_u64(state[j], ALPHA as u64);
                 let x2 = builder.mul(state[j], state[j]);
                 let x4 = builder.mul(x2, x2);
@@ -142,15 +174,15 @@ impl Rescue {
             *state = Self::mds_layer_circuit(state, builder);
 
             // Constants
-            for j in 0..SPONGE_WIDTH {
+            for j in 0..WIDTH {
                 state[j] = builder.add_const(
                     state[j],
-                    F::from_canonical_u64(ROUND_CONSTANTS[i * 2 * SPONGE_WIDTH + SPONGE_WIDTH + j]),
+                    F::from_canonical_u64(round_constants[i * 2 * WIDTH + WIDTH + j]),
                 );
             }
 
             // Inverse S-box
-            for j in 0..SPONGE_WIDTH {
+            for j in 0..WIDTH {
                 //state[j] = builder.exp_u64(state[j], ALPHA_INV as u64);
                 state[j] = builder.exp_inv(state[j]);
             }
@@ -164,29 +196,50 @@ impl Rescue {
     >(
         x: [F; L],
     ) -> (CircuitData<F, C, 2>, PartialWitness<F>) {
+        Self::circuit_generation_width::<F, C, SPONGE_WIDTH, SPONGE_RATE, L, { SPONGE_RATE / 2 }>(x)
+    }
+
+    /// Circuit generation for the Rescue-prime hash over a state of `WIDTH` elements, `RATE`
+    /// of which are overwritten by the input, with `H` output elements
+    pub fn circuit_generation_width<
+        F: RichField + Extendable<2>,
+        C: GenericConfig<2, F = F>,
+        const WIDTH: usize,
+        const RATE: usize,
+        const L: usize,
+        const H: usize,
+    >(
+        x: [F; L],
+    ) -> (CircuitData<F, C, 2>, PartialWitness<F>)
+    where
+        Width<WIDTH>: RescueParameters<WIDTH>,
+    {
+        assert!(RATE < WIDTH && H <= RATE);
+
         let config = CircuitConfig::standard_recursion_config();
         let mut builder = CircuitBuilder::<F, 2>::new(config);
 
+        let capacity = WIDTH - RATE;
         let input = builder.add_virtual_target_arr::<L>();
-        let mut state = builder.add_virtual_target_arr::<SPONGE_WIDTH>();
-        let mut output = [Target::default(); SPONGE_RATE / 2];
+        let mut state = builder.add_virtual_target_arr::<WIDTH>();
+        let mut output = [Target::default(); H];
 
         for i in 0..state.len() {
-            state[i] = Target::default();
+            state[i] = builder.zero();
         }
 
         // The arithmetic circuit
         let mut overwrite_index = 0;
         while overwrite_index < input.len() {
-            for i in 0..SPONGE_RATE {
-                state[i + SPONGE_CAPACITY] = input[overwrite_index];
+            for i in 0..RATE {
+                state[i + capacity] = input[overwrite_index];
                 overwrite_index += 1;
             }
             Self::rescue_permutation_circuit(&mut state, &mut builder);
         }
 
-        for i in SPONGE_CAPACITY..(SPONGE_CAPACITY + SPONGE_RATE / 2) {
-            output[i - SPONGE_CAPACITY] = state[i]
+        for i in capacity..(capacity + H) {
+            output[i - capacity] = state[i]
         }
 
         builder.register_public_inputs(&output);
@@ -230,6 +283,22 @@ impl Rescue {
     }
 }
 
+impl<const WIDTH: usize> Permutation<WIDTH> for Rescue
+where
+    Width<WIDTH>: RescueParameters<WIDTH>,
+{
+    fn permute<F: RichField + Extendable<2>>(state: &mut [F; WIDTH]) {
+        Self::rescue_permutation(state);
+    }
+
+    fn permute_circuit<F: RichField + Extendable<2>>(
+        state: &mut [Target; WIDTH],
+        builder: &mut CircuitBuilder<F, 2>,
+    ) {
+        Self::rescue_permutation_circuit(state, builder);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use plonky2::{
@@ -237,7 +306,8 @@ mod tests {
         plonk::config::PoseidonGoldilocksConfig,
     };
 
-    use super::{Rescue, SPONGE_RATE};
+    use super::{Rescue, RescueParameters, Width, SPONGE_RATE};
+    use crate::hashes::kat;
 
     #[test]
     fn rescue_test() {
@@ -262,4 +332,60 @@ mod tests {
             Rescue::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw);
         Rescue::proof_verification::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &proof);
     }
+
+    fn check_width<const WIDTH: usize, const RATE: usize>()
+    where
+        Width<WIDTH>: RescueParameters<WIDTH>,
+    {
+        let input: [GoldilocksField; RATE] =
+            core::array::from_fn(|i| GoldilocksField(i as u64 + 1));
+        let output = Rescue::rescue_hash_width::<GoldilocksField, WIDTH, RATE, RATE, 4>(input);
+
+        let (data, pw) = Rescue::circuit_generation_width::<
+            GoldilocksField,
+            PoseidonGoldilocksConfig,
+            WIDTH,
+            RATE,
+            RATE,
+            4,
+        >(input);
+        let proof =
+            Rescue::proof_generation::<GoldilocksField, PoseidonGoldilocksConfig, 2>(&data, &pw);
+        assert_eq!(proof.public_inputs, output);
+        assert!(data.verify(proof).is_ok());
+    }
+
+    #[test]
+    fn test_rescue_widths() {
+        check_width::<8, 4>();
+        check_width::<16, 12>();
+        check_width::<24, 20>();
+    }
+
+    fn check_kat<const WIDTH: usize, const RATE: usize>()
+    where
+        Width<WIDTH>: RescueParameters<WIDTH>,
+    {
+        kat::check_kat::<Rescue, _, WIDTH, RATE>(
+            include_str!("../../../kat/rescue.json"),
+            "rescue",
+            Rescue::rescue_hash_width::<GoldilocksField, WIDTH, RATE, RATE, 4>,
+            Rescue::circuit_generation_width::<
+                GoldilocksField,
+                PoseidonGoldilocksConfig,
+                WIDTH,
+                RATE,
+                RATE,
+                4,
+            >,
+        );
+    }
+
+    #[test]
+    fn test_rescue_kat() {
+        check_kat::<8, 4>();
+        check_kat::<12, 8>();
+        check_kat::<16, 12>();
+        check_kat::<24, 20>();
+    }
 }
@@ -0,0 +1,1534 @@
+// Rescue-prime constants for the widths other than 12, generated for a capacity of 4 with
+// `crate::parameters::rescue_prime` as in `rescue_constants.sage`. Width 16 uses the MDS
+// matrix of the reference, widths 8 and 24 a Cauchy matrix.
+
+use super::{constants, mds, RescueParameters};
+use crate::hashes::Width;
+
+impl RescueParameters<8> for Width<8> {
+    const ROUND_CONSTANTS: &'static [u64] = &ROUND_CONSTANTS_8;
+    const MDS_MATRIX: [[u64; 8]; 8] = MDS_MATRIX_8;
+}
+
+impl RescueParameters<12> for Width<12> {
+    const ROUND_CONSTANTS: &'static [u64] = &constants::ROUND_CONSTANTS;
+    const MDS_MATRIX: [[u64; 12]; 12] = mds::MDS_MATRIX;
+}
+
+impl RescueParameters<16> for Width<16> {
+    const ROUND_CONSTANTS: &'static [u64] = &ROUND_CONSTANTS_16;
+    const MDS_MATRIX: [[u64; 16]; 16] = MDS_MATRIX_16;
+}
+
+impl RescueParameters<24> for Width<24> {
+    const ROUND_CONSTANTS: &'static [u64] = &ROUND_CONSTANTS_24;
+    const MDS_MATRIX: [[u64; 24]; 24] = MDS_MATRIX_24;
+}
+
+pub const ROUND_CONSTANTS_8: [u64; 112] = [
+    18377162187661606380,
+    16138207094781267467,
+    6101534911753432929,
+    6709217546701790312,
+    16431329676179785938,
+    671301377895702381,
+    18037077827304586030,
+    18089922452082286581,
+    2898813091495940268,
+    17422355051878714986,
+    2368441303333426421,
+    6993103395452602366,
+    1830243317000610754,
+    5447389732074840042,
+    13233236975221269190,
+    859195956482598344,
+    4502679878365374330,
+    13924482801345228213,
+    1215213925626383177,
+    3618981317095786809,
+    6819092730742727595,
+    13135852747733608101,
+    243004534205580408,
+    11336688524982696259,
+    5243485963492499600,
+    10952658716994912403,
+    16080424562117477812,
+    8158657771417691732,
+    2943158609918875937,
+    16545232808845210058,
+    12811585484869135206,
+    3771683258544348301,
+    16329411205387487880,
+    14316751188489630557,
+    13817177665035944815,
+    16971339309775456954,
+    17535900548467046237,
+    8698490450140668462,
+    10268786741113790065,
+    4670416344181747877,
+    3984384869695953487,
+    10384079750595874268,
+    11457371227397330434,
+    9600221675783844297,
+    3346663312411450540,
+    3369568271608283392,
+    16065399029235427850,
+    5417080631209305368,
+    15840558891327303705,
+    5670653417392398481,
+    1946103292706714730,
+    7698690326084571671,
+    17128797218744232671,
+    18268323810986051914,
+    13922329809126264906,
+    12895299227546712278,
+    134202767984434769,
+    6784843431915015690,
+    8390724299640225503,
+    10493718054903097622,
+    2318639007496733112,
+    11803224030317275442,
+    10555027741898401251,
+    7831038536535857913,
+    11806417853492336906,
+    10653545463262127412,
+    714757692534451276,
+    14296320359178401162,
+    2546046014829543033,
+    5362587173036406549,
+    1755692376537844752,
+    9720413929923344740,
+    3280759401133850731,
+    12633545727885618060,
+    17718148665781983469,
+    3445093701620541516,
+    9906841279179665912,
+    6991881176864353376,
+    4197340869961499096,
+    14793610525863338512,
+    14315326668175085819,
+    3438893845423236738,
+    11578290091382765660,
+    3216083169018872381,
+    16807766008045033585,
+    13215253930167624729,
+    13551213509695640667,
+    3717421626093288766,
+    15865381159650949982,
+    9763278135029492975,
+    3236078088383202394,
+    10539552851548762184,
+    1943365464203517464,
+    5725819519598373822,
+    17768950924976535276,
+    4388454925180655866,
+    3730062119038801345,
+    5900936705570326110,
+    8632432802203640334,
+    3120827003531366315,
+    5141105001276952877,
+    1860187237356542638,
+    1332974307448548751,
+    13813118591821825485,
+    5203249237110405131,
+    14237891836832104422,
+    12769595236057404381,
+    5093054034929209467,
+    13759021729066934984,
+    5347195584665211659,
+    17898510089161646973,
+    6794667771457607818,
+];
+
+pub const MDS_MATRIX_8: [[u64; 8]; 8] = [
+    [
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+    ],
+    [
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+    ],
+    [
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+    ],
+    [
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+    ],
+    [
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+    ],
+    [
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+    ],
+    [
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+    ],
+    [
+        18446744069414584320,
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+    ],
+];
+
+pub const ROUND_CONSTANTS_16: [u64; 224] = [
+    18007298714617997645,
+    16567519826838041446,
+    10907348477981580003,
+    8130965315863604112,
+    27129093415146418,
+    1527834726971221627,
+    1516583727992754745,
+    2188879463313939152,
+    1382292348558923567,
+    2977176478357117381,
+    9253223892794224468,
+    17408952155724459064,
+    5723975267602261103,
+    9888219101831959277,
+    16538137699008607332,
+    8863323239431019710,
+    1229078452749916573,
+    14028615230051945737,
+    234129432172095968,
+    1854537764112908549,
+    6444657137032808782,
+    17645908152442874736,
+    12917361740778373572,
+    12445507604929744661,
+    11979757826136619281,
+    2755173895442300460,
+    6528350404244816683,
+    953691892091595725,
+    9950728879192406011,
+    8648640423188465863,
+    6166596795738140528,
+    9083483657920810102,
+    16305725763546380977,
+    8531056983341665393,
+    4493204324797708961,
+    18443752698477125791,
+    4054375422829828797,
+    11092744752398229101,
+    15658485247933403763,
+    9436188408217751471,
+    14231763424943027086,
+    15867467057543059339,
+    17544827513237109730,
+    17214504535224238657,
+    15040425330159296094,
+    8242277310005603450,
+    706129517793468897,
+    16747273853207085416,
+    13856796579929941416,
+    4850830558913119527,
+    15332840204257983271,
+    3766149268369888663,
+    1423462051350311070,
+    3363181430531802106,
+    4654532103377110890,
+    3619981226299633683,
+    12072542909043934912,
+    6798285051958101193,
+    12812839273994971605,
+    16872312096814998078,
+    6971631983394657053,
+    4408663559261701038,
+    7492874625748514258,
+    2696893454701400835,
+    11358771011175402135,
+    17021157058728022675,
+    16684527790962317565,
+    14349784478103374712,
+    812943372380950462,
+    5119976255577331873,
+    7909777434613868703,
+    7752542259877302089,
+    11945770278501051826,
+    14709303482661743199,
+    1867976815347003906,
+    9785454723882537063,
+    17796267935659488050,
+    16202250185136965228,
+    12660485154176198411,
+    15822178776326321521,
+    5874098767581609835,
+    3260644173296196974,
+    15018143663470636748,
+    15053402739162500331,
+    847755548397343621,
+    3378410157639921804,
+    7498741708260830057,
+    15477271461092650325,
+    3900929411112906715,
+    1933166944659314614,
+    4625905213154957617,
+    2350856475694364360,
+    8276554269454486583,
+    14082062301244208464,
+    13963166054220575923,
+    12071055870682729629,
+    12112033800717740835,
+    12521211227499998735,
+    3436843113690170263,
+    1287271008853141135,
+    11831859982816732717,
+    6663772072996436843,
+    12186689487896417877,
+    12094310115441264865,
+    6446457461302399320,
+    3256566726611095977,
+    9778990599759885203,
+    13227466579721299370,
+    1143492490017660544,
+    11666267576510378219,
+    13284817230214200570,
+    10745804575018192430,
+    13208446054293695137,
+    9428253436399938932,
+    9933593315926368612,
+    13364821888477600360,
+    7288668494189973166,
+    12634913392245490713,
+    7067547535047936036,
+    14833828415620413997,
+    402090104968837405,
+    1912300102922698668,
+    5163511839015539539,
+    6668791066701906041,
+    16307551378836233539,
+    16997506701402274811,
+    1520755906080082497,
+    6978383340005062586,
+    4438492012804328921,
+    136678596436248409,
+    2290106130388998091,
+    5850786423277476570,
+    1602631338421473330,
+    15877414301584796566,
+    918646276636943783,
+    9990947800798876016,
+    13251624558661292117,
+    14125238231671107331,
+    3194707052204339319,
+    11843014178383691550,
+    1551523732160548169,
+    9516037196679524036,
+    10955878419535963305,
+    6315312988517055139,
+    17549393970072415311,
+    2193794434925307799,
+    4224425468929822468,
+    12098518219161658525,
+    2663101549753162680,
+    16990554930921150117,
+    3754411787439861224,
+    18325446833226327797,
+    13281220809764924656,
+    8439511374085006587,
+    9974721487959531459,
+    10238523490128543881,
+    7437689350621199014,
+    4443662877344744984,
+    5901752027368536102,
+    9442077508352788497,
+    8461537889842755006,
+    8125845609783199038,
+    16737687106214749495,
+    8258834247341617472,
+    8570644165069258125,
+    9880042245101421162,
+    9392741638167857408,
+    11656280747644868928,
+    9092623893341862341,
+    6957951079628328262,
+    10351124316234484769,
+    16125746998243815325,
+    6692123541490589292,
+    15522328165975210467,
+    17157141773720183439,
+    1916362957239964923,
+    3565550199734141339,
+    6581561480944237601,
+    13343794192993185817,
+    13720194057286143299,
+    12986177652927948981,
+    15305720522056536857,
+    2365334735507733728,
+    5533729993280880514,
+    2142114100018494847,
+    16618059392373653935,
+    7264581638760480209,
+    14611023303260901058,
+    11947235282961379849,
+    8245200331469382945,
+    13206401294107917260,
+    7289418064860613025,
+    15943616058437118438,
+    2931743588929037416,
+    7171420501928620290,
+    13350776255810703523,
+    8956519402194435702,
+    14760999339613108064,
+    1916924992270578610,
+    5364736895897370964,
+    5158366070927342032,
+    15257509320703061644,
+    16116483709935374349,
+    27131756953541240,
+    18328098606993262160,
+    17558291636701873918,
+    15233029463528811449,
+    8255675513812337528,
+    1437311808454669745,
+    14425901825342766691,
+    6848722722321322114,
+    13650152192828887489,
+    10965228138883458570,
+    767254944474309781,
+    7091361302295460903,
+    4997467238776596866,
+    14002787885362136020,
+    803893679921714887,
+    16338398114416460715,
+    3878938854202929079,
+    2367731309881568560,
+    7388275296076020208,
+    728790437043395064,
+    11904007935235773344,
+];
+
+pub const MDS_MATRIX_16: [[u64; 16]; 16] = [
+    [
+        256, 2, 1073741824, 2048, 16777216, 128, 8, 16, 524288, 4194304, 1, 268435456, 1, 1024, 2,
+        8192,
+    ],
+    [
+        8192, 256, 2, 1073741824, 2048, 16777216, 128, 8, 16, 524288, 4194304, 1, 268435456, 1,
+        1024, 2,
+    ],
+    [
+        2, 8192, 256, 2, 1073741824, 2048, 16777216, 128, 8, 16, 524288, 4194304, 1, 268435456, 1,
+        1024,
+    ],
+    [
+        1024, 2, 8192, 256, 2, 1073741824, 2048, 16777216, 128, 8, 16, 524288, 4194304, 1,
+        268435456, 1,
+    ],
+    [
+        1, 1024, 2, 8192, 256, 2, 1073741824, 2048, 16777216, 128, 8, 16, 524288, 4194304, 1,
+        268435456,
+    ],
+    [
+        268435456, 1, 1024, 2, 8192, 256, 2, 1073741824, 2048, 16777216, 128, 8, 16, 524288,
+        4194304, 1,
+    ],
+    [
+        1, 268435456, 1, 1024, 2, 8192, 256, 2, 1073741824, 2048, 16777216, 128, 8, 16, 524288,
+        4194304,
+    ],
+    [
+        4194304, 1, 268435456, 1, 1024, 2, 8192, 256, 2, 1073741824, 2048, 16777216, 128, 8, 16,
+        524288,
+    ],
+    [
+        524288, 4194304, 1, 268435456, 1, 1024, 2, 8192, 256, 2, 1073741824, 2048, 16777216, 128,
+        8, 16,
+    ],
+    [
+        16, 524288, 4194304, 1, 268435456, 1, 1024, 2, 8192, 256, 2, 1073741824, 2048, 16777216,
+        128, 8,
+    ],
+    [
+        8, 16, 524288, 4194304, 1, 268435456, 1, 1024, 2, 8192, 256, 2, 1073741824, 2048, 16777216,
+        128,
+    ],
+    [
+        128, 8, 16, 524288, 4194304, 1, 268435456, 1, 1024, 2, 8192, 256, 2, 1073741824, 2048,
+        16777216,
+    ],
+    [
+        16777216, 128, 8, 16, 524288, 4194304, 1, 268435456, 1, 1024, 2, 8192, 256, 2, 1073741824,
+        2048,
+    ],
+    [
+        2048, 16777216, 128, 8, 16, 524288, 4194304, 1, 268435456, 1, 1024, 2, 8192, 256, 2,
+        1073741824,
+    ],
+    [
+        1073741824, 2048, 16777216, 128, 8, 16, 524288, 4194304, 1, 268435456, 1, 1024, 2, 8192,
+        256, 2,
+    ],
+    [
+        2, 1073741824, 2048, 16777216, 128, 8, 16, 524288, 4194304, 1, 268435456, 1, 1024, 2, 8192,
+        256,
+    ],
+];
+
+pub const ROUND_CONSTANTS_24: [u64; 336] = [
+    12883871531738893662,
+    10341478455417335314,
+    8029148273416105138,
+    11446040047400718156,
+    13310461296473941936,
+    16753100131193760414,
+    13492909428394968470,
+    4105698632506776508,
+    5806566149300185606,
+    9479298763393948549,
+    8150370863913969610,
+    8312338982088082295,
+    18066710387198733340,
+    15087687976866751632,
+    4873379252496211301,
+    2180889462065184193,
+    617640153066231387,
+    4854010599496003174,
+    17743408532614814512,
+    9643274930122600943,
+    18114797502920390742,
+    11306262604923566269,
+    16924964100013494406,
+    1638495064007331868,
+    4898014299550930220,
+    10923795948181240820,
+    4487375952096643712,
+    5969614712935646227,
+    18408862993072673377,
+    10276577745296089577,
+    7444020284798793760,
+    14549873795252794958,
+    3788718125185311626,
+    12924373982219954389,
+    1756184956963152468,
+    16598936485731452273,
+    14164721681505538448,
+    16129834966210450889,
+    17144404319855880885,
+    11311196640259073563,
+    1213876915210694485,
+    17144566183662259541,
+    12229960842486155561,
+    10788429618298939866,
+    2945990534542642505,
+    10547403688213889742,
+    12854294865108093897,
+    7445345204589922033,
+    876237746179006218,
+    6587840087004168529,
+    2658351452728610883,
+    2336475504722400258,
+    1783900933278994167,
+    7166436781367212708,
+    1772598442663012985,
+    9298235345145968767,
+    2079143062105016117,
+    8059535794530872079,
+    12699695385247227971,
+    1627969715205868010,
+    9101568807021439093,
+    10133864022783615589,
+    520853993084662335,
+    14727172456491971342,
+    4429992844051623373,
+    9509191624561102410,
+    14085481507899677400,
+    10336658169403568498,
+    9364733102409795608,
+    4084773211801644107,
+    877528882242921342,
+    8531107412841266045,
+    9789824370533146569,
+    15361860745104236089,
+    17669430803460788224,
+    3694376804623595521,
+    12730534134123731384,
+    3633485346294331043,
+    15782383524709236459,
+    15836962778870051859,
+    7522206228413073404,
+    18159713707559801155,
+    1803211567089656586,
+    16155822487917045349,
+    2103824379766896006,
+    9334008924738758023,
+    13072054983556062111,
+    13659111884058016343,
+    10376072819902047593,
+    6365651319620989485,
+    1117355170334920806,
+    977950275382076066,
+    4500169106747636427,
+    4232553438405314122,
+    5344460627658972648,
+    6045753987821517712,
+    6992364976224783520,
+    16047358903237907932,
+    17657482736467041562,
+    13496046036203433584,
+    13102600026533225445,
+    7458726771943338928,
+    5385003601554791860,
+    12749306148113176761,
+    16016874544577435168,
+    10362863572451555069,
+    9439578084775042199,
+    8849693385011306228,
+    3525572004666632996,
+    6187754254394672400,
+    3723165612917560530,
+    15667673612866104526,
+    14751960605092368968,
+    13708807001284459734,
+    10129628664446873111,
+    13302772469516802664,
+    5252967648450189338,
+    6176151236714756267,
+    11159000335032303786,
+    10301294166145840352,
+    1767022378779209105,
+    16055498360259315564,
+    13507567327434496224,
+    9495219691650846020,
+    18169634487966093212,
+    11519296599694408840,
+    14556092677869286953,
+    7138883534441995004,
+    17667088354072238965,
+    10950290802611519940,
+    4079267674895505452,
+    11268415281690126767,
+    8033851589970093521,
+    12687038466637417297,
+    4043120505620394745,
+    11760939014232873564,
+    11579607890715100359,
+    9484292846033260411,
+    15106447891100764644,
+    2583717144995129983,
+    4728219035142685419,
+    5627206327869605929,
+    16061015796203798730,
+    13437421835243644701,
+    4440689628421200309,
+    8736106511296558828,
+    10734374225178535446,
+    6720887386189047468,
+    11464368402107642788,
+    10152970109355593611,
+    452721708606145218,
+    1412914508862279814,
+    14572981358097804462,
+    12250587097676719672,
+    2075382511821269379,
+    7013633545979625929,
+    10018534061164317289,
+    9640837912129303195,
+    17847360302906136009,
+    1566874812607171156,
+    14411947387107845252,
+    18147402832776028178,
+    9837009791690554638,
+    3420328830747233663,
+    14462956701460117034,
+    12427471180259561392,
+    9603074913305561352,
+    1201526305174950389,
+    6278913249925226273,
+    2737019711728732100,
+    438340152398373196,
+    98008995678237763,
+    9230583849201506976,
+    6422459397767957616,
+    12602438782038982195,
+    10710013546555299661,
+    7967684358717517454,
+    1583037223263719796,
+    11243405697809230836,
+    16415447466564459908,
+    18381444757904130086,
+    14902136514631991198,
+    18376921476693588878,
+    4943000762325792742,
+    12519846766526546915,
+    7219420777306799242,
+    1730402211867928287,
+    8883609576438388700,
+    16520844312673632500,
+    13508241763025357678,
+    2888239230377299259,
+    6527001601456548987,
+    12281390733711988475,
+    6574882253021275439,
+    7683789089870956266,
+    11525021415680305813,
+    14471954386323625498,
+    10933501947657860398,
+    439635718686116546,
+    15924119671582738926,
+    11918706722925533708,
+    9478292085695576013,
+    15954056231870836497,
+    7100672146565345900,
+    1689643990365176504,
+    16707570337270464692,
+    13155782985045106892,
+    11841335322999648185,
+    12826475734911153013,
+    13789532836717150938,
+    8710243529719405510,
+    14867781243891926622,
+    15754102798138936632,
+    10329445509263891737,
+    1870732915996330879,
+    12409057340974601725,
+    863850594953607517,
+    11302948014473794422,
+    3867544976386855745,
+    528441367021662748,
+    17851342832043852704,
+    13768998996013172295,
+    9108450382774643600,
+    1456528179872214653,
+    16917136391965896161,
+    4837489575368739111,
+    6248744038120667839,
+    2457083384361533803,
+    2474193053873842469,
+    183045119978976986,
+    15561604663427402588,
+    9932730184220813619,
+    4586947110554860674,
+    16504354563720646365,
+    8811440784214988336,
+    4436388670066231381,
+    2284900150120940676,
+    12532823077678071070,
+    4717038951242463016,
+    17175673964849323695,
+    11878468800327884619,
+    7862063500573083265,
+    12806999513784390001,
+    5762985303630514187,
+    4817279621868239955,
+    4521145743587646126,
+    6996254869860881853,
+    11992565739026269161,
+    219209008013036698,
+    10897934781269971239,
+    10848403474745271965,
+    8034318721868695549,
+    4378147134024383686,
+    8918238052942247845,
+    6689255959123968417,
+    14771754181626283219,
+    4097651057829178762,
+    14604449125126504814,
+    6306554339036689430,
+    5740324137276410006,
+    11803252234013929345,
+    4364695089323240157,
+    9777045214571680563,
+    15179289882472049720,
+    7179944075311542347,
+    5773901427075289078,
+    14024665291768949972,
+    1088049869396634284,
+    11000164411144083199,
+    2419627445444351512,
+    2276029581532372703,
+    4909624303343762779,
+    1044833019339572881,
+    18147164964945807301,
+    596266504558839403,
+    12391218174201118974,
+    9171890079004621627,
+    6205307054628903247,
+    8301315346554527796,
+    5351091875704270232,
+    2831199329899632053,
+    11040958395027639172,
+    4814406267795825506,
+    17973949030105786018,
+    749922144442017454,
+    6231485256207524093,
+    9650173249796463253,
+    1581304318886611341,
+    4950091357578997304,
+    3540886721371305073,
+    9404689439461496318,
+    6158930652838026264,
+    10238020748141404584,
+    10132337191644647577,
+    6683712062082203883,
+    3999988543425727583,
+    13813910541149564477,
+    18081773427717551014,
+    17039339391670368139,
+    7051573200936866263,
+    14966276331093725786,
+    12910130944127416553,
+    15643377866871726354,
+    17614217660828506268,
+    3828140533977566491,
+    8642743231412651933,
+    2614361554944916505,
+    1034490148311450571,
+    4135247954309489329,
+    15136712442899746325,
+    13857265188921526181,
+    15107316183089594258,
+    5978444550635665316,
+    2697391834658600273,
+    1560784506993473034,
+    16656782146281977770,
+    11719511136998033568,
+    7948209618173705310,
+    8281101491563532645,
+    3863947009486436442,
+    3029293545985357665,
+    517794131692034800,
+    14193861867119885872,
+    15644472815071088870,
+    4986090722437428995,
+    16882177565146694544,
+    4244543852946884032,
+    2619079562774440974,
+    12159121766857965846,
+    6113656706414588560,
+    13614973076135616199,
+    14865293414018856309,
+    5568991165454121810,
+    8318738828235169909,
+    14140561207352386435,
+    1413359788828844968,
+];
+
+pub const MDS_MATRIX_24: [[u64; 24]; 24] = [
+    [
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+        2144970240629602828,
+        7127151117728362124,
+        6558842335791852203,
+        3609145578798505628,
+        17269292320303015109,
+    ],
+    [
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+        2144970240629602828,
+        7127151117728362124,
+        6558842335791852203,
+        3609145578798505628,
+    ],
+    [
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+        2144970240629602828,
+        7127151117728362124,
+        6558842335791852203,
+    ],
+    [
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+        2144970240629602828,
+        7127151117728362124,
+    ],
+    [
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+        2144970240629602828,
+    ],
+    [
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+        5709706497675942766,
+    ],
+    [
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+        899841174117784601,
+    ],
+    [
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+        461168601735364608,
+    ],
+    [
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+        14662796567996208050,
+    ],
+    [
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+        13106897101952467807,
+    ],
+    [
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+        15455380166266273350,
+    ],
+    [
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+        12810238937093461334,
+    ],
+    [
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+        3162298983328214455,
+    ],
+    [
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+        542551296159252480,
+    ],
+    [
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+        15651782846776010939,
+    ],
+    [
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+        576460752169205760,
+    ],
+    [
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+        7140675123644355221,
+    ],
+    [
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+        614891468980486144,
+    ],
+    [
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+        8269230100082399868,
+    ],
+    [
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+        8564559746513914149,
+    ],
+    [
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+        4782489203181558898,
+    ],
+    [
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+        3547450782579727754,
+    ],
+    [
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+        4427218576659500237,
+    ],
+    [
+        18446744069414584320,
+        9223372034707292160,
+        6148914689804861440,
+        4611686017353646080,
+        3689348813882916864,
+        3074457344902430720,
+        15811494916641072275,
+        2305843008676823040,
+        14347467609544676694,
+        1844674406941458432,
+        10061860401498864175,
+        1537228672451215360,
+        7094901565159455508,
+        17129119493027828298,
+        1229782937960972288,
+        1152921504338411520,
+        1085102592318504960,
+        7173733804772338347,
+        7767050134490351293,
+        922337203470729216,
+        11419412995351885532,
+        14254302235456724248,
+        7218291157597011256,
+        768614336225607680,
+    ],
+];
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{goldilocks_field::GoldilocksField, types::PrimeField64};
+
+    use super::*;
+    use crate::hashes::rescue_prime::{ALPHA, NUMBER_OF_ROUNDS, SPONGE_CAPACITY};
+    use crate::parameters::{
+        mds::to_u64_matrix,
+        rescue_prime::{mds_matrix, round_bounds, round_constants},
+    };
+
+    fn check_width<const WIDTH: usize>()
+    where
+        Width<WIDTH>: RescueParameters<WIDTH>,
+    {
+        let generated =
+            round_constants::<GoldilocksField>(WIDTH, SPONGE_CAPACITY, 128, NUMBER_OF_ROUNDS);
+        let generated: Vec<u64> = generated.iter().map(|x| x.to_canonical_u64()).collect();
+        assert_eq!(generated, Width::<WIDTH>::ROUND_CONSTANTS);
+        assert_eq!(
+            to_u64_matrix(&mds_matrix::<GoldilocksField>(WIDTH)),
+            Width::<WIDTH>::MDS_MATRIX.map(|row| row.to_vec())
+        );
+    }
+
+    fn check_rounds<const WIDTH: usize>()
+    where
+        Width<WIDTH>: RescueParameters<WIDTH>,
+    {
+        let bounds = round_bounds(64, WIDTH, SPONGE_CAPACITY, ALPHA as u64, 128);
+        assert!(NUMBER_OF_ROUNDS >= bounds.minimum());
+    }
+
+    #[test]
+    fn test_rescue_widths() {
+        check_width::<8>();
+        check_width::<16>();
+        check_width::<24>();
+    }
+
+    // Every width shares the 7 rounds of width 12, the same 40% margin over the 5 rounds minimum
+    #[test]
+    fn test_number_of_rounds() {
+        check_rounds::<8>();
+        check_rounds::<12>();
+        check_rounds::<16>();
+        check_rounds::<24>();
+    }
+}
@@ -1,2 +1,16 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
 pub mod hashes;
-pub mod examples;
\ No newline at end of file
+pub mod examples;
+pub mod parameters;
+pub mod prf;
+pub mod public_inputs;
+pub mod registry;
+pub mod sponge;
+pub mod transcript;
+pub mod bytes;
+pub mod commitment;
+pub mod digest;
+pub mod hash_chain;
+pub mod starks;
\ No newline at end of file
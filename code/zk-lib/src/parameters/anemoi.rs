@@ -0,0 +1,209 @@
+use plonky2::field::types::PrimeField64;
+
+use super::{
+    mds::{cauchy, circulant, is_mds},
+    rounds::{log2_binomial, wide_trail_rounds, RoundBounds},
+};
+
+/// First 100 digits of pi after the leading 3.
+const PI_0: &str = "1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679";
+/// The next 100 digits of pi.
+const PI_1: &str = "8214808651328230664709384460955058223172535940812848111745028410270193852110555964462294895493038196";
+
+/// Additive round constants `C` and `D` of Anemoi, each of size `rounds x columns`.
+///
+/// They are built from the digits of pi with an open Flystel, as in the reference
+/// implementation: with `g` the multiplicative generator, `delta = g^-1`,
+/// `pi_0 = PI_0^r` and `pi_1 = PI_1^i`,
+/// `C[r][i] = g * pi_0^2 + (pi_0 + pi_1)^alpha` and
+/// `D[r][i] = g * pi_1^2 + (pi_0 + pi_1)^alpha + delta`.
+pub fn round_constants<F: PrimeField64>(
+    rounds: usize,
+    columns: usize,
+    alpha: u64,
+) -> (Vec<Vec<F>>, Vec<Vec<F>>) {
+    let g = F::MULTIPLICATIVE_GROUP_GENERATOR;
+    let delta = g.inverse();
+    let pi_0 = decimal_to_field::<F>(PI_0);
+    let pi_1 = decimal_to_field::<F>(PI_1);
+
+    let mut c = Vec::with_capacity(rounds);
+    let mut d = Vec::with_capacity(rounds);
+    for r in 0..rounds {
+        let pi_0_r = pi_0.exp_u64(r as u64);
+        let (c_row, d_row) = (0..columns)
+            .map(|i| {
+                let pi_1_i = pi_1.exp_u64(i as u64);
+                let pow_alpha = (pi_0_r + pi_1_i).exp_u64(alpha);
+                (
+                    g * pi_0_r.square() + pow_alpha,
+                    g * pi_1_i.square() + pow_alpha + delta,
+                )
+            })
+            .unzip();
+        c.push(c_row);
+        d.push(d_row);
+    }
+    (c, d)
+}
+
+/// The Anemoi linear layer on one half of the state, as chosen by `get_mds` in `anemoi.sage`.
+///
+/// Up to 4 columns, it is the matrix of the fast M_2, M_3 or M_4 algorithm with the first
+/// power of the generator `b = g^k` that makes it MDS. Above, it is the first circulant
+/// matrix, in lexicographic order of non-decreasing rows with entries in `1..=columns`, that
+/// is MDS.
+///
+/// The circulant search does not scale: with 12 columns, no row with entries up to 16 gives
+/// an MDS matrix over Goldilocks, and the search space grows too fast to go further. Above 8
+/// columns a Cauchy matrix is used instead.
+pub fn mds_matrix<F: PrimeField64>(columns: usize) -> Vec<Vec<F>> {
+    match columns {
+        0 => panic!("Anemoi needs at least one column"),
+        1 => vec![vec![F::ONE]],
+        2..=4 => fast_mds_matrix(columns),
+        5..=8 => circulant_mds_matrix(columns),
+        _ => cauchy(columns),
+    }
+}
+
+fn fast_mds_matrix<F: PrimeField64>(columns: usize) -> Vec<Vec<F>> {
+    let mut b = F::ONE;
+    loop {
+        b *= F::MULTIPLICATIVE_GROUP_GENERATOR;
+        // Column i of the matrix is the image of the i-th unit vector.
+        let images: Vec<Vec<F>> = (0..columns)
+            .map(|i| {
+                let unit: Vec<F> = (0..columns).map(|j| F::from_bool(i == j)).collect();
+                fast_mds(&unit, b)
+            })
+            .collect();
+        let matrix: Vec<Vec<F>> = (0..columns)
+            .map(|i| images.iter().map(|image| image[i]).collect())
+            .collect();
+        if is_mds(&matrix) {
+            return matrix;
+        }
+    }
+}
+
+/// The addition chains of M_2, M_3 and M_4, from Figures 6 and 8 of
+/// [DL18](https://tosc.iacr.org/index.php/ToSC/article/view/888).
+fn fast_mds<F: PrimeField64>(input: &[F], b: F) -> Vec<F> {
+    let mut x = [F::ZERO; 4];
+    x[..input.len()].copy_from_slice(input);
+    match input.len() {
+        2 => {
+            x[0] += b * x[1];
+            x[1] += b * x[0];
+        }
+        3 => {
+            let t = x[0] + b * x[2];
+            x[2] += x[1];
+            x[2] += b * x[0];
+            x[0] = t + x[2];
+            x[1] += t;
+        }
+        4 => {
+            x[0] += x[1];
+            x[2] += x[3];
+            x[3] += b * x[0];
+            x[1] = b * (x[1] + x[2]);
+            x[0] += x[1];
+            x[2] += b * x[3];
+            x[1] += x[2];
+            x[3] += x[0];
+        }
+        _ => unreachable!(),
+    }
+    x[..input.len()].to_vec()
+}
+
+fn circulant_mds_matrix<F: PrimeField64>(columns: usize) -> Vec<Vec<F>> {
+    let mut upper_limit = columns as u64;
+    loop {
+        let mut row = vec![1u64; columns];
+        loop {
+            let field_row: Vec<F> = row.iter().map(|x| F::from_canonical_u64(*x)).collect();
+            let matrix = circulant(&field_row);
+            if is_mds(&matrix) {
+                return matrix;
+            }
+            if !next_combination(&mut row, upper_limit) {
+                break;
+            }
+        }
+        // No circulant MDS matrix with these coefficients, widen the search.
+        upper_limit += 1;
+    }
+}
+
+/// Advances `row` to the next non-decreasing sequence with entries in `1..=upper_limit`,
+/// following `itertools.combinations_with_replacement`.
+fn next_combination(row: &mut [u64], upper_limit: u64) -> bool {
+    let Some(i) = row.iter().rposition(|x| *x < upper_limit) else {
+        return false;
+    };
+    let value = row[i] + 1;
+    row[i..].iter_mut().for_each(|x| *x = value);
+    true
+}
+
+fn decimal_to_field<F: PrimeField64>(digits: &str) -> F {
+    let value = digits.bytes().fold(0u128, |acc, digit| {
+        (acc * 10 + (digit - b'0') as u128) % F::ORDER as u128
+    });
+    F::from_canonical_u64(value as u64)
+}
+
+/// Round bounds of Anemoi from `get_n_rounds` in `anemoi.sage`.
+///
+/// Statistical attacks follow the wide trail argument, the MDS matrix on each half of the
+/// state activating `columns + 1` Flystels every two rounds. The inverse power map in the
+/// Flystel defeats interpolation after one round. The Gröbner bound is the first `r` for which
+/// `binomial(4 * columns * r + kappa, 2 * columns * r)^2` reaches `2^security_level`, plus
+/// two rounds for the second model of the paper.
+pub fn round_bounds(
+    field_bits: usize,
+    columns: usize,
+    alpha: u64,
+    security_level: usize,
+) -> RoundBounds {
+    let kappa = match alpha {
+        3 => 1,
+        5 => 2,
+        7 => 4,
+        9 => 7,
+        11 => 9,
+        _ => panic!("No Gröbner estimate for alpha = {}", alpha),
+    };
+    let statistical = wide_trail_rounds(
+        security_level,
+        columns + 1,
+        field_bits as f64 - (alpha as f64).log2(),
+    );
+    let rounds = (1..)
+        .find(|&r| {
+            2.0 * log2_binomial((4 * columns * r + kappa) as f64, (2 * columns * r) as f64)
+                >= security_level as f64
+        })
+        .unwrap();
+
+    RoundBounds {
+        statistical,
+        interpolation: 1,
+        groebner: rounds + 2,
+    }
+}
+
+/// Number of rounds with the `min(5, columns + 1)` rounds of security margin of Anemoi, and
+/// at least 8 rounds.
+pub fn number_of_rounds(
+    field_bits: usize,
+    columns: usize,
+    alpha: u64,
+    security_level: usize,
+) -> usize {
+    let bounds = round_bounds(field_bits, columns, alpha, security_level);
+    (bounds.minimum() + (columns + 1).min(5)).max(8)
+}
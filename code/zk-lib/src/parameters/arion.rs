@@ -0,0 +1,102 @@
+use plonky2::field::types::PrimeField64;
+
+use super::{
+    mds::circulant,
+    rounds::{log2_binomial, RoundBounds},
+    shake::ShakeFieldElements,
+};
+
+/// Constants of the Arion permutation.
+pub struct ArionConstants<F> {
+    /// Coefficients `(c_1, c_2)` of the quadratic polynomials g_i, `rounds x (width - 1)`.
+    pub g_values: Vec<Vec<[F; 2]>>,
+    /// Constants of the polynomials h_i, `rounds x (width - 1)`.
+    pub h_values: Vec<Vec<F>>,
+    /// Affine round constants, `rounds x width`.
+    pub affine_constants: Vec<Vec<F>>,
+}
+
+/// Checks that `x^2 + c_1 * x + c_2` has no root, i.e. `c_1^2 - 4 * c_2` is not a square.
+/// Every pair of g_i coefficients must satisfy it.
+pub fn is_irreducible_quadratic<F: PrimeField64>(c_1: F, c_2: F) -> bool {
+    let discriminant = c_1.square() - F::from_canonical_u64(4) * c_2;
+    discriminant.exp_u64((F::ORDER - 1) / 2) == F::NEG_ONE
+}
+
+/// Derives Arion constants for a new parameter set.
+///
+/// The reference implementation draws them from Sage's unseeded `random_element()`, so the
+/// shipped tables cannot be regenerated. Here they are squeezed, in the same order, from
+/// SHAKE256 seeded with `"Arion(p,t,rounds)"`, with the same rejection of g_i coefficients
+/// that have roots.
+pub fn constants<F: PrimeField64>(width: usize, rounds: usize) -> ArionConstants<F> {
+    let seed = format!("Arion({},{},{})", F::ORDER, width, rounds);
+    let mut elements = ShakeFieldElements::<F>::new(&seed);
+    let mut next = || elements.next().unwrap();
+
+    let g_values = (0..rounds)
+        .map(|_| {
+            (0..width - 1)
+                .map(|_| loop {
+                    let (c_1, c_2) = (next(), next());
+                    if is_irreducible_quadratic(c_1, c_2) {
+                        break [c_1, c_2];
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let h_values = (0..rounds)
+        .map(|_| (0..width - 1).map(|_| next()).collect())
+        .collect();
+    let affine_constants = (0..rounds)
+        .map(|_| (0..width).map(|_| next()).collect())
+        .collect();
+
+    ArionConstants {
+        g_values,
+        h_values,
+        affine_constants,
+    }
+}
+
+/// The Arion linear layer, `circulant(1, 2, ..., width)`.
+pub fn matrix<F: PrimeField64>(width: usize) -> Vec<Vec<F>> {
+    let row: Vec<F> = (1..=width).map(F::from_canonical_usize).collect();
+    circulant(&row)
+}
+
+/// Round bounds of Arion.
+///
+/// Every round goes through the power map `x^(1/d_2)` on the last branch, whose differential
+/// probability is at most `d_2 / p`, and which defeats interpolation after one round. For
+/// Gröbner bases, the CICO system over `r` rounds has `width * r` variables, and per round
+/// `width - 1` equations of degree `d_1 + 2` and one of degree `d_2`. The bound is the first `r`
+/// for which `binomial(n_var + d_reg, n_var)^2` reaches `2^security_level`, with `d_reg`
+/// the Macaulay bound of the system.
+///
+/// The paper picks the final number of rounds from a table rather than from a fixed margin,
+/// so there is no `number_of_rounds` here.
+pub fn round_bounds(
+    field_bits: usize,
+    width: usize,
+    d_1: u64,
+    d_2: u64,
+    security_level: usize,
+) -> RoundBounds {
+    let statistical =
+        (security_level as f64 / (field_bits as f64 - (d_2 as f64).log2())).ceil() as usize;
+    let groebner = (1..)
+        .find(|&r| {
+            let n_var = (width * r) as f64;
+            let d_reg = 1 + r * ((width - 1) * (d_1 as usize + 1) + (d_2 as usize - 1));
+            2.0 * log2_binomial(n_var + d_reg as f64, n_var) >= security_level as f64
+        })
+        .unwrap();
+
+    RoundBounds {
+        statistical,
+        interpolation: 1,
+        groebner,
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::VecDeque;
+
+use plonky2::field::types::PrimeField64;
+
+/// The Grain LFSR used by the Poseidon reference implementation
+/// (`generate_parameters_grain.sage`) to derive round constants.
+pub struct GrainLfsr {
+    state: VecDeque<bool>,
+    field_bits: usize,
+}
+
+impl GrainLfsr {
+    /// Initializes the LFSR for a prime field of `field_bits` bits with the x^alpha S-box,
+    /// a state of `width` elements, `full_rounds` full rounds and `partial_rounds` partial
+    /// rounds. The first 160 bits of output are discarded.
+    pub fn new(field_bits: usize, width: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let mut state = VecDeque::with_capacity(80);
+        // Field type: 1 for a prime field, on 2 bits.
+        push_bits(&mut state, 1, 2);
+        // S-box type: 0 for x^alpha, on 4 bits.
+        push_bits(&mut state, 0, 4);
+        push_bits(&mut state, field_bits as u64, 12);
+        push_bits(&mut state, width as u64, 12);
+        push_bits(&mut state, full_rounds as u64, 10);
+        push_bits(&mut state, partial_rounds as u64, 10);
+        state.extend([true; 30]);
+
+        let mut lfsr = Self { state, field_bits };
+        for _ in 0..160 {
+            lfsr.clock();
+        }
+        lfsr
+    }
+
+    fn clock(&mut self) -> bool {
+        let s = &self.state;
+        let new_bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.pop_front();
+        self.state.push_back(new_bit);
+        new_bit
+    }
+
+    /// Self-shrinking output: bits are taken in pairs and the second one is
+    /// kept only when the first one is set.
+    pub fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.clock();
+            let bit = self.clock();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    /// Draws a field element, reading `field_bits` bits most significant first and
+    /// rejecting values that are not below the modulus.
+    pub fn next_field_element<F: PrimeField64>(&mut self) -> F {
+        loop {
+            let value =
+                (0..self.field_bits).fold(0u64, |acc, _| (acc << 1) | self.next_bit() as u64);
+            if value < F::ORDER {
+                return F::from_canonical_u64(value);
+            }
+        }
+    }
+}
+
+fn push_bits(state: &mut VecDeque<bool>, value: u64, len: usize) {
+    for i in (0..len).rev() {
+        state.push_back((value >> i) & 1 == 1);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::{goldilocks_field::GoldilocksField, types::PrimeField64};
+
+    use crate::parameters::poseidon::grain_round_constants;
+
+    // First constants from `generate_parameters_grain.sage 1 0 64 12 8 22 0xffffffff00000001`.
+    #[test]
+    fn test_grain_goldilocks() {
+        let constants = grain_round_constants::<GoldilocksField>(12, 8, 22);
+        assert_eq!(constants.len(), 12 * 30);
+        assert_eq!(
+            constants[..3]
+                .iter()
+                .map(|x| x.to_canonical_u64())
+                .collect::<Vec<_>>(),
+            [0x13dcf33aba214f46, 0x30b3b654a1da6d83, 0x1fc634ada6159b56]
+        );
+    }
+}
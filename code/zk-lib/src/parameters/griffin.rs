@@ -0,0 +1,112 @@
+use plonky2::field::types::PrimeField64;
+
+use super::{
+    rounds::{log2_binomial, RoundBounds},
+    shake::shake_field_elements,
+};
+
+/// Constants of the Griffin permutation.
+pub struct GriffinConstants<F> {
+    /// The `alpha_i` of the non-linear layer, for branches 2 to width - 1.
+    pub alphas: Vec<F>,
+    /// The `beta_i` of the non-linear layer, for branches 2 to width - 1.
+    pub betas: Vec<F>,
+    /// The `width * (rounds - 1)` additive round constants.
+    pub round_constants: Vec<F>,
+}
+
+/// Derives the Griffin constants from SHAKE256 seeded with
+/// `"Griffin(p,t,capacity,security_level)"` (see `griffin_constants.sage`).
+///
+/// The first two squeezed elements are `alpha_2` and `beta_2`, the other ones are obtained
+/// as `alpha_i = (i - 1) * alpha_2` and `beta_i = (i - 1)^2 * beta_2`.
+pub fn constants<F: PrimeField64>(
+    width: usize,
+    capacity: usize,
+    security_level: usize,
+    rounds: usize,
+) -> GriffinConstants<F> {
+    let seed = format!(
+        "Griffin({},{},{},{})",
+        F::ORDER,
+        width,
+        capacity,
+        security_level
+    );
+    let elements = shake_field_elements::<F>(&seed, width * (rounds - 1) + 2);
+    let (alpha, beta) = (elements[0], elements[1]);
+
+    GriffinConstants {
+        alphas: (2..width)
+            .map(|i| F::from_canonical_usize(i - 1) * alpha)
+            .collect(),
+        betas: (2..width)
+            .map(|i| F::from_canonical_usize((i - 1) * (i - 1)) * beta)
+            .collect(),
+        round_constants: elements[2..].to_vec(),
+    }
+}
+
+/// The Griffin linear layer for `width = 3` or a multiple of 4.
+///
+/// For widths above 4 the state is split in blocks of 4: each block is multiplied by the
+/// 4 x 4 matrix below, which is then doubled on the diagonal blocks.
+pub fn mds_matrix<F: PrimeField64>(width: usize) -> Vec<Vec<F>> {
+    const M4: [[u64; 4]; 4] = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+
+    let entry = |i: usize, j: usize| -> u64 {
+        match width {
+            3 => {
+                if i == j {
+                    2
+                } else {
+                    1
+                }
+            }
+            4 => M4[i][j],
+            _ => {
+                assert!(width % 4 == 0, "Griffin is only defined for width 3 or 4k");
+                M4[i % 4][j % 4] * if i / 4 == j / 4 { 2 } else { 1 }
+            }
+        }
+    };
+    (0..width)
+        .map(|i| {
+            (0..width)
+                .map(|j| F::from_canonical_u64(entry(i, j)))
+                .collect()
+        })
+        .collect()
+}
+
+/// Round bounds of Griffin from `get_number_of_rounds` in `griffin_constants.sage`.
+///
+/// The Griffin paper requires at least 6 rounds against statistical attacks whatever the
+/// parameters, and the inverse power map in the first branch defeats interpolation after
+/// one round. The Gröbner bound is `1 + R` where `R` is the first number of rounds for which
+/// both the `binomial(R * (d + t) + 1, 1 + t * R)` and `binomial(d^R + 1 + R, 1 + R)`
+/// estimates reach `2^(security_level / 2)`.
+pub fn round_bounds(width: usize, d: u64, security_level: usize) -> RoundBounds {
+    let target = (security_level / 2) as f64;
+    let rounds = (1..)
+        .find(|&r| {
+            let left = log2_binomial(
+                (r * (d as usize + width) + 1) as f64,
+                (1 + width * r) as f64,
+            );
+            let right = log2_binomial((d as f64).powi(r as i32) + 1.0 + r as f64, (1 + r) as f64);
+            left.min(right) >= target
+        })
+        .unwrap();
+
+    RoundBounds {
+        statistical: 6,
+        interpolation: 1,
+        groebner: 1 + rounds,
+    }
+}
+
+/// Number of rounds with the 20% security margin of Griffin.
+pub fn number_of_rounds(width: usize, d: u64, security_level: usize) -> usize {
+    (1.2 * round_bounds(width, d, security_level).minimum() as f64).ceil() as usize
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use plonky2::field::types::{Field, PrimeField64};
+
+/// Circulant matrix whose first row is `row`, every following row being the previous one
+/// shifted one position to the right (Sage's `matrix.circulant`).
+pub fn circulant<F: Field>(row: &[F]) -> Vec<Vec<F>> {
+    let n = row.len();
+    (0..n)
+        .map(|i| (0..n).map(|j| row[(j + n - i) % n]).collect())
+        .collect()
+}
+
+/// Cauchy matrix `M[i][j] = 1 / (x_i - y_j)` with `x_i = i` and `y_j = n + j`, which is MDS
+/// as long as the field is larger than `2n`.
+pub fn cauchy<F: Field>(n: usize) -> Vec<Vec<F>> {
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| (F::from_canonical_usize(i) - F::from_canonical_usize(n + j)).inverse())
+                .collect()
+        })
+        .collect()
+}
+
+/// Checks that every square sub-matrix of `m` is invertible.
+///
+/// The k x k minors are computed from the (k-1) x (k-1) ones with a Laplace expansion along
+/// the first row, as in `mds_search.sage` from mir-protocol/hash-constants. Row and column
+/// subsets are stored as bit masks.
+pub fn is_mds<F: Field>(m: &[Vec<F>]) -> bool {
+    let n = m.len();
+    assert!((1..32).contains(&n) && m.iter().all(|row| row.len() == n));
+
+    let mut minors: HashMap<(u32, u32), F> = HashMap::new();
+    for (i, row) in m.iter().enumerate() {
+        for (j, x) in row.iter().enumerate() {
+            if x.is_zero() {
+                return false;
+            }
+            minors.insert((1 << i, 1 << j), *x);
+        }
+    }
+
+    for k in 2..=n {
+        let subsets = subsets_of_size(n, k);
+        let mut next = HashMap::with_capacity(subsets.len() * subsets.len());
+        for &rows in &subsets {
+            let i = rows.trailing_zeros() as usize;
+            let rest = rows & (rows - 1);
+            for &cols in &subsets {
+                let mut det = F::ZERO;
+                let mut sign = F::ONE;
+                let mut remaining = cols;
+                while remaining != 0 {
+                    let c = remaining.trailing_zeros() as usize;
+                    remaining &= remaining - 1;
+                    det += sign * m[i][c] * minors[&(rest, cols & !(1 << c))];
+                    sign = -sign;
+                }
+                if det.is_zero() {
+                    return false;
+                }
+                next.insert((rows, cols), det);
+            }
+        }
+        minors = next;
+    }
+    true
+}
+
+fn subsets_of_size(n: usize, k: usize) -> Vec<u32> {
+    (0u32..1 << n)
+        .filter(|s| s.count_ones() as usize == k)
+        .collect()
+}
+
+/// Converts a matrix to its canonical `u64` representation, the format of the shipped tables.
+pub fn to_u64_matrix<F: PrimeField64>(m: &[Vec<F>]) -> Vec<Vec<u64>> {
+    m.iter()
+        .map(|row| row.iter().map(|x| x.to_canonical_u64()).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::goldilocks_field::GoldilocksField;
+
+    use super::*;
+
+    #[test]
+    fn test_cauchy_is_mds() {
+        assert!(is_mds(&cauchy::<GoldilocksField>(8)));
+        let not_mds = circulant(&[1u64, 2, 3, 4, 5, 6].map(GoldilocksField::from_canonical_u64));
+        assert!(!is_mds(&not_mds));
+    }
+}
@@ -0,0 +1,68 @@
+// Generation of the constants used by the hash functions
+//
+// Rust ports of the Sage/Python scripts that were used to produce the tables in
+// `hashes/*/constants.rs` and `hashes/*/mds.rs`, so the shipped values can be
+// checked and new parameter sets can be created without Sage.
+
+use plonky2::field::types::PrimeField64;
+
+pub mod anemoi;
+pub mod arion;
+pub mod grain;
+pub mod griffin;
+pub mod mds;
+pub mod poseidon;
+pub mod rescue_prime;
+pub mod rounds;
+pub mod shake;
+
+/// Returns the smallest `d >= start` such that `x -> x^d` is a permutation of the field,
+/// i.e. `gcd(d, p - 1) = 1`.
+pub fn smallest_permutation_exponent<F: PrimeField64>(start: u64) -> u64 {
+    let mut d = start;
+    while gcd(d, F::ORDER - 1) != 1 {
+        d += 1;
+    }
+    d
+}
+
+/// Returns `d^-1 mod (p - 1)`, the exponent of the inverse power map.
+///
+/// # Panics
+/// Panics if `x -> x^d` is not a permutation of the field.
+pub fn inverse_exponent<F: PrimeField64>(d: u64) -> u64 {
+    let modulus = (F::ORDER - 1) as i128;
+    let (g, x, _) = extended_gcd(d as i128, modulus);
+    assert_eq!(g, 1, "x^{} is not a permutation of the field", d);
+    x.rem_euclid(modulus) as u64
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::field::goldilocks_field::GoldilocksField;
+
+    use super::*;
+
+    #[test]
+    fn test_power_map_exponents() {
+        assert_eq!(smallest_permutation_exponent::<GoldilocksField>(3), 7);
+        assert_eq!(inverse_exponent::<GoldilocksField>(7), 10540996611094048183);
+        assert_eq!(inverse_exponent::<GoldilocksField>(161), 458304200482349921);
+    }
+}
@@ -0,0 +1,141 @@
+use plonky2::field::types::PrimeField64;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use super::{
+    grain::GrainLfsr,
+    mds,
+    rounds::{log2_binomial, RoundBounds},
+};
+
+/// Round constants as generated by plonky2 for its Goldilocks Poseidon: uniform samples
+/// from a ChaCha8 RNG seeded with 0.
+pub fn plonky2_round_constants<F: PrimeField64>(n: usize) -> Vec<F> {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    (0..n)
+        .map(|_| F::from_canonical_u64(rng.gen_range(0..F::ORDER)))
+        .collect()
+}
+
+/// Round constants following the Poseidon paper: `width * (full_rounds + partial_rounds)`
+/// elements drawn from the Grain LFSR.
+pub fn grain_round_constants<F: PrimeField64>(
+    width: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+) -> Vec<F> {
+    let field_bits = 64 - F::ORDER.leading_zeros() as usize;
+    let mut grain = GrainLfsr::new(field_bits, width, full_rounds, partial_rounds);
+    (0..width * (full_rounds + partial_rounds))
+        .map(|_| grain.next_field_element())
+        .collect()
+}
+
+/// Matrix of the form used by plonky2: circulant part plus a diagonal.
+pub fn circulant_plus_diagonal<F: PrimeField64>(circ: &[u64], diag: &[u64]) -> Vec<Vec<F>> {
+    let row: Vec<F> = circ.iter().map(|x| F::from_canonical_u64(*x)).collect();
+    let mut matrix = mds::circulant(&row);
+    for (i, d) in diag.iter().enumerate() {
+        matrix[i][i] += F::from_canonical_u64(*d);
+    }
+    matrix
+}
+
+/// MDS matrix for a new width: a Cauchy matrix, MDS by construction.
+pub fn mds_matrix<F: PrimeField64>(width: usize) -> Vec<Vec<F>> {
+    mds::cauchy(width)
+}
+
+/// Bounds on the number of full rounds for a given number of partial rounds, from
+/// `calc_round_numbers.py` of the Poseidon reference implementation.
+///
+/// The statistical bound is 6 full rounds, or 10 when the security level exceeds what the
+/// field and width can offer. Interpolation and the three Gröbner estimates of the paper are
+/// counted in full rounds once the partial rounds are deducted. The Gröbner bound also covers
+/// the attack of <https://eprint.iacr.org/2023/537>, checked by increasing the full rounds
+/// until its cost reaches `2^security_level`.
+pub fn round_bounds(
+    field_bits: usize,
+    width: usize,
+    alpha: u64,
+    security_level: usize,
+    partial_rounds: usize,
+) -> RoundBounds {
+    let (n, t, m) = (field_bits as f64, width as f64, security_level as f64);
+    let r_p = partial_rounds as f64;
+    let log_alpha = (alpha as f64).log2();
+    let rounds = |x: f64| x.ceil().max(0.0) as usize;
+
+    let statistical = if m <= (n - (alpha - 1) as f64 / 2.0).floor() * (t + 1.0) {
+        6
+    } else {
+        10
+    };
+    let interpolation =
+        rounds(1.0 + (m.min(n) / log_alpha).ceil() + (t.log2() / log_alpha).ceil() - r_p);
+    let groebner_1 = rounds(m.min(n) / log_alpha - r_p);
+    let groebner_2 = rounds(t - 1.0 + (m / (t + 1.0)).min(n / 2.0) / log_alpha - r_p);
+    let groebner_3 = rounds((t - 2.0 + m / (2.0 * log_alpha) - r_p) / (t - 1.0));
+    let mut groebner = groebner_1.max(groebner_2).max(groebner_3);
+    while !resists_2023_537(width, alpha, security_level, groebner, partial_rounds) {
+        groebner += 1;
+    }
+
+    RoundBounds {
+        statistical,
+        interpolation,
+        groebner,
+    }
+}
+
+fn resists_2023_537(
+    width: usize,
+    alpha: u64,
+    security_level: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+) -> bool {
+    let r_temp = (width / 3) as f64;
+    let (r_f, r_p, alpha) = (full_rounds as f64, partial_rounds as f64, alpha as f64);
+    let over = (r_f - 1.0) * width as f64 + r_p + r_temp + r_temp * (r_f / 2.0) + r_p + alpha;
+    let under = r_temp * (r_f / 2.0) + r_p + alpha;
+    (2.0 * log2_binomial(over.floor(), under.floor())).ceil() >= security_level as f64
+}
+
+/// Checks that `full_rounds` and `partial_rounds` meet the Poseidon bounds.
+pub fn is_secure(
+    field_bits: usize,
+    width: usize,
+    alpha: u64,
+    security_level: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+) -> bool {
+    full_rounds >= round_bounds(field_bits, width, alpha, security_level, partial_rounds).minimum()
+}
+
+/// Full and partial rounds minimising the number of S-boxes, with the security margin of
+/// the reference: two more full rounds and 7.5% more partial rounds.
+pub fn number_of_rounds(
+    field_bits: usize,
+    width: usize,
+    alpha: u64,
+    security_level: usize,
+) -> (usize, usize) {
+    let mut best: Option<(usize, usize, usize)> = None;
+    for partial_rounds in 1..500 {
+        let bound =
+            round_bounds(field_bits, width, alpha, security_level, partial_rounds).minimum();
+        // The full rounds are split evenly between the beginning and the end.
+        let full_rounds = bound.max(4).next_multiple_of(2) + 2;
+        let partial_rounds = (partial_rounds as f64 * 1.075).ceil() as usize;
+        let cost = full_rounds * width + partial_rounds;
+        if best.map_or(true, |(best_cost, best_full, _)| {
+            cost < best_cost || (cost == best_cost && full_rounds < best_full)
+        }) {
+            best = Some((cost, full_rounds, partial_rounds));
+        }
+    }
+    let (_, full_rounds, partial_rounds) = best.unwrap();
+    (full_rounds, partial_rounds)
+}
@@ -0,0 +1,89 @@
+use plonky2::field::types::PrimeField64;
+
+use super::{
+    mds,
+    rounds::{log2_binomial, wide_trail_rounds, RoundBounds},
+    shake::shake_field_elements,
+};
+
+/// Round constants of Rescue Prime Optimized: `2 * width * rounds` elements squeezed from
+/// SHAKE256 seeded with `"RPO(p,m,capacity,security_level)"` (see `rescue_constants.sage`).
+pub fn round_constants<F: PrimeField64>(
+    width: usize,
+    capacity: usize,
+    security_level: usize,
+    rounds: usize,
+) -> Vec<F> {
+    let seed = format!(
+        "RPO({},{},{},{})",
+        F::ORDER,
+        width,
+        capacity,
+        security_level
+    );
+    shake_field_elements(&seed, 2 * width * rounds)
+}
+
+/// MDS matrix of Rescue Prime Optimized. The reference only defines hand-picked circulant
+/// matrices with small entries for widths 12 and 16 (`get_mds` in `rescue_constants.sage`);
+/// other widths fall back to a Cauchy matrix.
+pub fn mds_matrix<F: PrimeField64>(width: usize) -> Vec<Vec<F>> {
+    let row: &[u64] = match width {
+        12 => &[7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8],
+        16 => &[
+            256, 2, 1073741824, 2048, 16777216, 128, 8, 16, 524288, 4194304, 1, 268435456, 1, 1024,
+            2, 8192,
+        ],
+        _ => return mds::cauchy(width),
+    };
+    let row: Vec<F> = row.iter().map(|x| F::from_canonical_u64(*x)).collect();
+    mds::circulant(&row)
+}
+
+/// Round bounds of Rescue Prime from `get_number_of_rounds` in `rescue_constants.sage`.
+///
+/// Statistical attacks follow the wide trail argument of the Rescue paper, the MDS matrix
+/// activating `width + 1` S-boxes every two rounds. The inverse S-box has maximal degree, so
+/// one round defeats interpolation. The Gröbner bound is the first `N` for which the
+/// `binomial(v + d, v)^2` cost of solving the `v = width * (N - 1) + rate` variable system
+/// of degree `d = (alpha - 1) * width * (N - 1) / 2 + 2` exceeds `2^security_level`.
+pub fn round_bounds(
+    field_bits: usize,
+    width: usize,
+    capacity: usize,
+    alpha: u64,
+    security_level: usize,
+) -> RoundBounds {
+    let rate = width - capacity;
+    let statistical = wide_trail_rounds(
+        security_level,
+        width + 1,
+        field_bits as f64 - ((alpha - 1) as f64).log2(),
+    );
+    let groebner = (1..)
+        .find(|&n| {
+            let d = (0.5 * (alpha - 1) as f64 * (width * (n - 1)) as f64 + 2.0).floor();
+            let v = (width * (n - 1) + rate) as f64;
+            2.0 * log2_binomial(v + d, v) > security_level as f64
+        })
+        .unwrap();
+
+    RoundBounds {
+        statistical,
+        interpolation: 1,
+        groebner,
+    }
+}
+
+/// Number of rounds with the 50% security margin of Rescue Prime, and at least 5 rounds
+/// before the margin.
+pub fn number_of_rounds(
+    field_bits: usize,
+    width: usize,
+    capacity: usize,
+    alpha: u64,
+    security_level: usize,
+) -> usize {
+    let bounds = round_bounds(field_bits, width, capacity, alpha, security_level);
+    (1.5 * bounds.minimum().max(5) as f64).ceil() as usize
+}
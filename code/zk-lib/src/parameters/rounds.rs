@@ -0,0 +1,66 @@
+// Lower bounds on the number of rounds
+//
+// Each hash module computes, from the attack estimates of its paper, the smallest number of
+// rounds resisting statistical, interpolation and Gröbner basis attacks for a field of
+// `field_bits` bits (`⌈log2 p⌉`), a state width, an S-box degree and a security level.
+// The fields are sized by bits rather than by a plonky2 field so that the same bounds can
+// be checked for the BLS12-381 instances of zk-dusk.
+
+/// Minimum number of rounds against each family of attacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundBounds {
+    /// Differential and linear cryptanalysis.
+    pub statistical: usize,
+    /// Interpolation attacks.
+    pub interpolation: usize,
+    /// Gröbner basis attacks.
+    pub groebner: usize,
+}
+
+impl RoundBounds {
+    /// The number of rounds resisting all the attacks, without security margin.
+    pub fn minimum(&self) -> usize {
+        self.statistical.max(self.interpolation).max(self.groebner)
+    }
+}
+
+/// `log2(binomial(n, k))`, computed in floating point since the binomials involved
+/// are far beyond 128 bits.
+pub fn log2_binomial(n: f64, k: f64) -> f64 {
+    let k = k.min(n - k);
+    if k < 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    (1..=k as u64)
+        .map(|i| ((n - k + i as f64) / i as f64).log2())
+        .sum()
+}
+
+/// Rounds needed against differential and linear trails with the wide trail strategy:
+/// every two rounds activate at least `branch_number` S-boxes, each one with a
+/// differential probability of at most `2^-bits_per_sbox`.
+pub fn wide_trail_rounds(security_level: usize, branch_number: usize, bits_per_sbox: f64) -> usize {
+    (2.0 * security_level as f64 / (branch_number as f64 * bits_per_sbox)).ceil() as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parameters::{anemoi, griffin, rescue_prime};
+
+    #[test]
+    fn test_log2_binomial() {
+        assert_eq!(log2_binomial(10.0, 0.0), 0.0);
+        assert!((log2_binomial(10.0, 3.0) - 120f64.log2()).abs() < 1e-9);
+        assert!((log2_binomial(64.0, 32.0) - 1832624140942590534f64.log2()).abs() < 1e-9);
+    }
+
+    // Outputs of the reference scripts for instances that are not shipped.
+    #[test]
+    fn test_reference_round_numbers() {
+        assert_eq!(rescue_prime::number_of_rounds(64, 12, 4, 7, 128), 8);
+        assert_eq!(rescue_prime::number_of_rounds(255, 4, 1, 5, 128), 11);
+        assert_eq!(griffin::number_of_rounds(4, 5, 128), 11);
+        assert_eq!(anemoi::number_of_rounds(255, 2, 5, 128), 14);
+    }
+}
@@ -0,0 +1,53 @@
+use plonky2::field::types::PrimeField64;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256, Shake256Reader,
+};
+
+/// Number of SHAKE256 output bytes consumed per field element, as in the reference scripts:
+/// one byte more than needed to hold the modulus, so the reduction bias is negligible.
+pub fn bytes_per_int<F: PrimeField64>() -> usize {
+    let bits = 64 - F::ORDER.leading_zeros() as usize;
+    (bits + 7) / 8 + 1
+}
+
+/// Endless stream of field elements squeezed out of SHAKE256.
+///
+/// Each element is read as a little-endian integer of `bytes_per_int` bytes and reduced
+/// modulo `p`, exactly like `get_round_constants` in the Griffin and Rescue Sage scripts.
+pub struct ShakeFieldElements<F: PrimeField64> {
+    reader: Shake256Reader,
+    chunk: Vec<u8>,
+    _field: core::marker::PhantomData<F>,
+}
+
+impl<F: PrimeField64> ShakeFieldElements<F> {
+    pub fn new(seed: &str) -> Self {
+        let mut hasher = Shake256::default();
+        hasher.update(seed.as_bytes());
+        Self {
+            reader: hasher.finalize_xof(),
+            chunk: vec![0u8; bytes_per_int::<F>()],
+            _field: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<F: PrimeField64> Iterator for ShakeFieldElements<F> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        self.reader.read(&mut self.chunk);
+        let value = self
+            .chunk
+            .iter()
+            .rev()
+            .fold(0u128, |acc, byte| (acc << 8) | *byte as u128);
+        Some(F::from_canonical_u64((value % F::ORDER as u128) as u64))
+    }
+}
+
+/// Squeezes the first `n` field elements out of SHAKE256 seeded with `seed`.
+pub fn shake_field_elements<F: PrimeField64>(seed: &str, n: usize) -> Vec<F> {
+    ShakeFieldElements::new(seed).take(n).collect()
+}
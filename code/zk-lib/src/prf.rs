@@ -0,0 +1,181 @@
+// Keyed hashing
+//
+// The PRF absorbs a key of `KEY_LEN` elements and then the message in the `Sponge`, with the
+// `PRF_DOMAIN` separator. The IO-pattern holds the length of the message, so messages of
+// different lengths never share a state. The same function is a MAC, and derives nullifiers
+// from a secret key. In the circuit, the key is a witness which is never made public.
+
+use plonky2::{
+    field::extension::Extendable, hash::hash_types::RichField, iop::target::Target,
+    plonk::circuit_builder::CircuitBuilder,
+};
+
+use crate::{
+    digest::{Digest, DigestTarget},
+    hashes::Permutation,
+    sponge::{hash_to_digest, hash_to_digest_circuit},
+};
+
+/// Number of elements of a key, 256 bits.
+pub const KEY_LEN: usize = 4;
+
+/// Domain separator of the keyed hashing, `"prf"` in ASCII.
+pub const PRF_DOMAIN: u64 = 0x70_72_66;
+
+/// The PRF keyed with `key` on `message`, with the permutation `H` over a state of `WIDTH`
+/// elements, `RATE` of which absorb the key and the message.
+pub fn prf<H, F, const WIDTH: usize, const RATE: usize>(
+    key: &[F; KEY_LEN],
+    message: &[F],
+) -> Digest<F>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    hash_to_digest::<H, F, WIDTH, RATE>(&[key, message], PRF_DOMAIN)
+}
+
+/// Circuit of [`prf`]. `key` should be set in the witness only, so that it stays private.
+pub fn prf_circuit<H, F, const WIDTH: usize, const RATE: usize>(
+    builder: &mut CircuitBuilder<F, 2>,
+    key: &[Target; KEY_LEN],
+    message: &[Target],
+) -> DigestTarget
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    hash_to_digest_circuit::<H, F, WIDTH, RATE>(builder, &[key, message], PRF_DOMAIN)
+}
+
+/// Tag of `message` under `key`.
+pub fn mac<H, F, const WIDTH: usize, const RATE: usize>(
+    key: &[F; KEY_LEN],
+    message: &[F],
+) -> Digest<F>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    prf::<H, F, WIDTH, RATE>(key, message)
+}
+
+/// Checks the tag of `message` under `key`.
+pub fn verify_mac<H, F, const WIDTH: usize, const RATE: usize>(
+    key: &[F; KEY_LEN],
+    message: &[F],
+    tag: &Digest<F>,
+) -> bool
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    // Compare every element, without stopping at the first difference
+    let expected = mac::<H, F, WIDTH, RATE>(key, message);
+    expected
+        .elements()
+        .iter()
+        .zip(tag.elements())
+        .fold(true, |equal, (left, right)| equal & (left == right))
+}
+
+/// Constrains `tag` to be the tag of `message` under `key`.
+pub fn verify_mac_circuit<H, F, const WIDTH: usize, const RATE: usize>(
+    builder: &mut CircuitBuilder<F, 2>,
+    key: &[Target; KEY_LEN],
+    message: &[Target],
+    tag: DigestTarget,
+) where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    let expected = prf_circuit::<H, F, WIDTH, RATE>(builder, key, message);
+    builder.connect_hashes(expected, tag);
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::{
+        field::{
+            goldilocks_field::GoldilocksField,
+            types::{Field, Sample},
+        },
+        iop::witness::{PartialWitness, WitnessWrite},
+        plonk::{circuit_data::CircuitConfig, config::PoseidonGoldilocksConfig},
+    };
+
+    use super::*;
+    use crate::hashes::{
+        anemoi::anemoi::Anemoi, griffin::griffin::Griffin, poseidon::poseidon::Poseidon,
+    };
+
+    type F = GoldilocksField;
+
+    #[test]
+    fn test_prf_separation() {
+        let key = F::rand_array();
+        let message = [F::ONE, F::TWO];
+        let output = prf::<Griffin, F, 12, 8>(&key, &message);
+
+        assert_eq!(output, prf::<Griffin, F, 12, 8>(&key, &message));
+        assert_ne!(output, prf::<Griffin, F, 12, 8>(&F::rand_array(), &message));
+        assert_ne!(output, prf::<Griffin, F, 12, 8>(&key, &message[..1]));
+        assert_ne!(output, prf::<Poseidon, F, 12, 8>(&key, &message));
+
+        // Zeros appended to the message change the output
+        assert_ne!(
+            prf::<Griffin, F, 12, 8>(&key, &[]),
+            prf::<Griffin, F, 12, 8>(&key, &[F::ZERO])
+        );
+    }
+
+    #[test]
+    fn test_mac() {
+        let key = F::rand_array();
+        let message = [F::ONE, F::TWO, F::NEG_ONE];
+        let tag = mac::<Anemoi, F, 12, 8>(&key, &message);
+        assert!(verify_mac::<Anemoi, F, 12, 8>(&key, &message, &tag));
+        assert!(!verify_mac::<Anemoi, F, 12, 8>(&key, &message[1..], &tag));
+        assert!(!verify_mac::<Anemoi, F, 12, 8>(
+            &F::rand_array(),
+            &message,
+            &tag
+        ));
+    }
+
+    fn check_prf<H: Permutation<12>>() {
+        let key = F::rand_array();
+        let messages: Vec<Vec<F>> = [0, 1, 4, 20].iter().map(|len| F::rand_vec(*len)).collect();
+
+        let mut builder = CircuitBuilder::<F, 2>::new(CircuitConfig::standard_recursion_config());
+        let key_targets = builder.add_virtual_target_arr();
+        let mut pw = PartialWitness::new();
+        pw.set_target_arr(&key_targets, &key);
+        let mut expected = Vec::new();
+        for message in &messages {
+            let targets = builder.add_virtual_targets(message.len());
+            let output = prf_circuit::<H, F, 12, 8>(&mut builder, &key_targets, &targets);
+            builder.register_public_inputs(&output.elements);
+
+            pw.set_target_arr(&targets, message);
+            expected.extend(prf::<H, F, 12, 8>(&key, message).elements());
+        }
+
+        let tag = builder.add_virtual_hash();
+        let targets = builder.add_virtual_targets(messages[2].len());
+        verify_mac_circuit::<H, F, 12, 8>(&mut builder, &key_targets, &targets, tag);
+        pw.set_target_arr(&targets, &messages[2]);
+        pw.set_hash_target(tag, mac::<H, F, 12, 8>(&key, &messages[2]).into());
+
+        let data = builder.build::<PoseidonGoldilocksConfig>();
+        let proof = data.prove(pw).unwrap();
+        assert_eq!(proof.public_inputs, expected);
+        assert!(data.verify(proof).is_ok());
+    }
+
+    #[test]
+    fn test_prf_circuit() {
+        check_prf::<Griffin>();
+        check_prf::<Poseidon>();
+    }
+}
@@ -0,0 +1,139 @@
+// Public-input compression
+//
+// A circuit with many public inputs hashes them in-circuit and registers only the `Digest`,
+// so a recursive verifier reads four elements whatever the number of values. The number of
+// values is absorbed before them by the `Sponge`, under the `PUBLIC_INPUTS_DOMAIN` separator.
+// The verifier recomputes the digest from the claimed values with `public_inputs_hash` and
+// compares it with the public inputs of the proof.
+
+use anyhow::{ensure, Result};
+use plonky2::{
+    field::extension::Extendable,
+    hash::hash_types::RichField,
+    iop::target::Target,
+    plonk::{circuit_builder::CircuitBuilder, config::GenericConfig, proof::ProofWithPublicInputs},
+};
+
+use crate::{
+    digest::{Digest, DigestTarget},
+    hashes::Permutation,
+    sponge::{hash_to_digest, hash_to_digest_circuit},
+};
+
+/// Domain separator of the public-input hashing, `"inputs"` in ASCII.
+pub const PUBLIC_INPUTS_DOMAIN: u64 = 0x69_6e_70_75_74_73;
+
+/// Digest of the public values `values`, with the permutation `H` over a state of `WIDTH`
+/// elements, `RATE` of which absorb the values.
+pub fn public_inputs_hash<H, F, const WIDTH: usize, const RATE: usize>(values: &[F]) -> Digest<F>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    let len = F::from_canonical_usize(values.len());
+    hash_to_digest::<H, F, WIDTH, RATE>(&[&[len], values], PUBLIC_INPUTS_DOMAIN)
+}
+
+/// Hashes `targets` as [`public_inputs_hash`] does and registers the digest as the public
+/// inputs, in place of the targets.
+pub fn register_public_inputs_hash<H, F, const WIDTH: usize, const RATE: usize>(
+    builder: &mut CircuitBuilder<F, 2>,
+    targets: &[Target],
+) -> DigestTarget
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    let len = builder.constant(F::from_canonical_usize(targets.len()));
+    let output = hash_to_digest_circuit::<H, F, WIDTH, RATE>(
+        builder,
+        &[&[len], targets],
+        PUBLIC_INPUTS_DOMAIN,
+    );
+    builder.register_public_inputs(&output.elements);
+    output
+}
+
+/// Checks that the public inputs of `proof` are the digest of the claimed `values`. The proof
+/// itself still has to be verified.
+pub fn check_public_inputs<H, F, C, const WIDTH: usize, const RATE: usize>(
+    proof: &ProofWithPublicInputs<F, C, 2>,
+    values: &[F],
+) -> Result<()>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F>,
+{
+    let digest = public_inputs_hash::<H, F, WIDTH, RATE>(values);
+    ensure!(
+        proof.public_inputs == digest.elements(),
+        "the public inputs are not the digest of the values"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::{
+        field::{goldilocks_field::GoldilocksField, types::Field},
+        hash::hash_types::NUM_HASH_OUT_ELTS,
+        iop::witness::{PartialWitness, WitnessWrite},
+        plonk::{circuit_data::CircuitConfig, config::PoseidonGoldilocksConfig},
+    };
+
+    use super::*;
+    use crate::hashes::{anemoi::anemoi::Anemoi, griffin::griffin::Griffin};
+
+    type F = GoldilocksField;
+    type C = PoseidonGoldilocksConfig;
+
+    #[test]
+    fn test_public_inputs_hash() {
+        // The number of values is part of the digest
+        assert_ne!(
+            public_inputs_hash::<Griffin, F, 12, 8>(&[]),
+            public_inputs_hash::<Griffin, F, 12, 8>(&[F::ZERO])
+        );
+        assert_ne!(
+            public_inputs_hash::<Griffin, F, 12, 8>(&[F::ONE]),
+            public_inputs_hash::<Griffin, F, 12, 8>(&[F::ONE, F::ZERO])
+        );
+    }
+
+    fn check_compression<H: Permutation<12>>(len: usize) {
+        // Squares of the inputs, which would otherwise all be public
+        let mut builder = CircuitBuilder::<F, 2>::new(CircuitConfig::standard_recursion_config());
+        let inputs = builder.add_virtual_targets(len);
+        let squares: Vec<Target> = inputs.iter().map(|x| builder.square(*x)).collect();
+        register_public_inputs_hash::<H, F, 12, 8>(&mut builder, &squares);
+
+        let values: Vec<F> = (0..len as u64).map(F::from_canonical_u64).collect();
+        let mut pw = PartialWitness::new();
+        pw.set_target_arr(&inputs, &values);
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        assert_eq!(proof.public_inputs.len(), NUM_HASH_OUT_ELTS);
+
+        let claimed: Vec<F> = values.iter().map(|x| *x * *x).collect();
+        assert!(check_public_inputs::<H, F, C, 12, 8>(&proof, &claimed).is_ok());
+        assert!(check_public_inputs::<H, F, C, 12, 8>(
+            &proof,
+            &[&claimed[..], &[F::ZERO]].concat()
+        )
+        .is_err());
+        if len > 0 {
+            let mut wrong = claimed.clone();
+            wrong[0] += F::ONE;
+            assert!(check_public_inputs::<H, F, C, 12, 8>(&proof, &wrong).is_err());
+        }
+        assert!(data.verify(proof).is_ok());
+    }
+
+    #[test]
+    fn test_public_inputs_compression() {
+        check_compression::<Griffin>(0);
+        check_compression::<Griffin>(100);
+        check_compression::<Anemoi>(13);
+    }
+}
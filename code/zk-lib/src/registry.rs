@@ -0,0 +1,423 @@
+// Runtime selection of the hashes
+//
+// `HashAlgorithm` names every hash of the library with a stable numeric ID, so that config
+// files and wire formats can carry the chosen algorithm. `HashAlgorithm::hasher` returns the
+// hash as a `HashFunction` trait object, which hashes and proves through dynamic dispatch.
+// The IDs are shared with the `HashAlgorithm` of zk-dusk and must never be reassigned.
+//
+// MiMC has random round constants. The registry draws them from a generator seeded with
+// `MIMC_SEED`, so the registered MiMC is the same on every run.
+
+use core::{fmt, str::FromStr};
+
+use anyhow::{ensure, Result};
+use plonky2::{
+    field::extension::Extendable,
+    hash::hash_types::RichField,
+    iop::witness::PartialWitness,
+    plonk::{circuit_data::CircuitData, config::GenericConfig, proof::ProofWithPublicInputs},
+};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::hashes::{
+    anemoi::{self, anemoi::Anemoi},
+    arion::{self, arion::Arion},
+    griffin::{self, griffin::Griffin},
+    mimc::mimc::MiMC,
+    poseidon::{self, poseidon::Poseidon},
+    rescue_prime::{self, rescue_prime::Rescue},
+};
+
+/// Seed of the round constants of the registered MiMC, `"mimc"` in ASCII.
+pub const MIMC_SEED: u64 = 0x6d_69_6d_63;
+
+/// Number of output elements of the sponges of the registry.
+const OUTPUT_LEN: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error {
+    /// An ID that is not assigned to a hash.
+    UnknownId(u8),
+    /// A name that is not the name of a hash.
+    UnknownName,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UnknownId(id) => write!(f, "unknown hash algorithm ID {}", id),
+            Error::UnknownName => write!(f, "unknown hash algorithm name"),
+        }
+    }
+}
+
+/// A hash of the library, identified by its ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum HashAlgorithm {
+    Poseidon = 1,
+    Rescue = 2,
+    Griffin = 3,
+    Anemoi = 4,
+    Arion = 5,
+    MiMC = 6,
+}
+
+impl HashAlgorithm {
+    pub const ALL: [HashAlgorithm; 6] = [
+        HashAlgorithm::Poseidon,
+        HashAlgorithm::Rescue,
+        HashAlgorithm::Griffin,
+        HashAlgorithm::Anemoi,
+        HashAlgorithm::Arion,
+        HashAlgorithm::MiMC,
+    ];
+
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_id(id: u8) -> Result<Self, Error> {
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.id() == id)
+            .ok_or(Error::UnknownId(id))
+    }
+
+    /// The lowercase name of the hash, which [`FromStr`] parses back.
+    pub fn name(self) -> &'static str {
+        match self {
+            HashAlgorithm::Poseidon => "poseidon",
+            HashAlgorithm::Rescue => "rescue",
+            HashAlgorithm::Griffin => "griffin",
+            HashAlgorithm::Anemoi => "anemoi",
+            HashAlgorithm::Arion => "arion",
+            HashAlgorithm::MiMC => "mimc",
+        }
+    }
+
+    /// The hash, over the field `F`, with proofs in the configuration `C`.
+    pub fn hasher<F, C>(self) -> Box<dyn HashFunction<F, C>>
+    where
+        F: RichField + Extendable<2>,
+        C: GenericConfig<2, F = F> + 'static,
+    {
+        match self {
+            HashAlgorithm::Poseidon => Box::new(Poseidon),
+            HashAlgorithm::Rescue => Box::new(Rescue),
+            HashAlgorithm::Griffin => Box::new(Griffin),
+            HashAlgorithm::Anemoi => Box::new(Anemoi),
+            HashAlgorithm::Arion => Box::new(Arion),
+            HashAlgorithm::MiMC => Box::new(MiMC::<F>::new_from_seed(MIMC_SEED)),
+        }
+    }
+}
+
+impl TryFrom<u8> for HashAlgorithm {
+    type Error = Error;
+
+    fn try_from(id: u8) -> Result<Self, Error> {
+        Self::from_id(id)
+    }
+}
+
+impl From<HashAlgorithm> for u8 {
+    fn from(algorithm: HashAlgorithm) -> Self {
+        algorithm.id()
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = Error;
+
+    /// Parses the name of a hash, ignoring the case. `rescue-prime` is accepted for Rescue.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let name = s.to_ascii_lowercase();
+        if name == "rescue-prime" {
+            return Ok(HashAlgorithm::Rescue);
+        }
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.name() == name)
+            .ok_or(Error::UnknownName)
+    }
+}
+
+/// Serialized as the name in human-readable formats and as the ID otherwise.
+impl Serialize for HashAlgorithm {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.name())
+        } else {
+            serializer.serialize_u8(self.id())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HashAlgorithm {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let name = String::deserialize(deserializer)?;
+            name.parse().map_err(de::Error::custom)
+        } else {
+            Self::from_id(u8::deserialize(deserializer)?).map_err(de::Error::custom)
+        }
+    }
+}
+
+/// A hash selected at runtime, over the field `F` with proofs in the configuration `C`.
+pub trait HashFunction<F, C>
+where
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F>,
+{
+    fn algorithm(&self) -> HashAlgorithm;
+
+    /// Number of elements of an input.
+    fn input_len(&self) -> usize;
+
+    /// Output of the hash on `input`, of [`HashFunction::input_len`] elements.
+    fn hash(&self, input: &[F]) -> Result<Vec<F>>;
+
+    /// Circuit of the hash, and the witness of `input`.
+    fn circuit(&self, input: &[F]) -> Result<(CircuitData<F, C, 2>, PartialWitness<F>)>;
+
+    /// Public inputs of the proofs of `input`: the output of the hash, preceded by the input
+    /// for the circuits which make it public.
+    fn public_inputs(&self, input: &[F]) -> Result<Vec<F>> {
+        self.hash(input)
+    }
+
+    /// Proof of the hash of `input`, with the circuit it verifies against.
+    fn prove(&self, input: &[F]) -> Result<(CircuitData<F, C, 2>, ProofWithPublicInputs<F, C, 2>)> {
+        let (data, pw) = self.circuit(input)?;
+        let proof = data.prove(pw)?;
+        Ok((data, proof))
+    }
+}
+
+fn input_array<F: Copy, const N: usize>(input: &[F]) -> Result<[F; N]> {
+    ensure!(
+        input.len() == N,
+        "expected {} input elements, got {}",
+        N,
+        input.len()
+    );
+    Ok(input.try_into().unwrap())
+}
+
+impl<F, C> HashFunction<F, C> for Poseidon
+where
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F>,
+{
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::Poseidon
+    }
+
+    fn input_len(&self) -> usize {
+        poseidon::SPONGE_RATE
+    }
+
+    fn hash(&self, input: &[F]) -> Result<Vec<F>> {
+        Ok(vec![Poseidon::poseidon_hash::<F, 2>(input_array(input)?)])
+    }
+
+    fn circuit(&self, input: &[F]) -> Result<(CircuitData<F, C, 2>, PartialWitness<F>)> {
+        Ok(Poseidon::circuit_generation::<F, C, 1>(input_array(input)?))
+    }
+}
+
+impl<F, C> HashFunction<F, C> for Rescue
+where
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F>,
+{
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::Rescue
+    }
+
+    fn input_len(&self) -> usize {
+        rescue_prime::SPONGE_RATE
+    }
+
+    fn hash(&self, input: &[F]) -> Result<Vec<F>> {
+        let input = input_array(input)?;
+        Ok(Rescue::rescue_hash::<F, { rescue_prime::SPONGE_RATE }>(input).to_vec())
+    }
+
+    fn circuit(&self, input: &[F]) -> Result<(CircuitData<F, C, 2>, PartialWitness<F>)> {
+        let input = input_array(input)?;
+        Ok(Rescue::circuit_generation::<
+            F,
+            C,
+            { rescue_prime::SPONGE_RATE },
+        >(input))
+    }
+}
+
+impl<F, C> HashFunction<F, C> for Griffin
+where
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F>,
+{
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::Griffin
+    }
+
+    fn input_len(&self) -> usize {
+        griffin::SPONGE_RATE
+    }
+
+    fn hash(&self, input: &[F]) -> Result<Vec<F>> {
+        Ok(Griffin::griffin_sponge::<F, OUTPUT_LEN>(input_array(input)?).to_vec())
+    }
+
+    fn circuit(&self, input: &[F]) -> Result<(CircuitData<F, C, 2>, PartialWitness<F>)> {
+        Ok(Griffin::circuit_generation::<F, C, OUTPUT_LEN>(
+            input_array(input)?,
+        ))
+    }
+}
+
+impl<F, C> HashFunction<F, C> for Anemoi
+where
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F>,
+{
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::Anemoi
+    }
+
+    fn input_len(&self) -> usize {
+        anemoi::SPONGE_RATE
+    }
+
+    fn hash(&self, input: &[F]) -> Result<Vec<F>> {
+        Ok(Anemoi::anemoi_hash::<F, OUTPUT_LEN>(input_array(input)?))
+    }
+
+    fn circuit(&self, input: &[F]) -> Result<(CircuitData<F, C, 2>, PartialWitness<F>)> {
+        Ok(Anemoi::circuit_generation::<F, C, OUTPUT_LEN>(input_array(
+            input,
+        )?))
+    }
+}
+
+impl<F, C> HashFunction<F, C> for Arion
+where
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F>,
+{
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::Arion
+    }
+
+    fn input_len(&self) -> usize {
+        arion::SPONGE_RATE
+    }
+
+    fn hash(&self, input: &[F]) -> Result<Vec<F>> {
+        Ok(Arion::arion_hash::<F, OUTPUT_LEN>(input_array(input)?).to_vec())
+    }
+
+    fn circuit(&self, input: &[F]) -> Result<(CircuitData<F, C, 2>, PartialWitness<F>)> {
+        Ok(Arion::circuit_generation::<F, C, OUTPUT_LEN>(input_array(
+            input,
+        )?))
+    }
+}
+
+impl<F, C> HashFunction<F, C> for MiMC<F>
+where
+    F: RichField + Extendable<2>,
+    C: GenericConfig<2, F = F>,
+{
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::MiMC
+    }
+
+    fn input_len(&self) -> usize {
+        2
+    }
+
+    fn hash(&self, input: &[F]) -> Result<Vec<F>> {
+        Ok(vec![self.permute_rounds(input_array(input)?)])
+    }
+
+    fn circuit(&self, input: &[F]) -> Result<(CircuitData<F, C, 2>, PartialWitness<F>)> {
+        Ok(self.circuit_generation::<C, 2>(input_array(input)?))
+    }
+
+    fn public_inputs(&self, input: &[F]) -> Result<Vec<F>> {
+        let mut public_inputs = input.to_vec();
+        public_inputs.extend(HashFunction::<F, C>::hash(self, input)?);
+        Ok(public_inputs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::{
+        field::{goldilocks_field::GoldilocksField, types::Field},
+        plonk::config::PoseidonGoldilocksConfig,
+    };
+
+    use super::*;
+
+    type F = GoldilocksField;
+    type C = PoseidonGoldilocksConfig;
+
+    #[test]
+    fn test_algorithm_ids() {
+        // The IDs are part of the wire formats
+        let ids: Vec<u8> = HashAlgorithm::ALL.iter().map(|a| a.id()).collect();
+        assert_eq!(ids, [1, 2, 3, 4, 5, 6]);
+        for algorithm in HashAlgorithm::ALL {
+            assert_eq!(HashAlgorithm::try_from(algorithm.id()), Ok(algorithm));
+            assert_eq!(algorithm.to_string().parse(), Ok(algorithm));
+            assert_eq!(
+                HashAlgorithm::hasher::<F, C>(algorithm).algorithm(),
+                algorithm
+            );
+        }
+        assert_eq!(HashAlgorithm::from_id(0), Err(Error::UnknownId(0)));
+        assert_eq!("Rescue-Prime".parse(), Ok(HashAlgorithm::Rescue));
+        assert_eq!("sha256".parse::<HashAlgorithm>(), Err(Error::UnknownName));
+
+        let json = serde_json::to_string(&HashAlgorithm::Anemoi).unwrap();
+        assert_eq!(json, "\"anemoi\"");
+        assert_eq!(
+            serde_json::from_str::<HashAlgorithm>(&json).unwrap(),
+            HashAlgorithm::Anemoi
+        );
+    }
+
+    #[test]
+    fn test_dynamic_hash() {
+        let poseidon = HashAlgorithm::Poseidon.hasher::<F, C>();
+        let input: Vec<F> = (0..poseidon.input_len() as u64)
+            .map(F::from_canonical_u64)
+            .collect();
+        assert_eq!(
+            poseidon.hash(&input).unwrap(),
+            [Poseidon::poseidon_hash::<F, 2>(
+                input.clone().try_into().unwrap()
+            )]
+        );
+        assert!(poseidon.hash(&input[1..]).is_err());
+
+        // The registered MiMC does not change between runs
+        let mimc = HashAlgorithm::MiMC.hasher::<F, C>();
+        assert_eq!(
+            mimc.hash(&[F::ONE, F::TWO]).unwrap(),
+            [MiMC::<F>::new_from_seed(MIMC_SEED).permute_rounds([F::ONE, F::TWO])]
+        );
+    }
+}
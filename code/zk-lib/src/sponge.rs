@@ -0,0 +1,692 @@
+// Duplex sponge following the SAFE API (https://eprint.iacr.org/2023/522)
+//
+// A sponge is started with an IO-pattern, the list of calls it is going to go through, which
+// is hashed with a domain separator into a tag written in the capacity. Every call is then
+// checked against the pattern, so two transcripts with different shapes never share a state.
+// `Sponge` runs natively over field elements and `SpongeCircuit` builds the same transcript
+// over targets, with any permutation of the crate. Elements of the quadratic extension are
+// absorbed and squeezed as their two coefficients, and count as two elements in the pattern.
+
+use core::marker::PhantomData;
+
+use plonky2::{
+    field::extension::{Extendable, FieldExtension},
+    hash::hash_types::{RichField, NUM_HASH_OUT_ELTS},
+    iop::{ext_target::ExtensionTarget, target::Target},
+    plonk::circuit_builder::CircuitBuilder,
+};
+use sha3::{Digest as _, Sha3_256};
+
+use crate::{
+    digest::{Digest, DigestTarget},
+    hashes::Permutation,
+};
+
+/// Number of capacity elements holding the 128-bit tag, as four 32-bit limbs. A capacity of 4
+/// elements is needed anyway for 128 bits of security over Goldilocks.
+pub const TAG_LEN: usize = 4;
+
+/// A call to the sponge, as listed in an IO-pattern.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Call {
+    /// Absorb the given number of elements.
+    Absorb(usize),
+    /// Squeeze the given number of elements.
+    Squeeze(usize),
+    /// Permute and erase the rate, so that the previous states cannot be recovered.
+    Ratchet,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error {
+    /// A call that does not follow the IO-pattern.
+    IOPatternViolation,
+    /// An IO-pattern that does not start with an absorb, does not end with a squeeze, or
+    /// has a call whose length is 0 or at least 2^31, or consecutive absorbs or squeezes
+    /// whose lengths add up to at least 2^31.
+    InvalidIOPattern,
+}
+
+/// Largest length of a call, and of consecutive calls of the same kind, which leaves the top
+/// bit of their `u32` encoding to tell absorbs from squeezes.
+const MAX_LEN: usize = u32::MAX as usize >> 1;
+
+fn validate_io_pattern(iopattern: &[Call]) -> Result<(), Error> {
+    match (iopattern.first(), iopattern.last()) {
+        (Some(Call::Absorb(_)), Some(Call::Squeeze(_))) => {}
+        _ => return Err(Error::InvalidIOPattern),
+    }
+    let valid = iopattern.iter().all(|call| match call {
+        Call::Absorb(len) | Call::Squeeze(len) => *len != 0 && *len <= MAX_LEN,
+        Call::Ratchet => true,
+    });
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidIOPattern)
+    }
+}
+
+/// Encodes an IO-pattern and a domain separator as in SAFE: consecutive absorbs, and
+/// consecutive squeezes, are aggregated into one big-endian `u32`, with the top bit set for
+/// absorbs, followed by the big-endian domain separator. A ratchet, which SAFE does not
+/// have, is encoded as an empty squeeze, so patterns without ratchets keep the SAFE encoding.
+pub fn tag_input(iopattern: &[Call], domain_sep: u64) -> Result<Vec<u8>, Error> {
+    const ABSORB_MASK: u32 = 0x8000_0000;
+
+    validate_io_pattern(iopattern)?;
+
+    let mut words: Vec<u32> = Vec::new();
+    let mut previous = None;
+    for call in iopattern {
+        match (call, previous) {
+            (Call::Absorb(len), Some(Call::Absorb(_)))
+            | (Call::Squeeze(len), Some(Call::Squeeze(_))) => {
+                let word = words.last_mut().unwrap();
+                let total = (*word & !ABSORB_MASK)
+                    .checked_add(*len as u32)
+                    .filter(|total| *total as usize <= MAX_LEN)
+                    .ok_or(Error::InvalidIOPattern)?;
+                *word = (*word & ABSORB_MASK) | total;
+            }
+            (Call::Absorb(len), _) => words.push(ABSORB_MASK + *len as u32),
+            (Call::Squeeze(len), _) => words.push(*len as u32),
+            (Call::Ratchet, _) => words.push(0),
+        }
+        previous = Some(*call);
+    }
+
+    let mut input: Vec<u8> = words.iter().flat_map(|word| word.to_be_bytes()).collect();
+    input.extend(domain_sep.to_be_bytes());
+    Ok(input)
+}
+
+/// The tag of an IO-pattern: the first 128 bits of the SHA3-256 hash of its encoding.
+pub fn tag<F: RichField>(iopattern: &[Call], domain_sep: u64) -> Result<[F; TAG_LEN], Error> {
+    Ok(hash_to_tag(&tag_input(iopattern, domain_sep)?))
+}
+
+/// The first 128 bits of the SHA3-256 hash of `input`, as `TAG_LEN` limbs of 32 bits.
+pub(crate) fn hash_to_tag<F: RichField>(input: &[u8]) -> [F; TAG_LEN] {
+    let digest = Sha3_256::digest(input);
+    core::array::from_fn(|i| {
+        F::from_canonical_u32(u32::from_le_bytes(
+            digest[4 * i..4 * i + 4].try_into().unwrap(),
+        ))
+    })
+}
+
+/// The operations a duplex needs on its state elements, natively or in a circuit.
+pub(crate) trait DuplexOps<T, const WIDTH: usize> {
+    fn zero(&mut self) -> T;
+    fn add(&mut self, left: T, right: T) -> T;
+    fn permute(&mut self, state: &mut [T; WIDTH]);
+}
+
+pub(crate) struct NativeOps<H, F>(pub(crate) PhantomData<(H, F)>);
+
+impl<H, F, const WIDTH: usize> DuplexOps<F, WIDTH> for NativeOps<H, F>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    fn zero(&mut self) -> F {
+        F::ZERO
+    }
+
+    fn add(&mut self, left: F, right: F) -> F {
+        left + right
+    }
+
+    fn permute(&mut self, state: &mut [F; WIDTH]) {
+        H::permute(state)
+    }
+}
+
+pub(crate) struct CircuitOps<'a, H, F: RichField + Extendable<2>> {
+    pub(crate) builder: &'a mut CircuitBuilder<F, 2>,
+    pub(crate) _permutation: PhantomData<H>,
+}
+
+impl<'a, H, F, const WIDTH: usize> DuplexOps<Target, WIDTH> for CircuitOps<'a, H, F>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    fn zero(&mut self) -> Target {
+        self.builder.zero()
+    }
+
+    fn add(&mut self, left: Target, right: Target) -> Target {
+        self.builder.add(left, right)
+    }
+
+    fn permute(&mut self, state: &mut [Target; WIDTH]) {
+        H::permute_circuit(state, self.builder)
+    }
+}
+
+/// The state of the duplex: the rate is `state[..RATE]` and the capacity, which starts with
+/// the tag, is `state[RATE..]`.
+#[derive(Debug, Clone)]
+struct Duplex<T, const WIDTH: usize, const RATE: usize> {
+    state: [T; WIDTH],
+    pos_absorb: usize,
+    pos_squeeze: usize,
+    iopattern: Vec<Call>,
+    io_count: usize,
+}
+
+impl<T: Copy, const WIDTH: usize, const RATE: usize> Duplex<T, WIDTH, RATE> {
+    fn start(ops: &mut impl DuplexOps<T, WIDTH>, iopattern: Vec<Call>, tag: [T; TAG_LEN]) -> Self {
+        assert!(RATE > 0 && RATE + TAG_LEN <= WIDTH);
+
+        let mut state = [ops.zero(); WIDTH];
+        state[RATE..RATE + TAG_LEN].copy_from_slice(&tag);
+        Self {
+            state,
+            pos_absorb: 0,
+            pos_squeeze: RATE,
+            iopattern,
+            io_count: 0,
+        }
+    }
+
+    /// Moves to the next call of the IO-pattern, or erases the state if `call` is not the
+    /// expected one.
+    fn follow(&mut self, ops: &mut impl DuplexOps<T, WIDTH>, call: Call) -> Result<(), Error> {
+        if self.iopattern.get(self.io_count) == Some(&call) {
+            self.io_count += 1;
+            Ok(())
+        } else {
+            self.state = [ops.zero(); WIDTH];
+            Err(Error::IOPatternViolation)
+        }
+    }
+
+    fn absorb(&mut self, ops: &mut impl DuplexOps<T, WIDTH>, input: &[T]) -> Result<(), Error> {
+        self.follow(ops, Call::Absorb(input.len()))?;
+
+        for element in input {
+            if self.pos_absorb == RATE {
+                ops.permute(&mut self.state);
+                self.pos_absorb = 0;
+            }
+            self.state[self.pos_absorb] = ops.add(self.state[self.pos_absorb], *element);
+            self.pos_absorb += 1;
+        }
+        // The next squeeze has to permute first
+        self.pos_squeeze = RATE;
+        Ok(())
+    }
+
+    fn squeeze(&mut self, ops: &mut impl DuplexOps<T, WIDTH>, len: usize) -> Result<Vec<T>, Error> {
+        self.follow(ops, Call::Squeeze(len))?;
+
+        let mut output = Vec::with_capacity(len);
+        for _ in 0..len {
+            if self.pos_squeeze == RATE {
+                ops.permute(&mut self.state);
+                self.pos_squeeze = 0;
+                self.pos_absorb = 0;
+            }
+            output.push(self.state[self.pos_squeeze]);
+            self.pos_squeeze += 1;
+        }
+        Ok(output)
+    }
+
+    fn ratchet(&mut self, ops: &mut impl DuplexOps<T, WIDTH>) -> Result<(), Error> {
+        self.follow(ops, Call::Ratchet)?;
+
+        ops.permute(&mut self.state);
+        let zero = ops.zero();
+        self.state[..RATE].fill(zero);
+        self.pos_absorb = 0;
+        self.pos_squeeze = RATE;
+        Ok(())
+    }
+
+    fn finish(&self) -> Result<(), Error> {
+        if self.io_count == self.iopattern.len() {
+            Ok(())
+        } else {
+            Err(Error::IOPatternViolation)
+        }
+    }
+}
+
+/// Native duplex sponge over a permutation `H` of `WIDTH` elements, absorbing and
+/// squeezing `RATE` elements per permutation.
+#[derive(Debug, Clone)]
+pub struct Sponge<H, F, const WIDTH: usize = 12, const RATE: usize = 8> {
+    duplex: Duplex<F, WIDTH, RATE>,
+    _permutation: PhantomData<H>,
+}
+
+impl<H, F, const WIDTH: usize, const RATE: usize> Sponge<H, F, WIDTH, RATE>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    /// Starts a sponge that will follow `iopattern`, with the tag of `iopattern` and
+    /// `domain_sep` in the capacity.
+    pub fn start(iopattern: impl Into<Vec<Call>>, domain_sep: u64) -> Result<Self, Error> {
+        let iopattern = iopattern.into();
+        let tag = tag(&iopattern, domain_sep)?;
+        Ok(Self {
+            duplex: Duplex::start(&mut Self::ops(), iopattern, tag),
+            _permutation: PhantomData,
+        })
+    }
+
+    fn ops() -> NativeOps<H, F> {
+        NativeOps(PhantomData)
+    }
+
+    /// Absorbs `input`, which must be the next call of the IO-pattern.
+    pub fn absorb(&mut self, input: &[F]) -> Result<(), Error> {
+        self.duplex.absorb(&mut Self::ops(), input)
+    }
+
+    /// Squeezes `len` elements, which must be the next call of the IO-pattern.
+    pub fn squeeze(&mut self, len: usize) -> Result<Vec<F>, Error> {
+        self.duplex.squeeze(&mut Self::ops(), len)
+    }
+
+    /// Absorbs the coefficients of `input`, which must be the next call of the IO-pattern.
+    pub fn absorb_extension(&mut self, input: &[F::Extension]) -> Result<(), Error> {
+        let coefficients: Vec<F> = input
+            .iter()
+            .flat_map(|element| element.to_basefield_array())
+            .collect();
+        self.absorb(&coefficients)
+    }
+
+    /// Squeezes `len` extension elements, that is `2 * len` elements which must be the next
+    /// call of the IO-pattern.
+    pub fn squeeze_extension(&mut self, len: usize) -> Result<Vec<F::Extension>, Error> {
+        let coefficients = self.squeeze(2 * len)?;
+        Ok(coefficients
+            .chunks_exact(2)
+            .map(|pair| F::Extension::from_basefield_array([pair[0], pair[1]]))
+            .collect())
+    }
+
+    /// Squeezes a digest, of `NUM_HASH_OUT_ELTS` elements.
+    pub fn squeeze_digest(&mut self) -> Result<Digest<F>, Error> {
+        let elements = self.squeeze(NUM_HASH_OUT_ELTS)?;
+        Ok(Digest(elements.try_into().unwrap()))
+    }
+
+    /// Permutes the state and erases the rate. Must be the next call of the IO-pattern.
+    pub fn ratchet(&mut self) -> Result<(), Error> {
+        self.duplex.ratchet(&mut Self::ops())
+    }
+
+    /// Ends the life of the sponge, checking that the whole IO-pattern was followed.
+    pub fn finish(self) -> Result<(), Error> {
+        self.duplex.finish()
+    }
+}
+
+/// In-circuit twin of [`Sponge`]. The IO-pattern is checked while the circuit is built, and
+/// the tag is a constant of the circuit.
+#[derive(Debug, Clone)]
+pub struct SpongeCircuit<H, F, const WIDTH: usize = 12, const RATE: usize = 8> {
+    duplex: Duplex<Target, WIDTH, RATE>,
+    _permutation: PhantomData<(H, F)>,
+}
+
+impl<H, F, const WIDTH: usize, const RATE: usize> SpongeCircuit<H, F, WIDTH, RATE>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    /// Starts a sponge that will follow `iopattern`, with the tag of `iopattern` and
+    /// `domain_sep` in the capacity.
+    pub fn start(
+        builder: &mut CircuitBuilder<F, 2>,
+        iopattern: impl Into<Vec<Call>>,
+        domain_sep: u64,
+    ) -> Result<Self, Error> {
+        let iopattern = iopattern.into();
+        let tag = tag::<F>(&iopattern, domain_sep)?.map(|limb| builder.constant(limb));
+        Ok(Self {
+            duplex: Duplex::start(&mut Self::ops(builder), iopattern, tag),
+            _permutation: PhantomData,
+        })
+    }
+
+    fn ops(builder: &mut CircuitBuilder<F, 2>) -> CircuitOps<'_, H, F> {
+        CircuitOps {
+            builder,
+            _permutation: PhantomData,
+        }
+    }
+
+    /// Absorbs `input`, which must be the next call of the IO-pattern.
+    pub fn absorb(
+        &mut self,
+        builder: &mut CircuitBuilder<F, 2>,
+        input: &[Target],
+    ) -> Result<(), Error> {
+        self.duplex.absorb(&mut Self::ops(builder), input)
+    }
+
+    /// Squeezes `len` elements, which must be the next call of the IO-pattern.
+    pub fn squeeze(
+        &mut self,
+        builder: &mut CircuitBuilder<F, 2>,
+        len: usize,
+    ) -> Result<Vec<Target>, Error> {
+        self.duplex.squeeze(&mut Self::ops(builder), len)
+    }
+
+    /// Absorbs the coefficients of `input`, which must be the next call of the IO-pattern.
+    pub fn absorb_extension(
+        &mut self,
+        builder: &mut CircuitBuilder<F, 2>,
+        input: &[ExtensionTarget<2>],
+    ) -> Result<(), Error> {
+        let coefficients: Vec<Target> = input.iter().flat_map(|element| element.0).collect();
+        self.absorb(builder, &coefficients)
+    }
+
+    /// Squeezes `len` extension elements, that is `2 * len` elements which must be the next
+    /// call of the IO-pattern.
+    pub fn squeeze_extension(
+        &mut self,
+        builder: &mut CircuitBuilder<F, 2>,
+        len: usize,
+    ) -> Result<Vec<ExtensionTarget<2>>, Error> {
+        let coefficients = self.squeeze(builder, 2 * len)?;
+        Ok(coefficients
+            .chunks_exact(2)
+            .map(|pair| ExtensionTarget([pair[0], pair[1]]))
+            .collect())
+    }
+
+    /// Squeezes a digest, of `NUM_HASH_OUT_ELTS` elements.
+    pub fn squeeze_digest(
+        &mut self,
+        builder: &mut CircuitBuilder<F, 2>,
+    ) -> Result<DigestTarget, Error> {
+        let elements = self.squeeze(builder, NUM_HASH_OUT_ELTS)?;
+        Ok(DigestTarget::from_vec(elements))
+    }
+
+    /// Permutes the state and erases the rate. Must be the next call of the IO-pattern.
+    pub fn ratchet(&mut self, builder: &mut CircuitBuilder<F, 2>) -> Result<(), Error> {
+        self.duplex.ratchet(&mut Self::ops(builder))
+    }
+
+    /// Ends the life of the sponge, checking that the whole IO-pattern was followed.
+    pub fn finish(self) -> Result<(), Error> {
+        self.duplex.finish()
+    }
+}
+
+/// Digest of `inputs`, absorbed in turn by a [`Sponge`] with the `domain_sep` separator. Empty
+/// inputs are skipped, as the IO-pattern can't hold empty calls, so there must be at least one
+/// non-empty input.
+pub(crate) fn hash_to_digest<H, F, const WIDTH: usize, const RATE: usize>(
+    inputs: &[&[F]],
+    domain_sep: u64,
+) -> Digest<F>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    let inputs: Vec<&[F]> = inputs
+        .iter()
+        .copied()
+        .filter(|input| !input.is_empty())
+        .collect();
+    let mut sponge = Sponge::<H, F, WIDTH, RATE>::start(digest_iopattern(&inputs), domain_sep)
+        .expect("the IO-pattern of a digest is valid");
+    for input in inputs {
+        sponge.absorb(input).unwrap();
+    }
+    let output = sponge.squeeze_digest().unwrap();
+    sponge.finish().unwrap();
+    output
+}
+
+/// Circuit of [`hash_to_digest`].
+pub(crate) fn hash_to_digest_circuit<H, F, const WIDTH: usize, const RATE: usize>(
+    builder: &mut CircuitBuilder<F, 2>,
+    inputs: &[&[Target]],
+    domain_sep: u64,
+) -> DigestTarget
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    let inputs: Vec<&[Target]> = inputs
+        .iter()
+        .copied()
+        .filter(|input| !input.is_empty())
+        .collect();
+    let mut sponge =
+        SpongeCircuit::<H, F, WIDTH, RATE>::start(builder, digest_iopattern(&inputs), domain_sep)
+            .expect("the IO-pattern of a digest is valid");
+    for input in inputs {
+        sponge.absorb(builder, input).unwrap();
+    }
+    let output = sponge.squeeze_digest(builder).unwrap();
+    sponge.finish().unwrap();
+    output
+}
+
+fn digest_iopattern<T>(inputs: &[&[T]]) -> Vec<Call> {
+    inputs
+        .iter()
+        .map(|input| Call::Absorb(input.len()))
+        .chain([Call::Squeeze(NUM_HASH_OUT_ELTS)])
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::{
+        field::{goldilocks_field::GoldilocksField, types::Field},
+        iop::witness::{PartialWitness, WitnessWrite},
+        plonk::{circuit_data::CircuitConfig, config::PoseidonGoldilocksConfig},
+    };
+
+    use super::*;
+    use crate::hashes::{
+        anemoi::anemoi::Anemoi, arion::arion::Arion, griffin::griffin::Griffin,
+        poseidon::poseidon::Poseidon, rescue_prime::rescue_prime::Rescue,
+    };
+
+    type F = GoldilocksField;
+
+    const IOPATTERN: [Call; 5] = [
+        Call::Absorb(3),
+        Call::Squeeze(2),
+        Call::Ratchet,
+        Call::Absorb(10),
+        Call::Squeeze(9),
+    ];
+
+    #[test]
+    fn test_tag_input() {
+        // SAFE aggregates consecutive calls of the same kind
+        assert_eq!(
+            tag_input(&[Call::Absorb(1), Call::Absorb(2), Call::Squeeze(1)], 7),
+            tag_input(&[Call::Absorb(3), Call::Squeeze(1)], 7)
+        );
+        assert_eq!(
+            tag_input(&[Call::Absorb(3), Call::Squeeze(1)], 7).unwrap(),
+            [0x80, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 7]
+        );
+        assert_ne!(
+            tag_input(&[Call::Absorb(3), Call::Squeeze(1)], 7),
+            tag_input(&[Call::Absorb(3), Call::Squeeze(1)], 8)
+        );
+        // A ratchet is not merged with the calls around it
+        assert_ne!(
+            tag_input(&[Call::Absorb(1), Call::Squeeze(1), Call::Squeeze(1)], 0),
+            tag_input(
+                &[
+                    Call::Absorb(1),
+                    Call::Squeeze(1),
+                    Call::Ratchet,
+                    Call::Squeeze(1)
+                ],
+                0
+            )
+        );
+
+        assert_eq!(tag_input(&[], 0), Err(Error::InvalidIOPattern));
+        assert_eq!(
+            tag_input(&[Call::Squeeze(1)], 0),
+            Err(Error::InvalidIOPattern)
+        );
+        assert_eq!(
+            tag_input(&[Call::Absorb(0), Call::Squeeze(1)], 0),
+            Err(Error::InvalidIOPattern)
+        );
+        assert_eq!(
+            tag_input(&[Call::Absorb(1), Call::Ratchet], 0),
+            Err(Error::InvalidIOPattern)
+        );
+        // Aggregated calls must not overflow into the bit of the absorbs
+        assert_eq!(
+            tag_input(
+                &[Call::Absorb(1), Call::Squeeze(MAX_LEN), Call::Squeeze(1)],
+                0
+            ),
+            Err(Error::InvalidIOPattern)
+        );
+        assert_eq!(
+            tag_input(
+                &[Call::Absorb(MAX_LEN), Call::Absorb(1), Call::Squeeze(1)],
+                0
+            ),
+            Err(Error::InvalidIOPattern)
+        );
+        assert!(tag_input(
+            &[Call::Absorb(MAX_LEN - 1), Call::Absorb(1), Call::Squeeze(1)],
+            0
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_io_pattern_violation() {
+        let input = [F::ONE; 3];
+        let mut sponge = Sponge::<Griffin, F>::start(IOPATTERN, 0).unwrap();
+        assert_eq!(sponge.absorb(&input[..2]), Err(Error::IOPatternViolation));
+
+        let mut sponge = Sponge::<Griffin, F>::start(IOPATTERN, 0).unwrap();
+        sponge.absorb(&input).unwrap();
+        assert_eq!(sponge.ratchet(), Err(Error::IOPatternViolation));
+
+        let mut sponge = Sponge::<Griffin, F>::start(IOPATTERN, 0).unwrap();
+        sponge.absorb(&input).unwrap();
+        sponge.squeeze(2).unwrap();
+        assert_eq!(sponge.finish(), Err(Error::IOPatternViolation));
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        let squeeze = |iopattern: &[Call], domain_sep| {
+            let mut sponge = Sponge::<Poseidon, F>::start(iopattern, domain_sep).unwrap();
+            sponge.absorb(&[F::ONE]).unwrap();
+            sponge.squeeze(1).unwrap()
+        };
+        let iopattern = [Call::Absorb(1), Call::Squeeze(1)];
+        assert_eq!(squeeze(&iopattern, 0), squeeze(&iopattern, 0));
+        assert_ne!(squeeze(&iopattern, 0), squeeze(&iopattern, 1));
+    }
+
+    fn check_sponge<H: Permutation<12>>() {
+        let input: Vec<F> = (1..14).map(F::from_canonical_u64).collect();
+
+        let mut sponge = Sponge::<H, F>::start(IOPATTERN, 42).unwrap();
+        sponge.absorb(&input[..3]).unwrap();
+        let mut output = sponge.squeeze(2).unwrap();
+        sponge.ratchet().unwrap();
+        sponge.absorb(&input[3..]).unwrap();
+        output.extend(sponge.squeeze(9).unwrap());
+        sponge.finish().unwrap();
+
+        let mut builder = CircuitBuilder::<F, 2>::new(CircuitConfig::standard_recursion_config());
+        let targets = builder.add_virtual_targets(input.len());
+        let mut sponge = SpongeCircuit::<H, F>::start(&mut builder, IOPATTERN, 42).unwrap();
+        sponge.absorb(&mut builder, &targets[..3]).unwrap();
+        let mut output_targets = sponge.squeeze(&mut builder, 2).unwrap();
+        sponge.ratchet(&mut builder).unwrap();
+        sponge.absorb(&mut builder, &targets[3..]).unwrap();
+        output_targets.extend(sponge.squeeze(&mut builder, 9).unwrap());
+        sponge.finish().unwrap();
+        builder.register_public_inputs(&output_targets);
+
+        let mut pw = PartialWitness::new();
+        pw.set_target_arr(&targets, &input);
+        let data = builder.build::<PoseidonGoldilocksConfig>();
+        let proof = data.prove(pw).unwrap();
+        assert_eq!(proof.public_inputs, output);
+        assert!(data.verify(proof).is_ok());
+    }
+
+    #[test]
+    fn test_extension_sponge() {
+        type FE = <F as Extendable<2>>::Extension;
+        let iopattern = [Call::Absorb(6), Call::Squeeze(6), Call::Squeeze(4)];
+        let input: Vec<FE> = (1..4)
+            .map(|i| FE::from_basefield_array([F::from_canonical_u64(i), F::NEG_ONE]))
+            .collect();
+
+        // Extension elements are absorbed and squeezed as their coefficients
+        let mut sponge = Sponge::<Griffin, F>::start(iopattern, 0).unwrap();
+        sponge.absorb_extension(&input).unwrap();
+        let output = sponge.squeeze_extension(3).unwrap();
+        let digest = sponge.squeeze_digest().unwrap();
+
+        let mut sponge = Sponge::<Griffin, F>::start(iopattern, 0).unwrap();
+        let coefficients = |elements: &[FE]| -> Vec<F> {
+            elements
+                .iter()
+                .flat_map(FieldExtension::<2>::to_basefield_array)
+                .collect()
+        };
+        sponge.absorb(&coefficients(&input)).unwrap();
+        let coefficients = coefficients(&output);
+        assert_eq!(sponge.squeeze(6).unwrap(), coefficients);
+        assert_eq!(sponge.squeeze(4).unwrap(), digest.elements());
+
+        let mut builder = CircuitBuilder::<F, 2>::new(CircuitConfig::standard_recursion_config());
+        let targets = builder.add_virtual_extension_targets(input.len());
+        let mut sponge = SpongeCircuit::<Griffin, F>::start(&mut builder, iopattern, 0).unwrap();
+        sponge.absorb_extension(&mut builder, &targets).unwrap();
+        let output_targets = sponge.squeeze_extension(&mut builder, 3).unwrap();
+        let digest_target = sponge.squeeze_digest(&mut builder).unwrap();
+        for target in &output_targets {
+            builder.register_public_inputs(&target.0);
+        }
+        builder.register_public_inputs(&digest_target.elements);
+
+        let mut pw = PartialWitness::new();
+        for (target, value) in targets.iter().zip(&input) {
+            pw.set_extension_target(*target, *value);
+        }
+        let data = builder.build::<PoseidonGoldilocksConfig>();
+        let proof = data.prove(pw).unwrap();
+        assert_eq!(proof.public_inputs[..6], coefficients);
+        assert_eq!(proof.public_inputs[6..], digest.elements()[..]);
+        assert!(data.verify(proof).is_ok());
+    }
+
+    #[test]
+    fn test_sponge_circuit() {
+        check_sponge::<Poseidon>();
+        check_sponge::<Rescue>();
+        check_sponge::<Griffin>();
+        check_sponge::<Anemoi>();
+        check_sponge::<Arion>();
+    }
+}
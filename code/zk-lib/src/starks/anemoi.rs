@@ -0,0 +1,229 @@
+// Starky AIR of the Anemoi permutation
+//
+// A row holds the state entering a round, and for every column of the Flystel, the 7-th root
+// `w` of `x - beta * y^2` with its cube `t`, where `(x, y)` is the column after the constants
+// and the linear layer. The next row is the output of the Flystel, except after the final
+// linear layer, which takes a row of its own before the output row.
+
+use core::marker::PhantomData;
+
+use plonky2::{
+    field::{
+        extension::{Extendable, FieldExtension},
+        packed::PackedField,
+        polynomial::PolynomialValues,
+    },
+    hash::hash_types::RichField,
+    plonk::circuit_builder::CircuitBuilder,
+};
+use starky::{
+    constraint_consumer::{ConstraintConsumer, RecursiveConstraintConsumer},
+    stark::Stark,
+    vars::{StarkEvaluationTargets, StarkEvaluationVars},
+};
+
+use super::*;
+use crate::hashes::{
+    anemoi::{
+        sbox::{ALPHA_INV, BETA, DELTA},
+        AnemoiParameters,
+    },
+    Width,
+};
+
+type Parameters = Width<WIDTH>;
+
+const NUMBER_OF_ROUNDS: usize = Parameters::NUMBER_OF_ROUNDS;
+/// Columns of the Flystel layer.
+const HALF: usize = WIDTH / 2;
+/// The rounds, the final linear layer and the output.
+const ROWS: usize = NUMBER_OF_ROUNDS + 2;
+
+const ROOTS: usize = WIDTH;
+const ROOT_CUBES: usize = ROOTS + HALF;
+const SELECTORS: usize = ROOT_CUBES + HALF;
+const COLUMNS: usize = SELECTORS + ROWS;
+
+fn linear_layer<T: Copy>(ops: &mut impl Algebra<T>, state: &[T; WIDTH]) -> [T; WIDTH] {
+    let mds = Parameters::MDS_MATRIX;
+    let mut product = |x: &dyn Fn(usize) -> T| -> [T; HALF] {
+        core::array::from_fn(|i| {
+            let zero = ops.constant(0);
+            (0..HALF).fold(zero, |acc, j| {
+                ops.mul_const_add(mds[i * HALF + j] as u64, x(j), acc)
+            })
+        })
+    };
+    let x = product(&|j| state[j]);
+    let y = product(&|j| state[HALF + (j + 1) % HALF]);
+
+    // Pseudo-Hadamard transform
+    let mut output = [state[0]; WIDTH];
+    for i in 0..HALF {
+        output[HALF + i] = ops.add(y[i], x[i]);
+        output[i] = ops.add(x[i], output[HALF + i]);
+    }
+    output
+}
+
+/// State after the constants and the linear layer of the round, entering the Flystel.
+fn flystel_inputs<T: Copy>(ops: &mut impl Algebra<T>, row: &[T]) -> [T; WIDTH] {
+    let state: [T; WIDTH] = core::array::from_fn(|i| {
+        let constant = round_constant(ops, &row[SELECTORS..], |r| {
+            (r < NUMBER_OF_ROUNDS).then(|| {
+                if i < HALF {
+                    Parameters::C[r * HALF + i] as u64
+                } else {
+                    Parameters::D[r * HALF + i - HALF] as u64
+                }
+            })
+        });
+        ops.add(row[i], constant)
+    });
+    linear_layer(ops, &state)
+}
+
+/// `x - beta * y^2` of a column of the Flystel.
+fn root_input<T: Copy>(ops: &mut impl Algebra<T>, inputs: &[T; WIDTH], i: usize) -> T {
+    let y2 = ops.square(inputs[HALF + i]);
+    let beta_y2 = ops.mul_const(BETA as u64, y2);
+    ops.sub(inputs[i], beta_y2)
+}
+
+/// State of the next row, which vanishes on the output row.
+fn next_state<T: Copy>(ops: &mut impl Algebra<T>, row: &[T]) -> [T; WIDTH] {
+    let selectors = &row[SELECTORS..];
+    let round = flag(ops, selectors, 0..NUMBER_OF_ROUNDS);
+    let last_linear = selectors[NUMBER_OF_ROUNDS];
+
+    let inputs = flystel_inputs(ops, row);
+    let mut flystel = [inputs[0]; WIDTH];
+    for i in 0..HALF {
+        let x = root_input(ops, &inputs, i);
+        let y = ops.sub(inputs[HALF + i], row[ROOTS + i]);
+        let y2 = ops.square(y);
+        let x = ops.mul_const_add(BETA as u64, y2, x);
+        flystel[i] = ops.add_const(x, DELTA as u64);
+        flystel[HALF + i] = y;
+    }
+
+    let state: [T; WIDTH] = core::array::from_fn(|i| row[i]);
+    let linear = linear_layer(ops, &state);
+    core::array::from_fn(|i| {
+        let flystel = ops.mul(round, flystel[i]);
+        let linear = ops.mul(last_linear, linear[i]);
+        ops.add(flystel, linear)
+    })
+}
+
+fn fill_row<F: RichField>(row: &mut [F; COLUMNS]) -> [F; WIDTH] {
+    let ops = &mut Native(PhantomData);
+
+    let inputs = flystel_inputs(ops, row);
+    for i in 0..HALF {
+        row[ROOTS + i] = root_input(ops, &inputs, i).exp_u64(ALPHA_INV as u64);
+        row[ROOT_CUBES + i] = ops.cube(row[ROOTS + i]);
+    }
+
+    next_state(ops, row)
+}
+
+fn eval<T: Copy>(ops: &mut impl Constraints<T>, local: &[T], next: &[T], public_inputs: &[T]) {
+    eval_public_io(ops, SELECTORS, COLUMNS, local, next, public_inputs);
+    let (local, next) = (&local[..COLUMNS], &next[..COLUMNS]);
+
+    eval_selectors(ops, &local[SELECTORS..], &next[SELECTORS..]);
+
+    // w^7 = x - beta * y^2
+    let inputs = flystel_inputs(ops, local);
+    for i in 0..HALF {
+        let cube = ops.cube(local[ROOTS + i]);
+        let difference = ops.sub(local[ROOT_CUBES + i], cube);
+        ops.constraint(difference);
+        let power = ops.mul(local[ROOT_CUBES + i], local[ROOT_CUBES + i]);
+        let power = ops.mul(power, local[ROOTS + i]);
+        let root_input = root_input(ops, &inputs, i);
+        let difference = ops.sub(power, root_input);
+        ops.constraint(difference);
+    }
+
+    let value = next_state(ops, local);
+    eval_next_state(ops, local[COLUMNS - 1], &next[..WIDTH], &value);
+}
+
+/// STARK proving `N` Anemoi permutations, whose inputs and outputs are its public inputs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnemoiStark<F, const D: usize, const N: usize> {
+    _field: PhantomData<F>,
+}
+
+impl<F, const D: usize, const N: usize> AnemoiStark<F, D, N> {
+    pub fn new() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, const N: usize> Stark<F, D>
+    for AnemoiStark<F, D, N>
+{
+    const COLUMNS: usize = COLUMNS + N + 1;
+    const PUBLIC_INPUTS: usize = 2 * WIDTH * N;
+
+    fn eval_packed_generic<FE, P, const D2: usize>(
+        &self,
+        vars: StarkEvaluationVars<FE, P, { Self::COLUMNS }, { Self::PUBLIC_INPUTS }>,
+        yield_constr: &mut ConstraintConsumer<P>,
+    ) where
+        FE: FieldExtension<D2, BaseField = F>,
+        P: PackedField<Scalar = FE>,
+    {
+        let public_inputs = vars.public_inputs.map(P::from);
+        eval(
+            &mut Packed(yield_constr),
+            vars.local_values,
+            vars.next_values,
+            &public_inputs,
+        );
+    }
+
+    fn eval_ext_circuit(
+        &self,
+        builder: &mut CircuitBuilder<F, D>,
+        vars: StarkEvaluationTargets<D, { Self::COLUMNS }, { Self::PUBLIC_INPUTS }>,
+        yield_constr: &mut RecursiveConstraintConsumer<F, D>,
+    ) {
+        let ops = &mut Recursive {
+            builder,
+            consumer: yield_constr,
+        };
+        eval(ops, vars.local_values, vars.next_values, vars.public_inputs);
+    }
+
+    fn constraint_degree(&self) -> usize {
+        CONSTRAINT_DEGREE
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, const N: usize> PermutationStark<F, D>
+    for AnemoiStark<F, D, N>
+{
+    const ROWS_PER_PERMUTATION: usize = ROWS;
+    const PERMUTATIONS: usize = N;
+
+    fn generate_trace(&self, inputs: &[[F; WIDTH]]) -> Vec<PolynomialValues<F>> {
+        generate_trace::<F, COLUMNS, N>(inputs, ROWS, fill_row::<F>)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::test::*, *};
+    use crate::hashes::anemoi::anemoi::Anemoi;
+
+    #[test]
+    fn test_anemoi_stark() {
+        check_stark::<_, Anemoi>(AnemoiStark::<F, 2, 3>::new());
+    }
+}
@@ -0,0 +1,297 @@
+// Starky AIR of the Arion permutation
+//
+// A row holds the state entering a round and the values of the GTDS layer: the root
+// `y = x_11^(1/161)` with the powers `y^3, y^9, y^27, y^81, y^135, y^21, y^5` proving
+// `y^161 = x_11` in degree 3, and for `i < 11` the cube `c_i` of `x_i`, the S-box `s_i = x_i^7`
+// and the output `o_i = s_i * g_i(sigma) + h_i(sigma)`. The initial linear layer takes the
+// first row of a permutation, then every round maps the row to the affine layer of its GTDS.
+
+use core::marker::PhantomData;
+
+use plonky2::{
+    field::{
+        extension::{Extendable, FieldExtension},
+        packed::PackedField,
+        polynomial::PolynomialValues,
+    },
+    hash::hash_types::RichField,
+    plonk::circuit_builder::CircuitBuilder,
+};
+use starky::{
+    constraint_consumer::{ConstraintConsumer, RecursiveConstraintConsumer},
+    stark::Stark,
+    vars::{StarkEvaluationTargets, StarkEvaluationVars},
+};
+
+use super::*;
+use crate::hashes::{
+    arion::{ArionParameters, E, NUMBER_OF_ROUNDS},
+    Width,
+};
+
+/// The initial linear layer, the rounds and the output.
+const ROWS: usize = NUMBER_OF_ROUNDS + 2;
+/// Rounds of the permutation, from the second row.
+const ROUNDS: core::ops::Range<usize> = 1..NUMBER_OF_ROUNDS + 1;
+
+const ROOT: usize = WIDTH;
+const ROOT_3: usize = ROOT + 1;
+const ROOT_9: usize = ROOT_3 + 1;
+const ROOT_27: usize = ROOT_9 + 1;
+const ROOT_81: usize = ROOT_27 + 1;
+const ROOT_135: usize = ROOT_81 + 1;
+const ROOT_21: usize = ROOT_135 + 1;
+const ROOT_5: usize = ROOT_21 + 1;
+const CUBES: usize = ROOT_5 + 1;
+const SBOXES: usize = CUBES + WIDTH - 1;
+const OUTPUTS: usize = SBOXES + WIDTH - 1;
+const SELECTORS: usize = OUTPUTS + WIDTH - 1;
+const COLUMNS: usize = SELECTORS + ROWS;
+
+type Parameters = Width<WIDTH>;
+
+/// The circulant matrix of the affine layer.
+fn mul_matrix<T: Copy>(ops: &mut impl Algebra<T>, state: &[T; WIDTH]) -> [T; WIDTH] {
+    let sigma = ops.sum(state);
+    let zero = ops.constant(0);
+    let sum = (0..WIDTH).fold(zero, |acc, i| ops.mul_const_add(i as u64, state[i], acc));
+
+    let mut output = [ops.add(sigma, sum); WIDTH];
+    for i in 1..WIDTH {
+        let term = ops.sub(output[i - 1], sigma);
+        output[i] = ops.mul_const_add(WIDTH as u64, state[i - 1], term);
+    }
+    output
+}
+
+/// `(g_i(sigma), h_i(sigma))` in the round of the row, 0 outside the rounds.
+fn polynomials<T: Copy>(ops: &mut impl Algebra<T>, row: &[T], i: usize, sigma: T) -> (T, T) {
+    let selectors = &row[SELECTORS..];
+    let index = |r: usize| (r - ROUNDS.start) * (WIDTH - 1) + i;
+    let g0 = round_constant(ops, selectors, |r| {
+        ROUNDS
+            .contains(&r)
+            .then(|| Parameters::G_VALUES[index(r)][0])
+    });
+    let g1 = round_constant(ops, selectors, |r| {
+        ROUNDS
+            .contains(&r)
+            .then(|| Parameters::G_VALUES[index(r)][1])
+    });
+    let h0 = round_constant(ops, selectors, |r| {
+        ROUNDS.contains(&r).then(|| Parameters::H_VALUES[index(r)])
+    });
+
+    let sigma2 = ops.square(sigma);
+    let g = ops.mul(g0, sigma);
+    let g = ops.add(g, sigma2);
+    let g = ops.add(g, g1);
+    let h = ops.mul(h0, sigma);
+    let h = ops.add(h, sigma2);
+    (g, h)
+}
+
+/// The output `s_i * g_i(sigma) + h_i(sigma)` of the GTDS for every `i < 11`, from the
+/// outputs already in the row.
+fn gtds_outputs<T: Copy>(ops: &mut impl Algebra<T>, row: &[T]) -> [T; WIDTH - 1] {
+    let mut outputs = [row[0]; WIDTH - 1];
+    let mut sigma = ops.add(row[WIDTH - 1], row[ROOT]);
+    for i in (0..WIDTH - 1).rev() {
+        let (g, h) = polynomials(ops, row, i, sigma);
+        let output = ops.mul(row[SBOXES + i], g);
+        outputs[i] = ops.add(output, h);
+
+        sigma = ops.add(sigma, row[OUTPUTS + i]);
+        sigma = ops.add(sigma, row[i]);
+    }
+    outputs
+}
+
+/// State of the next row, which vanishes on the output row.
+fn next_state<T: Copy>(ops: &mut impl Algebra<T>, row: &[T]) -> [T; WIDTH] {
+    let selectors = &row[SELECTORS..];
+    let round = flag(ops, selectors, ROUNDS);
+
+    let state: [T; WIDTH] = core::array::from_fn(|i| row[i]);
+    let initial = mul_matrix(ops, &state);
+
+    let gtds: [T; WIDTH] = core::array::from_fn(|i| {
+        if i < WIDTH - 1 {
+            row[OUTPUTS + i]
+        } else {
+            row[ROOT]
+        }
+    });
+    let affine = mul_matrix(ops, &gtds);
+
+    core::array::from_fn(|i| {
+        let initial = ops.mul(selectors[0], initial[i]);
+        let affine = ops.mul(round, affine[i]);
+        let constant = round_constant(ops, selectors, |r| {
+            ROUNDS
+                .contains(&r)
+                .then(|| Parameters::AFFINE_CONSTANTS[(r - ROUNDS.start) * WIDTH + i])
+        });
+        let state = ops.add(initial, affine);
+        ops.add(state, constant)
+    })
+}
+
+fn fill_row<F: RichField>(row: &mut [F; COLUMNS]) -> [F; WIDTH] {
+    let ops = &mut Native(PhantomData);
+
+    let root = row[WIDTH - 1].exp_u64(E as u64);
+    row[ROOT] = root;
+    row[ROOT_3] = ops.cube(root);
+    row[ROOT_9] = ops.cube(row[ROOT_3]);
+    row[ROOT_27] = ops.cube(row[ROOT_9]);
+    row[ROOT_81] = ops.cube(row[ROOT_27]);
+    row[ROOT_135] = row[ROOT_81] * row[ROOT_27] * row[ROOT_27];
+    row[ROOT_21] = row[ROOT_9] * row[ROOT_9] * row[ROOT_3];
+    row[ROOT_5] = row[ROOT_3] * root * root;
+
+    for i in 0..WIDTH - 1 {
+        row[CUBES + i] = ops.cube(row[i]);
+        row[SBOXES + i] = row[CUBES + i] * row[CUBES + i] * row[i];
+    }
+    // Every output only depends on the outputs of higher index
+    for i in (0..WIDTH - 1).rev() {
+        row[OUTPUTS + i] = gtds_outputs(ops, row)[i];
+    }
+
+    next_state(ops, row)
+}
+
+/// Constrains `power` to the product of `factors`, of at most 3 elements.
+fn eval_product<T: Copy>(ops: &mut impl Constraints<T>, power: T, factors: &[T]) {
+    let one = ops.constant(1);
+    let product = factors
+        .iter()
+        .fold(one, |acc, factor| ops.mul(acc, *factor));
+    let difference = ops.sub(power, product);
+    ops.constraint(difference);
+}
+
+fn eval<T: Copy>(ops: &mut impl Constraints<T>, local: &[T], next: &[T], public_inputs: &[T]) {
+    eval_public_io(ops, SELECTORS, COLUMNS, local, next, public_inputs);
+    let (local, next) = (&local[..COLUMNS], &next[..COLUMNS]);
+
+    eval_selectors(ops, &local[SELECTORS..], &next[SELECTORS..]);
+
+    // y^161 = y^135 * y^21 * y^5 = x_11
+    let root = local[ROOT];
+    eval_product(ops, local[ROOT_3], &[root, root, root]);
+    for (power, cube) in [(ROOT_9, ROOT_3), (ROOT_27, ROOT_9), (ROOT_81, ROOT_27)] {
+        eval_product(ops, local[power], &[local[cube]; 3]);
+    }
+    eval_product(
+        ops,
+        local[ROOT_135],
+        &[local[ROOT_81], local[ROOT_27], local[ROOT_27]],
+    );
+    eval_product(
+        ops,
+        local[ROOT_21],
+        &[local[ROOT_9], local[ROOT_9], local[ROOT_3]],
+    );
+    eval_product(ops, local[ROOT_5], &[local[ROOT_3], root, root]);
+    eval_product(
+        ops,
+        local[WIDTH - 1],
+        &[local[ROOT_135], local[ROOT_21], local[ROOT_5]],
+    );
+
+    for i in 0..WIDTH - 1 {
+        eval_product(ops, local[CUBES + i], &[local[i]; 3]);
+        eval_product(
+            ops,
+            local[SBOXES + i],
+            &[local[CUBES + i], local[CUBES + i], local[i]],
+        );
+    }
+    let outputs = gtds_outputs(ops, local);
+    for (i, output) in outputs.iter().enumerate() {
+        let difference = ops.sub(local[OUTPUTS + i], *output);
+        ops.constraint(difference);
+    }
+
+    let value = next_state(ops, local);
+    eval_next_state(ops, local[COLUMNS - 1], &next[..WIDTH], &value);
+}
+
+/// STARK proving `N` Arion permutations, whose inputs and outputs are its public inputs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArionStark<F, const D: usize, const N: usize> {
+    _field: PhantomData<F>,
+}
+
+impl<F, const D: usize, const N: usize> ArionStark<F, D, N> {
+    pub fn new() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, const N: usize> Stark<F, D>
+    for ArionStark<F, D, N>
+{
+    const COLUMNS: usize = COLUMNS + N + 1;
+    const PUBLIC_INPUTS: usize = 2 * WIDTH * N;
+
+    fn eval_packed_generic<FE, P, const D2: usize>(
+        &self,
+        vars: StarkEvaluationVars<FE, P, { Self::COLUMNS }, { Self::PUBLIC_INPUTS }>,
+        yield_constr: &mut ConstraintConsumer<P>,
+    ) where
+        FE: FieldExtension<D2, BaseField = F>,
+        P: PackedField<Scalar = FE>,
+    {
+        let public_inputs = vars.public_inputs.map(P::from);
+        eval(
+            &mut Packed(yield_constr),
+            vars.local_values,
+            vars.next_values,
+            &public_inputs,
+        );
+    }
+
+    fn eval_ext_circuit(
+        &self,
+        builder: &mut CircuitBuilder<F, D>,
+        vars: StarkEvaluationTargets<D, { Self::COLUMNS }, { Self::PUBLIC_INPUTS }>,
+        yield_constr: &mut RecursiveConstraintConsumer<F, D>,
+    ) {
+        let ops = &mut Recursive {
+            builder,
+            consumer: yield_constr,
+        };
+        eval(ops, vars.local_values, vars.next_values, vars.public_inputs);
+    }
+
+    fn constraint_degree(&self) -> usize {
+        CONSTRAINT_DEGREE
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, const N: usize> PermutationStark<F, D>
+    for ArionStark<F, D, N>
+{
+    const ROWS_PER_PERMUTATION: usize = ROWS;
+    const PERMUTATIONS: usize = N;
+
+    fn generate_trace(&self, inputs: &[[F; WIDTH]]) -> Vec<PolynomialValues<F>> {
+        generate_trace::<F, COLUMNS, N>(inputs, ROWS, fill_row::<F>)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::test::*, *};
+    use crate::hashes::arion::arion::Arion;
+
+    #[test]
+    fn test_arion_stark() {
+        check_stark::<_, Arion>(ArionStark::<F, 2, 3>::new());
+    }
+}
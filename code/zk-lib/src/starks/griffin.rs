@@ -0,0 +1,229 @@
+// Starky AIR of the Griffin permutation
+//
+// A row holds the state entering the non-linear layer of a round and the outputs `y` of the
+// layer: `y0`, the 7-th root of `x0`, with its cube `t0`, `y1 = x1^7` with the cube `c1` of
+// `x1`, and the products `y_i = x_i * (l_i^2 + alpha_i * l_i + beta_i)`. The initial linear
+// layer takes the first row of a permutation, then every round maps the row to the linear layer
+// applied to `y`, plus the constants of the round but for the last one.
+
+use core::marker::PhantomData;
+
+use plonky2::{
+    field::{
+        extension::{Extendable, FieldExtension},
+        packed::PackedField,
+        polynomial::PolynomialValues,
+    },
+    hash::hash_types::RichField,
+    plonk::circuit_builder::CircuitBuilder,
+};
+use starky::{
+    constraint_consumer::{ConstraintConsumer, RecursiveConstraintConsumer},
+    stark::Stark,
+    vars::{StarkEvaluationTargets, StarkEvaluationVars},
+};
+
+use super::*;
+use crate::hashes::{
+    griffin::{GriffinParameters, D_INV, NUMBER_OF_ROUNDS},
+    Width,
+};
+
+/// The initial linear layer, the rounds and the output.
+const ROWS: usize = NUMBER_OF_ROUNDS + 2;
+/// Rounds of the permutation, from the second row.
+const ROUNDS: core::ops::Range<usize> = 1..NUMBER_OF_ROUNDS + 1;
+
+const Y0: usize = WIDTH;
+const T0: usize = Y0 + 1;
+const C1: usize = T0 + 1;
+const Y1: usize = C1 + 1;
+/// `y_i` for `i >= 2`.
+const fn y(i: usize) -> usize {
+    Y1 + i - 1
+}
+const SELECTORS: usize = y(WIDTH);
+const COLUMNS: usize = SELECTORS + ROWS;
+
+type Parameters = Width<WIDTH>;
+
+/// `l_i` of the non-linear layer, from `y0`, `y1` and `x_{i-1}`.
+fn li<T: Copy>(ops: &mut impl Algebra<T>, row: &[T], i: usize) -> T {
+    let l = ops.mul_const_add((i - 1) as u64, row[Y0], row[Y1]);
+    if i > 2 {
+        ops.add(l, row[i - 1])
+    } else {
+        l
+    }
+}
+
+/// `x_i * (l_i^2 + alpha_i * l_i + beta_i)`.
+fn product<T: Copy>(ops: &mut impl Algebra<T>, row: &[T], i: usize) -> T {
+    let l = li(ops, row, i);
+    let l2 = ops.square(l);
+    let factor = ops.mul_const_add(Parameters::ALPHAS[i - 2] as u64, l, l2);
+    let factor = ops.add_const(factor, Parameters::BETAS[i - 2] as u64);
+    ops.mul(row[i], factor)
+}
+
+fn outputs<T: Copy>(row: &[T]) -> [T; WIDTH] {
+    core::array::from_fn(|i| match i {
+        0 => row[Y0],
+        1 => row[Y1],
+        _ => row[y(i)],
+    })
+}
+
+/// Constants added after the linear layer, in every round but the last one.
+fn constants<T: Copy>(ops: &mut impl Algebra<T>, row: &[T]) -> [T; WIDTH] {
+    core::array::from_fn(|i| {
+        round_constant(ops, &row[SELECTORS..], |r| {
+            (ROUNDS.start..NUMBER_OF_ROUNDS)
+                .contains(&r)
+                .then(|| Parameters::ROUND_CONSTANTS[(r - ROUNDS.start) * WIDTH + i] as u64)
+        })
+    })
+}
+
+/// State of the next row, which vanishes on the output row.
+fn next_state<T: Copy>(ops: &mut impl Algebra<T>, row: &[T]) -> [T; WIDTH] {
+    let selectors = &row[SELECTORS..];
+    let round = flag(ops, selectors, ROUNDS);
+
+    let state: [T; WIDTH] = core::array::from_fn(|i| row[i]);
+    let initial = ops.matrix_mul(&Parameters::MDS_MATRIX, &state);
+    let linear = ops.matrix_mul(&Parameters::MDS_MATRIX, &outputs(row));
+    let constants = constants(ops, row);
+
+    core::array::from_fn(|i| {
+        let initial = ops.mul(selectors[0], initial[i]);
+        let linear = ops.mul(round, linear[i]);
+        let state = ops.add(initial, linear);
+        ops.add(state, constants[i])
+    })
+}
+
+fn fill_row<F: RichField>(row: &mut [F; COLUMNS]) -> [F; WIDTH] {
+    let ops = &mut Native(PhantomData);
+
+    row[Y0] = row[0].exp_u64(D_INV);
+    row[T0] = ops.cube(row[Y0]);
+    row[C1] = ops.cube(row[1]);
+    row[Y1] = row[C1] * row[C1] * row[1];
+    for i in 2..WIDTH {
+        row[y(i)] = product(ops, row, i);
+    }
+
+    next_state(ops, row)
+}
+
+fn eval<T: Copy>(ops: &mut impl Constraints<T>, local: &[T], next: &[T], public_inputs: &[T]) {
+    eval_public_io(ops, SELECTORS, COLUMNS, local, next, public_inputs);
+    let (local, next) = (&local[..COLUMNS], &next[..COLUMNS]);
+
+    eval_selectors(ops, &local[SELECTORS..], &next[SELECTORS..]);
+
+    // y0^7 = x0
+    let cube = ops.cube(local[Y0]);
+    let difference = ops.sub(local[T0], cube);
+    ops.constraint(difference);
+    let power = ops.mul(local[T0], local[T0]);
+    let power = ops.mul(power, local[Y0]);
+    let difference = ops.sub(power, local[0]);
+    ops.constraint(difference);
+
+    // y1 = x1^7
+    let cube = ops.cube(local[1]);
+    let difference = ops.sub(local[C1], cube);
+    ops.constraint(difference);
+    let power = ops.mul(local[C1], local[C1]);
+    let power = ops.mul(power, local[1]);
+    let difference = ops.sub(local[Y1], power);
+    ops.constraint(difference);
+
+    for i in 2..WIDTH {
+        let product = product(ops, local, i);
+        let difference = ops.sub(local[y(i)], product);
+        ops.constraint(difference);
+    }
+
+    let value = next_state(ops, local);
+    eval_next_state(ops, local[COLUMNS - 1], &next[..WIDTH], &value);
+}
+
+/// STARK proving `N` Griffin permutations, whose inputs and outputs are its public inputs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GriffinStark<F, const D: usize, const N: usize> {
+    _field: PhantomData<F>,
+}
+
+impl<F, const D: usize, const N: usize> GriffinStark<F, D, N> {
+    pub fn new() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, const N: usize> Stark<F, D>
+    for GriffinStark<F, D, N>
+{
+    const COLUMNS: usize = COLUMNS + N + 1;
+    const PUBLIC_INPUTS: usize = 2 * WIDTH * N;
+
+    fn eval_packed_generic<FE, P, const D2: usize>(
+        &self,
+        vars: StarkEvaluationVars<FE, P, { Self::COLUMNS }, { Self::PUBLIC_INPUTS }>,
+        yield_constr: &mut ConstraintConsumer<P>,
+    ) where
+        FE: FieldExtension<D2, BaseField = F>,
+        P: PackedField<Scalar = FE>,
+    {
+        let public_inputs = vars.public_inputs.map(P::from);
+        eval(
+            &mut Packed(yield_constr),
+            vars.local_values,
+            vars.next_values,
+            &public_inputs,
+        );
+    }
+
+    fn eval_ext_circuit(
+        &self,
+        builder: &mut CircuitBuilder<F, D>,
+        vars: StarkEvaluationTargets<D, { Self::COLUMNS }, { Self::PUBLIC_INPUTS }>,
+        yield_constr: &mut RecursiveConstraintConsumer<F, D>,
+    ) {
+        let ops = &mut Recursive {
+            builder,
+            consumer: yield_constr,
+        };
+        eval(ops, vars.local_values, vars.next_values, vars.public_inputs);
+    }
+
+    fn constraint_degree(&self) -> usize {
+        CONSTRAINT_DEGREE
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, const N: usize> PermutationStark<F, D>
+    for GriffinStark<F, D, N>
+{
+    const ROWS_PER_PERMUTATION: usize = ROWS;
+    const PERMUTATIONS: usize = N;
+
+    fn generate_trace(&self, inputs: &[[F; WIDTH]]) -> Vec<PolynomialValues<F>> {
+        generate_trace::<F, COLUMNS, N>(inputs, ROWS, fill_row::<F>)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::test::*, *};
+    use crate::hashes::griffin::griffin::Griffin;
+
+    #[test]
+    fn test_griffin_stark() {
+        check_stark::<_, Griffin>(GriffinStark::<F, 2, 3>::new());
+    }
+}
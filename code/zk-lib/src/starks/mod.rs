@@ -0,0 +1,702 @@
+// Starky AIRs of the permutations over a state of 12 Goldilocks elements
+//
+// A permutation takes one row of the trace per round, holding the state entering the round and
+// the auxiliary values of its non-linear layer, and a last row holding the output. One-hot
+// selector columns, which cycle through the rows of a permutation, pick the round constants and
+// the shape of each round without preprocessed columns. Every constraint has degree at most 3,
+// so the STARKs are proven with the rate-1/2 `StarkConfig::standard_fast_config`.
+//
+// A STARK proves a fixed number `N` of permutations, whose inputs and outputs are its public
+// inputs. `N + 1` one-hot index columns after those of the AIR give the permutation of each row,
+// the padding permutations up to a power of two rows having index `N`, and bind the first and
+// the output row of the `k`-th permutation to the `k`-th public input and output. The trace is
+// always padded, and its last row must have index `N`, so a trace cannot stop before the output
+// of the last permutation; the verifier also checks that its size is the one fixed by `N`.
+//
+// The rounds are written once over `Algebra`, which fills the trace natively and evaluates the
+// constraints over packed fields and over extension targets in the recursive verifier.
+
+pub mod anemoi;
+pub mod arion;
+pub mod griffin;
+pub mod poseidon;
+pub mod rescue_prime;
+
+use core::marker::PhantomData;
+
+use anyhow::{ensure, Result};
+use plonky2::{
+    field::{
+        extension::Extendable, packed::PackedField, polynomial::PolynomialValues, types::Field,
+    },
+    hash::hash_types::RichField,
+    iop::{ext_target::ExtensionTarget, witness::PartialWitness},
+    plonk::{
+        circuit_builder::CircuitBuilder,
+        circuit_data::{CircuitConfig, CircuitData},
+        config::{AlgebraicHasher, GenericConfig},
+        proof::ProofWithPublicInputs,
+    },
+    util::{log2_strict, timing::TimingTree},
+};
+use starky::{
+    config::StarkConfig,
+    constraint_consumer::{ConstraintConsumer, RecursiveConstraintConsumer},
+    proof::{
+        StarkOpeningSetTarget, StarkProofTarget, StarkProofWithPublicInputs,
+        StarkProofWithPublicInputsTarget,
+    },
+    prover::prove,
+    recursive_verifier::{
+        add_virtual_stark_proof_with_pis, set_stark_proof_with_pis_target,
+        verify_stark_proof_circuit,
+    },
+    stark::Stark,
+    util::trace_rows_to_poly_values,
+    verifier::verify_stark_proof,
+};
+
+/// Width of the permuted state.
+pub const WIDTH: usize = 12;
+
+/// Degree of the constraints of every AIR.
+pub(crate) const CONSTRAINT_DEGREE: usize = 3;
+
+/// STARK proving permutations of a state of [`WIDTH`] elements.
+pub trait PermutationStark<F: RichField + Extendable<D>, const D: usize>:
+    Stark<F, D> + Copy
+{
+    /// Rows of the trace taken by a permutation: one per round and one for the output.
+    const ROWS_PER_PERMUTATION: usize;
+
+    /// Number of permutations proven by the STARK.
+    const PERMUTATIONS: usize;
+
+    /// Trace of the permutations of `inputs`, followed by permutations of the zero state up
+    /// to a power of two rows.
+    ///
+    /// # Panics
+    /// There must be exactly [`PermutationStark::PERMUTATIONS`] inputs.
+    fn generate_trace(&self, inputs: &[[F; WIDTH]]) -> Vec<PolynomialValues<F>>;
+
+    /// Log2 of the number of rows of the trace.
+    fn degree_bits() -> usize {
+        log2_strict(num_rows(Self::PERMUTATIONS, Self::ROWS_PER_PERMUTATION))
+    }
+}
+
+/// Rows of the trace of `permutations` permutations: a power of two leaving at least one
+/// padding row after the last output row.
+fn num_rows(permutations: usize, rows_per_permutation: usize) -> usize {
+    (permutations.max(1) * rows_per_permutation + 1).next_power_of_two()
+}
+
+/// Public inputs of a STARK proving the permutations of `inputs` into `outputs`: the input and
+/// the output of each permutation in turn.
+pub fn permutation_public_inputs<F: Copy>(inputs: &[[F; WIDTH]], outputs: &[[F; WIDTH]]) -> Vec<F> {
+    assert_eq!(inputs.len(), outputs.len());
+    inputs
+        .iter()
+        .zip(outputs)
+        .flat_map(|(input, output)| input.iter().chain(output).copied())
+        .collect()
+}
+
+/// Outputs of the first `n` permutations of a trace.
+pub fn permutation_outputs<F, S, const D: usize>(
+    trace: &[PolynomialValues<F>],
+    n: usize,
+) -> Vec<[F; WIDTH]>
+where
+    F: RichField + Extendable<D>,
+    S: PermutationStark<F, D>,
+{
+    (0..n)
+        .map(|i| {
+            let row = (i + 1) * S::ROWS_PER_PERMUTATION - 1;
+            core::array::from_fn(|j| trace[j].values[row])
+        })
+        .collect()
+}
+
+/// Proves the permutations of `inputs`.
+pub fn prove_permutations<F, C, S, const D: usize>(
+    stark: S,
+    config: &StarkConfig,
+    inputs: &[[F; WIDTH]],
+) -> Result<StarkProofWithPublicInputs<F, C, D>>
+where
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    S: PermutationStark<F, D>,
+    [(); S::COLUMNS]:,
+    [(); S::PUBLIC_INPUTS]:,
+{
+    let trace = stark.generate_trace(inputs);
+    let outputs = permutation_outputs::<F, S, D>(&trace, inputs.len());
+    let public_inputs = permutation_public_inputs(inputs, &outputs)
+        .try_into()
+        .expect("one input and one output per permutation");
+    prove::<F, C, S, D>(
+        stark,
+        config,
+        trace,
+        public_inputs,
+        &mut TimingTree::default(),
+    )
+}
+
+/// Verifies a proof of [`PermutationStark::PERMUTATIONS`] permutations, rejecting the proofs
+/// of a trace of another size.
+pub fn verify_permutations<F, C, S, const D: usize>(
+    stark: S,
+    config: &StarkConfig,
+    proof: StarkProofWithPublicInputs<F, C, D>,
+) -> Result<()>
+where
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    S: PermutationStark<F, D>,
+    [(); S::COLUMNS]:,
+    [(); S::PUBLIC_INPUTS]:,
+{
+    let degree_bits = proof.proof.recover_degree_bits(config);
+    ensure!(
+        degree_bits == S::degree_bits(),
+        "proof of a trace of 2^{} rows instead of 2^{}",
+        degree_bits,
+        S::degree_bits()
+    );
+    verify_stark_proof(stark, proof, config)
+}
+
+/// Plonky2 circuit verifying a proof of a [`PermutationStark`], to wrap the proof of many
+/// permutations in a proof that other circuits can verify. The public inputs of the STARK, the
+/// inputs and outputs of the permutations, are those of the circuit.
+pub struct StarkVerifierCircuit<F, C, const D: usize>
+where
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+{
+    pub data: CircuitData<F, C, D>,
+    proof: StarkProofWithPublicInputsTarget<D>,
+}
+
+impl<F, C, const D: usize> StarkVerifierCircuit<F, C, D>
+where
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    C::Hasher: AlgebraicHasher<F>,
+{
+    pub fn new<S>(stark: S, config: &StarkConfig) -> Self
+    where
+        S: PermutationStark<F, D>,
+        [(); S::COLUMNS]:,
+        [(); S::PUBLIC_INPUTS]:,
+    {
+        let mut builder = CircuitBuilder::new(CircuitConfig::standard_recursion_config());
+        let proof = add_virtual_stark_proof_with_pis(&mut builder, stark, config, S::degree_bits());
+        verify_stark_proof_circuit::<F, C, S, D>(&mut builder, stark, clone_target(&proof), config);
+        builder.register_public_inputs(&proof.public_inputs);
+        Self {
+            data: builder.build::<C>(),
+            proof,
+        }
+    }
+
+    pub fn prove(
+        &self,
+        proof: &StarkProofWithPublicInputs<F, C, D>,
+    ) -> Result<ProofWithPublicInputs<F, C, D>> {
+        let mut pw = PartialWitness::new();
+        set_stark_proof_with_pis_target(&mut pw, &self.proof, proof);
+        self.data.prove(pw)
+    }
+
+    pub fn verify(&self, proof: ProofWithPublicInputs<F, C, D>) -> Result<()> {
+        self.data.verify(proof)
+    }
+}
+
+/// Copy of the targets of a STARK proof, which starky does not make `Clone`, to set them once
+/// the verifier has consumed them.
+fn clone_target<const D: usize>(
+    target: &StarkProofWithPublicInputsTarget<D>,
+) -> StarkProofWithPublicInputsTarget<D> {
+    let proof = &target.proof;
+    let openings = &proof.openings;
+    StarkProofWithPublicInputsTarget {
+        proof: StarkProofTarget {
+            trace_cap: proof.trace_cap.clone(),
+            permutation_zs_cap: proof.permutation_zs_cap.clone(),
+            quotient_polys_cap: proof.quotient_polys_cap.clone(),
+            openings: StarkOpeningSetTarget {
+                local_values: openings.local_values.clone(),
+                next_values: openings.next_values.clone(),
+                permutation_zs: openings.permutation_zs.clone(),
+                permutation_zs_next: openings.permutation_zs_next.clone(),
+                quotient_polys: openings.quotient_polys.clone(),
+            },
+            opening_proof: proof.opening_proof.clone(),
+        },
+        public_inputs: target.public_inputs.clone(),
+    }
+}
+
+/// Field operations over which the rounds are written, natively over `T = F` to fill the
+/// trace, and over packed fields or extension targets to evaluate the constraints.
+pub(crate) trait Algebra<T: Copy> {
+    fn add(&mut self, a: T, b: T) -> T;
+    fn sub(&mut self, a: T, b: T) -> T;
+    fn mul(&mut self, a: T, b: T) -> T;
+    fn constant(&mut self, c: u64) -> T;
+    fn mul_const(&mut self, c: u64, a: T) -> T;
+
+    fn mul_const_add(&mut self, c: u64, a: T, b: T) -> T {
+        let product = self.mul_const(c, a);
+        self.add(product, b)
+    }
+
+    fn add_const(&mut self, a: T, c: u64) -> T {
+        let c = self.constant(c);
+        self.add(a, c)
+    }
+
+    fn square(&mut self, x: T) -> T {
+        self.mul(x, x)
+    }
+
+    fn cube(&mut self, x: T) -> T {
+        let x2 = self.square(x);
+        self.mul(x2, x)
+    }
+
+    fn sum(&mut self, terms: &[T]) -> T {
+        let zero = self.constant(0);
+        terms.iter().fold(zero, |acc, term| self.add(acc, *term))
+    }
+
+    /// Product of a square matrix of constants with `x`.
+    fn matrix_mul<const N: usize>(&mut self, matrix: &[[u64; N]; N], x: &[T; N]) -> [T; N] {
+        core::array::from_fn(|i| {
+            let zero = self.constant(0);
+            (0..N).fold(zero, |acc, j| self.mul_const_add(matrix[i][j], x[j], acc))
+        })
+    }
+}
+
+/// Constraint sink of an AIR.
+pub(crate) trait Constraints<T: Copy>: Algebra<T> {
+    fn constraint(&mut self, c: T);
+    fn constraint_first_row(&mut self, c: T);
+    fn constraint_last_row(&mut self, c: T);
+    fn constraint_transition(&mut self, c: T);
+}
+
+/// Native operations, to fill the trace.
+pub(crate) struct Native<F>(pub(crate) PhantomData<F>);
+
+impl<F: Field> Algebra<F> for Native<F> {
+    fn add(&mut self, a: F, b: F) -> F {
+        a + b
+    }
+
+    fn sub(&mut self, a: F, b: F) -> F {
+        a - b
+    }
+
+    fn mul(&mut self, a: F, b: F) -> F {
+        a * b
+    }
+
+    fn constant(&mut self, c: u64) -> F {
+        F::from_canonical_u64(c)
+    }
+
+    fn mul_const(&mut self, c: u64, a: F) -> F {
+        a * F::from_canonical_u64(c)
+    }
+}
+
+/// Evaluation of the constraints over packed fields.
+pub(crate) struct Packed<'a, P: PackedField>(pub(crate) &'a mut ConstraintConsumer<P>);
+
+impl<P: PackedField> Algebra<P> for Packed<'_, P> {
+    fn add(&mut self, a: P, b: P) -> P {
+        a + b
+    }
+
+    fn sub(&mut self, a: P, b: P) -> P {
+        a - b
+    }
+
+    fn mul(&mut self, a: P, b: P) -> P {
+        a * b
+    }
+
+    fn constant(&mut self, c: u64) -> P {
+        P::from(P::Scalar::from_canonical_u64(c))
+    }
+
+    fn mul_const(&mut self, c: u64, a: P) -> P {
+        a * P::Scalar::from_canonical_u64(c)
+    }
+}
+
+impl<P: PackedField> Constraints<P> for Packed<'_, P> {
+    fn constraint(&mut self, c: P) {
+        self.0.constraint(c);
+    }
+
+    fn constraint_first_row(&mut self, c: P) {
+        self.0.constraint_first_row(c);
+    }
+
+    fn constraint_last_row(&mut self, c: P) {
+        self.0.constraint_last_row(c);
+    }
+
+    fn constraint_transition(&mut self, c: P) {
+        self.0.constraint_transition(c);
+    }
+}
+
+/// Evaluation of the constraints in the recursive verifier.
+pub(crate) struct Recursive<'a, F: RichField + Extendable<D>, const D: usize> {
+    pub(crate) builder: &'a mut CircuitBuilder<F, D>,
+    pub(crate) consumer: &'a mut RecursiveConstraintConsumer<F, D>,
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> Algebra<ExtensionTarget<D>>
+    for Recursive<'_, F, D>
+{
+    fn add(&mut self, a: ExtensionTarget<D>, b: ExtensionTarget<D>) -> ExtensionTarget<D> {
+        self.builder.add_extension(a, b)
+    }
+
+    fn sub(&mut self, a: ExtensionTarget<D>, b: ExtensionTarget<D>) -> ExtensionTarget<D> {
+        self.builder.sub_extension(a, b)
+    }
+
+    fn mul(&mut self, a: ExtensionTarget<D>, b: ExtensionTarget<D>) -> ExtensionTarget<D> {
+        self.builder.mul_extension(a, b)
+    }
+
+    fn constant(&mut self, c: u64) -> ExtensionTarget<D> {
+        self.builder
+            .constant_extension(F::Extension::from_canonical_u64(c))
+    }
+
+    fn mul_const(&mut self, c: u64, a: ExtensionTarget<D>) -> ExtensionTarget<D> {
+        self.builder
+            .mul_const_extension(F::from_canonical_u64(c), a)
+    }
+
+    fn mul_const_add(
+        &mut self,
+        c: u64,
+        a: ExtensionTarget<D>,
+        b: ExtensionTarget<D>,
+    ) -> ExtensionTarget<D> {
+        self.builder
+            .mul_const_add_extension(F::from_canonical_u64(c), a, b)
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> Constraints<ExtensionTarget<D>>
+    for Recursive<'_, F, D>
+{
+    fn constraint(&mut self, c: ExtensionTarget<D>) {
+        self.consumer.constraint(self.builder, c);
+    }
+
+    fn constraint_first_row(&mut self, c: ExtensionTarget<D>) {
+        self.consumer.constraint_first_row(self.builder, c);
+    }
+
+    fn constraint_last_row(&mut self, c: ExtensionTarget<D>) {
+        self.consumer.constraint_last_row(self.builder, c);
+    }
+
+    fn constraint_transition(&mut self, c: ExtensionTarget<D>) {
+        self.consumer.constraint_transition(self.builder, c);
+    }
+}
+
+/// Constrains the round selectors to start with the first round and to move to the next
+/// round from a row to the next, back to the first one after the output row.
+pub(crate) fn eval_selectors<T: Copy>(ops: &mut impl Constraints<T>, local: &[T], next: &[T]) {
+    let one = ops.constant(1);
+    let first = ops.sub(local[0], one);
+    ops.constraint_first_row(first);
+    for selector in &local[1..] {
+        ops.constraint_first_row(*selector);
+    }
+
+    let n = local.len();
+    for i in 0..n {
+        let shift = ops.sub(next[(i + 1) % n], local[i]);
+        ops.constraint_transition(shift);
+    }
+}
+
+/// Sum of the selectors of the rounds in `rounds`, which is 1 on these rounds and 0 elsewhere.
+pub(crate) fn flag<T: Copy>(
+    ops: &mut impl Algebra<T>,
+    selectors: &[T],
+    rounds: impl IntoIterator<Item = usize>,
+) -> T {
+    let terms: Vec<T> = rounds.into_iter().map(|r| selectors[r]).collect();
+    ops.sum(&terms)
+}
+
+/// The constant `constant(r)` of the current round `r`, 0 on the rounds without constant.
+pub(crate) fn round_constant<T: Copy>(
+    ops: &mut impl Algebra<T>,
+    selectors: &[T],
+    constant: impl Fn(usize) -> Option<u64>,
+) -> T {
+    let zero = ops.constant(0);
+    (0..selectors.len()).fold(zero, |acc, r| match constant(r) {
+        Some(c) => ops.mul_const_add(c, selectors[r], acc),
+        None => acc,
+    })
+}
+
+/// Constrains `next` to `value` on every row but the output row of a permutation.
+/// `value` must vanish on the output row.
+pub(crate) fn eval_next_state<T: Copy>(
+    ops: &mut impl Constraints<T>,
+    last_selector: T,
+    next: &[T],
+    value: &[T],
+) {
+    let one = ops.constant(1);
+    let not_last = ops.sub(one, last_selector);
+    for (next, value) in next.iter().zip(value) {
+        let gated = ops.mul(not_last, *next);
+        let difference = ops.sub(gated, *value);
+        ops.constraint_transition(difference);
+    }
+}
+
+/// Constrains the `N + 1` index columns after the `columns` of an AIR to be one-hot on the
+/// index of the permutation of the row, ending on a padding row, and the state of the first
+/// and output rows of the `k`-th permutation to be its public input and output. `first_selector` is the column of the
+/// selector of the first row of a permutation; the one of the output row is the last column.
+pub(crate) fn eval_public_io<T: Copy>(
+    ops: &mut impl Constraints<T>,
+    first_selector: usize,
+    columns: usize,
+    local: &[T],
+    next: &[T],
+    public_inputs: &[T],
+) {
+    let first = local[first_selector];
+    let last = local[columns - 1];
+    let index = &local[columns..];
+    let next_index = &next[columns..];
+    let n = index.len() - 1;
+
+    // The index starts at 0 and moves to the next permutation after an output row, staying at
+    // N for the padding permutations, which the last row must have reached
+    let one = ops.constant(1);
+    let start = ops.sub(index[0], one);
+    ops.constraint_first_row(start);
+    let end = ops.sub(index[n], one);
+    ops.constraint_last_row(end);
+    for k in 0..=n {
+        if k > 0 {
+            ops.constraint_first_row(index[k]);
+        }
+        let mut value = index[k];
+        if k < n {
+            let leaving = ops.mul(last, index[k]);
+            value = ops.sub(value, leaving);
+        }
+        if k > 0 {
+            let entering = ops.mul(last, index[k - 1]);
+            value = ops.add(value, entering);
+        }
+        let difference = ops.sub(next_index[k], value);
+        ops.constraint_transition(difference);
+    }
+
+    for (selector, offset) in [(first, 0), (last, WIDTH)] {
+        for j in 0..WIDTH {
+            let terms: Vec<T> = (0..n)
+                .map(|k| {
+                    let difference = ops.sub(local[j], public_inputs[2 * WIDTH * k + offset + j]);
+                    ops.mul(index[k], difference)
+                })
+                .collect();
+            let sum = ops.sum(&terms);
+            let constraint = ops.mul(selector, sum);
+            ops.constraint(constraint);
+        }
+    }
+}
+
+/// Trace of the `N` permutations of `inputs` with `rows_per_permutation` rows each, and at
+/// least one padding row, followed by the `N + 1` index columns. `fill_row` completes a row from its state and selectors, and
+/// returns the state of the next row.
+pub(crate) fn generate_trace<F: RichField, const COLUMNS: usize, const N: usize>(
+    inputs: &[[F; WIDTH]],
+    rows_per_permutation: usize,
+    fill_row: impl Fn(&mut [F; COLUMNS]) -> [F; WIDTH],
+) -> Vec<PolynomialValues<F>> {
+    assert_eq!(inputs.len(), N, "the STARK proves {N} permutations");
+
+    let num_rows = num_rows(N, rows_per_permutation);
+    let selectors = COLUMNS - rows_per_permutation;
+
+    let mut rows = Vec::with_capacity(num_rows);
+    let mut state = [F::ZERO; WIDTH];
+    for i in 0..num_rows {
+        let round = i % rows_per_permutation;
+        if round == 0 {
+            state = inputs
+                .get(i / rows_per_permutation)
+                .copied()
+                .unwrap_or_default();
+        }
+        let mut row = [F::ZERO; COLUMNS];
+        row[..WIDTH].copy_from_slice(&state);
+        row[selectors + round] = F::ONE;
+        state = fill_row(&mut row);
+        rows.push(row);
+    }
+
+    let mut trace = trace_rows_to_poly_values(rows);
+    trace.extend((0..=N).map(|k| {
+        let index = (0..num_rows).map(|i| F::from_bool((i / rows_per_permutation).min(N) == k));
+        PolynomialValues::new(index.collect())
+    }));
+    trace
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use plonky2::{
+        field::{goldilocks_field::GoldilocksField, types::Sample},
+        plonk::config::PoseidonGoldilocksConfig,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use starky::stark_testing::{test_stark_circuit_constraints, test_stark_low_degree};
+
+    use super::*;
+    use crate::hashes::Permutation;
+
+    pub(crate) type F = GoldilocksField;
+    pub(crate) type C = PoseidonGoldilocksConfig;
+
+    /// Checks the trace against the permutation `H`, the degree of the constraints and their
+    /// recursive evaluation, and proves a few permutations.
+    pub(crate) fn check_stark<S, H>(stark: S)
+    where
+        S: PermutationStark<F, 2>,
+        H: Permutation<WIDTH>,
+        [(); S::COLUMNS]:,
+        [(); S::PUBLIC_INPUTS]:,
+    {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let inputs: Vec<[F; WIDTH]> = (0..3)
+            .map(|_| core::array::from_fn(|_| F::sample(&mut rng)))
+            .collect();
+
+        let trace = stark.generate_trace(&inputs);
+        let expected: Vec<[F; WIDTH]> = inputs
+            .iter()
+            .map(|input| {
+                let mut state = *input;
+                H::permute(&mut state);
+                state
+            })
+            .collect();
+        assert_eq!(
+            permutation_outputs::<F, S, 2>(&trace, inputs.len()),
+            expected
+        );
+
+        test_stark_low_degree(stark).unwrap();
+        test_stark_circuit_constraints::<F, C, S, 2>(stark).unwrap();
+
+        let config = StarkConfig::standard_fast_config();
+        let proof = prove_permutations::<F, C, S, 2>(stark, &config, &inputs).unwrap();
+        assert_eq!(
+            proof.public_inputs,
+            permutation_public_inputs(&inputs, &expected)
+        );
+        verify_permutations(stark, &config, proof).unwrap();
+    }
+
+    #[test]
+    fn test_altered_public_inputs_fail() {
+        use super::poseidon::PoseidonStark;
+
+        let stark = PoseidonStark::<F, 2, 2>::new();
+        let config = StarkConfig::standard_fast_config();
+        let inputs = [[F::ONE; WIDTH], [F::TWO; WIDTH]];
+        let proof = prove_permutations::<F, C, _, 2>(stark, &config, &inputs).unwrap();
+
+        // An input and an output element of each permutation
+        for i in [0, WIDTH + 1, 3 * WIDTH - 1, 4 * WIDTH - 1] {
+            let mut altered = proof.clone();
+            altered.public_inputs[i] += F::ONE;
+            assert!(verify_permutations(stark, &config, altered).is_err());
+        }
+        verify_permutations(stark, &config, proof).unwrap();
+    }
+
+    #[test]
+    fn test_truncated_trace_fails() {
+        use super::poseidon::PoseidonStark;
+
+        type S = PoseidonStark<F, 2, 2>;
+        let stark = S::new();
+        let config = StarkConfig::standard_fast_config();
+        let inputs = [[F::ONE; WIDTH], [F::TWO; WIDTH]];
+        let mut trace = stark.generate_trace(&inputs);
+        let outputs = permutation_outputs::<F, S, 2>(&trace, inputs.len());
+
+        // A trace stopping inside the second permutation, with a forged output for it
+        let rows = (S::ROWS_PER_PERMUTATION + 1).next_power_of_two();
+        assert!(rows < 2 * S::ROWS_PER_PERMUTATION);
+        for column in &mut trace {
+            column.values.truncate(rows);
+        }
+        let mut public_inputs = permutation_public_inputs(&inputs, &outputs);
+        public_inputs[3 * WIDTH..].fill(F::from_canonical_u64(1234));
+
+        let proof = prove::<F, C, S, 2>(
+            stark,
+            &config,
+            trace,
+            public_inputs.try_into().unwrap(),
+            &mut TimingTree::default(),
+        )
+        .unwrap();
+        assert!(verify_stark_proof(stark, proof.clone(), &config).is_err());
+        assert!(verify_permutations(stark, &config, proof).is_err());
+    }
+
+    #[test]
+    fn test_stark_verifier_circuit() {
+        use super::griffin::GriffinStark;
+
+        let stark = GriffinStark::<F, 2, 4>::new();
+        let config = StarkConfig::standard_fast_config();
+        let inputs = [[F::ONE; WIDTH]; 4];
+        let proof = prove_permutations::<F, C, _, 2>(stark, &config, &inputs).unwrap();
+
+        let circuit = StarkVerifierCircuit::<F, C, 2>::new(stark, &config);
+        let wrapped = circuit.prove(&proof).unwrap();
+        assert_eq!(wrapped.public_inputs, proof.public_inputs);
+
+        let mut altered = wrapped.clone();
+        altered.public_inputs[WIDTH] += F::ONE;
+        assert!(circuit.verify(altered).is_err());
+        circuit.verify(wrapped).unwrap();
+    }
+}
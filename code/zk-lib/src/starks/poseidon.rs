@@ -0,0 +1,186 @@
+// Starky AIR of the Poseidon permutation
+//
+// A row holds the state entering a round, and for every element `u_i`, the state after the
+// constant layer, its cube `c_i` and its S-box `s_i = u_i^7`. The S-boxes are computed on every
+// element in every round; the partial rounds only use `s_0` and keep the other `u_i`. The next
+// row is the MDS layer applied to the S-box layer.
+
+use core::marker::PhantomData;
+
+use plonky2::{
+    field::{
+        extension::{Extendable, FieldExtension},
+        packed::PackedField,
+        polynomial::PolynomialValues,
+    },
+    hash::hash_types::RichField,
+    plonk::circuit_builder::CircuitBuilder,
+};
+use starky::{
+    constraint_consumer::{ConstraintConsumer, RecursiveConstraintConsumer},
+    stark::Stark,
+    vars::{StarkEvaluationTargets, StarkEvaluationVars},
+};
+
+use super::*;
+use crate::hashes::{
+    poseidon::{PoseidonParameters, HALF_N_FULL_ROUNDS, N_PARTIAL_ROUNDS, N_ROUNDS},
+    Width,
+};
+
+const ROWS: usize = N_ROUNDS + 1;
+
+const CUBES: usize = WIDTH;
+const SBOXES: usize = CUBES + WIDTH;
+const SELECTORS: usize = SBOXES + WIDTH;
+const COLUMNS: usize = SELECTORS + ROWS;
+
+const PARTIAL_ROUNDS: core::ops::Range<usize> =
+    HALF_N_FULL_ROUNDS..HALF_N_FULL_ROUNDS + N_PARTIAL_ROUNDS;
+
+type Parameters = Width<WIDTH>;
+
+/// State after the constant layer of the round.
+fn round_inputs<T: Copy>(ops: &mut impl Algebra<T>, row: &[T]) -> [T; WIDTH] {
+    core::array::from_fn(|i| {
+        let constant = round_constant(ops, &row[SELECTORS..], |r| {
+            (r < N_ROUNDS).then(|| Parameters::ROUND_CONSTANTS[i + WIDTH * r])
+        });
+        ops.add(row[i], constant)
+    })
+}
+
+/// State after the S-box layer, which vanishes on the output row.
+fn sbox_layer<T: Copy>(ops: &mut impl Algebra<T>, row: &[T], inputs: &[T; WIDTH]) -> [T; WIDTH] {
+    let selectors = &row[SELECTORS..];
+    let partial = flag(ops, selectors, PARTIAL_ROUNDS);
+    let full = flag(
+        ops,
+        selectors,
+        (0..HALF_N_FULL_ROUNDS).chain(PARTIAL_ROUNDS.end..N_ROUNDS),
+    );
+    let any = ops.add(full, partial);
+
+    core::array::from_fn(|i| {
+        if i == 0 {
+            ops.mul(any, row[SBOXES])
+        } else {
+            let sbox = ops.mul(full, row[SBOXES + i]);
+            let kept = ops.mul(partial, inputs[i]);
+            ops.add(sbox, kept)
+        }
+    })
+}
+
+fn fill_row<F: RichField>(row: &mut [F; COLUMNS]) -> [F; WIDTH] {
+    let ops = &mut Native(PhantomData);
+
+    let inputs = round_inputs(ops, row);
+    for i in 0..WIDTH {
+        row[CUBES + i] = ops.cube(inputs[i]);
+        row[SBOXES + i] = row[CUBES + i] * row[CUBES + i] * inputs[i];
+    }
+
+    let sboxes = sbox_layer(ops, row, &inputs);
+    ops.matrix_mul(&Parameters::MDS_MATRIX, &sboxes)
+}
+
+fn eval<T: Copy>(ops: &mut impl Constraints<T>, local: &[T], next: &[T], public_inputs: &[T]) {
+    eval_public_io(ops, SELECTORS, COLUMNS, local, next, public_inputs);
+    let (local, next) = (&local[..COLUMNS], &next[..COLUMNS]);
+
+    eval_selectors(ops, &local[SELECTORS..], &next[SELECTORS..]);
+
+    let inputs = round_inputs(ops, local);
+    for i in 0..WIDTH {
+        let cube = ops.cube(inputs[i]);
+        let difference = ops.sub(local[CUBES + i], cube);
+        ops.constraint(difference);
+
+        let sbox = ops.mul(local[CUBES + i], local[CUBES + i]);
+        let sbox = ops.mul(sbox, inputs[i]);
+        let difference = ops.sub(local[SBOXES + i], sbox);
+        ops.constraint(difference);
+    }
+
+    let sboxes = sbox_layer(ops, local, &inputs);
+    let value = ops.matrix_mul(&Parameters::MDS_MATRIX, &sboxes);
+    eval_next_state(ops, local[COLUMNS - 1], &next[..WIDTH], &value);
+}
+
+/// STARK proving `N` Poseidon permutations, whose inputs and outputs are its public inputs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PoseidonStark<F, const D: usize, const N: usize> {
+    _field: PhantomData<F>,
+}
+
+impl<F, const D: usize, const N: usize> PoseidonStark<F, D, N> {
+    pub fn new() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, const N: usize> Stark<F, D>
+    for PoseidonStark<F, D, N>
+{
+    const COLUMNS: usize = COLUMNS + N + 1;
+    const PUBLIC_INPUTS: usize = 2 * WIDTH * N;
+
+    fn eval_packed_generic<FE, P, const D2: usize>(
+        &self,
+        vars: StarkEvaluationVars<FE, P, { Self::COLUMNS }, { Self::PUBLIC_INPUTS }>,
+        yield_constr: &mut ConstraintConsumer<P>,
+    ) where
+        FE: FieldExtension<D2, BaseField = F>,
+        P: PackedField<Scalar = FE>,
+    {
+        let public_inputs = vars.public_inputs.map(P::from);
+        eval(
+            &mut Packed(yield_constr),
+            vars.local_values,
+            vars.next_values,
+            &public_inputs,
+        );
+    }
+
+    fn eval_ext_circuit(
+        &self,
+        builder: &mut CircuitBuilder<F, D>,
+        vars: StarkEvaluationTargets<D, { Self::COLUMNS }, { Self::PUBLIC_INPUTS }>,
+        yield_constr: &mut RecursiveConstraintConsumer<F, D>,
+    ) {
+        let ops = &mut Recursive {
+            builder,
+            consumer: yield_constr,
+        };
+        eval(ops, vars.local_values, vars.next_values, vars.public_inputs);
+    }
+
+    fn constraint_degree(&self) -> usize {
+        CONSTRAINT_DEGREE
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, const N: usize> PermutationStark<F, D>
+    for PoseidonStark<F, D, N>
+{
+    const ROWS_PER_PERMUTATION: usize = ROWS;
+    const PERMUTATIONS: usize = N;
+
+    fn generate_trace(&self, inputs: &[[F; WIDTH]]) -> Vec<PolynomialValues<F>> {
+        generate_trace::<F, COLUMNS, N>(inputs, ROWS, fill_row::<F>)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::test::*, *};
+    use crate::hashes::poseidon::poseidon::Poseidon;
+
+    #[test]
+    fn test_poseidon_stark() {
+        check_stark::<_, Poseidon>(PoseidonStark::<F, 2, 3>::new());
+    }
+}
@@ -0,0 +1,187 @@
+// Starky AIR of the Rescue-Prime permutation
+//
+// A row holds the state entering a half-round, whose MDS and constant layers give `u`, and
+// both S-boxes of `u`: `s = u^7` with the cube `c` of `u`, and the 7-th root `y` of `u` with its
+// cube `t`. The next row is `s` after the first half of a round and `y` after the second one.
+
+use core::marker::PhantomData;
+
+use plonky2::{
+    field::{
+        extension::{Extendable, FieldExtension},
+        packed::PackedField,
+        polynomial::PolynomialValues,
+    },
+    hash::hash_types::RichField,
+    plonk::circuit_builder::CircuitBuilder,
+};
+use starky::{
+    constraint_consumer::{ConstraintConsumer, RecursiveConstraintConsumer},
+    stark::Stark,
+    vars::{StarkEvaluationTargets, StarkEvaluationVars},
+};
+
+use super::*;
+use crate::hashes::{
+    rescue_prime::{RescueParameters, ALPHA_INV, NUMBER_OF_ROUNDS},
+    Width,
+};
+
+/// Half-rounds of the permutation.
+const HALF_ROUNDS: usize = 2 * NUMBER_OF_ROUNDS;
+const ROWS: usize = HALF_ROUNDS + 1;
+
+const CUBES: usize = WIDTH;
+const SBOXES: usize = CUBES + WIDTH;
+const ROOTS: usize = SBOXES + WIDTH;
+const ROOT_CUBES: usize = ROOTS + WIDTH;
+const SELECTORS: usize = ROOT_CUBES + WIDTH;
+const COLUMNS: usize = SELECTORS + ROWS;
+
+type Parameters = Width<WIDTH>;
+
+/// State after the MDS and constant layers of the half-round.
+fn sbox_inputs<T: Copy>(ops: &mut impl Algebra<T>, row: &[T]) -> [T; WIDTH] {
+    let state: [T; WIDTH] = core::array::from_fn(|i| row[i]);
+    let state = ops.matrix_mul(&Parameters::MDS_MATRIX, &state);
+    core::array::from_fn(|i| {
+        let constant = round_constant(ops, &row[SELECTORS..], |r| {
+            (r < HALF_ROUNDS).then(|| Parameters::ROUND_CONSTANTS[r * WIDTH + i])
+        });
+        ops.add(state[i], constant)
+    })
+}
+
+/// State after the S-box layer, which vanishes on the output row.
+fn sbox_layer<T: Copy>(ops: &mut impl Algebra<T>, row: &[T]) -> [T; WIDTH] {
+    let selectors = &row[SELECTORS..];
+    let forward = flag(ops, selectors, (0..HALF_ROUNDS).step_by(2));
+    let backward = flag(ops, selectors, (1..HALF_ROUNDS).step_by(2));
+    core::array::from_fn(|i| {
+        let sbox = ops.mul(forward, row[SBOXES + i]);
+        let root = ops.mul(backward, row[ROOTS + i]);
+        ops.add(sbox, root)
+    })
+}
+
+fn fill_row<F: RichField>(row: &mut [F; COLUMNS]) -> [F; WIDTH] {
+    let ops = &mut Native(PhantomData);
+
+    let inputs = sbox_inputs(ops, row);
+    for i in 0..WIDTH {
+        row[CUBES + i] = ops.cube(inputs[i]);
+        row[SBOXES + i] = row[CUBES + i] * row[CUBES + i] * inputs[i];
+        row[ROOTS + i] = inputs[i].exp_u64(ALPHA_INV as u64);
+        row[ROOT_CUBES + i] = ops.cube(row[ROOTS + i]);
+    }
+
+    sbox_layer(ops, row)
+}
+
+fn eval<T: Copy>(ops: &mut impl Constraints<T>, local: &[T], next: &[T], public_inputs: &[T]) {
+    eval_public_io(ops, SELECTORS, COLUMNS, local, next, public_inputs);
+    let (local, next) = (&local[..COLUMNS], &next[..COLUMNS]);
+
+    eval_selectors(ops, &local[SELECTORS..], &next[SELECTORS..]);
+
+    let inputs = sbox_inputs(ops, local);
+    for i in 0..WIDTH {
+        // s = u^7
+        let cube = ops.cube(inputs[i]);
+        let difference = ops.sub(local[CUBES + i], cube);
+        ops.constraint(difference);
+        let sbox = ops.mul(local[CUBES + i], local[CUBES + i]);
+        let sbox = ops.mul(sbox, inputs[i]);
+        let difference = ops.sub(local[SBOXES + i], sbox);
+        ops.constraint(difference);
+
+        // y^7 = u
+        let cube = ops.cube(local[ROOTS + i]);
+        let difference = ops.sub(local[ROOT_CUBES + i], cube);
+        ops.constraint(difference);
+        let power = ops.mul(local[ROOT_CUBES + i], local[ROOT_CUBES + i]);
+        let power = ops.mul(power, local[ROOTS + i]);
+        let difference = ops.sub(power, inputs[i]);
+        ops.constraint(difference);
+    }
+
+    let value = sbox_layer(ops, local);
+    eval_next_state(ops, local[COLUMNS - 1], &next[..WIDTH], &value);
+}
+
+/// STARK proving `N` Rescue-Prime permutations, whose inputs and outputs are its public inputs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RescueStark<F, const D: usize, const N: usize> {
+    _field: PhantomData<F>,
+}
+
+impl<F, const D: usize, const N: usize> RescueStark<F, D, N> {
+    pub fn new() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, const N: usize> Stark<F, D>
+    for RescueStark<F, D, N>
+{
+    const COLUMNS: usize = COLUMNS + N + 1;
+    const PUBLIC_INPUTS: usize = 2 * WIDTH * N;
+
+    fn eval_packed_generic<FE, P, const D2: usize>(
+        &self,
+        vars: StarkEvaluationVars<FE, P, { Self::COLUMNS }, { Self::PUBLIC_INPUTS }>,
+        yield_constr: &mut ConstraintConsumer<P>,
+    ) where
+        FE: FieldExtension<D2, BaseField = F>,
+        P: PackedField<Scalar = FE>,
+    {
+        let public_inputs = vars.public_inputs.map(P::from);
+        eval(
+            &mut Packed(yield_constr),
+            vars.local_values,
+            vars.next_values,
+            &public_inputs,
+        );
+    }
+
+    fn eval_ext_circuit(
+        &self,
+        builder: &mut CircuitBuilder<F, D>,
+        vars: StarkEvaluationTargets<D, { Self::COLUMNS }, { Self::PUBLIC_INPUTS }>,
+        yield_constr: &mut RecursiveConstraintConsumer<F, D>,
+    ) {
+        let ops = &mut Recursive {
+            builder,
+            consumer: yield_constr,
+        };
+        eval(ops, vars.local_values, vars.next_values, vars.public_inputs);
+    }
+
+    fn constraint_degree(&self) -> usize {
+        CONSTRAINT_DEGREE
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, const N: usize> PermutationStark<F, D>
+    for RescueStark<F, D, N>
+{
+    const ROWS_PER_PERMUTATION: usize = ROWS;
+    const PERMUTATIONS: usize = N;
+
+    fn generate_trace(&self, inputs: &[[F; WIDTH]]) -> Vec<PolynomialValues<F>> {
+        generate_trace::<F, COLUMNS, N>(inputs, ROWS, fill_row::<F>)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::test::*, *};
+    use crate::hashes::rescue_prime::rescue_prime::Rescue;
+
+    #[test]
+    fn test_rescue_stark() {
+        check_stark::<_, Rescue>(RescueStark::<F, 2, 3>::new());
+    }
+}
@@ -0,0 +1,238 @@
+// Fiat-Shamir transcript over the permutations of the crate
+//
+// The transcript is a duplex sponge in overwrite mode, as plonky2's `Challenger`: appended
+// elements are buffered and written over the rate when a challenge is requested, and the
+// challenges are read from the rate of the permuted state. The capacity starts with a tag
+// derived from a label, so that transcripts of different protocols never collide.
+// `TranscriptCircuit` goes through the same steps over targets and gives the same challenges.
+
+use core::marker::PhantomData;
+
+use plonky2::{
+    field::extension::Extendable,
+    hash::hash_types::{HashOut, HashOutTarget, RichField},
+    iop::target::Target,
+    plonk::circuit_builder::CircuitBuilder,
+};
+
+use crate::{
+    hashes::Permutation,
+    sponge::{hash_to_tag, CircuitOps, DuplexOps, NativeOps, TAG_LEN},
+};
+
+#[derive(Debug, Clone)]
+struct Duplexer<T, const WIDTH: usize, const RATE: usize> {
+    state: [T; WIDTH],
+    input_buffer: Vec<T>,
+    output_buffer: Vec<T>,
+}
+
+impl<T: Copy, const WIDTH: usize, const RATE: usize> Duplexer<T, WIDTH, RATE> {
+    fn new(ops: &mut impl DuplexOps<T, WIDTH>, tag: [T; TAG_LEN]) -> Self {
+        assert!(RATE > 0 && RATE + TAG_LEN <= WIDTH);
+
+        let mut state = [ops.zero(); WIDTH];
+        state[RATE..RATE + TAG_LEN].copy_from_slice(&tag);
+        Self {
+            state,
+            input_buffer: Vec::with_capacity(RATE),
+            output_buffer: Vec::with_capacity(RATE),
+        }
+    }
+
+    fn append(&mut self, ops: &mut impl DuplexOps<T, WIDTH>, elements: &[T]) {
+        // Outputs squeezed before this input must not be handed out as challenges
+        self.output_buffer.clear();
+
+        for element in elements {
+            self.input_buffer.push(*element);
+            if self.input_buffer.len() == RATE {
+                self.duplexing(ops);
+            }
+        }
+    }
+
+    fn challenge(&mut self, ops: &mut impl DuplexOps<T, WIDTH>) -> T {
+        if !self.input_buffer.is_empty() || self.output_buffer.is_empty() {
+            self.duplexing(ops);
+        }
+        self.output_buffer.pop().unwrap()
+    }
+
+    fn duplexing(&mut self, ops: &mut impl DuplexOps<T, WIDTH>) {
+        for (i, element) in self.input_buffer.drain(..).enumerate() {
+            self.state[i] = element;
+        }
+        ops.permute(&mut self.state);
+
+        self.output_buffer.clear();
+        self.output_buffer.extend_from_slice(&self.state[..RATE]);
+    }
+}
+
+/// Native Fiat-Shamir transcript over a permutation `H` of `WIDTH` elements, with a rate of
+/// `RATE` elements.
+#[derive(Debug, Clone)]
+pub struct Transcript<H, F, const WIDTH: usize = 12, const RATE: usize = 8> {
+    duplexer: Duplexer<F, WIDTH, RATE>,
+    _permutation: PhantomData<H>,
+}
+
+impl<H, F, const WIDTH: usize, const RATE: usize> Transcript<H, F, WIDTH, RATE>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    /// Starts a transcript for the protocol named `label`.
+    pub fn new(label: &[u8]) -> Self {
+        Self {
+            duplexer: Duplexer::new(&mut Self::ops(), hash_to_tag(label)),
+            _permutation: PhantomData,
+        }
+    }
+
+    fn ops() -> NativeOps<H, F> {
+        NativeOps(PhantomData)
+    }
+
+    pub fn append_field(&mut self, element: F) {
+        self.duplexer.append(&mut Self::ops(), &[element]);
+    }
+
+    pub fn append_fields(&mut self, elements: &[F]) {
+        self.duplexer.append(&mut Self::ops(), elements);
+    }
+
+    pub fn append_hash(&mut self, hash: HashOut<F>) {
+        self.duplexer.append(&mut Self::ops(), &hash.elements);
+    }
+
+    /// Derives a challenge from everything appended so far.
+    pub fn challenge(&mut self) -> F {
+        self.duplexer.challenge(&mut Self::ops())
+    }
+
+    pub fn challenges(&mut self, n: usize) -> Vec<F> {
+        (0..n).map(|_| self.challenge()).collect()
+    }
+}
+
+/// In-circuit twin of [`Transcript`], giving the same challenges as targets.
+#[derive(Debug, Clone)]
+pub struct TranscriptCircuit<H, F, const WIDTH: usize = 12, const RATE: usize = 8> {
+    duplexer: Duplexer<Target, WIDTH, RATE>,
+    _permutation: PhantomData<(H, F)>,
+}
+
+impl<H, F, const WIDTH: usize, const RATE: usize> TranscriptCircuit<H, F, WIDTH, RATE>
+where
+    H: Permutation<WIDTH>,
+    F: RichField + Extendable<2>,
+{
+    /// Starts a transcript for the protocol named `label`.
+    pub fn new(builder: &mut CircuitBuilder<F, 2>, label: &[u8]) -> Self {
+        let tag = hash_to_tag::<F>(label).map(|limb| builder.constant(limb));
+        Self {
+            duplexer: Duplexer::new(&mut Self::ops(builder), tag),
+            _permutation: PhantomData,
+        }
+    }
+
+    fn ops(builder: &mut CircuitBuilder<F, 2>) -> CircuitOps<'_, H, F> {
+        CircuitOps {
+            builder,
+            _permutation: PhantomData,
+        }
+    }
+
+    pub fn append_field(&mut self, builder: &mut CircuitBuilder<F, 2>, element: Target) {
+        self.duplexer.append(&mut Self::ops(builder), &[element]);
+    }
+
+    pub fn append_fields(&mut self, builder: &mut CircuitBuilder<F, 2>, elements: &[Target]) {
+        self.duplexer.append(&mut Self::ops(builder), elements);
+    }
+
+    pub fn append_hash(&mut self, builder: &mut CircuitBuilder<F, 2>, hash: HashOutTarget) {
+        self.duplexer
+            .append(&mut Self::ops(builder), &hash.elements);
+    }
+
+    /// Derives a challenge from everything appended so far.
+    pub fn challenge(&mut self, builder: &mut CircuitBuilder<F, 2>) -> Target {
+        self.duplexer.challenge(&mut Self::ops(builder))
+    }
+
+    pub fn challenges(&mut self, builder: &mut CircuitBuilder<F, 2>, n: usize) -> Vec<Target> {
+        (0..n).map(|_| self.challenge(builder)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use plonky2::{
+        field::{goldilocks_field::GoldilocksField, types::Field},
+        iop::witness::{PartialWitness, WitnessWrite},
+        plonk::{circuit_data::CircuitConfig, config::PoseidonGoldilocksConfig},
+    };
+
+    use super::*;
+    use crate::hashes::{anemoi::anemoi::Anemoi, griffin::griffin::Griffin};
+
+    type F = GoldilocksField;
+
+    #[test]
+    fn test_transcript_separation() {
+        let challenge = |label: &[u8], elements: &[F]| {
+            let mut transcript = Transcript::<Griffin, F>::new(label);
+            transcript.append_fields(elements);
+            transcript.challenge()
+        };
+        assert_eq!(challenge(b"a", &[F::ONE]), challenge(b"a", &[F::ONE]));
+        assert_ne!(challenge(b"a", &[F::ONE]), challenge(b"b", &[F::ONE]));
+        assert_ne!(challenge(b"a", &[F::ONE]), challenge(b"a", &[F::TWO]));
+
+        // Challenges drawn one after the other are distinct
+        let mut transcript = Transcript::<Griffin, F>::new(b"a");
+        transcript.append_field(F::ONE);
+        let challenges = transcript.challenges(10);
+        assert!((1..10).all(|i| !challenges[..i].contains(&challenges[i])));
+    }
+
+    fn check_transcript<H: Permutation<12>>() {
+        let elements: Vec<F> = (1..14).map(F::from_canonical_u64).collect();
+        let hash = HashOut::from([F::ONE, F::TWO, F::NEG_ONE, F::ZERO]);
+
+        let mut transcript = Transcript::<H, F>::new(b"protocol");
+        transcript.append_field(elements[0]);
+        let mut challenges = vec![transcript.challenge()];
+        transcript.append_hash(hash);
+        transcript.append_fields(&elements[1..]);
+        challenges.extend(transcript.challenges(10));
+
+        let mut builder = CircuitBuilder::<F, 2>::new(CircuitConfig::standard_recursion_config());
+        let element_targets = builder.add_virtual_targets(elements.len());
+        let hash_target = builder.add_virtual_hash();
+        let mut transcript = TranscriptCircuit::<H, F>::new(&mut builder, b"protocol");
+        transcript.append_field(&mut builder, element_targets[0]);
+        let mut challenge_targets = vec![transcript.challenge(&mut builder)];
+        transcript.append_hash(&mut builder, hash_target);
+        transcript.append_fields(&mut builder, &element_targets[1..]);
+        challenge_targets.extend(transcript.challenges(&mut builder, 10));
+        builder.register_public_inputs(&challenge_targets);
+
+        let mut pw = PartialWitness::new();
+        pw.set_target_arr(&element_targets, &elements);
+        pw.set_hash_target(hash_target, hash);
+        let data = builder.build::<PoseidonGoldilocksConfig>();
+        let proof = data.prove(pw).unwrap();
+        assert_eq!(proof.public_inputs, challenges);
+        assert!(data.verify(proof).is_ok());
+    }
+
+    #[test]
+    fn test_transcript_circuit() {
+        check_transcript::<Griffin>();
+        check_transcript::<Anemoi>();
+    }
+}